@@ -0,0 +1,91 @@
+use tfhe::prelude::*;
+use tfhe::{generate_keys, ConfigBuilder, FheUint64, FheUint8};
+
+use tfhe_trivium::{AesCtrStreamByte, TransCiphering};
+
+use criterion::Criterion;
+
+pub fn aes_byte_gen(c: &mut Criterion) {
+    let config = ConfigBuilder::default()
+        .enable_function_evaluation()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let key_string = "2B7E151628AED2A6ABF7158809CF4F3C".to_string();
+    let mut key = [0u8; 16];
+
+    for i in (0..key_string.len()).step_by(2) {
+        key[i >> 1] = u8::from_str_radix(&key_string[i..i + 2], 16).unwrap();
+    }
+
+    let iv_string = "F0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF".to_string();
+    let mut iv = [0u8; 16];
+
+    for i in (0..iv_string.len()).step_by(2) {
+        iv[i >> 1] = u8::from_str_radix(&iv_string[i..i + 2], 16).unwrap();
+    }
+
+    let cipher_key = key.map(|x| FheUint8::encrypt(x, &client_key));
+
+    let mut aes = AesCtrStreamByte::<FheUint8>::new(cipher_key, iv, &server_key);
+
+    c.bench_function("aes byte generate 64 bits", |b| b.iter(|| aes.next_64()));
+}
+
+pub fn aes_byte_trans(c: &mut Criterion) {
+    let config = ConfigBuilder::default()
+        .enable_function_evaluation()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let key_string = "2B7E151628AED2A6ABF7158809CF4F3C".to_string();
+    let mut key = [0u8; 16];
+
+    for i in (0..key_string.len()).step_by(2) {
+        key[i >> 1] = u8::from_str_radix(&key_string[i..i + 2], 16).unwrap();
+    }
+
+    let iv_string = "F0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF".to_string();
+    let mut iv = [0u8; 16];
+
+    for i in (0..iv_string.len()).step_by(2) {
+        iv[i >> 1] = u8::from_str_radix(&iv_string[i..i + 2], 16).unwrap();
+    }
+
+    let cipher_key = key.map(|x| FheUint8::encrypt(x, &client_key));
+
+    let ciphered_message = FheUint64::try_encrypt(0u64, &client_key).unwrap();
+    let mut aes = AesCtrStreamByte::<FheUint8>::new(cipher_key, iv, &server_key);
+
+    c.bench_function("aes byte transencrypt 64 bits", |b| {
+        b.iter(|| aes.trans_encrypt_64(ciphered_message.clone()))
+    });
+}
+
+pub fn aes_byte_warmup(c: &mut Criterion) {
+    let config = ConfigBuilder::default()
+        .enable_function_evaluation()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let key_string = "2B7E151628AED2A6ABF7158809CF4F3C".to_string();
+    let mut key = [0u8; 16];
+
+    for i in (0..key_string.len()).step_by(2) {
+        key[i >> 1] = u8::from_str_radix(&key_string[i..i + 2], 16).unwrap();
+    }
+
+    let iv_string = "F0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF".to_string();
+    let mut iv = [0u8; 16];
+
+    for i in (0..iv_string.len()).step_by(2) {
+        iv[i >> 1] = u8::from_str_radix(&iv_string[i..i + 2], 16).unwrap();
+    }
+
+    c.bench_function("aes byte key expansion", |b| {
+        b.iter(|| {
+            let cipher_key = key.map(|x| FheUint8::encrypt(x, &client_key));
+            let _aes = AesCtrStreamByte::<FheUint8>::new(cipher_key, iv, &server_key);
+        })
+    });
+}
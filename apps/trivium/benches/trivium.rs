@@ -43,6 +43,14 @@ criterion_group!(
     kreyvium_byte::kreyvium_byte_warmup
 );
 
+mod aes_byte;
+criterion_group!(
+    aes_byte,
+    aes_byte::aes_byte_gen,
+    aes_byte::aes_byte_trans,
+    aes_byte::aes_byte_warmup
+);
+
 criterion_main!(
     trivium_bool,
     trivium_shortint,
@@ -50,4 +58,5 @@ criterion_main!(
     kreyvium_bool,
     kreyvium_shortint,
     kreyvium_byte,
+    aes_byte,
 );
@@ -0,0 +1,283 @@
+//! This module implements the AES-128 block cipher in counter (CTR) mode, using u8 or FheUint8
+//! for the representation of the inner bytes.
+
+use std::collections::VecDeque;
+
+use tfhe::prelude::*;
+use tfhe::{set_server_key, unset_server_key, FheUint8, ServerKey};
+
+use rayon::prelude::*;
+
+/// The AES substitution box, from FIPS 197.
+const SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+/// The round constants used in the key expansion.
+const RCON: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// Number of rounds of AES-128.
+const ROUNDS: usize = 10;
+
+/// Internal trait specifying which operations are necessary for AesCtrStreamByte generic type
+pub trait AesByteInput<OpOutput>:
+    Sized
+    + Clone
+    + Send
+    + Sync
+    + std::ops::BitXor<Output = OpOutput>
+    + std::ops::BitXor<u8, Output = OpOutput>
+    + std::ops::Shr<u8, Output = OpOutput>
+    + std::ops::Shl<u8, Output = OpOutput>
+    + std::ops::Mul<u8, Output = OpOutput>
+{
+}
+impl AesByteInput<u8> for u8 {}
+impl AesByteInput<u8> for &u8 {}
+impl AesByteInput<FheUint8> for FheUint8 {}
+impl AesByteInput<FheUint8> for &FheUint8 {}
+
+/// Internal trait specifying how the AES substitution box is evaluated on the generic type of
+/// AesCtrStreamByte: with a table lookup for u8, and with a function evaluation (WoPBS) for
+/// FheUint8.
+pub trait AesSubByte {
+    fn sub_byte(&self) -> Self;
+}
+
+impl AesSubByte for u8 {
+    fn sub_byte(&self) -> Self {
+        SBOX[*self as usize]
+    }
+}
+
+impl AesSubByte for FheUint8 {
+    fn sub_byte(&self) -> Self {
+        self.map(|x| SBOX[x as usize] as u64)
+    }
+}
+
+/// AesCtrStreamByte: a struct implementing AES-128 in counter mode as a stream cipher, using T
+/// for the internal representation of bytes (u8 or FheUint8). To be able to compute FHE
+/// operations, it also owns an Option for a ServerKey.
+///
+/// The key schedule is computed once at construction, under encryption for FheUint8. Each
+/// keystream block is the encryption of the 128 bits counter, which starts at the input vector
+/// and is incremented as a big endian integer, as in NIST SP 800-38A.
+pub struct AesCtrStreamByte<T> {
+    round_keys: Vec<[T; 16]>,
+    counter: u128,
+    keystream: VecDeque<T>,
+    fhe_key: Option<ServerKey>,
+}
+
+impl AesCtrStreamByte<u8> {
+    /// Constructor for `AesCtrStreamByte<u8>`: arguments are the secret key and the initial
+    /// counter block.
+    pub fn new(key: [u8; 16], iv: [u8; 16]) -> AesCtrStreamByte<u8> {
+        AesCtrStreamByte::<u8>::new_from_key(key, iv, None)
+    }
+}
+
+impl AesCtrStreamByte<FheUint8> {
+    /// Constructor for `AesCtrStreamByte<FheUint8>`: arguments are the encrypted secret key, the
+    /// initial counter block, and the FHE server key. The key expansion is run under encryption
+    /// before returning.
+    ///
+    /// The substitution box is evaluated with a WoPBS, the server key must therefore be
+    /// generated from a config with function evaluation enabled.
+    pub fn new(
+        key: [FheUint8; 16],
+        iv: [u8; 16],
+        server_key: &ServerKey,
+    ) -> AesCtrStreamByte<FheUint8> {
+        AesCtrStreamByte::<FheUint8>::new_from_key(key, iv, Some(server_key.clone()))
+    }
+}
+
+impl<T> AesCtrStreamByte<T>
+where
+    T: AesByteInput<T> + AesSubByte + Send,
+    for<'a> &'a T: AesByteInput<T>,
+{
+    /// Internal generic constructor: arguments are the secret key, the initial counter block,
+    /// and an optional FHE server key
+    fn new_from_key(key: [T; 16], iv: [u8; 16], sk: Option<ServerKey>) -> Self {
+        let mut ret = Self {
+            round_keys: Vec::with_capacity(ROUNDS + 1),
+            counter: u128::from_be_bytes(iv),
+            keystream: VecDeque::with_capacity(16),
+            fhe_key: sk,
+        };
+
+        ret.set_server_key();
+        ret.round_keys = Self::expand_key(key);
+        ret.unset_server_key();
+
+        ret
+    }
+
+    fn set_server_key(&self) {
+        if let Some(sk) = &self.fhe_key {
+            set_server_key(sk.clone());
+            rayon::broadcast(|_| set_server_key(sk.clone()));
+        }
+    }
+
+    fn unset_server_key(&self) {
+        if self.fhe_key.is_some() {
+            unset_server_key();
+            rayon::broadcast(|_| unset_server_key());
+        }
+    }
+
+    /// The AES-128 key expansion: computes the 11 round keys from the secret key. One derived
+    /// word out of 4 goes through the substitution box, for a total of 40 evaluations.
+    fn expand_key(key: [T; 16]) -> Vec<[T; 16]> {
+        let mut words: Vec<[T; 4]> = key
+            .chunks_exact(4)
+            .map(|word| [0, 1, 2, 3].map(|i| word[i].clone()))
+            .collect();
+
+        for i in 4..4 * (ROUNDS + 1) {
+            let previous = &words[i - 1];
+            let temp: [T; 4] = if i % 4 == 0 {
+                // RotWord then SubWord, in parallel
+                let mut sub_word: Vec<T> = (0..4)
+                    .into_par_iter()
+                    .map(|j| previous[(j + 1) % 4].sub_byte())
+                    .collect();
+                sub_word[0] = &sub_word[0] ^ RCON[i / 4 - 1];
+                [0, 1, 2, 3].map(|j| sub_word[j].clone())
+            } else {
+                previous.clone()
+            };
+
+            let word = [0, 1, 2, 3].map(|j| &words[i - 4][j] ^ &temp[j]);
+            words.push(word);
+        }
+
+        words
+            .chunks_exact(4)
+            .map(|round_words| std::array::from_fn(|i| round_words[i / 4][i % 4].clone()))
+            .collect()
+    }
+
+    /// Multiplication by x (i.e. by 2) in the field GF(2^8) used by AES.
+    fn xtime(x: &T) -> T {
+        let shifted = x << 1u8;
+        let reduction = (x >> 7u8) * 0x1bu8;
+        &shifted ^ &reduction
+    }
+
+    /// The SubBytes step, each byte goes through the substitution box, in parallel.
+    fn sub_bytes(state: &[T; 16]) -> [T; 16] {
+        let substituted: Vec<T> = state.par_iter().map(|x| x.sub_byte()).collect();
+        std::array::from_fn(|i| substituted[i].clone())
+    }
+
+    /// The ShiftRows step, row r of the state is rotated by r bytes to the left. Bytes are stored
+    /// column by column, i.e. byte i is in row i % 4 and column i / 4.
+    fn shift_rows(state: &[T; 16]) -> [T; 16] {
+        std::array::from_fn(|i| {
+            let (row, column) = (i % 4, i / 4);
+            state[row + 4 * ((column + row) % 4)].clone()
+        })
+    }
+
+    /// The MixColumns step, each column is multiplied by a fixed polynomial, all the xtime
+    /// products being computed in parallel.
+    fn mix_columns(state: &[T; 16]) -> [T; 16] {
+        let doubled: Vec<T> = state.par_iter().map(Self::xtime).collect();
+
+        let mixed: Vec<T> = (0..16)
+            .into_par_iter()
+            .map(|i| {
+                let (row, column) = (i % 4, i / 4);
+                let byte = |r: usize| 4 * column + (row + r) % 4;
+                // 2 * a0 + 3 * a1 + a2 + a3, rotated for each row
+                let doubled_sum = &doubled[byte(0)] ^ &doubled[byte(1)];
+                let sum = &state[byte(1)] ^ &state[byte(2)];
+                let sum = &sum ^ &state[byte(3)];
+                &doubled_sum ^ &sum
+            })
+            .collect();
+
+        std::array::from_fn(|i| mixed[i].clone())
+    }
+
+    /// The AddRoundKey step, the round key is XORed to the state.
+    fn add_round_key(state: &[T; 16], round_key: &[T; 16]) -> [T; 16] {
+        let added: Vec<T> = state
+            .par_iter()
+            .zip(round_key.par_iter())
+            .map(|(x, k)| x ^ k)
+            .collect();
+        std::array::from_fn(|i| added[i].clone())
+    }
+
+    /// Encrypts the current counter with AES-128 and increments it, returning the 16 bytes of
+    /// keystream.
+    fn next_block(&mut self) -> [T; 16] {
+        let counter_block = self.counter.to_be_bytes();
+        self.counter = self.counter.wrapping_add(1);
+
+        self.set_server_key();
+
+        // The counter is public, the first AddRoundKey is done with scalar operations
+        let mut state: [T; 16] = std::array::from_fn(|i| &self.round_keys[0][i] ^ counter_block[i]);
+
+        for round in 1..=ROUNDS {
+            state = Self::shift_rows(&Self::sub_bytes(&state));
+            if round != ROUNDS {
+                state = Self::mix_columns(&state);
+            }
+            state = Self::add_round_key(&state, &self.round_keys[round]);
+        }
+
+        self.unset_server_key();
+
+        state
+    }
+
+    /// Computes the next 64 bits of the keystream, outputting them (in 8 bytes) all at once in a
+    /// Vec (first value is oldest, last is newest). A new AES block is encrypted every 2 calls.
+    pub fn next_64(&mut self) -> Vec<T> {
+        if self.keystream.len() < 8 {
+            let block = self.next_block();
+            self.keystream.extend(block);
+        }
+
+        self.keystream.drain(..8).collect()
+    }
+
+    /// Computes the next byte of the keystream.
+    pub fn next_byte(&mut self) -> T {
+        if self.keystream.is_empty() {
+            let block = self.next_block();
+            self.keystream.extend(block);
+        }
+
+        self.keystream.pop_front().unwrap()
+    }
+}
+
+impl AesCtrStreamByte<FheUint8> {
+    pub fn get_server_key(&self) -> &ServerKey {
+        self.fhe_key.as_ref().unwrap()
+    }
+}
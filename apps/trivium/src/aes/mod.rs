@@ -0,0 +1,5 @@
+mod aes_byte;
+pub use aes_byte::AesCtrStreamByte;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,95 @@
+use tfhe::prelude::*;
+use tfhe::{generate_keys, ConfigBuilder, FheUint64, FheUint8};
+
+use crate::{AesCtrStreamByte, TransCiphering};
+
+// Values for these tests come from FIPS 197 appendix C.1 and NIST SP 800-38A appendix F.5.1
+
+fn get_bytes_from_hexadecimal_string(a: &str) -> Vec<u8> {
+    assert_eq!(a.len() % 2, 0);
+    (0..a.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&a[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn get_16_bytes_from_hexadecimal_string(a: &str) -> [u8; 16] {
+    get_bytes_from_hexadecimal_string(a).try_into().unwrap()
+}
+
+#[test]
+fn aes_test_fips_197() {
+    // In counter mode, the first block of the keystream is the encryption of the input vector
+    let key = get_16_bytes_from_hexadecimal_string("000102030405060708090A0B0C0D0E0F");
+    let plaintext = get_16_bytes_from_hexadecimal_string("00112233445566778899AABBCCDDEEFF");
+    let ciphertext = get_bytes_from_hexadecimal_string("69C4E0D86A7B0430D8CDB78070B4C55A");
+
+    let mut aes = AesCtrStreamByte::<u8>::new(key, plaintext);
+
+    let mut vec = aes.next_64();
+    vec.extend(aes.next_64());
+    assert_eq!(vec, ciphertext);
+}
+
+#[test]
+fn aes_test_ctr_clear_byte() {
+    let key = get_16_bytes_from_hexadecimal_string("2B7E151628AED2A6ABF7158809CF4F3C");
+    let iv = get_16_bytes_from_hexadecimal_string("F0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF");
+
+    let plaintext = get_bytes_from_hexadecimal_string(
+        "6BC1BEE22E409F96E93D7E117393172AAE2D8A571E03AC9C9EB76FAC45AF8E51\
+        30C81C46A35CE411E5FBC1191A0A52EFF69F2445DF4F9B17AD2B417BE66C3710",
+    );
+    let ciphertext = get_bytes_from_hexadecimal_string(
+        "874D6191B620E3261BEF6864990DB6CE9806F66B7970FDFF8617187BB9FFFDFF\
+        5AE4DF3EDBD5D35E5B4F09020DB03EAB1E031DDA2FBE03D1792170A0F3009CEE",
+    );
+
+    let mut aes = AesCtrStreamByte::<u8>::new(key, iv);
+
+    // Start with a single byte so that the next calls to next_64 straddle the AES blocks
+    let mut stream = vec![aes.next_byte()];
+    while stream.len() < 64 {
+        stream.extend(aes.next_64());
+    }
+    stream.truncate(64);
+
+    let encrypted: Vec<u8> = plaintext.iter().zip(stream).map(|(p, s)| p ^ s).collect();
+    assert_eq!(encrypted, ciphertext);
+}
+
+#[test]
+fn aes_test_fhe_byte_transciphering_long() {
+    let config = ConfigBuilder::default()
+        .enable_function_evaluation()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let key = get_16_bytes_from_hexadecimal_string("2B7E151628AED2A6ABF7158809CF4F3C");
+    let iv = get_16_bytes_from_hexadecimal_string("F0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF");
+
+    let plaintext = get_bytes_from_hexadecimal_string("6BC1BEE22E409F96E93D7E117393172A");
+    let ciphertext = get_bytes_from_hexadecimal_string("874D6191B620E3261BEF6864990DB6CE");
+
+    let cipher_key = key.map(|x| FheUint8::encrypt(x, &client_key));
+
+    let mut aes = AesCtrStreamByte::<FheUint8>::new(cipher_key, iv, &server_key);
+
+    // First 8 bytes as a big endian u64
+    let ciphered_word = u64::from_be_bytes(ciphertext[..8].try_into().unwrap());
+    let trans_ciphered_word = aes.trans_decrypt_64(FheUint64::encrypt(ciphered_word, &client_key));
+    let decrypted_word: u64 = trans_ciphered_word.decrypt(&client_key);
+    assert_eq!(decrypted_word.to_be_bytes(), plaintext[..8]);
+
+    // Last 8 bytes one by one
+    let ciphered_bytes: Vec<_> = ciphertext[8..]
+        .iter()
+        .map(|x| FheUint8::encrypt(*x, &client_key))
+        .collect();
+    let trans_ciphered_bytes = aes.trans_decrypt_bytes(ciphered_bytes);
+    let decrypted_bytes: Vec<u8> = trans_ciphered_bytes
+        .iter()
+        .map(|x| x.decrypt(&client_key))
+        .collect();
+    assert_eq!(decrypted_bytes, plaintext[8..]);
+}
@@ -362,13 +362,15 @@ fn kreyvium_test_fhe_byte_transciphering_long() {
 
     let cipher_key = key.map(|x| FheUint8::encrypt(x, &client_key));
 
-    let ciphered_message = FheUint64::try_encrypt(0u64, &client_key).unwrap();
+    // A non-zero message, so that the keystream and the message are really XORed together
+    let clear_message = 0x0123_4567_89AB_CDEFu64;
+    let ciphered_message = FheUint64::try_encrypt(clear_message, &client_key).unwrap();
 
     let mut kreyvium = KreyviumStreamByte::<FheUint8>::new(cipher_key, iv, &server_key);
 
     let trans_ciphered_message = kreyvium.trans_encrypt_64(ciphered_message);
-    let ciphered_message = trans_ciphered_message.decrypt(&client_key);
+    let ciphered_message: u64 = trans_ciphered_message.decrypt(&client_key);
 
-    let hexadecimal = get_hexagonal_string_from_u64(vec![ciphered_message]);
+    let hexadecimal = get_hexagonal_string_from_u64(vec![ciphered_message ^ clear_message]);
     assert_eq!(output, hexadecimal);
 }
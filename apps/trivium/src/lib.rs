@@ -6,5 +6,8 @@ pub use kreyvium::{KreyviumStream, KreyviumStreamByte, KreyviumStreamShortint};
 mod trivium;
 pub use trivium::{TriviumStream, TriviumStreamByte, TriviumStreamShortint};
 
+mod aes;
+pub use aes::AesCtrStreamByte;
+
 mod trans_ciphering;
 pub use trans_ciphering::TransCiphering;
@@ -1,7 +1,10 @@
-//! This module will contain extensions of some TriviumStream of KreyviumStream objects,
-//! when trans ciphering is available to them.
+//! This module will contain extensions of some TriviumStream, KreyviumStream or AesCtrStreamByte
+//! objects, when trans ciphering is available to them.
 
-use crate::{KreyviumStreamByte, KreyviumStreamShortint, TriviumStreamByte, TriviumStreamShortint};
+use crate::{
+    AesCtrStreamByte, KreyviumStreamByte, KreyviumStreamShortint, TriviumStreamByte,
+    TriviumStreamShortint,
+};
 use tfhe::shortint::Ciphertext;
 
 use tfhe::prelude::*;
@@ -28,12 +31,13 @@ fn transcipher_from_fheu8_stream(
     set_server_key(fhe_server_key.clone());
     rayon::broadcast(|_| set_server_key(fhe_server_key.clone()));
 
-    let ret: FheUint64 = stream
+    let keystream: FheUint64 = stream
         .into_par_iter()
         .enumerate()
-        .map(|(i, x)| &cipher ^ &(FheUint64::cast_from(x) << (8 * (7 - i) as u8)))
+        .map(|(i, x)| FheUint64::cast_from(x) << (8 * (7 - i) as u8))
         .reduce_with(|a, b| a | b)
         .unwrap();
+    let ret = &cipher ^ &keystream;
 
     unset_server_key();
     rayon::broadcast(|_| unset_server_key());
@@ -74,7 +78,8 @@ fn transcipher_from_1_1_stream(
 
 impl TransCiphering for TriviumStreamByte<FheUint8> {
     /// `TriviumStreamByte<FheUint8>`: since a full step outputs 8 bytes, these bytes
-    /// are each shifted by a number in [0, 8), and XORed with the input cipher
+    /// are each shifted by a number in [0, 8), packed in a full word, and XORed with the input
+    /// cipher
     fn trans_encrypt_64(&mut self, cipher: FheUint64) -> FheUint64 {
         transcipher_from_fheu8_stream(self.next_64(), cipher, self.get_server_key())
     }
@@ -82,12 +87,50 @@ impl TransCiphering for TriviumStreamByte<FheUint8> {
 
 impl TransCiphering for KreyviumStreamByte<FheUint8> {
     /// `KreyviumStreamByte<FheUint8>`: since a full step outputs 8 bytes, these bytes
-    /// are each shifted by a number in [0, 8), and XORed with the input cipher
+    /// are each shifted by a number in [0, 8), packed in a full word, and XORed with the input
+    /// cipher
     fn trans_encrypt_64(&mut self, cipher: FheUint64) -> FheUint64 {
         transcipher_from_fheu8_stream(self.next_64(), cipher, self.get_server_key())
     }
 }
 
+impl TransCiphering for AesCtrStreamByte<FheUint8> {
+    /// `AesCtrStreamByte<FheUint8>`: 8 bytes of the AES-CTR keystream are each shifted by a
+    /// number in [0, 8), packed in a full word, and XORed with the input cipher
+    fn trans_encrypt_64(&mut self, cipher: FheUint64) -> FheUint64 {
+        transcipher_from_fheu8_stream(self.next_64(), cipher, self.get_server_key())
+    }
+}
+
+impl AesCtrStreamByte<FheUint8> {
+    /// Trans ciphers a payload byte by byte: each input byte is XORed with the next byte of the
+    /// AES-CTR keystream. Called on the trivial encryptions of an AES-CTR encrypted payload, this
+    /// returns the encryptions of the plain bytes under the FHE key.
+    pub fn trans_encrypt_bytes(&mut self, cipher: Vec<FheUint8>) -> Vec<FheUint8> {
+        let stream: Vec<FheUint8> = cipher.iter().map(|_| self.next_byte()).collect();
+
+        set_server_key(self.get_server_key().clone());
+        rayon::broadcast(|_| set_server_key(self.get_server_key().clone()));
+
+        let ret = cipher
+            .par_iter()
+            .zip(stream.par_iter())
+            .map(|(c, s)| c ^ s)
+            .collect();
+
+        unset_server_key();
+        rayon::broadcast(|_| unset_server_key());
+
+        ret
+    }
+
+    /// Inverse of [`AesCtrStreamByte::trans_encrypt_bytes`], which is the same operation in
+    /// counter mode.
+    pub fn trans_decrypt_bytes(&mut self, cipher: Vec<FheUint8>) -> Vec<FheUint8> {
+        self.trans_encrypt_bytes(cipher)
+    }
+}
+
 impl TransCiphering for TriviumStreamShortint {
     /// TriviumStreamShortint: since a full step outputs 64 shortints, these bits
     /// are paired 2 by 2 in the HL parameter space and packed in a full word,
@@ -335,14 +335,17 @@ fn trivium_test_fhe_byte_transciphering_long() {
 
     let cipher_key = key.map(|x| FheUint8::encrypt(x, &client_key));
 
-    let mut ciphered_message = vec![FheUint64::try_encrypt(0u64, &client_key).unwrap(); 9];
+    // A non-zero message, so that the keystream and the message are really XORed together
+    let clear_message = 0x0123_4567_89AB_CDEFu64;
+    let mut ciphered_message = vec![FheUint64::try_encrypt(clear_message, &client_key).unwrap(); 9];
 
     let mut trivium = TriviumStreamByte::<FheUint8>::new(cipher_key, iv, &server_key);
 
     let mut vec = Vec::<u64>::with_capacity(8);
     while vec.len() < 8 {
         let trans_ciphered_message = trivium.trans_encrypt_64(ciphered_message.pop().unwrap());
-        vec.push(trans_ciphered_message.decrypt(&client_key));
+        let ciphered_message: u64 = trans_ciphered_message.decrypt(&client_key);
+        vec.push(ciphered_message ^ clear_message);
     }
 
     let hexadecimal = get_hexagonal_string_from_u64(vec);
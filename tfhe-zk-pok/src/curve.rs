@@ -0,0 +1,40 @@
+//! Curve types used by the proofs.
+//!
+//! All proofs use the first group of the BLS12-381 pairing friendly curve, which has a prime
+//! order of 255 bits. No pairing is needed, only the hardness of the discrete logarithm in this
+//! group.
+
+pub use ark_bls12_381::{Fr, G1Affine, G1Projective};
+use ark_ff::{Field, PrimeField};
+
+/// Converts a signed integer to a scalar
+pub fn scalar_from_i128(value: i128) -> Fr {
+    let abs = Fr::from(value.unsigned_abs());
+    if value < 0 {
+        -abs
+    } else {
+        abs
+    }
+}
+
+/// Returns `[1, x, x^2, ..., x^(n-1)]`
+pub fn powers(x: Fr, n: usize) -> Vec<Fr> {
+    let mut current = Fr::ONE;
+    (0..n)
+        .map(|_| {
+            let value = current;
+            current *= x;
+            value
+        })
+        .collect()
+}
+
+/// Returns `2^exponent` as a scalar
+pub fn pow2(exponent: u32) -> Fr {
+    Fr::from(2u64).pow([u64::from(exponent)])
+}
+
+/// Reduces 64 uniformly random bytes to a scalar, with a negligible bias
+pub fn scalar_from_wide_bytes(bytes: &[u8; 64]) -> Fr {
+    Fr::from_le_bytes_mod_order(bytes)
+}
@@ -0,0 +1,12 @@
+//! Zero-knowledge proofs of knowledge for TFHE-rs.
+//!
+//! The [`proofs::pke`] module proves that a list of LWE ciphertexts encrypted with a compact
+//! public key is well formed, without revealing the messages or the encryption randomness.
+//!
+//! Proofs are built on top of a generic argument, in [`proofs::binary`], showing that a committed
+//! vector of bits satisfies a set of public linear equations.
+
+pub mod curve;
+pub mod proofs;
+mod serialization;
+mod transcript;
@@ -0,0 +1,577 @@
+//! Proof that a committed vector of bits satisfies public linear equations.
+//!
+//! This is the range proof of Bulletproofs (<https://eprint.iacr.org/2017/1066>, section 4.2),
+//! where the equation `<a, 2^n> = v` on the committed bits `a` is replaced by an arbitrary set
+//! of linear equations over the scalar field. The equations are compressed into a single one
+//! with the powers of a challenge `z`, the same way aggregated range proofs are built.
+//!
+//! The proof has a size logarithmic in the number of bits thanks to the inner product argument.
+
+use crate::curve::{powers, Fr, G1Affine, G1Projective};
+use crate::serialization::{canonical, canonical_vec};
+use crate::transcript::Transcript;
+use ark_ec::scalar_mul::fixed_base::FixedBase;
+use ark_ec::{CurveGroup, Group, VariableBaseMSM};
+use ark_ff::{batch_inversion, Field, PrimeField, UniformRand, Zero};
+use rand::RngCore;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Independent generators used to commit to vectors of bits
+///
+/// The discrete logarithms of the generators must be unknown to the provers, they are sampled
+/// by a trusted party which then forgets them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Generators {
+    #[serde(with = "canonical")]
+    g: G1Affine,
+    #[serde(with = "canonical")]
+    h: G1Affine,
+    #[serde(with = "canonical")]
+    u: G1Affine,
+    #[serde(with = "canonical_vec")]
+    g_list: Vec<G1Affine>,
+    #[serde(with = "canonical_vec")]
+    h_list: Vec<G1Affine>,
+}
+
+impl Generators {
+    /// Samples generators to commit to vectors of up to `capacity` bits
+    ///
+    /// The capacity is rounded up to the next power of two.
+    pub fn new(capacity: usize, rng: &mut impl RngCore) -> Self {
+        let capacity = capacity.next_power_of_two();
+        let count = 2 * capacity + 3;
+
+        let scalars = (0..count).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let scalar_size = Fr::MODULUS_BIT_SIZE as usize;
+        let window = FixedBase::get_mul_window_size(count);
+        let table = FixedBase::get_window_table(scalar_size, window, G1Projective::generator());
+        let points = FixedBase::msm::<G1Projective>(scalar_size, window, &table, &scalars);
+
+        let mut points = G1Projective::normalize_batch(&points);
+        let h_list = points.split_off(3 + capacity);
+        let g_list = points.split_off(3);
+
+        Self {
+            g: points[0],
+            h: points[1],
+            u: points[2],
+            g_list,
+            h_list,
+        }
+    }
+
+    /// The maximum number of bits that can be committed with these generators
+    pub fn capacity(&self) -> usize {
+        self.g_list.len()
+    }
+
+    /// Absorbs all the generators, so that the challenges depend on them
+    pub(crate) fn append_to(&self, transcript: &mut Transcript) {
+        transcript.append_point(b"g", &self.g);
+        transcript.append_point(b"h", &self.h);
+        transcript.append_point(b"u", &self.u);
+        transcript.append_points(b"g_list", &self.g_list);
+        transcript.append_points(b"h_list", &self.h_list);
+    }
+
+    pub(crate) fn is_well_formed(&self) -> bool {
+        self.g_list.len() == self.h_list.len() && self.g_list.len().is_power_of_two()
+    }
+}
+
+/// Public linear equations `<row_j, bits> = target_j`, for `j` in `0..m`
+pub(crate) trait LinearEquations {
+    /// Returns the rows and targets combined with the powers of `z`, that is
+    /// `sum_j z^(j + 2) row_j`, padded with zeros to `len` elements, and
+    /// `sum_j z^(j + 2) target_j`
+    fn combine(&self, z: Fr, len: usize) -> (Vec<Fr>, Fr);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Proof {
+    #[serde(with = "canonical")]
+    a: G1Affine,
+    #[serde(with = "canonical")]
+    s: G1Affine,
+    #[serde(with = "canonical")]
+    t1: G1Affine,
+    #[serde(with = "canonical")]
+    t2: G1Affine,
+    #[serde(with = "canonical")]
+    t_hat: Fr,
+    #[serde(with = "canonical")]
+    tau_x: Fr,
+    #[serde(with = "canonical")]
+    mu: Fr,
+    #[serde(with = "canonical_vec")]
+    l_list: Vec<G1Affine>,
+    #[serde(with = "canonical_vec")]
+    r_list: Vec<G1Affine>,
+    #[serde(with = "canonical")]
+    a_final: Fr,
+    #[serde(with = "canonical")]
+    b_final: Fr,
+}
+
+fn inner_product(lhs: &[Fr], rhs: &[Fr]) -> Fr {
+    lhs.par_iter()
+        .zip(rhs.par_iter())
+        .map(|(l, r)| *l * r)
+        .sum()
+}
+
+fn msm(bases: &[G1Affine], scalars: &[Fr]) -> G1Projective {
+    G1Projective::msm(bases, scalars).unwrap()
+}
+
+/// Number of committed elements for a vector of `bit_count` bits
+pub(crate) fn padded_len(bit_count: usize) -> usize {
+    bit_count.next_power_of_two()
+}
+
+/// Proves that `bits` satisfy the linear `equations`
+///
+/// All the public values the equations depend on must have been appended to the `transcript`.
+///
+/// # Panics
+///
+/// Panics if there are more bits than the capacity of the generators.
+pub(crate) fn prove(
+    generators: &Generators,
+    transcript: &mut Transcript,
+    bits: &[bool],
+    equations: &impl LinearEquations,
+    rng: &mut impl RngCore,
+) -> Proof {
+    let n = padded_len(bits.len());
+    assert!(
+        n <= generators.capacity(),
+        "The proof needs {n} generators, but only {} are available",
+        generators.capacity()
+    );
+    let g_list = &generators.g_list[..n];
+    let h_list = &generators.h_list[..n];
+    transcript.append_u64(b"n", n as u64);
+
+    let a_l = bits
+        .iter()
+        .map(|&bit| Fr::from(bit))
+        .chain(std::iter::repeat(Fr::ZERO))
+        .take(n)
+        .collect::<Vec<_>>();
+
+    // A = alpha * h + <a_l, G> + <a_l - 1, H>, bits are in {0, 1} so no multiplication is needed
+    let alpha = Fr::rand(rng);
+    let mut a = generators.h * alpha;
+    let padded_bits = bits.iter().chain(std::iter::repeat(&false));
+    for ((bit, g), h) in padded_bits.zip(g_list).zip(h_list) {
+        if *bit {
+            a += *g;
+        } else {
+            a -= G1Projective::from(*h);
+        }
+    }
+
+    let s_l = (0..n).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    let s_r = (0..n).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+    let rho = Fr::rand(rng);
+    let s = generators.h * rho + msm(g_list, &s_l) + msm(h_list, &s_r);
+
+    let [a, s] = <[G1Affine; 2]>::try_from(G1Projective::normalize_batch(&[a, s])).unwrap();
+    transcript.append_point(b"A", &a);
+    transcript.append_point(b"S", &s);
+    let y = transcript.challenge_scalar(b"y");
+    let z = transcript.challenge_scalar(b"z");
+
+    let (w, _) = equations.combine(z, n);
+    let y_n = powers(y, n);
+
+    // l(X) = l0 + l1 X and r(X) = r0 + r1 X
+    let l0 = a_l.iter().map(|a| *a - z).collect::<Vec<_>>();
+    let l1 = s_l;
+    let r0 = a_l
+        .par_iter()
+        .zip(y_n.par_iter())
+        .zip(w.par_iter())
+        .map(|((a, y_i), w_i)| *y_i * (*a - Fr::ONE + z) + w_i)
+        .collect::<Vec<_>>();
+    let r1 = y_n
+        .par_iter()
+        .zip(s_r.par_iter())
+        .map(|(y_i, s)| *y_i * s)
+        .collect::<Vec<_>>();
+
+    // t(X) = <l(X), r(X)> = t0 + t1 X + t2 X^2
+    let t1 = inner_product(&l0, &r1) + inner_product(&l1, &r0);
+    let t2 = inner_product(&l1, &r1);
+    let tau1 = Fr::rand(rng);
+    let tau2 = Fr::rand(rng);
+    let t1_commit = generators.g * t1 + generators.h * tau1;
+    let t2_commit = generators.g * t2 + generators.h * tau2;
+
+    let [t1_commit, t2_commit] =
+        <[G1Affine; 2]>::try_from(G1Projective::normalize_batch(&[t1_commit, t2_commit])).unwrap();
+    transcript.append_point(b"T1", &t1_commit);
+    transcript.append_point(b"T2", &t2_commit);
+    let x = transcript.challenge_scalar(b"x");
+
+    let l = l0
+        .par_iter()
+        .zip(l1.par_iter())
+        .map(|(l0, l1)| *l0 + x * l1)
+        .collect::<Vec<_>>();
+    let r = r0
+        .par_iter()
+        .zip(r1.par_iter())
+        .map(|(r0, r1)| *r0 + x * r1)
+        .collect::<Vec<_>>();
+    let t_hat = inner_product(&l, &r);
+    let tau_x = tau1 * x + tau2 * x * x;
+    let mu = alpha + rho * x;
+
+    transcript.append_scalar(b"t_hat", &t_hat);
+    transcript.append_scalar(b"tau_x", &tau_x);
+    transcript.append_scalar(b"mu", &mu);
+    let x_u = transcript.challenge_scalar(b"x_u");
+
+    // H'_i = y^-i H_i, so that <r, H'> commits to a_r + s_r X
+    let y_inv_n = powers(y.inverse().unwrap(), n);
+    let h_prime_list = G1Projective::normalize_batch(
+        &h_list
+            .par_iter()
+            .zip(y_inv_n.par_iter())
+            .map(|(h, y_inv)| *h * y_inv)
+            .collect::<Vec<_>>(),
+    );
+    let u = generators.u * x_u;
+
+    let (l_list, r_list, a_final, b_final) =
+        inner_product_prove(transcript, g_list.to_vec(), h_prime_list, u, l, r);
+
+    Proof {
+        a,
+        s,
+        t1: t1_commit,
+        t2: t2_commit,
+        t_hat,
+        tau_x,
+        mu,
+        l_list,
+        r_list,
+        a_final,
+        b_final,
+    }
+}
+
+type InnerProductProof = (Vec<G1Affine>, Vec<G1Affine>, Fr, Fr);
+
+/// Proves that `P = <a, G> + <b, H> + <a, b> u`, halving the vectors at each round
+fn inner_product_prove(
+    transcript: &mut Transcript,
+    mut g: Vec<G1Affine>,
+    mut h: Vec<G1Affine>,
+    u: G1Projective,
+    mut a: Vec<Fr>,
+    mut b: Vec<Fr>,
+) -> InnerProductProof {
+    let mut l_list = Vec::new();
+    let mut r_list = Vec::new();
+
+    let mut n = a.len();
+    while n > 1 {
+        n /= 2;
+        let (a_lo, a_hi) = a.split_at(n);
+        let (b_lo, b_hi) = b.split_at(n);
+        let (g_lo, g_hi) = g.split_at(n);
+        let (h_lo, h_hi) = h.split_at(n);
+
+        let c_l = inner_product(a_lo, b_hi);
+        let c_r = inner_product(a_hi, b_lo);
+        let l = msm(g_hi, a_lo) + msm(h_lo, b_hi) + u * c_l;
+        let r = msm(g_lo, a_hi) + msm(h_hi, b_lo) + u * c_r;
+
+        let [l, r] = <[G1Affine; 2]>::try_from(G1Projective::normalize_batch(&[l, r])).unwrap();
+        transcript.append_point(b"L", &l);
+        transcript.append_point(b"R", &r);
+        l_list.push(l);
+        r_list.push(r);
+
+        let x = transcript.challenge_scalar(b"ipa");
+        let x_inv = x.inverse().unwrap();
+
+        let fold_scalars = |lo: &[Fr], hi: &[Fr], lo_factor: Fr, hi_factor: Fr| {
+            lo.par_iter()
+                .zip(hi.par_iter())
+                .map(|(lo, hi)| *lo * lo_factor + *hi * hi_factor)
+                .collect::<Vec<_>>()
+        };
+        let fold_points = |lo: &[G1Affine], hi: &[G1Affine], lo_factor: Fr, hi_factor: Fr| {
+            G1Projective::normalize_batch(
+                &lo.par_iter()
+                    .zip(hi.par_iter())
+                    .map(|(lo, hi)| *lo * lo_factor + *hi * hi_factor)
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let new_a = fold_scalars(a_lo, a_hi, x, x_inv);
+        let new_b = fold_scalars(b_lo, b_hi, x_inv, x);
+        let new_g = fold_points(g_lo, g_hi, x_inv, x);
+        let new_h = fold_points(h_lo, h_hi, x, x_inv);
+        a = new_a;
+        b = new_b;
+        g = new_g;
+        h = new_h;
+    }
+
+    (l_list, r_list, a[0], b[0])
+}
+
+/// Verifies a proof that the bits committed by the prover satisfy the linear `equations`
+///
+/// The `transcript` must be in the same state as the one given to the prover.
+pub(crate) fn verify(
+    generators: &Generators,
+    transcript: &mut Transcript,
+    bit_count: usize,
+    equations: &impl LinearEquations,
+    proof: &Proof,
+) -> Result<(), ()> {
+    let n = padded_len(bit_count);
+    let rounds = n.ilog2() as usize;
+    if n > generators.capacity() || proof.l_list.len() != rounds || proof.r_list.len() != rounds {
+        return Err(());
+    }
+    let g_list = &generators.g_list[..n];
+    let h_list = &generators.h_list[..n];
+    transcript.append_u64(b"n", n as u64);
+
+    transcript.append_point(b"A", &proof.a);
+    transcript.append_point(b"S", &proof.s);
+    let y = transcript.challenge_scalar(b"y");
+    let z = transcript.challenge_scalar(b"z");
+    transcript.append_point(b"T1", &proof.t1);
+    transcript.append_point(b"T2", &proof.t2);
+    let x = transcript.challenge_scalar(b"x");
+    transcript.append_scalar(b"t_hat", &proof.t_hat);
+    transcript.append_scalar(b"tau_x", &proof.tau_x);
+    transcript.append_scalar(b"mu", &proof.mu);
+    let x_u = transcript.challenge_scalar(b"x_u");
+
+    let mut ipa_challenges = Vec::with_capacity(rounds);
+    for (l, r) in proof.l_list.iter().zip(&proof.r_list) {
+        transcript.append_point(b"L", l);
+        transcript.append_point(b"R", r);
+        ipa_challenges.push(transcript.challenge_scalar(b"ipa"));
+    }
+    let mut ipa_challenges_inv = ipa_challenges.clone();
+    batch_inversion(&mut ipa_challenges_inv);
+
+    let (w, v) = equations.combine(z, n);
+    let y_n = powers(y, n);
+    let y_inv_n = powers(y.inverse().unwrap(), n);
+    let sum_y_n: Fr = y_n.iter().sum();
+    let sum_w: Fr = w.iter().sum();
+
+    // t0 = <l0, r0> = v + delta, when the bits satisfy the equations
+    let delta = (z - z * z) * sum_y_n - z * sum_w;
+    let t_check = generators.g * (proof.t_hat - v - delta) + generators.h * proof.tau_x
+        - proof.t1 * x
+        - proof.t2 * (x * x);
+    if !t_check.is_zero() {
+        return Err(());
+    }
+
+    // s_i is the coefficient of G_i in the folded generator, the i-th bit of the index (from the
+    // most significant) selects x_k or x_k^-1 in the k-th round
+    let mut s = vec![ipa_challenges_inv.iter().product::<Fr>(); n];
+    let squares = ipa_challenges
+        .iter()
+        .map(|x| x.square())
+        .collect::<Vec<_>>();
+    for i in 1..n {
+        let highest_bit = i.ilog2() as usize;
+        let round = rounds - 1 - highest_bit;
+        s[i] = s[i - (1 << highest_bit)] * squares[round];
+    }
+
+    let a_final = proof.a_final;
+    let b_final = proof.b_final;
+
+    let mut bases = Vec::with_capacity(2 * n + 2 * rounds + 4);
+    let mut scalars = Vec::with_capacity(2 * n + 2 * rounds + 4);
+
+    // A + x S - mu h + x_u (t_hat - a b) u + sum x_k^2 L_k + x_k^-2 R_k
+    //   + <-z - a s, G> + <y^-n (z y^n + w - b s^-1), H> = 0
+    bases.extend([proof.a, proof.s, generators.h, generators.u]);
+    scalars.extend([
+        Fr::ONE,
+        x,
+        -proof.mu,
+        x_u * (proof.t_hat - a_final * b_final),
+    ]);
+    for (k, (l, r)) in proof.l_list.iter().zip(&proof.r_list).enumerate() {
+        bases.push(*l);
+        scalars.push(squares[k]);
+        bases.push(*r);
+        scalars.push(ipa_challenges_inv[k].square());
+    }
+    bases.extend_from_slice(g_list);
+    scalars.extend(
+        s.par_iter()
+            .map(|s_i| -z - a_final * s_i)
+            .collect::<Vec<_>>(),
+    );
+    bases.extend_from_slice(h_list);
+    scalars.extend(
+        (0..n)
+            .into_par_iter()
+            .map(|i| y_inv_n[i] * (z * y_n[i] + w[i] - b_final * s[n - 1 - i]))
+            .collect::<Vec<_>>(),
+    );
+
+    if msm(&bases, &scalars).is_zero() {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::pow2;
+
+    /// Proves that the bits are the decomposition of the given values
+    struct Decompositions {
+        values: Vec<u64>,
+        bits_per_value: usize,
+    }
+
+    impl LinearEquations for Decompositions {
+        fn combine(&self, z: Fr, len: usize) -> (Vec<Fr>, Fr) {
+            let mut row = vec![Fr::ZERO; len];
+            let mut target = Fr::ZERO;
+            let z_powers = powers(z, self.values.len() + 2);
+            for (j, value) in self.values.iter().enumerate() {
+                let z_j = z_powers[j + 2];
+                for i in 0..self.bits_per_value {
+                    row[j * self.bits_per_value + i] += z_j * pow2(i as u32);
+                }
+                target += z_j * Fr::from(*value);
+            }
+            (row, target)
+        }
+    }
+
+    fn decompose(values: &[u64], bits_per_value: usize) -> Vec<bool> {
+        values
+            .iter()
+            .flat_map(|value| (0..bits_per_value).map(move |i| (value >> i) & 1 == 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_binary_linear_proof() {
+        let rng = &mut rand::thread_rng();
+        let generators = Generators::new(64, rng);
+
+        let bits_per_value = 8;
+        let values = vec![0, 1, 200, 255, 17];
+        let bits = decompose(&values, bits_per_value);
+        let equations = Decompositions {
+            values: values.clone(),
+            bits_per_value,
+        };
+
+        let proof = prove(
+            &generators,
+            &mut Transcript::new(b"test"),
+            &bits,
+            &equations,
+            rng,
+        );
+        assert!(verify(
+            &generators,
+            &mut Transcript::new(b"test"),
+            bits.len(),
+            &equations,
+            &proof
+        )
+        .is_ok());
+
+        let serialized = bincode::serialize(&proof).unwrap();
+        let deserialized: Proof = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, proof);
+
+        // Other public values
+        let mut other_values = values.clone();
+        other_values[2] = 201;
+        let other_equations = Decompositions {
+            values: other_values,
+            bits_per_value,
+        };
+        assert!(verify(
+            &generators,
+            &mut Transcript::new(b"test"),
+            bits.len(),
+            &other_equations,
+            &proof
+        )
+        .is_err());
+
+        // Other transcript
+        assert!(verify(
+            &generators,
+            &mut Transcript::new(b"other"),
+            bits.len(),
+            &equations,
+            &proof
+        )
+        .is_err());
+
+        // Tampered proof
+        let mut tampered = proof;
+        tampered.t_hat += Fr::ONE;
+        assert!(verify(
+            &generators,
+            &mut Transcript::new(b"test"),
+            bits.len(),
+            &equations,
+            &tampered
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_binary_linear_proof_rejects_non_binary_witness() {
+        let rng = &mut rand::thread_rng();
+        let generators = Generators::new(16, rng);
+
+        // 300 does not fit in 8 bits, so no vector of 8 bits satisfies the equation
+        let values = vec![300];
+        let equations = Decompositions {
+            values,
+            bits_per_value: 8,
+        };
+        let bits = decompose(&[300 % 256], 8);
+
+        let proof = prove(
+            &generators,
+            &mut Transcript::new(b"test"),
+            &bits,
+            &equations,
+            rng,
+        );
+        assert!(verify(
+            &generators,
+            &mut Transcript::new(b"test"),
+            bits.len(),
+            &equations,
+            &proof
+        )
+        .is_err());
+    }
+}
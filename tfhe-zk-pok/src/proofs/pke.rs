@@ -0,0 +1,764 @@
+//! Proof of correct encryption for a list of LWE ciphertexts encrypted with a compact public key.
+//!
+//! The compact public key is made of two polynomials `a` and `b` of `d` coefficients, the
+//! ciphertext modulus is `q = 2^64`. The messages are split in bins of at most `d` messages, each
+//! bin being encrypted with a fresh binary vector `r` as
+//!
+//! ```text
+//! c1 = a * r + e1            (mod q)
+//! c2 = (b * r)[..k] + e2 + delta * m (mod q)
+//! ```
+//!
+//! where `*` is the semi reverse negacyclic product of the compact public key encryption and
+//! `delta = 2^63 / (t * c)`, `c` being the room left for the carries above the messages. The proof
+//! shows, without revealing them, that the prover knows `r`, `e1`, `e2` and `m` such that the above
+//! equations hold and:
+//! - `r` is binary
+//! - `e1` lies in `[-B1, B1[` and `e2` in `[-B2, B2[`
+//! - `m` lies in `[0, t[`, i.e. the padding bit of the plaintexts is zero
+//!
+//! Each equation modulo `q` is lifted to the integers with a bounded quotient, then every
+//! witness value is decomposed in bits and the resulting linear equations are proven with the
+//! argument of the [binary](super::binary) module. As all values are small compared to the
+//! scalar field, equations holding over the field also hold over the integers.
+
+use super::binary::{self, Generators, LinearEquations};
+use crate::curve::{pow2, powers, Fr};
+use crate::transcript::Transcript;
+use ark_ff::Field;
+use rand::RngCore;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const DOMAIN_SEPARATOR: &[u8] = b"tfhe-zk-pok::pke";
+const CRS_DOMAIN_SEPARATOR: &[u8] = b"tfhe-zk-pok::pke::crs";
+
+/// Public parameters (common reference string) of the proof
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicParams {
+    generators: Generators,
+    lwe_dimension: usize,
+    max_num_messages: usize,
+    message_bits: u32,
+    carry_bits: u32,
+    mask_noise_bound_log2: u32,
+    body_noise_bound_log2: u32,
+}
+
+/// Public values of the proven statement
+#[derive(Clone, Debug)]
+pub struct PublicCommit {
+    a: Vec<u64>,
+    b: Vec<u64>,
+    c1: Vec<u64>,
+    c2: Vec<u64>,
+}
+
+/// Secret values known by the prover
+#[derive(Clone, Debug)]
+pub struct PrivateCommit {
+    r: Vec<u64>,
+    e1: Vec<i64>,
+    m: Vec<u64>,
+    e2: Vec<i64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Proof {
+    proof: binary::Proof,
+}
+
+/// Positions of the bits of each witness value in the committed vector
+#[derive(Copy, Clone)]
+struct Layout {
+    d: usize,
+    e1_bits: usize,
+    e2_bits: usize,
+    m_bits: usize,
+    quotient_bits: usize,
+}
+
+/// Positions of the bits of the witness of one bin
+struct BinLayout {
+    r: usize,
+    e1: usize,
+    quotient1: usize,
+    e2: usize,
+    m: usize,
+    quotient2: usize,
+    end: usize,
+}
+
+impl Layout {
+    fn new(params: &PublicParams) -> Self {
+        let d = params.lwe_dimension;
+        Self {
+            d,
+            e1_bits: params.mask_noise_bound_log2 as usize + 1,
+            e2_bits: params.body_noise_bound_log2 as usize + 1,
+            m_bits: params.message_bits as usize,
+            quotient_bits: (usize::BITS - (quotient_offset(d) * 2 - 1).leading_zeros()) as usize,
+        }
+    }
+
+    fn num_bins(&self, num_messages: usize) -> usize {
+        (num_messages + self.d - 1) / self.d
+    }
+
+    fn bin_sizes(&self, num_messages: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.num_bins(num_messages)).map(move |bin| (num_messages - bin * self.d).min(self.d))
+    }
+
+    fn bin(&self, start: usize, bin_size: usize) -> BinLayout {
+        let r = start;
+        let e1 = r + self.d;
+        let quotient1 = e1 + self.d * self.e1_bits;
+        let e2 = quotient1 + self.d * self.quotient_bits;
+        let m = e2 + bin_size * self.e2_bits;
+        let quotient2 = m + bin_size * self.m_bits;
+        let end = quotient2 + bin_size * self.quotient_bits;
+        BinLayout {
+            r,
+            e1,
+            quotient1,
+            e2,
+            m,
+            quotient2,
+            end,
+        }
+    }
+
+    fn bit_count(&self, num_messages: usize) -> usize {
+        let mut start = 0;
+        for bin_size in self.bin_sizes(num_messages) {
+            start = self.bin(start, bin_size).end;
+        }
+        start
+    }
+}
+
+/// The quotients of the equations lifted to the integers lie in `[-d - 2, d + 2[`, they are
+/// shifted by this offset to be non negative
+fn quotient_offset(d: usize) -> usize {
+    d + 2
+}
+
+/// Coefficient of `r[t]` in the `k`-th coefficient of `key * r`, for the semi reverse
+/// negacyclic product, as a signed integer
+fn product_coefficient(key: &[u64], k: usize, t: usize) -> i128 {
+    let d = key.len();
+    let j = d - 1 - t;
+    if k >= j {
+        i128::from(key[k - j])
+    } else {
+        -i128::from(key[k + d - j])
+    }
+}
+
+/// First `count` coefficients of `key * r` over the integers
+fn integer_product(key: &[u64], r: &[u64], count: usize) -> Vec<i128> {
+    (0..count)
+        .into_par_iter()
+        .map(|k| {
+            r.iter()
+                .enumerate()
+                .filter(|(_, r_t)| **r_t == 1)
+                .map(|(t, _)| product_coefficient(key, k, t))
+                .sum()
+        })
+        .collect()
+}
+
+/// Combined coefficients of `r`, `sum_k weights[k] * (key * r)[k]`
+fn combined_product_row(key: &[Fr], weights: &[Fr]) -> Vec<Fr> {
+    let d = key.len();
+    (0..d)
+        .into_par_iter()
+        .map(|t| {
+            let j = d - 1 - t;
+            let mut acc = Fr::ZERO;
+            for (k, weight) in weights.iter().enumerate() {
+                if k >= j {
+                    acc += *weight * key[k - j];
+                } else {
+                    acc -= *weight * key[k + d - j];
+                }
+            }
+            acc
+        })
+        .collect()
+}
+
+fn push_bits(bits: &mut [bool], start: usize, value: u64, count: usize) {
+    for (i, bit) in bits[start..start + count].iter_mut().enumerate() {
+        *bit = (value >> i) & 1 == 1;
+    }
+}
+
+fn bits_weights(start: usize, count: usize, factor: Fr, row: &mut [Fr]) {
+    let mut current = factor;
+    for coefficient in &mut row[start..start + count] {
+        *coefficient += current;
+        current.double_in_place();
+    }
+}
+
+/// Generates the public parameters for a compact public key of dimension `lwe_dimension`,
+/// for lists of up to `max_num_messages` messages
+///
+/// Messages are encoded with `delta = 2^(63 - message_bits - carry_bits)` and proven to be
+/// smaller than `2^message_bits`, the mask noise smaller than `2^mask_noise_bound_log2` and the
+/// body noise smaller than `2^body_noise_bound_log2` in absolute value.
+pub fn crs_gen(
+    lwe_dimension: usize,
+    max_num_messages: usize,
+    message_bits: u32,
+    carry_bits: u32,
+    mask_noise_bound_log2: u32,
+    body_noise_bound_log2: u32,
+    rng: &mut impl RngCore,
+) -> PublicParams {
+    assert!(lwe_dimension.is_power_of_two());
+    assert!(message_bits + carry_bits < 63);
+    assert!(mask_noise_bound_log2 < 62 && body_noise_bound_log2 < 62);
+
+    let mut params = PublicParams {
+        generators: Generators::new(1, rng),
+        lwe_dimension,
+        max_num_messages,
+        message_bits,
+        carry_bits,
+        mask_noise_bound_log2,
+        body_noise_bound_log2,
+    };
+    let bit_count = Layout::new(&params).bit_count(max_num_messages);
+    params.generators = Generators::new(bit_count, rng);
+    params
+}
+
+impl PublicParams {
+    pub fn lwe_dimension(&self) -> usize {
+        self.lwe_dimension
+    }
+
+    pub fn max_num_messages(&self) -> usize {
+        self.max_num_messages
+    }
+
+    pub fn message_bits(&self) -> u32 {
+        self.message_bits
+    }
+
+    pub fn carry_bits(&self) -> u32 {
+        self.carry_bits
+    }
+
+    pub fn mask_noise_bound_log2(&self) -> u32 {
+        self.mask_noise_bound_log2
+    }
+
+    pub fn body_noise_bound_log2(&self) -> u32 {
+        self.body_noise_bound_log2
+    }
+
+    /// Digest of the whole parameters, generators included, absorbed first in the transcript so
+    /// that a proof is only valid for the parameters it was generated with
+    fn digest(&self) -> [u8; 32] {
+        let mut transcript = Transcript::new(CRS_DOMAIN_SEPARATOR);
+        transcript.append_u64(b"d", self.lwe_dimension as u64);
+        transcript.append_u64(b"max_num_messages", self.max_num_messages as u64);
+        transcript.append_u64(b"message_bits", u64::from(self.message_bits));
+        transcript.append_u64(b"carry_bits", u64::from(self.carry_bits));
+        transcript.append_u64(b"B1", u64::from(self.mask_noise_bound_log2));
+        transcript.append_u64(b"B2", u64::from(self.body_noise_bound_log2));
+        self.generators.append_to(&mut transcript);
+        transcript.digest()
+    }
+
+    fn delta(&self) -> u64 {
+        1u64 << (63 - self.message_bits - self.carry_bits)
+    }
+
+    fn is_well_formed(&self) -> bool {
+        self.generators.is_well_formed()
+            && self.lwe_dimension.is_power_of_two()
+            && self
+                .message_bits
+                .checked_add(self.carry_bits)
+                .is_some_and(|bits| bits < 63)
+            && self.mask_noise_bound_log2 < 62
+            && self.body_noise_bound_log2 < 62
+            && Layout::new(self).bit_count(self.max_num_messages) <= self.generators.capacity()
+    }
+}
+
+/// Gathers the public and private values of a proof
+///
+/// - `a` and `b` are the mask and body of the compact public key
+/// - `c1` are the masks of the bins of the list, `c2` the bodies of all the ciphertexts
+/// - `r` are the binary vectors used for the bins, `e1` and `e2` the noises of the masks and
+///   bodies, `m` the messages
+#[allow(clippy::too_many_arguments)]
+pub fn commit(
+    a: Vec<u64>,
+    b: Vec<u64>,
+    c1: Vec<u64>,
+    c2: Vec<u64>,
+    r: Vec<u64>,
+    e1: Vec<i64>,
+    m: Vec<u64>,
+    e2: Vec<i64>,
+) -> (PublicCommit, PrivateCommit) {
+    (
+        PublicCommit { a, b, c1, c2 },
+        PrivateCommit { r, e1, m, e2 },
+    )
+}
+
+impl PublicCommit {
+    fn is_consistent_with(&self, params: &PublicParams) -> bool {
+        let layout = Layout::new(params);
+        let num_messages = self.c2.len();
+        self.a.len() == params.lwe_dimension
+            && self.b.len() == params.lwe_dimension
+            && num_messages > 0
+            && num_messages <= params.max_num_messages
+            && self.c1.len() == layout.num_bins(num_messages) * params.lwe_dimension
+    }
+}
+
+fn transcript(params: &PublicParams, public_commit: &PublicCommit) -> Transcript {
+    let mut transcript = Transcript::new(DOMAIN_SEPARATOR);
+    transcript.append_bytes(b"crs", &params.digest());
+    transcript.append_u64(b"k", public_commit.c2.len() as u64);
+    transcript.append_u64_slice(b"a", &public_commit.a);
+    transcript.append_u64_slice(b"b", &public_commit.b);
+    transcript.append_u64_slice(b"c1", &public_commit.c1);
+    transcript.append_u64_slice(b"c2", &public_commit.c2);
+    transcript
+}
+
+struct Equations<'a> {
+    params: &'a PublicParams,
+    public_commit: &'a PublicCommit,
+}
+
+impl<'a> LinearEquations for Equations<'a> {
+    fn combine(&self, z: Fr, len: usize) -> (Vec<Fr>, Fr) {
+        let layout = Layout::new(self.params);
+        let d = layout.d;
+        let commit = self.public_commit;
+
+        let q = pow2(64);
+        let quotient_offset = Fr::from(quotient_offset(d) as u64);
+        let b1 = pow2(self.params.mask_noise_bound_log2);
+        let b2 = pow2(self.params.body_noise_bound_log2);
+        let delta = Fr::from(self.params.delta());
+
+        let a = commit.a.iter().map(|x| Fr::from(*x)).collect::<Vec<_>>();
+        let b = commit.b.iter().map(|x| Fr::from(*x)).collect::<Vec<_>>();
+
+        let mut row = vec![Fr::ZERO; len];
+        let mut target = Fr::ZERO;
+        // Powers of z, starting at z^2, one per equation
+        let mut z_power = z.square();
+
+        let mut start = 0;
+        let mut body_index = 0;
+        for (bin_index, bin_size) in layout
+            .bin_sizes(commit.c2.len())
+            .enumerate()
+            .collect::<Vec<_>>()
+        {
+            let bin = layout.bin(start, bin_size);
+            let c1 = &commit.c1[bin_index * d..(bin_index + 1) * d];
+            let c2 = &commit.c2[body_index..body_index + bin_size];
+
+            let mask_weights = powers(z, d)
+                .into_iter()
+                .map(|z_k| z_k * z_power)
+                .collect::<Vec<_>>();
+            z_power *= z.pow([d as u64]);
+            let body_weights = powers(z, bin_size)
+                .into_iter()
+                .map(|z_k| z_k * z_power)
+                .collect::<Vec<_>>();
+            z_power *= z.pow([bin_size as u64]);
+
+            // a * r + (e1 + B1) - q (quotient1 + offset) = c1 + B1 - q offset
+            let r_row_mask = combined_product_row(&a, &mask_weights);
+            let r_row_body = combined_product_row(&b, &body_weights);
+            for (t, coefficient) in row[bin.r..bin.r + d].iter_mut().enumerate() {
+                *coefficient += r_row_mask[t] + r_row_body[t];
+            }
+
+            for (k, weight) in mask_weights.iter().enumerate() {
+                bits_weights(
+                    bin.e1 + k * layout.e1_bits,
+                    layout.e1_bits,
+                    *weight,
+                    &mut row,
+                );
+                bits_weights(
+                    bin.quotient1 + k * layout.quotient_bits,
+                    layout.quotient_bits,
+                    -(*weight * q),
+                    &mut row,
+                );
+                target += *weight * (Fr::from(c1[k]) + b1 - q * quotient_offset);
+            }
+
+            // b * r + (e2 + B2) + delta m - q (quotient2 + offset) = c2 + B2 - q offset
+            for (k, weight) in body_weights.iter().enumerate() {
+                bits_weights(
+                    bin.e2 + k * layout.e2_bits,
+                    layout.e2_bits,
+                    *weight,
+                    &mut row,
+                );
+                bits_weights(
+                    bin.m + k * layout.m_bits,
+                    layout.m_bits,
+                    *weight * delta,
+                    &mut row,
+                );
+                bits_weights(
+                    bin.quotient2 + k * layout.quotient_bits,
+                    layout.quotient_bits,
+                    -(*weight * q),
+                    &mut row,
+                );
+                target += *weight * (Fr::from(c2[k]) + b2 - q * quotient_offset);
+            }
+
+            start = bin.end;
+            body_index += bin_size;
+        }
+
+        (row, target)
+    }
+}
+
+/// Decomposes the witness in bits
+///
+/// # Panics
+///
+/// Panics if the witness does not match the public values, or is out of the bounds of the
+/// public parameters.
+fn witness_bits(
+    params: &PublicParams,
+    public_commit: &PublicCommit,
+    private_commit: &PrivateCommit,
+) -> Vec<bool> {
+    let layout = Layout::new(params);
+    let d = layout.d;
+    let num_messages = public_commit.c2.len();
+    let num_bins = layout.num_bins(num_messages);
+
+    assert_eq!(private_commit.r.len(), num_bins * d);
+    assert_eq!(private_commit.e1.len(), num_bins * d);
+    assert_eq!(private_commit.m.len(), num_messages);
+    assert_eq!(private_commit.e2.len(), num_messages);
+    assert!(private_commit.r.iter().all(|r| *r <= 1), "r must be binary");
+
+    let q = 1i128 << 64;
+    let offset = quotient_offset(d) as i128;
+    let b1 = 1i64 << params.mask_noise_bound_log2;
+    let b2 = 1i64 << params.body_noise_bound_log2;
+    let encode_noise = |e: i64, bound: i64| {
+        assert!(
+            -bound <= e && e < bound,
+            "noise {e} is out of the bound {bound}"
+        );
+        (e + bound) as u64
+    };
+    let quotient = |lhs: i128, c: u64| {
+        let diff = lhs - i128::from(c);
+        assert_eq!(diff % q, 0, "the ciphertext does not match the witness");
+        let quotient = diff / q + offset;
+        assert!(0 <= quotient && quotient < 2 * offset);
+        quotient as u64
+    };
+
+    let mut bits = vec![false; layout.bit_count(num_messages)];
+    let mut start = 0;
+    let mut body_index = 0;
+    for (bin_index, bin_size) in layout
+        .bin_sizes(num_messages)
+        .enumerate()
+        .collect::<Vec<_>>()
+    {
+        let bin = layout.bin(start, bin_size);
+        let r = &private_commit.r[bin_index * d..(bin_index + 1) * d];
+        let e1 = &private_commit.e1[bin_index * d..(bin_index + 1) * d];
+        let c1 = &public_commit.c1[bin_index * d..(bin_index + 1) * d];
+        let m = &private_commit.m[body_index..body_index + bin_size];
+        let e2 = &private_commit.e2[body_index..body_index + bin_size];
+        let c2 = &public_commit.c2[body_index..body_index + bin_size];
+
+        for (t, r_t) in r.iter().enumerate() {
+            bits[bin.r + t] = *r_t == 1;
+        }
+
+        let a_r = integer_product(&public_commit.a, r, d);
+        for k in 0..d {
+            push_bits(
+                &mut bits,
+                bin.e1 + k * layout.e1_bits,
+                encode_noise(e1[k], b1),
+                layout.e1_bits,
+            );
+            let quotient1 = quotient(a_r[k] + i128::from(e1[k]), c1[k]);
+            push_bits(
+                &mut bits,
+                bin.quotient1 + k * layout.quotient_bits,
+                quotient1,
+                layout.quotient_bits,
+            );
+        }
+
+        let b_r = integer_product(&public_commit.b, r, bin_size);
+        for k in 0..bin_size {
+            assert!(
+                m[k] < (1 << params.message_bits),
+                "message {} does not fit in {} bits",
+                m[k],
+                params.message_bits
+            );
+            push_bits(
+                &mut bits,
+                bin.e2 + k * layout.e2_bits,
+                encode_noise(e2[k], b2),
+                layout.e2_bits,
+            );
+            push_bits(&mut bits, bin.m + k * layout.m_bits, m[k], layout.m_bits);
+            let lhs = b_r[k] + i128::from(e2[k]) + i128::from(m[k] * params.delta());
+            push_bits(
+                &mut bits,
+                bin.quotient2 + k * layout.quotient_bits,
+                quotient(lhs, c2[k]),
+                layout.quotient_bits,
+            );
+        }
+
+        start = bin.end;
+        body_index += bin_size;
+    }
+
+    bits
+}
+
+/// Proves that the ciphertexts of `public_commit` are well formed
+///
+/// # Panics
+///
+/// Panics if the commits do not match the public parameters, or if the private commit does not
+/// satisfy the relation.
+pub fn prove(
+    public: (&PublicParams, &PublicCommit),
+    private_commit: &PrivateCommit,
+    rng: &mut impl RngCore,
+) -> Proof {
+    let (params, public_commit) = public;
+    assert!(
+        public_commit.is_consistent_with(params),
+        "The public commit does not match the public parameters"
+    );
+
+    let bits = witness_bits(params, public_commit, private_commit);
+    let equations = Equations {
+        params,
+        public_commit,
+    };
+    let proof = binary::prove(
+        &params.generators,
+        &mut transcript(params, public_commit),
+        &bits,
+        &equations,
+        rng,
+    );
+    Proof { proof }
+}
+
+/// Verifies that the ciphertexts of `public_commit` are well formed
+#[allow(clippy::result_unit_err)]
+pub fn verify(proof: &Proof, public: (&PublicParams, &PublicCommit)) -> Result<(), ()> {
+    let (params, public_commit) = public;
+    if !params.is_well_formed() || !public_commit.is_consistent_with(params) {
+        return Err(());
+    }
+
+    let layout = Layout::new(params);
+    let equations = Equations {
+        params,
+        public_commit,
+    };
+    binary::verify(
+        &params.generators,
+        &mut transcript(params, public_commit),
+        layout.bit_count(public_commit.c2.len()),
+        &equations,
+        &proof.proof,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    /// Semi reverse negacyclic product, as used by the compact public key encryption
+    fn product(key: &[u64], r: &[u64]) -> Vec<u64> {
+        integer_product(key, r, key.len())
+            .into_iter()
+            .map(|x| x as u64)
+            .collect()
+    }
+
+    struct TestCase {
+        params: PublicParams,
+        public_commit: PublicCommit,
+        private_commit: PrivateCommit,
+    }
+
+    fn encrypt(
+        params: &PublicParams,
+        messages: &[u64],
+        rng: &mut impl Rng,
+    ) -> (PublicCommit, PrivateCommit) {
+        let d = params.lwe_dimension;
+        let b1 = 1i64 << params.mask_noise_bound_log2;
+        let b2 = 1i64 << params.body_noise_bound_log2;
+        let num_bins = (messages.len() + d - 1) / d;
+
+        let a = (0..d).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+        let b = (0..d).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+
+        let r = (0..num_bins * d)
+            .map(|_| rng.gen_range(0..2))
+            .collect::<Vec<_>>();
+        let e1 = (0..num_bins * d)
+            .map(|_| rng.gen_range(-b1..b1))
+            .collect::<Vec<_>>();
+        let e2 = (0..messages.len())
+            .map(|_| rng.gen_range(-b2..b2))
+            .collect::<Vec<_>>();
+
+        let mut c1 = Vec::new();
+        let mut c2 = Vec::new();
+        for (bin, messages) in messages.chunks(d).enumerate() {
+            let r = &r[bin * d..(bin + 1) * d];
+            let a_r = product(&a, r);
+            let b_r = product(&b, r);
+            c1.extend(
+                a_r.iter()
+                    .zip(&e1[bin * d..(bin + 1) * d])
+                    .map(|(x, e)| x.wrapping_add(*e as u64)),
+            );
+            c2.extend(
+                messages
+                    .iter()
+                    .zip(&b_r)
+                    .zip(&e2[bin * d..bin * d + messages.len()])
+                    .map(|((m, x), e)| x.wrapping_add(*e as u64).wrapping_add(m * params.delta())),
+            );
+        }
+
+        commit(a, b, c1, c2, r, e1, messages.to_vec(), e2)
+    }
+
+    fn test_case(d: usize, max_num_messages: usize, num_messages: usize) -> TestCase {
+        let rng = &mut rand::thread_rng();
+        let params = crs_gen(d, max_num_messages, 2, 2, 20, 40, rng);
+        let messages = (0..num_messages)
+            .map(|_| rng.gen_range(0..4))
+            .collect::<Vec<_>>();
+        let (public_commit, private_commit) = encrypt(&params, &messages, rng);
+        TestCase {
+            params,
+            public_commit,
+            private_commit,
+        }
+    }
+
+    #[test]
+    fn test_pke_proof() {
+        let rng = &mut rand::thread_rng();
+        // Two bins, the last one being partially filled
+        let TestCase {
+            params,
+            public_commit,
+            private_commit,
+        } = test_case(64, 80, 70);
+
+        let proof = prove((&params, &public_commit), &private_commit, rng);
+        assert!(verify(&proof, (&params, &public_commit)).is_ok());
+
+        let serialized = bincode::serialize(&proof).unwrap();
+        let deserialized: Proof = bincode::deserialize(&serialized).unwrap();
+        assert!(verify(&deserialized, (&params, &public_commit)).is_ok());
+
+        let serialized = bincode::serialize(&params).unwrap();
+        let deserialized: PublicParams = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, params);
+
+        // Any change in the ciphertexts or the key invalidates the proof
+        let mut other = public_commit.clone();
+        other.c2[3] = other.c2[3].wrapping_add(1);
+        assert!(verify(&proof, (&params, &other)).is_err());
+
+        let mut other = public_commit.clone();
+        other.c1[65] = other.c1[65].wrapping_add(1);
+        assert!(verify(&proof, (&params, &other)).is_err());
+
+        let mut other = public_commit.clone();
+        other.a[0] = other.a[0].wrapping_add(1);
+        assert!(verify(&proof, (&params, &other)).is_err());
+
+        // The proof is bound to the public parameters, even to the values not used by the
+        // verification equations
+        let mut other_params = params;
+        other_params.max_num_messages = public_commit.c2.len();
+        assert!(other_params.is_well_formed());
+        assert!(verify(&proof, (&other_params, &public_commit)).is_err());
+    }
+
+    #[test]
+    fn test_pke_proof_rejects_invalid_encryptions() {
+        let rng = &mut rand::thread_rng();
+        let TestCase {
+            params,
+            public_commit,
+            private_commit,
+        } = test_case(32, 8, 8);
+        let delta = params.delta();
+
+        // The prover refuses a witness that does not match the ciphertexts
+        let mut wrong_public = public_commit.clone();
+        wrong_public.c2[0] = wrong_public.c2[0].wrapping_add(4 * delta);
+        let result = std::panic::catch_unwind(|| {
+            prove(
+                (&params, &wrong_public),
+                &private_commit,
+                &mut rand::thread_rng(),
+            )
+        });
+        assert!(result.is_err());
+
+        // A consistent witness is also refused when a message overflows into the carries
+        let mut carry_private = private_commit.clone();
+        carry_private.m[0] += 4;
+        let result = std::panic::catch_unwind(|| {
+            prove(
+                (&params, &wrong_public),
+                &carry_private,
+                &mut rand::thread_rng(),
+            )
+        });
+        assert!(result.is_err());
+
+        // A proof for a valid encryption is not valid for the modified one
+        let proof = prove((&params, &public_commit), &private_commit, rng);
+        assert!(verify(&proof, (&params, &wrong_public)).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! Serde adapters for curve types.
+//!
+//! Points and scalars are serialized with their compressed canonical encoding, deserialization
+//! checks that points are on the curve and in the prime order subgroup.
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+fn to_bytes<T: CanonicalSerialize, E: serde::ser::Error>(value: &T) -> Result<Vec<u8>, E> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value.serialize_compressed(&mut bytes).map_err(E::custom)?;
+    Ok(bytes)
+}
+
+/// For a single point or scalar
+pub(crate) mod canonical {
+    use super::*;
+
+    pub(crate) fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: CanonicalSerialize,
+        S: Serializer,
+    {
+        to_bytes::<_, S::Error>(value)?.serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: CanonicalDeserialize,
+        D: Deserializer<'de>,
+    {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        T::deserialize_compressed(bytes.as_slice()).map_err(D::Error::custom)
+    }
+}
+
+/// For a vector of points or scalars
+pub(crate) mod canonical_vec {
+    use super::*;
+
+    pub(crate) fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: CanonicalSerialize,
+        S: Serializer,
+    {
+        let mut bytes = Vec::new();
+        (values.len() as u64)
+            .serialize_compressed(&mut bytes)
+            .map_err(serde::ser::Error::custom)?;
+        for value in values {
+            value
+                .serialize_compressed(&mut bytes)
+                .map_err(serde::ser::Error::custom)?;
+        }
+        bytes.serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: CanonicalDeserialize,
+        D: Deserializer<'de>,
+    {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        let mut reader = bytes.as_slice();
+        let len = u64::deserialize_compressed(&mut reader).map_err(D::Error::custom)?;
+        // Do not trust the length for the allocation, each element takes at least one byte
+        let mut values = Vec::with_capacity((len as usize).min(reader.len()));
+        for _ in 0..len {
+            values.push(T::deserialize_compressed(&mut reader).map_err(D::Error::custom)?);
+        }
+        if !reader.is_empty() {
+            return Err(D::Error::custom("trailing bytes after the last element"));
+        }
+        Ok(values)
+    }
+}
@@ -0,0 +1,87 @@
+//! Fiat-Shamir transcript.
+//!
+//! Every message of the prover is absorbed in a SHAKE256 sponge, challenges are squeezed from
+//! the current state and absorbed back so that later challenges depend on them.
+
+use crate::curve::{scalar_from_wide_bytes, Fr, G1Affine};
+use ark_ff::Zero;
+use ark_serialize::CanonicalSerialize;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+
+#[derive(Clone)]
+pub(crate) struct Transcript {
+    state: Shake256,
+}
+
+impl Transcript {
+    pub(crate) fn new(domain_separator: &[u8]) -> Self {
+        let mut transcript = Self {
+            state: Shake256::default(),
+        };
+        transcript.append_bytes(b"domain", domain_separator);
+        transcript
+    }
+
+    pub(crate) fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.state.update(&(label.len() as u64).to_le_bytes());
+        self.state.update(label);
+        self.state.update(&(bytes.len() as u64).to_le_bytes());
+        self.state.update(bytes);
+    }
+
+    pub(crate) fn append_u64(&mut self, label: &[u8], value: u64) {
+        self.append_bytes(label, &value.to_le_bytes());
+    }
+
+    pub(crate) fn append_u64_slice(&mut self, label: &[u8], values: &[u64]) {
+        let bytes = values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect::<Vec<_>>();
+        self.append_bytes(label, &bytes);
+    }
+
+    pub(crate) fn append_point(&mut self, label: &[u8], point: &G1Affine) {
+        let mut bytes = Vec::with_capacity(point.compressed_size());
+        point.serialize_compressed(&mut bytes).unwrap();
+        self.append_bytes(label, &bytes);
+    }
+
+    pub(crate) fn append_points(&mut self, label: &[u8], points: &[G1Affine]) {
+        let mut bytes = Vec::with_capacity(points.iter().map(|p| p.compressed_size()).sum());
+        for point in points {
+            point.serialize_compressed(&mut bytes).unwrap();
+        }
+        self.append_bytes(label, &bytes);
+    }
+
+    pub(crate) fn append_scalar(&mut self, label: &[u8], scalar: &Fr) {
+        let mut bytes = Vec::with_capacity(scalar.compressed_size());
+        scalar.serialize_compressed(&mut bytes).unwrap();
+        self.append_bytes(label, &bytes);
+    }
+
+    /// Returns a digest of everything absorbed so far
+    pub(crate) fn digest(&self) -> [u8; 32] {
+        let mut digest = [0u8; 32];
+        self.state.clone().finalize_xof().read(&mut digest);
+        digest
+    }
+
+    /// Returns a non-zero challenge, so that it can always be inverted
+    pub(crate) fn challenge_scalar(&mut self, label: &[u8]) -> Fr {
+        loop {
+            self.append_bytes(b"challenge", label);
+
+            let mut bytes = [0u8; 64];
+            self.state.clone().finalize_xof().read(&mut bytes);
+            let challenge = scalar_from_wide_bytes(&bytes);
+
+            self.append_scalar(label, &challenge);
+            if !challenge.is_zero() {
+                return challenge;
+            }
+        }
+    }
+}
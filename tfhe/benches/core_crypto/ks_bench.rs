@@ -20,7 +20,7 @@ fn criterion_bench(criterion: &mut Criterion) {
     .into_iter()
     {
         let lwe_dimension = params.lwe_dimension;
-        let lwe_modular_std_dev = params.lwe_modular_std_dev;
+        let lwe_noise_distribution = params.lwe_noise_distribution;
         let ciphertext_modulus = params.ciphertext_modulus;
         let encoding_with_padding = if ciphertext_modulus.is_native_modulus() {
             Scalar::ONE << (Scalar::BITS - 1)
@@ -59,7 +59,7 @@ fn criterion_bench(criterion: &mut Criterion) {
             &lwe_sk,
             ks_decomp_base_log,
             ks_decomp_level_count,
-            lwe_modular_std_dev,
+            lwe_noise_distribution,
             ciphertext_modulus,
             &mut encryption_generator,
         );
@@ -68,7 +68,7 @@ fn criterion_bench(criterion: &mut Criterion) {
         let ct = allocate_and_encrypt_new_lwe_ciphertext(
             &big_lwe_sk,
             plaintext,
-            lwe_modular_std_dev,
+            lwe_noise_distribution,
             ciphertext_modulus,
             &mut encryption_generator,
         );
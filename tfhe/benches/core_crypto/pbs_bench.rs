@@ -185,7 +185,7 @@ fn mem_optimized_pbs<Scalar: UnsignedTorus + CastInto<usize> + Serialize>(c: &mu
         let lwe_ciphertext_in: LweCiphertextOwned<Scalar> = allocate_and_encrypt_new_lwe_ciphertext(
             &input_lwe_secret_key,
             Plaintext(Scalar::ZERO),
-            params.lwe_modular_std_dev.unwrap(),
+            params.lwe_noise_distribution.unwrap(),
             tfhe::core_crypto::prelude::CiphertextModulus::new_native(),
             &mut encryption_generator,
         );
@@ -292,7 +292,7 @@ fn multi_bit_pbs<
         let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
             &input_lwe_secret_key,
             Plaintext(Scalar::ZERO),
-            params.lwe_modular_std_dev.unwrap(),
+            params.lwe_noise_distribution.unwrap(),
             tfhe::core_crypto::prelude::CiphertextModulus::new_native(),
             &mut encryption_generator,
         );
@@ -381,7 +381,7 @@ fn multi_bit_deterministic_pbs<
         let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
             &input_lwe_secret_key,
             Plaintext(Scalar::ZERO),
-            params.lwe_modular_std_dev.unwrap(),
+            params.lwe_noise_distribution.unwrap(),
             tfhe::core_crypto::prelude::CiphertextModulus::new_native(),
             &mut encryption_generator,
         );
@@ -461,7 +461,7 @@ fn pbs_throughput<Scalar: UnsignedTorus + CastInto<usize> + Sync + Send + Serial
                 allocate_and_encrypt_new_lwe_ciphertext(
                     &input_lwe_secret_key,
                     Plaintext(Scalar::ZERO),
-                    params.lwe_modular_std_dev.unwrap(),
+                    params.lwe_noise_distribution.unwrap(),
                     tfhe::core_crypto::prelude::CiphertextModulus::new_native(),
                     &mut encryption_generator,
                 )
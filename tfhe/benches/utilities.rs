@@ -14,8 +14,8 @@ pub struct CryptoParametersRecord<Scalar: UnsignedInteger> {
     pub lwe_dimension: Option<LweDimension>,
     pub glwe_dimension: Option<GlweDimension>,
     pub polynomial_size: Option<PolynomialSize>,
-    pub lwe_modular_std_dev: Option<StandardDev>,
-    pub glwe_modular_std_dev: Option<StandardDev>,
+    pub lwe_noise_distribution: Option<DynamicDistribution>,
+    pub glwe_noise_distribution: Option<DynamicDistribution>,
     pub pbs_base_log: Option<DecompositionBaseLog>,
     pub pbs_level: Option<DecompositionLevelCount>,
     pub ks_base_log: Option<DecompositionBaseLog>,
@@ -37,8 +37,8 @@ impl<Scalar: UnsignedInteger> From<BooleanParameters> for CryptoParametersRecord
             lwe_dimension: Some(params.lwe_dimension),
             glwe_dimension: Some(params.glwe_dimension),
             polynomial_size: Some(params.polynomial_size),
-            lwe_modular_std_dev: Some(params.lwe_modular_std_dev),
-            glwe_modular_std_dev: Some(params.glwe_modular_std_dev),
+            lwe_noise_distribution: Some(params.lwe_noise_distribution),
+            glwe_noise_distribution: Some(params.glwe_noise_distribution),
             pbs_base_log: Some(params.pbs_base_log),
             pbs_level: Some(params.pbs_level),
             ks_base_log: Some(params.ks_base_log),
@@ -65,8 +65,8 @@ where
             lwe_dimension: Some(params.lwe_dimension()),
             glwe_dimension: Some(params.glwe_dimension()),
             polynomial_size: Some(params.polynomial_size()),
-            lwe_modular_std_dev: Some(params.lwe_modular_std_dev()),
-            glwe_modular_std_dev: Some(params.glwe_modular_std_dev()),
+            lwe_noise_distribution: Some(params.lwe_noise_distribution()),
+            glwe_noise_distribution: Some(params.glwe_noise_distribution()),
             pbs_base_log: Some(params.pbs_base_log()),
             pbs_level: Some(params.pbs_level()),
             ks_base_log: Some(params.ks_base_log()),
@@ -97,8 +97,8 @@ impl<Scalar: UnsignedInteger> From<ShortintKeySwitchingParameters>
             lwe_dimension: None,
             glwe_dimension: None,
             polynomial_size: None,
-            lwe_modular_std_dev: None,
-            glwe_modular_std_dev: None,
+            lwe_noise_distribution: None,
+            glwe_noise_distribution: None,
             pbs_base_log: None,
             pbs_level: None,
             ks_base_log: Some(params.ks_base_log),
@@ -47,6 +47,8 @@ fn gen_c_api() {
         "boolean-c-api",
         #[cfg(feature = "shortint-c-api")]
         "shortint-c-api",
+        #[cfg(feature = "integer-c-api")]
+        "integer-c-api",
         #[cfg(feature = "high-level-c-api")]
         "high-level-c-api",
         #[cfg(feature = "boolean")]
@@ -55,6 +57,8 @@ fn gen_c_api() {
         "shortint",
         #[cfg(feature = "integer")]
         "integer",
+        #[cfg(feature = "safe-deserialization")]
+        "safe-deserialization",
     ];
 
     let parse_expand_vec = if parse_expand_features_vec.is_empty() {
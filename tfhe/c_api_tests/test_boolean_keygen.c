@@ -99,8 +99,8 @@ void test_custom_keygen(void) {
       .lwe_dimension = 10,
       .glwe_dimension = 1,
       .polynomial_size = 1024,
-      .lwe_modular_std_dev = 10e-100,
-      .glwe_modular_std_dev = 10e-100,
+      .lwe_noise_distribution = new_gaussian_from_std_dev(10e-100),
+      .glwe_noise_distribution = new_gaussian_from_std_dev(10e-100),
       .pbs_base_log = 3,
       .pbs_level = 1,
       .ks_base_log = 4,
@@ -0,0 +1,217 @@
+#include <tfhe.h>
+
+#include <assert.h>
+#include <inttypes.h>
+#include <stdio.h>
+
+int heterogeneous_compact_list(const ClientKey *client_key, const ServerKey *server_key,
+                               const CompactPublicKey *public_key) {
+  int ok;
+  CompactCiphertextListBuilder *builder = NULL;
+  CompactCiphertextList *list = NULL;
+  CompactCiphertextList *deserialized_list = NULL;
+  CompactCiphertextListExpander *expander = NULL;
+  Buffer value_buffer = {.pointer = NULL, .length = 0};
+  BufferView deser_view = {.pointer = NULL, .length = 0};
+  FheUint32 *a = NULL;
+  FheInt64 *b = NULL;
+  FheBool *c = NULL;
+  FheUint2 *d = NULL;
+  FheUint8 *wrong_type = NULL;
+
+  const uint64_t max_serialization_size = UINT64_C(1) << UINT64_C(20);
+
+  ok = compact_ciphertext_list_builder_new(public_key, &builder);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_push_u32(builder, 38382);
+  assert(ok == 0);
+  ok = compact_ciphertext_list_builder_push_i64(builder, -1);
+  assert(ok == 0);
+  ok = compact_ciphertext_list_builder_push_bool(builder, true);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_builder_build(builder, &list);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_safe_serialize(list, &value_buffer, max_serialization_size);
+  assert(ok == 0);
+
+  deser_view.pointer = value_buffer.pointer;
+  deser_view.length = value_buffer.length;
+
+  // The list holds 3 values, so it is not conformant with a 4 to 10 values constraint
+  ok = compact_ciphertext_list_safe_deserialize_conformant(deser_view, max_serialization_size,
+                                                           server_key, 4, 10, &deserialized_list);
+  assert(ok != 0);
+  assert(deserialized_list == NULL);
+
+  ok = compact_ciphertext_list_safe_deserialize_conformant(deser_view, max_serialization_size,
+                                                           server_key, 3, 3, &deserialized_list);
+  assert(ok == 0);
+
+  size_t len = 0;
+  ok = compact_ciphertext_list_len(deserialized_list, &len);
+  assert(ok == 0);
+  assert(len == 3);
+
+  FheTypes kind;
+  ok = compact_ciphertext_list_get_kind_of(deserialized_list, 1, &kind);
+  assert(ok == 0);
+  assert(kind == Type_FheInt64);
+
+  ok = compact_ciphertext_list_get_kind_of(deserialized_list, 3, &kind);
+  assert(ok != 0);
+
+  ok = compact_ciphertext_list_expand(deserialized_list, &expander);
+  assert(ok == 0);
+
+  ok = compact_ciphertext_list_expander_len(expander, &len);
+  assert(ok == 0);
+  assert(len == 3);
+
+  ok = compact_ciphertext_list_expander_get_kind_of(expander, 2, &kind);
+  assert(ok == 0);
+  assert(kind == Type_FheBool);
+
+  ok = compact_ciphertext_list_expander_get_fhe_uint32(expander, 0, &a);
+  assert(ok == 0);
+  ok = compact_ciphertext_list_expander_get_fhe_int64(expander, 1, &b);
+  assert(ok == 0);
+  ok = compact_ciphertext_list_expander_get_fhe_bool(expander, 2, &c);
+  assert(ok == 0);
+
+  // Wrong type
+  ok = compact_ciphertext_list_expander_get_fhe_uint8(expander, 0, &wrong_type);
+  assert(ok != 0);
+  assert(wrong_type == NULL);
+
+  // Out of bounds
+  ok = compact_ciphertext_list_expander_get_fhe_uint8(expander, 3, &wrong_type);
+  assert(ok != 0);
+
+  uint32_t clear_a = 0;
+  ok = fhe_uint32_decrypt(a, client_key, &clear_a);
+  assert(ok == 0);
+  assert(clear_a == 38382);
+
+  int64_t clear_b = 0;
+  ok = fhe_int64_decrypt(b, client_key, &clear_b);
+  assert(ok == 0);
+  assert(clear_b == -1);
+
+  bool clear_c = false;
+  ok = fhe_bool_decrypt(c, client_key, &clear_c);
+  assert(ok == 0);
+  assert(clear_c == true);
+
+  // Casting a FheBool to a small integer type
+  ok = fhe_bool_cast_into_fhe_uint2(c, &d);
+  assert(ok == 0);
+
+  uint8_t clear_d = 0;
+  ok = fhe_uint2_decrypt(d, client_key, &clear_d);
+  assert(ok == 0);
+  assert(clear_d == 1);
+
+  destroy_buffer(&value_buffer);
+  fhe_uint32_destroy(a);
+  fhe_int64_destroy(b);
+  fhe_bool_destroy(c);
+  fhe_uint2_destroy(d);
+  compact_ciphertext_list_expander_destroy(expander);
+  compact_ciphertext_list_destroy(deserialized_list);
+  compact_ciphertext_list_destroy(list);
+  compact_ciphertext_list_builder_destroy(builder);
+  return ok;
+}
+
+int int128_compact_list_safe_serialization(const ClientKey *client_key,
+                                           const ServerKey *server_key,
+                                           const CompactPublicKey *public_key) {
+  int ok;
+  CompactFheInt128List *list = NULL;
+  CompactFheInt128List *deserialized_list = NULL;
+  CompactFheInt16List *wrong_type_list = NULL;
+  FheInt128 *expand_output[3] = {NULL};
+  Buffer value_buffer = {.pointer = NULL, .length = 0};
+  BufferView deser_view = {.pointer = NULL, .length = 0};
+
+  const uint64_t max_serialization_size = UINT64_C(1) << UINT64_C(25);
+
+  // -1, 2^64 and -2^64
+  I128 clears[3] = {{UINT64_MAX, UINT64_MAX}, {0, 1}, {0, UINT64_MAX}};
+
+  ok = compact_fhe_int128_list_try_encrypt_with_compact_public_key_i128(&clears[0], 3, public_key,
+                                                                        &list);
+  assert(ok == 0);
+
+  ok = compact_fhe_int128_list_safe_serialize(list, &value_buffer, max_serialization_size);
+  assert(ok == 0);
+
+  deser_view.pointer = value_buffer.pointer;
+  deser_view.length = value_buffer.length;
+
+  // The list does not hold FheInt16
+  ok = compact_fhe_int16_list_safe_deserialize_conformant(deser_view, max_serialization_size,
+                                                          server_key, 3, 3, &wrong_type_list);
+  assert(ok != 0);
+
+  ok = compact_fhe_int128_list_safe_deserialize_conformant(deser_view, max_serialization_size,
+                                                           server_key, 1, 3, &deserialized_list);
+  assert(ok == 0);
+
+  ok = compact_fhe_int128_list_expand(deserialized_list, &expand_output[0], 3);
+  assert(ok == 0);
+
+  for (size_t i = 0; i < 3; ++i) {
+    I128 clear = {0, 0};
+    ok = fhe_int128_decrypt(expand_output[i], client_key, &clear);
+    assert(ok == 0);
+    assert(clear.w0 == clears[i].w0);
+    assert(clear.w1 == clears[i].w1);
+
+    fhe_int128_destroy(expand_output[i]);
+  }
+
+  destroy_buffer(&value_buffer);
+  compact_fhe_int128_list_destroy(list);
+  compact_fhe_int128_list_destroy(deserialized_list);
+  return ok;
+}
+
+int main(void) {
+  int ok = 0;
+  ConfigBuilder *builder;
+  Config *config;
+
+  ok = config_builder_default(&builder);
+  assert(ok == 0);
+  ok = config_builder_use_custom_parameters(&builder,
+                                            SHORTINT_PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+  assert(ok == 0);
+  ok = config_builder_build(builder, &config);
+  assert(ok == 0);
+
+  ClientKey *client_key = NULL;
+  ServerKey *server_key = NULL;
+  CompactPublicKey *public_key = NULL;
+
+  ok = generate_keys(config, &client_key, &server_key);
+  assert(ok == 0);
+  ok = compact_public_key_new(client_key, &public_key);
+  assert(ok == 0);
+
+  ok = set_server_key(server_key);
+  assert(ok == 0);
+
+  ok = heterogeneous_compact_list(client_key, server_key, public_key);
+  assert(ok == 0);
+  ok = int128_compact_list_safe_serialization(client_key, server_key, public_key);
+  assert(ok == 0);
+
+  client_key_destroy(client_key);
+  compact_public_key_destroy(public_key);
+  server_key_destroy(server_key);
+  return ok;
+}
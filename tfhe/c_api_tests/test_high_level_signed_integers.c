@@ -0,0 +1,285 @@
+#include <tfhe.h>
+
+#include <assert.h>
+#include <inttypes.h>
+#include <stdio.h>
+#include <string.h>
+
+int int8_overflowing_ops(const ClientKey *client_key) {
+  int ok;
+  FheInt8 *lhs = NULL;
+  FheInt8 *rhs = NULL;
+  FheInt8 *result = NULL;
+  FheBool *overflowed = NULL;
+
+  int8_t lhs_clear = INT8_MAX;
+  int8_t rhs_clear = 2;
+
+  ok = fhe_int8_try_encrypt_with_client_key_i8(lhs_clear, client_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_int8_try_encrypt_with_client_key_i8(rhs_clear, client_key, &rhs);
+  assert(ok == 0);
+
+  ok = fhe_int8_overflowing_add(lhs, rhs, &result, &overflowed);
+  assert(ok == 0);
+
+  int8_t clear;
+  bool clear_overflowed;
+  ok = fhe_int8_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  ok = fhe_bool_decrypt(overflowed, client_key, &clear_overflowed);
+  assert(ok == 0);
+
+  assert(clear == (int8_t)((uint8_t)lhs_clear + (uint8_t)rhs_clear));
+  assert(clear_overflowed == true);
+
+  fhe_int8_destroy(result);
+  fhe_bool_destroy(overflowed);
+
+  ok = fhe_int8_overflowing_sub(lhs, rhs, &result, &overflowed);
+  assert(ok == 0);
+
+  ok = fhe_int8_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  ok = fhe_bool_decrypt(overflowed, client_key, &clear_overflowed);
+  assert(ok == 0);
+
+  assert(clear == lhs_clear - rhs_clear);
+  assert(clear_overflowed == false);
+
+  fhe_int8_destroy(result);
+  fhe_bool_destroy(overflowed);
+
+  ok = fhe_int8_overflowing_mul(lhs, rhs, &result, &overflowed);
+  assert(ok == 0);
+
+  ok = fhe_int8_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  ok = fhe_bool_decrypt(overflowed, client_key, &clear_overflowed);
+  assert(ok == 0);
+
+  assert(clear == (int8_t)((uint8_t)lhs_clear * (uint8_t)rhs_clear));
+  assert(clear_overflowed == true);
+
+  fhe_int8_destroy(lhs);
+  fhe_int8_destroy(rhs);
+  fhe_int8_destroy(result);
+  fhe_bool_destroy(overflowed);
+  return ok;
+}
+
+int int16_abs_min_max_rotate(const ClientKey *client_key) {
+  int ok;
+  FheInt16 *lhs = NULL;
+  FheInt16 *rhs = NULL;
+  FheInt16 *result = NULL;
+  FheUint16 *amount = NULL;
+
+  int16_t lhs_clear = -1234;
+  int16_t rhs_clear = 567;
+  int16_t clear;
+
+  ok = fhe_int16_try_encrypt_with_client_key_i16(lhs_clear, client_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_int16_try_encrypt_with_client_key_i16(rhs_clear, client_key, &rhs);
+  assert(ok == 0);
+
+  ok = fhe_int16_abs(lhs, &result);
+  assert(ok == 0);
+  ok = fhe_int16_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  assert(clear == 1234);
+  fhe_int16_destroy(result);
+
+  ok = fhe_int16_min(lhs, rhs, &result);
+  assert(ok == 0);
+  ok = fhe_int16_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  assert(clear == lhs_clear);
+  fhe_int16_destroy(result);
+
+  ok = fhe_int16_scalar_max(lhs, -5, &result);
+  assert(ok == 0);
+  ok = fhe_int16_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  assert(clear == -5);
+  fhe_int16_destroy(result);
+
+  // The rotation amount of a signed type is unsigned
+  ok = fhe_uint16_try_encrypt_with_client_key_u16(4, client_key, &amount);
+  assert(ok == 0);
+
+  ok = fhe_int16_rotate_left(rhs, amount, &result);
+  assert(ok == 0);
+  ok = fhe_int16_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  uint16_t expected = ((uint16_t)rhs_clear << 4) | ((uint16_t)rhs_clear >> 12);
+  assert(clear == (int16_t)expected);
+
+  fhe_int16_destroy(lhs);
+  fhe_int16_destroy(rhs);
+  fhe_int16_destroy(result);
+  fhe_uint16_destroy(amount);
+  return ok;
+}
+
+int int32_casting_and_leading_zeros(const ClientKey *client_key) {
+  int ok;
+  FheInt32 *value = NULL;
+  FheUint12 *casted = NULL;
+  FheInt128 *extended = NULL;
+  FheUint32 *count = NULL;
+
+  int32_t clear = -2;
+
+  ok = fhe_int32_try_encrypt_with_client_key_i32(clear, client_key, &value);
+  assert(ok == 0);
+
+  ok = fhe_int32_cast_into_fhe_uint12(value, &casted);
+  assert(ok == 0);
+
+  uint16_t clear_casted;
+  ok = fhe_uint12_decrypt(casted, client_key, &clear_casted);
+  assert(ok == 0);
+  assert(clear_casted == (((uint16_t)clear) & 0x0FFF));
+
+  // Sign extension
+  ok = fhe_int32_cast_into_fhe_int128(value, &extended);
+  assert(ok == 0);
+
+  I128 clear_extended;
+  ok = fhe_int128_decrypt(extended, client_key, &clear_extended);
+  assert(ok == 0);
+  assert(clear_extended.w0 == (uint64_t)(int64_t)clear);
+  assert(clear_extended.w1 == UINT64_MAX);
+
+  ok = fhe_uint12_leading_zeros(casted, &count);
+  assert(ok == 0);
+
+  uint32_t clear_count;
+  ok = fhe_uint32_decrypt(count, client_key, &clear_count);
+  assert(ok == 0);
+  assert(clear_count == 0);
+
+  fhe_int32_destroy(value);
+  fhe_uint12_destroy(casted);
+  fhe_int128_destroy(extended);
+  fhe_uint32_destroy(count);
+  return ok;
+}
+
+int int8_safe_serialization(const ClientKey *client_key, const ServerKey *server_key) {
+  int ok;
+  FheInt8 *value = NULL;
+  FheInt8 *deserialized_value = NULL;
+  FheInt16 *wrong_type_value = NULL;
+  Buffer value_buffer = {.pointer = NULL, .length = 0};
+  BufferView deser_view = {.pointer = NULL, .length = 0};
+
+  const uint64_t max_serialization_size = UINT64_C(1) << UINT64_C(20);
+
+  int8_t clear = -42;
+
+  ok = fhe_int8_try_encrypt_with_client_key_i8(clear, client_key, &value);
+  assert(ok == 0);
+
+  ok = fhe_int8_safe_serialize(value, &value_buffer, max_serialization_size);
+  assert(ok == 0);
+
+  deser_view.pointer = value_buffer.pointer;
+  deser_view.length = value_buffer.length;
+
+  // The number of blocks does not match a FheInt16
+  ok = fhe_int16_safe_deserialize_conformant(deser_view, max_serialization_size, server_key,
+                                             &wrong_type_value);
+  assert(ok != 0);
+
+  ok = fhe_int8_safe_deserialize_conformant(deser_view, max_serialization_size, server_key,
+                                            &deserialized_value);
+  assert(ok == 0);
+
+  int8_t decrypted;
+  ok = fhe_int8_decrypt(deserialized_value, client_key, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == clear);
+
+  destroy_buffer(&value_buffer);
+  fhe_int8_destroy(value);
+  fhe_int8_destroy(deserialized_value);
+  return ok;
+}
+
+int i128_and_u128_bytes(void) {
+  int ok;
+  uint8_t bytes[16];
+  uint8_t round_trip[16];
+
+  for (size_t i = 0; i < 16; ++i) {
+    bytes[i] = (uint8_t)i;
+  }
+
+  U128 u;
+  ok = u128_from_little_endian_bytes(bytes, 16, &u);
+  assert(ok == 0);
+  assert(u.w0 == UINT64_C(0x0706050403020100));
+  assert(u.w1 == UINT64_C(0x0F0E0D0C0B0A0908));
+
+  ok = u128_big_endian_bytes(u, round_trip, 16);
+  assert(ok == 0);
+  for (size_t i = 0; i < 16; ++i) {
+    assert(round_trip[i] == bytes[15 - i]);
+  }
+
+  I128 value;
+  ok = i128_from_big_endian_bytes(bytes, 16, &value);
+  assert(ok == 0);
+  assert(value.w0 == UINT64_C(0x08090A0B0C0D0E0F));
+  assert(value.w1 == UINT64_C(0x0001020304050607));
+
+  ok = i128_big_endian_bytes(value, round_trip, 16);
+  assert(ok == 0);
+  assert(memcmp(bytes, round_trip, 16) == 0);
+
+  // Wrong length
+  ok = i128_from_little_endian_bytes(bytes, 15, &value);
+  assert(ok != 0);
+
+  return 0;
+}
+
+int main(void) {
+  int ok = 0;
+  ConfigBuilder *builder;
+  Config *config;
+
+  ok = config_builder_default(&builder);
+  assert(ok == 0);
+  ok = config_builder_build(builder, &config);
+  assert(ok == 0);
+
+  ClientKey *client_key = NULL;
+  ServerKey *server_key = NULL;
+
+  ok = generate_keys(config, &client_key, &server_key);
+  assert(ok == 0);
+
+  ok = set_server_key(server_key);
+  assert(ok == 0);
+
+  ok = int8_overflowing_ops(client_key);
+  assert(ok == 0);
+  ok = int16_abs_min_max_rotate(client_key);
+  assert(ok == 0);
+  ok = int32_casting_and_leading_zeros(client_key);
+  assert(ok == 0);
+  ok = int8_safe_serialization(client_key, server_key);
+  assert(ok == 0);
+  ok = i128_and_u128_bytes();
+  assert(ok == 0);
+
+  client_key_destroy(client_key);
+  server_key_destroy(server_key);
+  return ok;
+}
@@ -0,0 +1,242 @@
+#include "tfhe.h"
+#include <assert.h>
+#include <inttypes.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+// With 2 bits of message per block, 10 blocks give a 20 bits integer
+#define NUM_BLOCKS 10
+#define MESSAGE_MASK ((UINT64_C(1) << 20) - 1)
+
+void test_radix_arithmetic(const IntegerClientKey *cks, const IntegerServerKey *sks) {
+  IntegerRadixCiphertext *lhs = NULL;
+  IntegerRadixCiphertext *rhs = NULL;
+  IntegerRadixCiphertext *result = NULL;
+  uint64_t lhs_clear = 838383;
+  uint64_t rhs_clear = 1024;
+  uint64_t decrypted = 0;
+
+  int ok = integer_client_key_encrypt_radix(cks, lhs_clear, NUM_BLOCKS, &lhs);
+  assert(ok == 0);
+  ok = integer_client_key_encrypt_radix(cks, rhs_clear, NUM_BLOCKS, &rhs);
+  assert(ok == 0);
+
+  size_t num_blocks = 0;
+  ok = integer_radix_ciphertext_num_blocks(lhs, &num_blocks);
+  assert(ok == 0);
+  assert(num_blocks == NUM_BLOCKS);
+
+  ok = integer_server_key_radix_add(sks, lhs, rhs, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == ((lhs_clear + rhs_clear) & MESSAGE_MASK));
+  integer_destroy_radix_ciphertext(result);
+
+  ok = integer_server_key_radix_mul(sks, lhs, rhs, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == ((lhs_clear * rhs_clear) & MESSAGE_MASK));
+  integer_destroy_radix_ciphertext(result);
+
+  ok = integer_server_key_radix_bitxor(sks, lhs, rhs, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == (lhs_clear ^ rhs_clear));
+  integer_destroy_radix_ciphertext(result);
+
+  ok = integer_server_key_radix_scalar_sub(sks, lhs, 3, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == lhs_clear - 3);
+  integer_destroy_radix_ciphertext(result);
+
+  ok = integer_server_key_radix_scalar_rotate_left(sks, lhs, 4, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == (((lhs_clear << 4) | (lhs_clear >> 16)) & MESSAGE_MASK));
+
+  integer_destroy_radix_ciphertext(lhs);
+  integer_destroy_radix_ciphertext(rhs);
+  integer_destroy_radix_ciphertext(result);
+}
+
+void test_radix_comparisons(const IntegerClientKey *cks, const IntegerServerKey *sks) {
+  IntegerRadixCiphertext *lhs = NULL;
+  IntegerRadixCiphertext *rhs = NULL;
+  IntegerRadixCiphertext *result = NULL;
+  IntegerBooleanBlock *condition = NULL;
+  uint64_t lhs_clear = 4096;
+  uint64_t rhs_clear = 77;
+  uint64_t decrypted = 0;
+  bool decrypted_bool = false;
+
+  int ok = integer_client_key_encrypt_radix(cks, lhs_clear, NUM_BLOCKS, &lhs);
+  assert(ok == 0);
+  ok = integer_client_key_encrypt_radix(cks, rhs_clear, NUM_BLOCKS, &rhs);
+  assert(ok == 0);
+
+  ok = integer_server_key_radix_lt(sks, lhs, rhs, &condition);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_bool(cks, condition, &decrypted_bool);
+  assert(ok == 0);
+  assert(decrypted_bool == false);
+  integer_destroy_boolean_block(condition);
+
+  ok = integer_server_key_radix_scalar_eq(sks, lhs, lhs_clear, &condition);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_bool(cks, condition, &decrypted_bool);
+  assert(ok == 0);
+  assert(decrypted_bool == true);
+
+  ok = integer_server_key_radix_if_then_else(sks, condition, rhs, lhs, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == rhs_clear);
+  integer_destroy_radix_ciphertext(result);
+
+  ok = integer_server_key_boolean_block_into_radix(sks, condition, 3, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == 1);
+
+  // Operands with a different number of blocks are rejected
+  IntegerRadixCiphertext *short_ct = NULL;
+  IntegerRadixCiphertext *bad_result = NULL;
+  ok = integer_client_key_encrypt_radix(cks, 1, 2, &short_ct);
+  assert(ok == 0);
+  ok = integer_server_key_radix_max(sks, lhs, short_ct, &bad_result);
+  assert(ok != 0);
+
+  integer_destroy_radix_ciphertext(lhs);
+  integer_destroy_radix_ciphertext(rhs);
+  integer_destroy_radix_ciphertext(result);
+  integer_destroy_radix_ciphertext(short_ct);
+  integer_destroy_boolean_block(condition);
+}
+
+void test_signed_radix_and_casting(const IntegerClientKey *cks, const IntegerServerKey *sks) {
+  IntegerSignedRadixCiphertext *lhs = NULL;
+  IntegerSignedRadixCiphertext *rhs = NULL;
+  IntegerSignedRadixCiphertext *result = NULL;
+  IntegerSignedRadixCiphertext *extended = NULL;
+  IntegerRadixCiphertext *as_unsigned = NULL;
+  int64_t lhs_clear = -300;
+  int64_t rhs_clear = 7;
+  int64_t decrypted = 0;
+
+  // 6 blocks give a 12 bits signed integer
+  int ok = integer_client_key_encrypt_signed_radix(cks, lhs_clear, 6, &lhs);
+  assert(ok == 0);
+  ok = integer_client_key_encrypt_signed_radix(cks, rhs_clear, 6, &rhs);
+  assert(ok == 0);
+
+  ok = integer_server_key_signed_radix_div(sks, lhs, rhs, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_signed_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == lhs_clear / rhs_clear);
+  integer_destroy_signed_radix_ciphertext(result);
+
+  ok = integer_server_key_signed_radix_abs(sks, lhs, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_signed_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == -lhs_clear);
+  integer_destroy_signed_radix_ciphertext(result);
+
+  ok = integer_server_key_signed_radix_scalar_mul(sks, lhs, -2, &result);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_signed_radix(cks, result, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == lhs_clear * -2);
+
+  // Sign extension to 32 bits
+  ok = integer_server_key_signed_radix_cast_into_signed_radix(sks, lhs, 16, &extended);
+  assert(ok == 0);
+  ok = integer_client_key_decrypt_signed_radix(cks, extended, &decrypted);
+  assert(ok == 0);
+  assert(decrypted == lhs_clear);
+
+  // Reinterpretation as a 32 bits unsigned integer
+  ok = integer_server_key_signed_radix_cast_into_radix(sks, extended, 16, &as_unsigned);
+  assert(ok == 0);
+  uint64_t decrypted_unsigned = 0;
+  ok = integer_client_key_decrypt_radix(cks, as_unsigned, &decrypted_unsigned);
+  assert(ok == 0);
+  assert(decrypted_unsigned == (uint64_t)(uint32_t)(int32_t)lhs_clear);
+
+  integer_destroy_signed_radix_ciphertext(lhs);
+  integer_destroy_signed_radix_ciphertext(rhs);
+  integer_destroy_signed_radix_ciphertext(result);
+  integer_destroy_signed_radix_ciphertext(extended);
+  integer_destroy_radix_ciphertext(as_unsigned);
+}
+
+void test_wide_radix_with_serde(const IntegerClientKey *cks, const IntegerServerKey *sks) {
+  IntegerRadixCiphertext *ct = NULL;
+  IntegerRadixCiphertext *deser_ct = NULL;
+  IntegerRadixCiphertext *result = NULL;
+  IntegerServerKey *deser_sks = NULL;
+  Buffer ct_buffer = {.pointer = NULL, .length = 0};
+  Buffer sks_buffer = {.pointer = NULL, .length = 0};
+
+  // 40 blocks give an 80 bits integer
+  uint64_t words[2] = {UINT64_MAX, 0xABCD};
+  uint64_t decrypted_words[2] = {0, 0};
+
+  int ok = integer_client_key_encrypt_radix_words(cks, words, 2, 40, &ct);
+  assert(ok == 0);
+
+  ok = integer_serialize_radix_ciphertext(ct, &ct_buffer);
+  assert(ok == 0);
+  BufferView ct_view = {.pointer = ct_buffer.pointer, .length = ct_buffer.length};
+  ok = integer_deserialize_radix_ciphertext(ct_view, &deser_ct);
+  assert(ok == 0);
+
+  ok = integer_serialize_server_key(sks, &sks_buffer);
+  assert(ok == 0);
+  BufferView sks_view = {.pointer = sks_buffer.pointer, .length = sks_buffer.length};
+  ok = integer_deserialize_server_key(sks_view, &deser_sks);
+  assert(ok == 0);
+
+  // UINT64_MAX + 1 carries into the second word
+  ok = integer_server_key_radix_scalar_add(deser_sks, deser_ct, 1, &result);
+  assert(ok == 0);
+
+  ok = integer_client_key_decrypt_radix_words(cks, result, decrypted_words, 2);
+  assert(ok == 0);
+  assert(decrypted_words[0] == 0);
+  assert(decrypted_words[1] == 0xABCE);
+
+  destroy_buffer(&ct_buffer);
+  destroy_buffer(&sks_buffer);
+  integer_destroy_radix_ciphertext(ct);
+  integer_destroy_radix_ciphertext(deser_ct);
+  integer_destroy_radix_ciphertext(result);
+  integer_destroy_server_key(deser_sks);
+}
+
+int main(void) {
+  IntegerClientKey *cks = NULL;
+  IntegerServerKey *sks = NULL;
+  ShortintPBSParameters params = SHORTINT_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+  int ok = integer_gen_keys_with_parameters(params, &cks, &sks);
+  assert(ok == 0);
+
+  test_radix_arithmetic(cks, sks);
+  test_radix_comparisons(cks, sks);
+  test_signed_radix_and_casting(cks, sks);
+  test_wide_radix_with_serde(cks, sks);
+
+  integer_destroy_client_key(cks);
+  integer_destroy_server_key(sks);
+  return EXIT_SUCCESS;
+}
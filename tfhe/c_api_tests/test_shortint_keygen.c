@@ -102,8 +102,8 @@ void test_custom_keygen(void) {
       .lwe_dimension = 10,
       .glwe_dimension = 1,
       .polynomial_size = 1024,
-      .lwe_modular_std_dev = 10e-100,
-      .glwe_modular_std_dev = 10e-100,
+      .lwe_noise_distribution = new_gaussian_from_std_dev(10e-100),
+      .glwe_noise_distribution = new_gaussian_from_std_dev(10e-100),
       .pbs_base_log = 2,
       .pbs_level = 3,
       .ks_base_log = 2,
@@ -112,6 +112,7 @@ void test_custom_keygen(void) {
       .carry_modulus = 2,
       .modulus_power_of_2_exponent = 64,
       .encryption_key_choice = ShortintEncryptionKeyChoiceBig,
+      .secret_key_distribution = ShortintSecretKeyDistributionBinary,
   };
 
   int gen_keys_ok = shortint_gen_keys_with_parameters(params, &cks, &sks);
@@ -1,30 +1,33 @@
-#[macro_use]
-extern crate log;
-
 mod ciphertext;
-mod engine;
-mod execution;
-mod parser;
 
-use env_logger::Env;
 use std::env;
+use std::process::exit;
+use tfhe::integer::regex::Regex;
 
 fn main() {
-    let env = Env::default().filter_or("RUST_LOG", "info");
-    env_logger::init_from_env(env);
-
     let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: {} <content> <pattern>", args[0]);
+        exit(1);
+    }
     let content = &args[1];
     let pattern = &args[2];
 
+    let regex = match Regex::new(pattern) {
+        Ok(regex) => regex,
+        Err(err) => {
+            eprintln!("{err}");
+            exit(1);
+        }
+    };
+
     let (client_key, server_key) = ciphertext::gen_keys();
     let ct_content = ciphertext::encrypt_str(&client_key, content).unwrap();
 
-    let ct_res = engine::has_match(&server_key, &ct_content, pattern).unwrap();
-    let res: u64 = client_key.decrypt(&ct_res);
-    if res == 0 {
-        println!("no match");
-    } else {
+    let ct_res = server_key.regex_is_match_parallelized(&ct_content, &regex);
+    if client_key.decrypt_bool(&ct_res) {
         println!("match");
+    } else {
+        println!("no match");
     }
 }
@@ -2,7 +2,7 @@ use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 use tfhe::boolean::parameters::{BooleanParameters, VEC_BOOLEAN_PARAM};
-use tfhe::core_crypto::commons::dispersion::StandardDev;
+use tfhe::core_crypto::commons::dispersion::DynamicDistribution;
 use tfhe::core_crypto::commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
 use tfhe::keycache::NamedParam;
 use tfhe::shortint::parameters::multi_bit::ALL_MULTI_BIT_PARAMETER_VEC;
@@ -15,8 +15,8 @@ use tfhe::shortint::parameters::{
 pub trait ParamDetails {
     fn lwe_dimension(&self) -> LweDimension;
     fn glwe_dimension(&self) -> GlweDimension;
-    fn lwe_modular_std_dev(&self) -> StandardDev;
-    fn glwe_modular_std_dev(&self) -> StandardDev;
+    fn lwe_noise_distribution(&self) -> DynamicDistribution;
+    fn glwe_noise_distribution(&self) -> DynamicDistribution;
     fn polynomial_size(&self) -> PolynomialSize;
     fn log_ciphertext_modulus(&self) -> usize;
 }
@@ -30,11 +30,11 @@ impl ParamDetails for BooleanParameters {
         self.glwe_dimension
     }
 
-    fn lwe_modular_std_dev(&self) -> StandardDev {
-        self.lwe_modular_std_dev
+    fn lwe_noise_distribution(&self) -> DynamicDistribution {
+        self.lwe_noise_distribution
     }
-    fn glwe_modular_std_dev(&self) -> StandardDev {
-        self.glwe_modular_std_dev
+    fn glwe_noise_distribution(&self) -> DynamicDistribution {
+        self.glwe_noise_distribution
     }
 
     fn polynomial_size(&self) -> PolynomialSize {
@@ -55,11 +55,11 @@ impl ParamDetails for ShortintParameterSet {
         self.glwe_dimension()
     }
 
-    fn lwe_modular_std_dev(&self) -> StandardDev {
-        self.lwe_modular_std_dev()
+    fn lwe_noise_distribution(&self) -> DynamicDistribution {
+        ShortintParameterSet::lwe_noise_distribution(self)
     }
-    fn glwe_modular_std_dev(&self) -> StandardDev {
-        self.glwe_modular_std_dev()
+    fn glwe_noise_distribution(&self) -> DynamicDistribution {
+        ShortintParameterSet::glwe_noise_distribution(self)
     }
 
     fn polynomial_size(&self) -> PolynomialSize {
@@ -72,18 +72,34 @@ impl ParamDetails for ShortintParameterSet {
     }
 }
 
+/// Format a noise distribution in the lattice_estimator format
+fn format_noise_distribution(noise: DynamicDistribution, log_ciphertext_modulus: usize) -> String {
+    match noise {
+        DynamicDistribution::Gaussian(std_dev) => {
+            let modular_std_dev = log_ciphertext_modulus as f64 + std_dev.0.log2();
+            format!("ND.DiscreteGaussian({})", 2.0_f64.powf(modular_std_dev))
+        }
+        DynamicDistribution::TUniform(t_uniform) => {
+            let bound = 1u128 << t_uniform.bound_log2();
+            format!("ND.Uniform(-{bound}, {bound})")
+        }
+    }
+}
+
 ///Function to print in the lattice_estimator format the parameters
 /// Format:   LWE.Parameters(n=722, q=2^32, Xs=ND.UniformMod(2),
 /// Xe=ND.DiscreteGaussian(56139.60810663548), tag='test_lattice_estimator')
 pub fn format_lwe_parameters_to_lattice_estimator<T: ParamDetails + NamedParam>(
     param: &T,
 ) -> String {
-    let modular_std_dev =
-        param.log_ciphertext_modulus() as f64 + param.lwe_modular_std_dev().0.log2();
+    let noise_distribution = format_noise_distribution(
+        param.lwe_noise_distribution(),
+        param.log_ciphertext_modulus(),
+    );
 
     format!(
-        "{}_LWE = LWE.Parameters(\n n = {},\n q ={},\n Xs=ND.UniformMod(2), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_lwe' \n)\n\n",
-        param.name(), param.lwe_dimension().0, (1u128<<param.log_ciphertext_modulus() as u128), 2.0_f64.powf(modular_std_dev), param.name())
+        "{}_LWE = LWE.Parameters(\n n = {},\n q ={},\n Xs=ND.UniformMod(2), \n Xe={},\n tag='{}_lwe' \n)\n\n",
+        param.name(), param.lwe_dimension().0, (1u128<<param.log_ciphertext_modulus() as u128), noise_distribution, param.name())
 }
 
 ///Function to print in the lattice_estimator format the parameters
@@ -92,12 +108,14 @@ pub fn format_lwe_parameters_to_lattice_estimator<T: ParamDetails + NamedParam>(
 pub fn format_glwe_parameters_to_lattice_estimator<T: ParamDetails + NamedParam>(
     param: &T,
 ) -> String {
-    let modular_std_dev =
-        param.log_ciphertext_modulus() as f64 + param.glwe_modular_std_dev().0.log2();
+    let noise_distribution = format_noise_distribution(
+        param.glwe_noise_distribution(),
+        param.log_ciphertext_modulus(),
+    );
 
     format!(
-        "{}_GLWE = LWE.Parameters(\n n = {},\n q = {},\n Xs=ND.UniformMod(2), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_glwe' \n)\n\n",
-        param.name(), param.glwe_dimension().0*param.polynomial_size().0, (1u128<<param.log_ciphertext_modulus() as u128), 2.0_f64.powf(modular_std_dev), param.name())
+        "{}_GLWE = LWE.Parameters(\n n = {},\n q = {},\n Xs=ND.UniformMod(2), \n Xe={},\n tag='{}_glwe' \n)\n\n",
+        param.name(), param.glwe_dimension().0*param.polynomial_size().0, (1u128<<param.log_ciphertext_modulus() as u128), noise_distribution, param.name())
 }
 
 fn write_file(file: &mut File, filename: &Path, line: impl Into<String>) {
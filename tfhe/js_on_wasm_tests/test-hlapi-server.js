@@ -0,0 +1,91 @@
+const test = require('node:test');
+const assert = require('node:assert').strict;
+const {
+    init_panic_hook,
+    set_server_key,
+    TfheClientKey,
+    TfheCompressedServerKey,
+    TfheConfigBuilder,
+    FheBool,
+    FheUint8,
+    FheInt8,
+} = require("../pkg/tfhe.js");
+
+init_panic_hook();
+
+function generateAndSetKeys() {
+    let config = TfheConfigBuilder.default()
+        .build();
+
+    let clientKey = TfheClientKey.generate(config);
+    let compressedServerKey = TfheCompressedServerKey.new(clientKey);
+    let serverKey = compressedServerKey.decompress();
+    set_server_key(serverKey);
+
+    return clientKey;
+}
+
+test('hlapi_server_uint8_operations', (t) => {
+    let clientKey = generateAndSetKeys();
+
+    let clear_a = 200;
+    let clear_b = 100;
+    let a = FheUint8.encrypt_with_client_key(clear_a, clientKey);
+    let b = FheUint8.encrypt_with_client_key(clear_b, clientKey);
+
+    assert.deepStrictEqual(a.add(b).decrypt(clientKey), (clear_a + clear_b) % 256);
+    assert.deepStrictEqual(a.sub(b).decrypt(clientKey), clear_a - clear_b);
+    assert.deepStrictEqual(a.mul(b).decrypt(clientKey), (clear_a * clear_b) % 256);
+    assert.deepStrictEqual(a.bitand(b).decrypt(clientKey), clear_a & clear_b);
+    assert.deepStrictEqual(a.bitor(b).decrypt(clientKey), clear_a | clear_b);
+    assert.deepStrictEqual(a.bitxor(b).decrypt(clientKey), clear_a ^ clear_b);
+    assert.deepStrictEqual(a.min(b).decrypt(clientKey), clear_b);
+
+    let a_lt_b = a.lt(b);
+    assert.deepStrictEqual(a_lt_b.decrypt(clientKey), false);
+    assert.deepStrictEqual(a.ge(b).decrypt(clientKey), true);
+    assert.deepStrictEqual(a.eq(a).decrypt(clientKey), true);
+
+    let selected = FheUint8.if_then_else(a_lt_b, a, b);
+    assert.deepStrictEqual(selected.decrypt(clientKey), clear_b);
+
+    // Casting
+    assert.deepStrictEqual(a.cast_into_fhe_int8().decrypt(clientKey), clear_a - 256);
+    assert.deepStrictEqual(a.cast_into_fhe_uint128().decrypt(clientKey), BigInt(clear_a));
+    assert.deepStrictEqual(a_lt_b.cast_into_fhe_uint8().decrypt(clientKey), 0);
+
+    // Results of operations can be serialized and sent back to the client
+    let serialized = a.add(b).serialize();
+    let deserialized = FheUint8.deserialize(serialized);
+    assert.deepStrictEqual(deserialized.decrypt(clientKey), (clear_a + clear_b) % 256);
+});
+
+test('hlapi_server_bool_and_int8_operations', (t) => {
+    let clientKey = generateAndSetKeys();
+
+    let t_ = FheBool.encrypt_with_client_key(true, clientKey);
+    let f_ = FheBool.encrypt_with_client_key(false, clientKey);
+
+    assert.deepStrictEqual(t_.bitand(f_).decrypt(clientKey), false);
+    assert.deepStrictEqual(t_.bitor(f_).decrypt(clientKey), true);
+    assert.deepStrictEqual(t_.bitxor(t_).decrypt(clientKey), false);
+    assert.deepStrictEqual(f_.not().decrypt(clientKey), true);
+    assert.deepStrictEqual(t_.ne(f_).decrypt(clientKey), true);
+
+    let serialized = t_.serialize();
+    assert.deepStrictEqual(FheBool.deserialize(serialized).decrypt(clientKey), true);
+
+    let clear_a = -7;
+    let clear_b = 5;
+    let a = FheInt8.encrypt_with_client_key(clear_a, clientKey);
+    let b = FheInt8.encrypt_with_client_key(clear_b, clientKey);
+
+    assert.deepStrictEqual(a.mul(b).decrypt(clientKey), clear_a * clear_b);
+    assert.deepStrictEqual(a.neg().decrypt(clientKey), -clear_a);
+    assert.deepStrictEqual(a.max(b).decrypt(clientKey), clear_b);
+    assert.deepStrictEqual(a.lt(b).decrypt(clientKey), true);
+
+    // Sign extension, then reinterpretation as unsigned
+    assert.deepStrictEqual(a.cast_into_fhe_uint8().decrypt(clientKey), clear_a + 256);
+    assert.deepStrictEqual(a.cast_into_fhe_int64().decrypt(clientKey), BigInt(clear_a));
+});
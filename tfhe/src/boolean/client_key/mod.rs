@@ -6,8 +6,11 @@
 use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::boolean::engine::{BooleanEngine, WithThreadLocalEngine};
 use crate::boolean::parameters::BooleanParameters;
+use crate::core_crypto::algorithms::allocate_and_expand_lwe_secret_key_to_binary;
+use crate::core_crypto::commons::parameters::SecretKeyDistribution;
 use crate::core_crypto::entities::*;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
 
 /// A structure containing the client key, which must be kept secret.
@@ -134,4 +137,16 @@ impl ClientKey {
     pub fn new(parameter_set: &BooleanParameters) -> Self {
         BooleanEngine::with_thread_local_mut(|engine| engine.create_client_key(*parameter_set))
     }
+
+    /// Returns the LWE secret key the bootstrapping key is generated for: the LWE secret key
+    /// itself if it is binary, its binary expansion otherwise.
+    pub(crate) fn bootstrap_input_lwe_secret_key(&self) -> Cow<'_, LweSecretKeyOwned<u32>> {
+        match self.parameters.secret_key_distribution {
+            SecretKeyDistribution::Binary => Cow::Borrowed(&self.lwe_secret_key),
+            distribution => Cow::Owned(allocate_and_expand_lwe_secret_key_to_binary(
+                &self.lwe_secret_key,
+                distribution.coefficient_bit_count(),
+            )),
+        }
+    }
 }
@@ -5,6 +5,7 @@ use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::generators::{DeterministicSeeder, EncryptionRandomGenerator};
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder};
 use crate::core_crypto::commons::parameters::{CiphertextModulus, PBSOrder};
+use crate::core_crypto::commons::traits::Container;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use serde::{Deserialize, Serialize};
@@ -158,11 +159,11 @@ impl Bootstrapper {
     pub(crate) fn new_server_key(&mut self, cks: &ClientKey) -> ServerKey {
         let standard_bootstrapping_key: LweBootstrapKeyOwned<u32> =
             par_allocate_and_generate_new_lwe_bootstrap_key(
-                &cks.lwe_secret_key,
+                &cks.bootstrap_input_lwe_secret_key(),
                 &cks.glwe_secret_key,
                 cks.parameters.pbs_base_log,
                 cks.parameters.pbs_level,
-                cks.parameters.glwe_modular_std_dev,
+                cks.parameters.glwe_noise_distribution,
                 CiphertextModulus::new_native(),
                 &mut self.encryption_generator,
             );
@@ -199,7 +200,7 @@ impl Bootstrapper {
             &cks.lwe_secret_key,
             cks.parameters.ks_base_log,
             cks.parameters.ks_level,
-            cks.parameters.lwe_modular_std_dev,
+            cks.parameters.lwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.encryption_generator,
         );
@@ -214,22 +215,22 @@ impl Bootstrapper {
     pub(crate) fn new_compressed_server_key(&mut self, cks: &ClientKey) -> CompressedServerKey {
         #[cfg(not(feature = "__wasm_api"))]
         let bootstrapping_key = par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
-            &cks.lwe_secret_key,
+            &cks.bootstrap_input_lwe_secret_key(),
             &cks.glwe_secret_key,
             cks.parameters.pbs_base_log,
             cks.parameters.pbs_level,
-            cks.parameters.glwe_modular_std_dev,
+            cks.parameters.glwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.seeder,
         );
 
         #[cfg(feature = "__wasm_api")]
         let bootstrapping_key = allocate_and_generate_new_seeded_lwe_bootstrap_key(
-            &cks.lwe_secret_key,
+            &cks.bootstrap_input_lwe_secret_key(),
             &cks.glwe_secret_key,
             cks.parameters.pbs_base_log,
             cks.parameters.pbs_level,
-            cks.parameters.glwe_modular_std_dev,
+            cks.parameters.glwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.seeder,
         );
@@ -242,7 +243,7 @@ impl Bootstrapper {
             &cks.lwe_secret_key,
             cks.parameters.ks_base_log,
             cks.parameters.ks_level,
-            cks.parameters.lwe_modular_std_dev,
+            cks.parameters.lwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.seeder,
         );
@@ -281,8 +282,9 @@ impl Bootstrapper {
         );
         let stack = self.computation_buffers.stack();
 
+        let expanded_input = expand_bootstrap_input(input, fourier_bsk);
         programmable_bootstrap_lwe_ciphertext_mem_optimized(
-            input,
+            expanded_input.as_ref().unwrap_or(input),
             &mut buffer_lwe_after_pbs,
             &accumulator,
             fourier_bsk,
@@ -324,8 +326,9 @@ impl Bootstrapper {
         let stack = self.computation_buffers.stack();
 
         // Compute a bootstrap
+        let expanded_ciphertext = expand_bootstrap_input(&ciphertext, fourier_bsk);
         programmable_bootstrap_lwe_ciphertext_mem_optimized(
-            &ciphertext,
+            expanded_ciphertext.as_ref().unwrap_or(&ciphertext),
             &mut buffer_lwe_after_pbs,
             &lookup_table,
             fourier_bsk,
@@ -378,14 +381,25 @@ impl Bootstrapper {
         );
 
         // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
-            &buffer_lwe_after_ks,
-            &mut ciphertext,
-            &lookup_table,
-            fourier_bsk,
-            fft,
-            stack,
-        );
+        let expanded_ciphertext = expand_bootstrap_input(&buffer_lwe_after_ks, fourier_bsk);
+        match expanded_ciphertext {
+            Some(expanded_ciphertext) => programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                &expanded_ciphertext,
+                &mut ciphertext,
+                &lookup_table,
+                fourier_bsk,
+                fft,
+                stack,
+            ),
+            None => programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                &buffer_lwe_after_ks,
+                &mut ciphertext,
+                &lookup_table,
+                fourier_bsk,
+                fft,
+                stack,
+            ),
+        }
 
         Ciphertext::Encrypted(ciphertext)
     }
@@ -401,12 +415,23 @@ impl Bootstrapper {
     }
 }
 
+/// Returns the input re-encoded for the binary expansion of its secret key if the bootstrapping
+/// key was generated for a non binary key, `None` if it can be bootstrapped as is.
+fn expand_bootstrap_input<C: Container<Element = u32>>(
+    input: &LweCiphertext<C>,
+    fourier_bsk: &FourierLweBootstrapKeyOwned,
+) -> Option<LweCiphertextOwned<u32>> {
+    (input.lwe_size().to_lwe_dimension() != fourier_bsk.input_lwe_dimension()).then(|| {
+        allocate_and_expand_lwe_ciphertext_for_binary_key(input, fourier_bsk.input_lwe_dimension())
+    })
+}
+
 impl ServerKey {
     pub(crate) fn keyswitch(&self, input: &LweCiphertextOwned<u32>) -> LweCiphertextOwned<u32> {
         // Allocate the output of the KS
         let mut output = LweCiphertext::new(
             0u32,
-            self.bootstrapping_key.input_lwe_dimension().to_lwe_size(),
+            self.key_switching_key.output_lwe_size(),
             input.ciphertext_modulus(),
         );
 
@@ -83,15 +83,17 @@ const LOG2_Q_32: usize = 32;
 impl BooleanEngine {
     pub fn create_client_key(&mut self, parameters: BooleanParameters) -> ClientKey {
         // generate the lwe secret key
-        let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        let lwe_secret_key = allocate_and_generate_new_lwe_secret_key(
             parameters.lwe_dimension,
+            parameters.secret_key_distribution,
             &mut self.secret_generator,
         );
 
         // generate the glwe secret key
-        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        let glwe_secret_key = allocate_and_generate_new_glwe_secret_key(
             parameters.glwe_dimension,
             parameters.polynomial_size,
+            parameters.secret_key_distribution,
             &mut self.secret_generator,
         );
 
@@ -116,11 +118,11 @@ impl BooleanEngine {
         let (lwe_sk, encryption_noise) = match client_parameters.encryption_key_choice {
             EncryptionKeyChoice::Big => (
                 client_key.glwe_secret_key.as_lwe_secret_key(),
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution,
             ),
             EncryptionKeyChoice::Small => {
                 let view = LweSecretKey::from_container(client_key.lwe_secret_key.as_ref());
-                (view, client_key.parameters.lwe_modular_std_dev)
+                (view, client_key.parameters.lwe_noise_distribution)
             }
         };
 
@@ -159,11 +161,11 @@ impl BooleanEngine {
         let (lwe_sk, encryption_noise) = match client_parameters.encryption_key_choice {
             EncryptionKeyChoice::Big => (
                 client_key.glwe_secret_key.as_lwe_secret_key(),
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution,
             ),
             EncryptionKeyChoice::Small => {
                 let view = LweSecretKey::from_container(client_key.lwe_secret_key.as_ref());
-                (view, client_key.parameters.lwe_modular_std_dev)
+                (view, client_key.parameters.lwe_noise_distribution)
             }
         };
 
@@ -228,7 +230,7 @@ cks1 has {choice1:?}, cks2 has: {choice2:?}
             &lwe_sk2,
             params.ks_base_log,
             params.ks_level,
-            cks2.parameters.lwe_modular_std_dev,
+            cks2.parameters.lwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.encryption_generator,
         )
@@ -249,11 +251,11 @@ cks1 has {choice1:?}, cks2 has: {choice2:?}
         let (lwe_sk, encryption_noise) = match cks.parameters.encryption_key_choice {
             EncryptionKeyChoice::Big => (
                 cks.glwe_secret_key.as_lwe_secret_key(),
-                cks.parameters.glwe_modular_std_dev,
+                cks.parameters.glwe_noise_distribution,
             ),
             EncryptionKeyChoice::Small => {
                 let view = LweSecretKey::from_container(cks.lwe_secret_key.as_ref());
-                (view, cks.parameters.lwe_modular_std_dev)
+                (view, cks.parameters.lwe_noise_distribution)
             }
         };
 
@@ -280,11 +282,11 @@ cks1 has {choice1:?}, cks2 has: {choice2:?}
         let (lwe_sk, encryption_noise) = match cks.parameters.encryption_key_choice {
             EncryptionKeyChoice::Big => (
                 cks.glwe_secret_key.as_lwe_secret_key(),
-                cks.parameters.glwe_modular_std_dev,
+                cks.parameters.glwe_noise_distribution,
             ),
             EncryptionKeyChoice::Small => {
                 let view = LweSecretKey::from_container(cks.lwe_secret_key.as_ref());
-                (view, cks.parameters.lwe_modular_std_dev)
+                (view, cks.parameters.lwe_noise_distribution)
             }
         };
 
@@ -589,10 +591,7 @@ fn convert_into_lwe_ciphertext_32(
                     .key_switching_key
                     .input_key_lwe_dimension()
                     .to_lwe_size(),
-                PBSOrder::BootstrapKeyswitch => server_key
-                    .bootstrapping_key
-                    .input_lwe_dimension()
-                    .to_lwe_size(),
+                PBSOrder::BootstrapKeyswitch => server_key.key_switching_key.output_lwe_size(),
             };
 
             allocate_and_trivially_encrypt_new_lwe_ciphertext(
@@ -9,6 +9,8 @@ named_params_impl!( BooleanParameters =>
     DEFAULT_PARAMETERS_KS_PBS,
     PARAMETERS_ERROR_PROB_2_POW_MINUS_165,
     PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS,
+    PARAMETERS_TERNARY,
+    PARAMETERS_TUNIFORM,
     TFHE_LIB_PARAMETERS
 );
 
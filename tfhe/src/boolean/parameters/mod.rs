@@ -18,10 +18,10 @@
 //! Failing to properly fix the parameters will potentially result with an incorrect and/or insecure
 //! computation.
 
-pub use crate::core_crypto::commons::dispersion::StandardDev;
+pub use crate::core_crypto::commons::dispersion::{DynamicDistribution, StandardDev};
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, EncryptionKeyChoice, GlweDimension,
-    LweDimension, PolynomialSize,
+    LweDimension, PolynomialSize, SecretKeyDistribution,
 };
 
 use serde::{Deserialize, Serialize};
@@ -44,13 +44,14 @@ pub struct BooleanParameters {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
-    pub lwe_modular_std_dev: StandardDev,
-    pub glwe_modular_std_dev: StandardDev,
+    pub lwe_noise_distribution: DynamicDistribution,
+    pub glwe_noise_distribution: DynamicDistribution,
     pub pbs_base_log: DecompositionBaseLog,
     pub pbs_level: DecompositionLevelCount,
     pub ks_base_log: DecompositionBaseLog,
     pub ks_level: DecompositionLevelCount,
     pub encryption_key_choice: EncryptionKeyChoice,
+    pub secret_key_distribution: SecretKeyDistribution,
 }
 
 impl BooleanParameters {
@@ -67,25 +68,27 @@ impl BooleanParameters {
         lwe_dimension: LweDimension,
         glwe_dimension: GlweDimension,
         polynomial_size: PolynomialSize,
-        lwe_modular_std_dev: StandardDev,
-        glwe_modular_std_dev: StandardDev,
+        lwe_noise_distribution: DynamicDistribution,
+        glwe_noise_distribution: DynamicDistribution,
         pbs_base_log: DecompositionBaseLog,
         pbs_level: DecompositionLevelCount,
         ks_base_log: DecompositionBaseLog,
         ks_level: DecompositionLevelCount,
         encryption_key_choice: EncryptionKeyChoice,
+        secret_key_distribution: SecretKeyDistribution,
     ) -> Self {
         Self {
             lwe_dimension,
             glwe_dimension,
             polynomial_size,
-            lwe_modular_std_dev,
-            glwe_modular_std_dev,
+            lwe_noise_distribution,
+            glwe_noise_distribution,
             pbs_base_log,
             pbs_level,
             ks_base_log,
             ks_level,
             encryption_key_choice,
+            secret_key_distribution,
         }
     }
 }
@@ -124,26 +127,36 @@ pub const DEFAULT_PARAMETERS: BooleanParameters = BooleanParameters {
     lwe_dimension: LweDimension(722),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.000013071021089943935),
-    glwe_modular_std_dev: StandardDev(0.00000004990272175010415),
+    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.000013071021089943935,
+    )),
+    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.00000004990272175010415,
+    )),
     pbs_base_log: DecompositionBaseLog(6),
     pbs_level: DecompositionLevelCount(3),
     ks_base_log: DecompositionBaseLog(3),
     ks_level: DecompositionLevelCount(4),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 pub const DEFAULT_PARAMETERS_KS_PBS: BooleanParameters = BooleanParameters {
     lwe_dimension: LweDimension(664),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.00003808282923459771),
-    glwe_modular_std_dev: StandardDev(0.00000004990272175010415),
+    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.00003808282923459771,
+    )),
+    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.00000004990272175010415,
+    )),
     pbs_base_log: DecompositionBaseLog(6),
     pbs_level: DecompositionLevelCount(3),
     ks_base_log: DecompositionBaseLog(3),
     ks_level: DecompositionLevelCount(4),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 /// The secret keys generated with this parameter set are uniform binary.
@@ -153,26 +166,36 @@ pub const PARAMETERS_ERROR_PROB_2_POW_MINUS_165: BooleanParameters = BooleanPara
     lwe_dimension: LweDimension(767),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000005104350373791501),
-    glwe_modular_std_dev: StandardDev(0.0000000009313225746154785),
+    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.000005104350373791501,
+    )),
+    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.0000000009313225746154785,
+    )),
     pbs_base_log: DecompositionBaseLog(10),
     pbs_level: DecompositionLevelCount(2),
     ks_base_log: DecompositionBaseLog(3),
     ks_level: DecompositionLevelCount(5),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 pub const PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS: BooleanParameters = BooleanParameters {
     lwe_dimension: LweDimension(700),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.0000196095987892077),
-    glwe_modular_std_dev: StandardDev(0.00000004990272175010415),
+    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.0000196095987892077,
+    )),
+    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.00000004990272175010415,
+    )),
     pbs_base_log: DecompositionBaseLog(5),
     pbs_level: DecompositionLevelCount(4),
     ks_base_log: DecompositionBaseLog(2),
     ks_level: DecompositionLevelCount(7),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 /// Parameter sets given in TFHE-lib:
@@ -182,13 +205,44 @@ pub const TFHE_LIB_PARAMETERS: BooleanParameters = BooleanParameters {
     lwe_dimension: LweDimension(630),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000030517578125),
-    glwe_modular_std_dev: StandardDev(0.00000002980232238769531),
+    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.000030517578125,
+    )),
+    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.00000002980232238769531,
+    )),
     pbs_base_log: DecompositionBaseLog(7),
     pbs_level: DecompositionLevelCount(3),
     ks_base_log: DecompositionBaseLog(2),
     ks_level: DecompositionLevelCount(8),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
+};
+
+/// Untested parameter set using ternary secret keys, with the dimensions and noise of
+/// [`PARAMETERS_ERROR_PROB_2_POW_MINUS_165`].
+///
+/// This set is not derived for ternary keys: its security level and probability of error have not
+/// been estimated. Ternary keys increase the noise terms depending on the secret keys and the
+/// bootstrapping key is twice as large as with binary keys, so the probability of error is higher
+/// than the one of [`PARAMETERS_ERROR_PROB_2_POW_MINUS_165`] and the bootstrapping about twice as
+/// slow. It is meant for experimentation and must not be used in production.
+pub const PARAMETERS_TERNARY: BooleanParameters = BooleanParameters {
+    secret_key_distribution: SecretKeyDistribution::Ternary,
+    ..PARAMETERS_ERROR_PROB_2_POW_MINUS_165
+};
+
+/// Parameter set using bounded [`TUniform`](crate::core_crypto::commons::dispersion::TUniform)
+/// noise, with the dimensions and decompositions of [`PARAMETERS_ERROR_PROB_2_POW_MINUS_165`].
+///
+/// The bounds are the smallest powers of two giving at least the variance of the gaussian noise of
+/// [`PARAMETERS_ERROR_PROB_2_POW_MINUS_165`], so that the security level is preserved. The
+/// noise is therefore slightly larger and the probability of error is higher than $2^{-165}$, it
+/// however remains negligible.
+pub const PARAMETERS_TUNIFORM: BooleanParameters = BooleanParameters {
+    lwe_noise_distribution: DynamicDistribution::new_t_uniform(16),
+    glwe_noise_distribution: DynamicDistribution::new_t_uniform(3),
+    ..PARAMETERS_ERROR_PROB_2_POW_MINUS_165
 };
 
 pub const VEC_BOOLEAN_PARAM: [BooleanParameters; 2] = [DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS];
@@ -256,6 +256,100 @@ mod tfhe_lib_parameters_tests {
     }
 }
 
+#[cfg(not(feature = "__coverage"))]
+mod ternary_parameters_tests {
+    use super::*;
+    use crate::boolean::parameters::PARAMETERS_TERNARY;
+
+    #[test]
+    fn test_encrypt_decrypt_lwe_secret_key_ternary_parameters() {
+        test_encrypt_decrypt_lwe_secret_key(PARAMETERS_TERNARY);
+    }
+    #[test]
+    fn test_and_gate_ternary_parameters() {
+        test_and_gate(PARAMETERS_TERNARY);
+    }
+    #[test]
+    fn test_nand_gate_ternary_parameters() {
+        test_nand_gate(PARAMETERS_TERNARY);
+    }
+    #[test]
+    fn test_or_gate_ternary_parameters() {
+        test_or_gate(PARAMETERS_TERNARY);
+    }
+    #[test]
+    fn test_nor_gate_ternary_parameters() {
+        test_nor_gate(PARAMETERS_TERNARY);
+    }
+    #[test]
+    fn test_xor_gate_ternary_parameters() {
+        test_xor_gate(PARAMETERS_TERNARY);
+    }
+    #[test]
+    fn test_xnor_gate_ternary_parameters() {
+        test_xnor_gate(PARAMETERS_TERNARY);
+    }
+    #[test]
+    fn test_not_gate_ternary_parameters() {
+        test_not_gate(PARAMETERS_TERNARY);
+    }
+    #[test]
+    fn test_mux_gate_ternary_parameters() {
+        test_mux_gate(PARAMETERS_TERNARY);
+    }
+    #[test]
+    fn test_deep_circuit_ternary_parameters() {
+        test_deep_circuit(PARAMETERS_TERNARY);
+    }
+}
+
+#[cfg(not(feature = "__coverage"))]
+mod tuniform_parameters_tests {
+    use super::*;
+    use crate::boolean::parameters::PARAMETERS_TUNIFORM;
+
+    #[test]
+    fn test_encrypt_decrypt_lwe_secret_key_tuniform_parameters() {
+        test_encrypt_decrypt_lwe_secret_key(PARAMETERS_TUNIFORM);
+    }
+    #[test]
+    fn test_and_gate_tuniform_parameters() {
+        test_and_gate(PARAMETERS_TUNIFORM);
+    }
+    #[test]
+    fn test_nand_gate_tuniform_parameters() {
+        test_nand_gate(PARAMETERS_TUNIFORM);
+    }
+    #[test]
+    fn test_or_gate_tuniform_parameters() {
+        test_or_gate(PARAMETERS_TUNIFORM);
+    }
+    #[test]
+    fn test_nor_gate_tuniform_parameters() {
+        test_nor_gate(PARAMETERS_TUNIFORM);
+    }
+    #[test]
+    fn test_xor_gate_tuniform_parameters() {
+        test_xor_gate(PARAMETERS_TUNIFORM);
+    }
+    #[test]
+    fn test_xnor_gate_tuniform_parameters() {
+        test_xnor_gate(PARAMETERS_TUNIFORM);
+    }
+    #[test]
+    fn test_not_gate_tuniform_parameters() {
+        test_not_gate(PARAMETERS_TUNIFORM);
+    }
+    #[test]
+    fn test_mux_gate_tuniform_parameters() {
+        test_mux_gate(PARAMETERS_TUNIFORM);
+    }
+    #[test]
+    fn test_deep_circuit_tuniform_parameters() {
+        test_deep_circuit(PARAMETERS_TUNIFORM);
+    }
+}
+
 /// test encryption and decryption with the LWE secret key
 fn test_encrypt_decrypt_lwe_secret_key(parameters: BooleanParameters) {
     let keys = KEY_CACHE.get_from_param(parameters);
@@ -1,6 +1,7 @@
-use crate::core_crypto::commons::dispersion::StandardDev;
+use crate::c_api::core_crypto::DynamicDistribution;
 use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    SecretKeyDistribution,
 };
 
 #[repr(C)]
@@ -36,19 +37,50 @@ impl BooleanEncryptionKeyChoice {
     }
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum BooleanSecretKeyDistribution {
+    BooleanSecretKeyDistributionBinary,
+    BooleanSecretKeyDistributionTernary,
+}
+
+impl From<BooleanSecretKeyDistribution> for SecretKeyDistribution {
+    fn from(value: BooleanSecretKeyDistribution) -> Self {
+        match value {
+            BooleanSecretKeyDistribution::BooleanSecretKeyDistributionBinary => Self::Binary,
+            BooleanSecretKeyDistribution::BooleanSecretKeyDistributionTernary => Self::Ternary,
+        }
+    }
+}
+
+impl BooleanSecretKeyDistribution {
+    // TryFrom::try_from cannot be marked as const, so we have to have
+    // our own function
+    const fn try_convert(rust_distribution: SecretKeyDistribution) -> Result<Self, &'static str> {
+        match rust_distribution {
+            SecretKeyDistribution::Binary => Ok(Self::BooleanSecretKeyDistributionBinary),
+            SecretKeyDistribution::Ternary => Ok(Self::BooleanSecretKeyDistributionTernary),
+            SecretKeyDistribution::BoundedGaussian { .. } => {
+                Err("Bounded gaussian secret keys are not supported by the C API")
+            }
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct BooleanParameters {
     pub lwe_dimension: usize,
     pub glwe_dimension: usize,
     pub polynomial_size: usize,
-    pub lwe_modular_std_dev: f64,
-    pub glwe_modular_std_dev: f64,
+    pub lwe_noise_distribution: DynamicDistribution,
+    pub glwe_noise_distribution: DynamicDistribution,
     pub pbs_base_log: usize,
     pub pbs_level: usize,
     pub ks_base_log: usize,
     pub ks_level: usize,
     pub encryption_key_choice: BooleanEncryptionKeyChoice,
+    pub secret_key_distribution: BooleanSecretKeyDistribution,
 }
 
 impl From<BooleanParameters> for crate::boolean::parameters::BooleanParameters {
@@ -57,31 +89,57 @@ impl From<BooleanParameters> for crate::boolean::parameters::BooleanParameters {
             lwe_dimension: LweDimension(c_params.lwe_dimension),
             glwe_dimension: GlweDimension(c_params.glwe_dimension),
             polynomial_size: PolynomialSize(c_params.polynomial_size),
-            lwe_modular_std_dev: StandardDev(c_params.lwe_modular_std_dev),
-            glwe_modular_std_dev: StandardDev(c_params.glwe_modular_std_dev),
+            lwe_noise_distribution: c_params.lwe_noise_distribution.into(),
+            glwe_noise_distribution: c_params.glwe_noise_distribution.into(),
             pbs_base_log: DecompositionBaseLog(c_params.pbs_base_log),
             pbs_level: DecompositionLevelCount(c_params.pbs_level),
             ks_base_log: DecompositionBaseLog(c_params.ks_base_log),
             ks_level: DecompositionLevelCount(c_params.ks_level),
             encryption_key_choice: c_params.encryption_key_choice.into(),
+            secret_key_distribution: c_params.secret_key_distribution.into(),
         }
     }
 }
 
-impl From<crate::boolean::parameters::BooleanParameters> for BooleanParameters {
-    fn from(rust_params: crate::boolean::parameters::BooleanParameters) -> Self {
-        Self::convert(rust_params)
+impl TryFrom<crate::boolean::parameters::BooleanParameters> for BooleanParameters {
+    type Error = &'static str;
+
+    fn try_from(
+        rust_params: crate::boolean::parameters::BooleanParameters,
+    ) -> Result<Self, Self::Error> {
+        Self::try_convert(rust_params)
     }
 }
 
 impl BooleanParameters {
+    // Used for the exposed parameter sets, so an unsupported set
+    // fails to compile instead of panicking at runtime
     const fn convert(rust_params: crate::boolean::parameters::BooleanParameters) -> Self {
-        Self {
+        match Self::try_convert(rust_params) {
+            Ok(c_params) => c_params,
+            Err(msg) => panic!("{}", msg),
+        }
+    }
+
+    const fn try_convert(
+        rust_params: crate::boolean::parameters::BooleanParameters,
+    ) -> Result<Self, &'static str> {
+        let secret_key_distribution =
+            match BooleanSecretKeyDistribution::try_convert(rust_params.secret_key_distribution) {
+                Ok(distribution) => distribution,
+                Err(msg) => return Err(msg),
+            };
+
+        Ok(Self {
             lwe_dimension: rust_params.lwe_dimension.0,
             glwe_dimension: rust_params.glwe_dimension.0,
             polynomial_size: rust_params.polynomial_size.0,
-            lwe_modular_std_dev: rust_params.lwe_modular_std_dev.0,
-            glwe_modular_std_dev: rust_params.glwe_modular_std_dev.0,
+            lwe_noise_distribution: DynamicDistribution::convert(
+                rust_params.lwe_noise_distribution,
+            ),
+            glwe_noise_distribution: DynamicDistribution::convert(
+                rust_params.glwe_noise_distribution,
+            ),
             pbs_base_log: rust_params.pbs_base_log.0,
             pbs_level: rust_params.pbs_level.0,
             ks_base_log: rust_params.ks_base_log.0,
@@ -89,7 +147,8 @@ impl BooleanParameters {
             encryption_key_choice: BooleanEncryptionKeyChoice::convert(
                 rust_params.encryption_key_choice,
             ),
-        }
+            secret_key_distribution,
+        })
     }
 }
 
@@ -1,6 +1,74 @@
 use super::utils::*;
 use std::os::raw::c_int;
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum DynamicDistributionTag {
+    Gaussian,
+    TUniform,
+}
+
+/// C representation of a noise distribution, the `tag` selects which of the other fields is used.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DynamicDistribution {
+    pub tag: DynamicDistributionTag,
+    pub gaussian_std_dev: f64,
+    pub t_uniform_bound_log2: u32,
+}
+
+impl DynamicDistribution {
+    // From::from cannot be marked as const, so we have to have
+    // our own function
+    pub(crate) const fn convert(
+        rust_distribution: crate::core_crypto::commons::dispersion::DynamicDistribution,
+    ) -> Self {
+        use crate::core_crypto::commons::dispersion::DynamicDistribution as RustDistribution;
+        match rust_distribution {
+            RustDistribution::Gaussian(std_dev) => Self {
+                tag: DynamicDistributionTag::Gaussian,
+                gaussian_std_dev: std_dev.0,
+                t_uniform_bound_log2: 0,
+            },
+            RustDistribution::TUniform(t_uniform) => Self {
+                tag: DynamicDistributionTag::TUniform,
+                gaussian_std_dev: 0.0,
+                t_uniform_bound_log2: t_uniform.bound_log2(),
+            },
+        }
+    }
+}
+
+impl From<DynamicDistribution> for crate::core_crypto::commons::dispersion::DynamicDistribution {
+    fn from(c_distribution: DynamicDistribution) -> Self {
+        use crate::core_crypto::commons::dispersion::StandardDev;
+        match c_distribution.tag {
+            DynamicDistributionTag::Gaussian => {
+                Self::new_gaussian_from_std_dev(StandardDev(c_distribution.gaussian_std_dev))
+            }
+            DynamicDistributionTag::TUniform => {
+                Self::new_t_uniform(c_distribution.t_uniform_bound_log2)
+            }
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn new_gaussian_from_std_dev(std_dev: f64) -> DynamicDistribution {
+    DynamicDistribution::convert(
+        crate::core_crypto::commons::dispersion::DynamicDistribution::new_gaussian_from_std_dev(
+            crate::core_crypto::commons::dispersion::StandardDev(std_dev),
+        ),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn new_t_uniform(bound_log2: u32) -> DynamicDistribution {
+    DynamicDistribution::convert(
+        crate::core_crypto::commons::dispersion::DynamicDistribution::new_t_uniform(bound_log2),
+    )
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn core_crypto_lwe_multi_bit_bootstrapping_key_element_size(
     input_lwe_sk_dim: usize,
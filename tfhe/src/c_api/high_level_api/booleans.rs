@@ -7,8 +7,15 @@ pub struct FheBool(pub(in crate::c_api) crate::high_level_api::FheBool);
 impl_destroy_on_type!(FheBool);
 impl_clone_on_type!(FheBool);
 
+impl_serialize_deserialize_on_type!(FheBool);
+
 impl_binary_fn_on_type!(FheBool => bitand, bitor, bitxor);
 impl_unary_fn_on_type!(FheBool => not);
+impl_comparison_fn_on_type!(
+    lhs_type: FheBool,
+    rhs_type: FheBool,
+    comparison_fn_names: eq, ne,
+);
 
 impl_decrypt_on_type!(FheBool, bool);
 impl_try_encrypt_trivial_on_type!(FheBool{crate::high_level_api::FheBool}, bool);
@@ -0,0 +1,302 @@
+use crate::c_api::high_level_api::booleans::FheBool;
+use crate::c_api::high_level_api::i128::I128;
+use crate::c_api::high_level_api::i256::I256;
+use crate::c_api::high_level_api::integers::{
+    FheInt128, FheInt16, FheInt256, FheInt32, FheInt64, FheInt8, FheUint10, FheUint12, FheUint128,
+    FheUint14, FheUint16, FheUint2, FheUint24, FheUint256, FheUint32, FheUint4, FheUint48,
+    FheUint512, FheUint64, FheUint8,
+};
+use crate::c_api::high_level_api::keys::CompactPublicKey;
+use crate::c_api::high_level_api::u128::U128;
+use crate::c_api::high_level_api::u256::U256;
+use crate::c_api::high_level_api::utils::CApiIntegerType;
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
+/// The type of a value stored in a CompactCiphertextList
+///
+/// Variants are prefixed to avoid clashing with the ciphertext types in C
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum FheTypes {
+    Type_FheBool,
+    Type_FheUint2,
+    Type_FheUint3,
+    Type_FheUint4,
+    Type_FheUint8,
+    Type_FheUint10,
+    Type_FheUint12,
+    Type_FheUint14,
+    Type_FheUint16,
+    Type_FheUint24,
+    Type_FheUint32,
+    Type_FheUint48,
+    Type_FheUint64,
+    Type_FheUint128,
+    Type_FheUint256,
+    Type_FheUint512,
+    Type_FheInt8,
+    Type_FheInt16,
+    Type_FheInt32,
+    Type_FheInt64,
+    Type_FheInt128,
+    Type_FheInt256,
+    Type_FheUintN,
+    Type_FheIntN,
+}
+
+impl From<crate::high_level_api::errors::Type> for FheTypes {
+    fn from(value: crate::high_level_api::errors::Type) -> Self {
+        use crate::high_level_api::errors::Type;
+        match value {
+            Type::FheBool => Self::Type_FheBool,
+            Type::FheUint2 => Self::Type_FheUint2,
+            Type::FheUint3 => Self::Type_FheUint3,
+            Type::FheUint4 => Self::Type_FheUint4,
+            Type::FheUint8 => Self::Type_FheUint8,
+            Type::FheUint10 => Self::Type_FheUint10,
+            Type::FheUint12 => Self::Type_FheUint12,
+            Type::FheUint14 => Self::Type_FheUint14,
+            Type::FheUint16 => Self::Type_FheUint16,
+            Type::FheUint24 => Self::Type_FheUint24,
+            Type::FheUint32 => Self::Type_FheUint32,
+            Type::FheUint48 => Self::Type_FheUint48,
+            Type::FheUint64 => Self::Type_FheUint64,
+            Type::FheUint128 => Self::Type_FheUint128,
+            Type::FheUint256 => Self::Type_FheUint256,
+            Type::FheUint512 => Self::Type_FheUint512,
+            Type::FheInt8 => Self::Type_FheInt8,
+            Type::FheInt16 => Self::Type_FheInt16,
+            Type::FheInt32 => Self::Type_FheInt32,
+            Type::FheInt64 => Self::Type_FheInt64,
+            Type::FheInt128 => Self::Type_FheInt128,
+            Type::FheInt256 => Self::Type_FheInt256,
+            Type::FheUintN(_) => Self::Type_FheUintN,
+            Type::FheIntN(_) => Self::Type_FheIntN,
+        }
+    }
+}
+
+/// The builder keeps a reference to the public key it was created with,
+/// so the public key must not be destroyed before the builder.
+pub struct CompactCiphertextListBuilder(
+    crate::high_level_api::CompactCiphertextListBuilder<'static>,
+);
+pub struct CompactCiphertextList(crate::high_level_api::CompactCiphertextList);
+pub struct CompactCiphertextListExpander(crate::high_level_api::CompactCiphertextListExpander);
+
+impl_destroy_on_type!(CompactCiphertextListBuilder);
+impl_destroy_on_type!(CompactCiphertextList);
+impl_destroy_on_type!(CompactCiphertextListExpander);
+
+impl_clone_on_type!(CompactCiphertextList);
+impl_serialize_deserialize_on_type!(CompactCiphertextList);
+impl_safe_serialize_on_type!(CompactCiphertextList);
+
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_builder_new(
+    compact_public_key: *const CompactPublicKey,
+    builder: *mut *mut CompactCiphertextListBuilder,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(builder).unwrap();
+
+        let pk = get_ref_checked(compact_public_key).unwrap();
+
+        let inner = crate::high_level_api::CompactCiphertextList::builder(&pk.0);
+
+        *builder = Box::into_raw(Box::new(CompactCiphertextListBuilder(inner)));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_builder_build(
+    builder: *const CompactCiphertextListBuilder,
+    list: *mut *mut CompactCiphertextList,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(list).unwrap();
+
+        let builder = get_ref_checked(builder).unwrap();
+
+        let inner = builder.0.build();
+
+        *list = Box::into_raw(Box::new(CompactCiphertextList(inner)));
+    })
+}
+
+macro_rules! define_builder_push_method {
+    ($($input_type:ty),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<compact_ciphertext_list_builder_push_ $input_type:snake>](
+                    builder: *mut CompactCiphertextListBuilder,
+                    value: $input_type,
+                ) -> c_int {
+                    catch_panic(|| {
+                        let builder = get_mut_checked(builder).unwrap();
+                        builder.0.push(<$input_type as CApiIntegerType>::to_rust(value));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+define_builder_push_method!(bool, u8, u16, u32, u64, U128, U256, i8, i16, i32, i64, I128, I256);
+
+/// Deserializes a list and checks that it is conformant with the server key
+/// and holds between `min_num_elements` and `max_num_elements` (inclusive) values
+#[cfg(feature = "safe-deserialization")]
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_safe_deserialize_conformant(
+    buffer_view: crate::c_api::buffer::BufferView,
+    serialized_size_limit: u64,
+    server_key: *const crate::c_api::high_level_api::keys::ServerKey,
+    min_num_elements: usize,
+    max_num_elements: usize,
+    result: *mut *mut CompactCiphertextList,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let sk = get_ref_checked(server_key).unwrap();
+
+        let buffer_view: &[u8] = buffer_view.into();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let num_elements_constraint = crate::conformance::ListSizeConstraint::try_size_in_range(
+            min_num_elements,
+            max_num_elements,
+        )
+        .unwrap();
+
+        let inner = crate::high_level_api::safe_deserialize_conformant_compact_list(
+            buffer_view,
+            serialized_size_limit,
+            &sk.0,
+            num_elements_constraint,
+        )
+        .unwrap();
+
+        *result = Box::into_raw(Box::new(CompactCiphertextList(inner)));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_len(
+    list: *const CompactCiphertextList,
+    len: *mut usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(len).unwrap();
+
+        let list = get_ref_checked(list).unwrap();
+
+        *len = list.0.len();
+    })
+}
+
+/// Returns an error if the index is out of bounds
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_get_kind_of(
+    list: *const CompactCiphertextList,
+    index: usize,
+    result_type: *mut FheTypes,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result_type).unwrap();
+
+        let list = get_ref_checked(list).unwrap();
+
+        *result_type = FheTypes::from(list.0.get_kind_of(index).unwrap());
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_expand(
+    list: *const CompactCiphertextList,
+    expander: *mut *mut CompactCiphertextListExpander,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(expander).unwrap();
+
+        let list = get_ref_checked(list).unwrap();
+
+        let inner = list.0.expand();
+
+        *expander = Box::into_raw(Box::new(CompactCiphertextListExpander(inner)));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_expander_len(
+    expander: *const CompactCiphertextListExpander,
+    len: *mut usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(len).unwrap();
+
+        let expander = get_ref_checked(expander).unwrap();
+
+        *len = expander.0.len();
+    })
+}
+
+/// Returns an error if the index is out of bounds
+#[no_mangle]
+pub unsafe extern "C" fn compact_ciphertext_list_expander_get_kind_of(
+    expander: *const CompactCiphertextListExpander,
+    index: usize,
+    result_type: *mut FheTypes,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result_type).unwrap();
+
+        let expander = get_ref_checked(expander).unwrap();
+
+        *result_type = FheTypes::from(expander.0.get_kind_of(index).unwrap());
+    })
+}
+
+macro_rules! define_expander_get_method {
+    ($($wrapper_type:ty),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                /// Returns an error if the index is out of bounds or if the
+                /// value at this index is not of the requested type
+                #[no_mangle]
+                pub unsafe extern "C" fn [<compact_ciphertext_list_expander_get_ $wrapper_type:snake>](
+                    expander: *const CompactCiphertextListExpander,
+                    index: usize,
+                    result: *mut *mut $wrapper_type,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                        let expander = get_ref_checked(expander).unwrap();
+
+                        // First fill the result with a null ptr so that if we fail and the return code is not
+                        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+                        *result = std::ptr::null_mut();
+
+                        let inner = expander.0.get(index).unwrap().unwrap();
+
+                        *result = Box::into_raw(Box::new($wrapper_type(inner)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+define_expander_get_method!(
+    FheBool, FheUint2, FheUint4, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint24,
+    FheUint32, FheUint48, FheUint64, FheUint128, FheUint256, FheUint512, FheInt8, FheInt16,
+    FheInt32, FheInt64, FheInt128, FheInt256
+);
@@ -1,3 +1,6 @@
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
 /// w0, w1, w2, w3 are words in little endian order
 /// using two's complement representation
 #[repr(C)]
@@ -25,3 +28,73 @@ impl From<I256> for crate::integer::I256 {
         Self([value.w0, value.w1, value.w2, value.w3])
     }
 }
+
+/// Creates a I256 from little endian bytes (two's complement representation)
+///
+/// len must be 32
+#[no_mangle]
+pub unsafe extern "C" fn i256_from_little_endian_bytes(
+    input: *const u8,
+    len: usize,
+    result: *mut I256,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let mut inner = crate::integer::U256::default();
+
+        let input = std::slice::from_raw_parts(input, len);
+        inner.copy_from_le_byte_slice(input);
+
+        *result = I256::from(crate::integer::I256::from(inner.0));
+    })
+}
+
+/// Creates a I256 from big endian bytes (two's complement representation)
+///
+/// len must be 32
+#[no_mangle]
+pub unsafe extern "C" fn i256_from_big_endian_bytes(
+    input: *const u8,
+    len: usize,
+    result: *mut I256,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let mut inner = crate::integer::U256::default();
+
+        let input = std::slice::from_raw_parts(input, len);
+        inner.copy_from_be_byte_slice(input);
+
+        *result = I256::from(crate::integer::I256::from(inner.0));
+    })
+}
+
+/// len must be 32
+#[no_mangle]
+pub unsafe extern "C" fn i256_little_endian_bytes(
+    input: I256,
+    result: *mut u8,
+    len: usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let bytes = std::slice::from_raw_parts_mut(result, len);
+        crate::integer::U256::from(crate::integer::I256::from(input).0)
+            .copy_to_le_byte_slice(bytes);
+    })
+}
+
+/// len must be 32
+#[no_mangle]
+pub unsafe extern "C" fn i256_big_endian_bytes(input: I256, result: *mut u8, len: usize) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let bytes = std::slice::from_raw_parts_mut(result, len);
+        crate::integer::U256::from(crate::integer::I256::from(input).0)
+            .copy_to_be_byte_slice(bytes);
+    })
+}
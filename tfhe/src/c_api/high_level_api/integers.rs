@@ -10,6 +10,7 @@ use crate::c_api::high_level_api::i128::I128;
 use crate::c_api::high_level_api::i256::I256;
 use crate::c_api::high_level_api::u128::U128;
 use crate::c_api::high_level_api::u256::U256;
+use crate::c_api::high_level_api::u512::U512;
 use crate::c_api::utils::*;
 use std::os::raw::c_int;
 
@@ -121,6 +122,49 @@ macro_rules! impl_operations_for_integer_type {
 
         impl_unary_fn_on_type!($name => neg, not);
 
+        impl_binary_fn_with_bool_output_on_type!($name =>
+            overflowing_add,
+            overflowing_sub,
+            overflowing_mul,
+            checked_mul,
+        );
+
+        impl_unary_fn_with_output_type_on_type!($name => FheUint32:
+            leading_zeros,
+            trailing_zeros,
+            count_ones,
+            ilog2,
+        );
+
+        // The exponent is always an unsigned type
+        impl_scalar_binary_fn_on_type!($name, u32 => pow);
+        impl_binary_fn_on_type!(
+            lhs_type: $name,
+            rhs_type: $fhe_unsigned_type,
+            binary_fn_names: pow,
+        );
+
+        ::paste::paste! {
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:snake _checked_ilog2>](
+                sself: *const $name,
+                result: *mut *mut FheUint32,
+                is_ok: *mut *mut FheBool,
+            ) -> c_int {
+                $crate::c_api::utils::catch_panic(|| {
+                    check_ptr_is_non_null_and_aligned(result).unwrap();
+                    check_ptr_is_non_null_and_aligned(is_ok).unwrap();
+
+                    let sself = $crate::c_api::utils::get_ref_checked(sself).unwrap();
+
+                    let (r, ok) = sself.0.checked_ilog2();
+
+                    *result = Box::into_raw(Box::new(FheUint32(r)));
+                    *is_ok = Box::into_raw(Box::new(FheBool(ok)));
+                })
+            }
+        }
+
         // Implement div_rem.
         // We can't use the macro above as div_rem returns a tuple.
         //
@@ -189,8 +233,9 @@ macro_rules! impl_operations_for_integer_type {
                 })
             }
 
-             // map cmux to if_then_else
-             pub unsafe extern "C" fn [<$name:snake _cmux>](
+            // map cmux to if_then_else
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:snake _cmux>](
                 condition_ct: *const FheBool,
                 then_ct: *const $name,
                 else_ct: *const $name,
@@ -215,7 +260,7 @@ macro_rules! create_integer_wrapper_type {
         clear_shift_type: $clear_shift_type:ty
         $(,)?
     ) => {
-        pub struct $name($crate::high_level_api::$name);
+        pub struct $name(pub(in crate::c_api) $crate::high_level_api::$name);
 
         impl_destroy_on_type!($name);
 
@@ -303,6 +348,50 @@ macro_rules! create_integer_wrapper_type {
 
             impl_serialize_deserialize_on_type!([<Compact $name List>]);
 
+            impl_safe_serialize_on_type!([<Compact $name List>]);
+
+            /// Deserializes a list and checks that it is conformant with the server key
+            /// and holds between `min_num_elements` and `max_num_elements` (inclusive) integers
+            #[cfg(feature = "safe-deserialization")]
+            #[no_mangle]
+            pub unsafe extern "C" fn [<compact_ $name:snake _list_safe_deserialize_conformant>](
+                buffer_view: crate::c_api::buffer::BufferView,
+                serialized_size_limit: u64,
+                server_key: *const crate::c_api::high_level_api::keys::ServerKey,
+                min_num_elements: usize,
+                max_num_elements: usize,
+                result: *mut *mut [<Compact $name List>],
+            ) -> ::std::os::raw::c_int {
+                $crate::c_api::utils::catch_panic(|| {
+                    check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    let sk = $crate::c_api::utils::get_ref_checked(server_key).unwrap();
+
+                    let buffer_view: &[u8] = buffer_view.into();
+
+                    // First fill the result with a null ptr so that if we fail and the return code is not
+                    // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+                    *result = std::ptr::null_mut();
+
+                    let num_elements_constraint =
+                        crate::conformance::ListSizeConstraint::try_size_in_range(
+                            min_num_elements,
+                            max_num_elements,
+                        )
+                        .unwrap();
+
+                    let inner = crate::high_level_api::safe_deserialize_conformant_compact_integer_list(
+                        buffer_view,
+                        serialized_size_limit,
+                        &sk.0,
+                        num_elements_constraint,
+                    )
+                    .unwrap();
+
+                    *result = Box::into_raw(Box::new([<Compact $name List>](inner)));
+                })
+            }
+
             #[no_mangle]
             pub unsafe extern "C" fn [<compact_ $name:snake _list_len>](
                 sself: *const [<Compact $name List>],
@@ -326,7 +415,7 @@ macro_rules! create_integer_wrapper_type {
                     let list = $crate::c_api::utils::get_ref_checked(sself).unwrap();
                     let expanded = list.0.expand();
 
-                    let num_to_take = output_len.max(list.0.len());
+                    let num_to_take = output_len.min(list.0.len());
                     let iter = expanded.into_iter().take(num_to_take).enumerate();
                     for (i, fhe_uint) in iter {
                         let ptr = output.wrapping_add(i);
@@ -352,15 +441,44 @@ macro_rules! create_integer_wrapper_type {
 
 }
 
+create_integer_wrapper_type!(name: FheUint2, clear_scalar_type: u8);
+create_integer_wrapper_type!(name: FheUint4, clear_scalar_type: u8);
 create_integer_wrapper_type!(name: FheUint8, clear_scalar_type: u8);
 create_integer_wrapper_type!(name: FheUint10, clear_scalar_type: u16);
 create_integer_wrapper_type!(name: FheUint12, clear_scalar_type: u16);
 create_integer_wrapper_type!(name: FheUint14, clear_scalar_type: u16);
 create_integer_wrapper_type!(name: FheUint16, clear_scalar_type: u16);
+create_integer_wrapper_type!(name: FheUint24, clear_scalar_type: u32);
 create_integer_wrapper_type!(name: FheUint32, clear_scalar_type: u32);
+create_integer_wrapper_type!(name: FheUint48, clear_scalar_type: u64);
 create_integer_wrapper_type!(name: FheUint64, clear_scalar_type: u64);
 create_integer_wrapper_type!(name: FheUint128, clear_scalar_type: U128);
 create_integer_wrapper_type!(name: FheUint256, clear_scalar_type: U256);
+// There are no shifts by a U512 amount in the Rust API
+create_integer_wrapper_type!(
+    name: FheUint512,
+    fhe_unsigned_type: FheUint512,
+    clear_scalar_type: U512,
+    clear_shift_type: U256,
+);
+
+impl_decrypt_on_type!(FheUint2, u8);
+impl_try_encrypt_trivial_on_type!(FheUint2{crate::high_level_api::FheUint2}, u8);
+impl_try_encrypt_with_client_key_on_type!(FheUint2{crate::high_level_api::FheUint2}, u8);
+impl_try_encrypt_with_public_key_on_type!(FheUint2{crate::high_level_api::FheUint2}, u8);
+impl_try_encrypt_with_compact_public_key_on_type!(FheUint2{crate::high_level_api::FheUint2}, u8);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint2{crate::high_level_api::CompactFheUint2}, u8);
+impl_try_encrypt_with_client_key_on_type!(CompressedFheUint2{crate::high_level_api::CompressedFheUint2}, u8);
+impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheUint2List{crate::high_level_api::CompactFheUint2List}, u8);
+
+impl_decrypt_on_type!(FheUint4, u8);
+impl_try_encrypt_trivial_on_type!(FheUint4{crate::high_level_api::FheUint4}, u8);
+impl_try_encrypt_with_client_key_on_type!(FheUint4{crate::high_level_api::FheUint4}, u8);
+impl_try_encrypt_with_public_key_on_type!(FheUint4{crate::high_level_api::FheUint4}, u8);
+impl_try_encrypt_with_compact_public_key_on_type!(FheUint4{crate::high_level_api::FheUint4}, u8);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint4{crate::high_level_api::CompactFheUint4}, u8);
+impl_try_encrypt_with_client_key_on_type!(CompressedFheUint4{crate::high_level_api::CompressedFheUint4}, u8);
+impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheUint4List{crate::high_level_api::CompactFheUint4List}, u8);
 
 impl_decrypt_on_type!(FheUint8, u8);
 impl_try_encrypt_trivial_on_type!(FheUint8{crate::high_level_api::FheUint8}, u8);
@@ -407,15 +525,33 @@ impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint16{crate::high_l
 impl_try_encrypt_with_client_key_on_type!(CompressedFheUint16{crate::high_level_api::CompressedFheUint16}, u16);
 impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheUint16List{crate::high_level_api::CompactFheUint16List}, u16);
 
+impl_decrypt_on_type!(FheUint24, u32);
+impl_try_encrypt_trivial_on_type!(FheUint24{crate::high_level_api::FheUint24}, u32);
+impl_try_encrypt_with_client_key_on_type!(FheUint24{crate::high_level_api::FheUint24}, u32);
+impl_try_encrypt_with_public_key_on_type!(FheUint24{crate::high_level_api::FheUint24}, u32);
+impl_try_encrypt_with_compact_public_key_on_type!(FheUint24{crate::high_level_api::FheUint24}, u32);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint24{crate::high_level_api::CompactFheUint24}, u32);
+impl_try_encrypt_with_client_key_on_type!(CompressedFheUint24{crate::high_level_api::CompressedFheUint24}, u32);
+impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheUint24List{crate::high_level_api::CompactFheUint24List}, u32);
+
 impl_decrypt_on_type!(FheUint32, u32);
 impl_try_encrypt_trivial_on_type!(FheUint32{crate::high_level_api::FheUint32}, u32);
 impl_try_encrypt_with_client_key_on_type!(FheUint32{crate::high_level_api::FheUint32}, u32);
 impl_try_encrypt_with_public_key_on_type!(FheUint32{crate::high_level_api::FheUint32}, u32);
-impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint32{crate::high_level_api::CompactFheUint32}, u32);
 impl_try_encrypt_with_compact_public_key_on_type!(FheUint32{crate::high_level_api::FheUint32}, u32);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint32{crate::high_level_api::CompactFheUint32}, u32);
 impl_try_encrypt_with_client_key_on_type!(CompressedFheUint32{crate::high_level_api::CompressedFheUint32}, u32);
 impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheUint32List{crate::high_level_api::CompactFheUint32List}, u32);
 
+impl_decrypt_on_type!(FheUint48, u64);
+impl_try_encrypt_trivial_on_type!(FheUint48{crate::high_level_api::FheUint48}, u64);
+impl_try_encrypt_with_client_key_on_type!(FheUint48{crate::high_level_api::FheUint48}, u64);
+impl_try_encrypt_with_public_key_on_type!(FheUint48{crate::high_level_api::FheUint48}, u64);
+impl_try_encrypt_with_compact_public_key_on_type!(FheUint48{crate::high_level_api::FheUint48}, u64);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint48{crate::high_level_api::CompactFheUint48}, u64);
+impl_try_encrypt_with_client_key_on_type!(CompressedFheUint48{crate::high_level_api::CompressedFheUint48}, u64);
+impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheUint48List{crate::high_level_api::CompactFheUint48List}, u64);
+
 impl_decrypt_on_type!(FheUint64, u64);
 impl_try_encrypt_trivial_on_type!(FheUint64{crate::high_level_api::FheUint64}, u64);
 impl_try_encrypt_with_client_key_on_type!(FheUint64{crate::high_level_api::FheUint64}, u64);
@@ -432,6 +568,7 @@ impl_try_encrypt_with_public_key_on_type!(FheUint128{crate::high_level_api::FheU
 impl_try_encrypt_with_compact_public_key_on_type!(FheUint128{crate::high_level_api::FheUint128}, U128);
 impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint128{crate::high_level_api::CompactFheUint128}, U128);
 impl_try_encrypt_with_client_key_on_type!(CompressedFheUint128{crate::high_level_api::CompressedFheUint128}, U128);
+impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheUint128List{crate::high_level_api::CompactFheUint128List}, U128);
 
 impl_decrypt_on_type!(FheUint256, U256);
 impl_try_encrypt_trivial_on_type!(FheUint256{crate::high_level_api::FheUint256}, U256);
@@ -440,6 +577,16 @@ impl_try_encrypt_with_public_key_on_type!(FheUint256{crate::high_level_api::FheU
 impl_try_encrypt_with_compact_public_key_on_type!(FheUint256{crate::high_level_api::FheUint256}, U256);
 impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint256{crate::high_level_api::CompactFheUint256}, U256);
 impl_try_encrypt_with_client_key_on_type!(CompressedFheUint256{crate::high_level_api::CompressedFheUint256}, U256);
+impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheUint256List{crate::high_level_api::CompactFheUint256List}, U256);
+
+impl_decrypt_on_type!(FheUint512, U512);
+impl_try_encrypt_trivial_on_type!(FheUint512{crate::high_level_api::FheUint512}, U512);
+impl_try_encrypt_with_client_key_on_type!(FheUint512{crate::high_level_api::FheUint512}, U512);
+impl_try_encrypt_with_public_key_on_type!(FheUint512{crate::high_level_api::FheUint512}, U512);
+impl_try_encrypt_with_compact_public_key_on_type!(FheUint512{crate::high_level_api::FheUint512}, U512);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheUint512{crate::high_level_api::CompactFheUint512}, U512);
+impl_try_encrypt_with_client_key_on_type!(CompressedFheUint512{crate::high_level_api::CompressedFheUint512}, U512);
+impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheUint512List{crate::high_level_api::CompactFheUint512List}, U512);
 
 create_integer_wrapper_type!(
     name: FheInt8,
@@ -483,6 +630,7 @@ impl_try_encrypt_trivial_on_type!(FheInt8{crate::high_level_api::FheInt8}, i8);
 impl_try_encrypt_with_client_key_on_type!(FheInt8{crate::high_level_api::FheInt8}, i8);
 impl_try_encrypt_with_public_key_on_type!(FheInt8{crate::high_level_api::FheInt8}, i8);
 impl_try_encrypt_with_compact_public_key_on_type!(FheInt8{crate::high_level_api::FheInt8}, i8);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheInt8{crate::high_level_api::CompactFheInt8}, i8);
 impl_try_encrypt_with_client_key_on_type!(CompressedFheInt8{crate::high_level_api::CompressedFheInt8}, i8);
 impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheInt8List{crate::high_level_api::CompactFheInt8List}, i8);
 
@@ -491,6 +639,7 @@ impl_try_encrypt_trivial_on_type!(FheInt16{crate::high_level_api::FheInt16}, i16
 impl_try_encrypt_with_client_key_on_type!(FheInt16{crate::high_level_api::FheInt16}, i16);
 impl_try_encrypt_with_public_key_on_type!(FheInt16{crate::high_level_api::FheInt16}, i16);
 impl_try_encrypt_with_compact_public_key_on_type!(FheInt16{crate::high_level_api::FheInt16}, i16);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheInt16{crate::high_level_api::CompactFheInt16}, i16);
 impl_try_encrypt_with_client_key_on_type!(CompressedFheInt16{crate::high_level_api::CompressedFheInt16}, i16);
 impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheInt16List{crate::high_level_api::CompactFheInt16List}, i16);
 
@@ -499,6 +648,7 @@ impl_try_encrypt_trivial_on_type!(FheInt32{crate::high_level_api::FheInt32}, i32
 impl_try_encrypt_with_client_key_on_type!(FheInt32{crate::high_level_api::FheInt32}, i32);
 impl_try_encrypt_with_public_key_on_type!(FheInt32{crate::high_level_api::FheInt32}, i32);
 impl_try_encrypt_with_compact_public_key_on_type!(FheInt32{crate::high_level_api::FheInt32}, i32);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheInt32{crate::high_level_api::CompactFheInt32}, i32);
 impl_try_encrypt_with_client_key_on_type!(CompressedFheInt32{crate::high_level_api::CompressedFheInt32}, i32);
 impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheInt32List{crate::high_level_api::CompactFheInt32List}, i32);
 
@@ -507,6 +657,7 @@ impl_try_encrypt_trivial_on_type!(FheInt64{crate::high_level_api::FheInt64}, i64
 impl_try_encrypt_with_client_key_on_type!(FheInt64{crate::high_level_api::FheInt64}, i64);
 impl_try_encrypt_with_public_key_on_type!(FheInt64{crate::high_level_api::FheInt64}, i64);
 impl_try_encrypt_with_compact_public_key_on_type!(FheInt64{crate::high_level_api::FheInt64}, i64);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheInt64{crate::high_level_api::CompactFheInt64}, i64);
 impl_try_encrypt_with_client_key_on_type!(CompressedFheInt64{crate::high_level_api::CompressedFheInt64}, i64);
 impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheInt64List{crate::high_level_api::CompactFheInt64List}, i64);
 
@@ -515,14 +666,40 @@ impl_try_encrypt_trivial_on_type!(FheInt128{crate::high_level_api::FheInt128}, I
 impl_try_encrypt_with_client_key_on_type!(FheInt128{crate::high_level_api::FheInt128}, I128);
 impl_try_encrypt_with_public_key_on_type!(FheInt128{crate::high_level_api::FheInt128}, I128);
 impl_try_encrypt_with_compact_public_key_on_type!(FheInt128{crate::high_level_api::FheInt128}, I128);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheInt128{crate::high_level_api::CompactFheInt128}, I128);
 impl_try_encrypt_with_client_key_on_type!(CompressedFheInt128{crate::high_level_api::CompressedFheInt128}, I128);
+impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheInt128List{crate::high_level_api::CompactFheInt128List}, I128);
 
 impl_decrypt_on_type!(FheInt256, I256);
 impl_try_encrypt_trivial_on_type!(FheInt256{crate::high_level_api::FheInt256}, I256);
 impl_try_encrypt_with_client_key_on_type!(FheInt256{crate::high_level_api::FheInt256}, I256);
 impl_try_encrypt_with_public_key_on_type!(FheInt256{crate::high_level_api::FheInt256}, I256);
 impl_try_encrypt_with_compact_public_key_on_type!(FheInt256{crate::high_level_api::FheInt256}, I256);
+impl_try_encrypt_with_compact_public_key_on_type!(CompactFheInt256{crate::high_level_api::CompactFheInt256}, I256);
 impl_try_encrypt_with_client_key_on_type!(CompressedFheInt256{crate::high_level_api::CompressedFheInt256}, I256);
+impl_try_encrypt_list_with_compact_public_key_on_type!(CompactFheInt256List{crate::high_level_api::CompactFheInt256List}, I256);
+
+impl_unary_fn_on_type!(FheUint2 => sqrt);
+impl_unary_fn_on_type!(FheUint4 => sqrt);
+impl_unary_fn_on_type!(FheUint8 => sqrt);
+impl_unary_fn_on_type!(FheUint10 => sqrt);
+impl_unary_fn_on_type!(FheUint12 => sqrt);
+impl_unary_fn_on_type!(FheUint14 => sqrt);
+impl_unary_fn_on_type!(FheUint16 => sqrt);
+impl_unary_fn_on_type!(FheUint24 => sqrt);
+impl_unary_fn_on_type!(FheUint32 => sqrt);
+impl_unary_fn_on_type!(FheUint48 => sqrt);
+impl_unary_fn_on_type!(FheUint64 => sqrt);
+impl_unary_fn_on_type!(FheUint128 => sqrt);
+impl_unary_fn_on_type!(FheUint256 => sqrt);
+impl_unary_fn_on_type!(FheUint512 => sqrt);
+
+impl_unary_fn_on_type!(FheInt8 => abs);
+impl_unary_fn_on_type!(FheInt16 => abs);
+impl_unary_fn_on_type!(FheInt32 => abs);
+impl_unary_fn_on_type!(FheInt64 => abs);
+impl_unary_fn_on_type!(FheInt128 => abs);
+impl_unary_fn_on_type!(FheInt256 => abs);
 
 #[no_mangle]
 pub unsafe extern "C" fn compact_fhe_uint256_list_try_encrypt_with_compact_public_key_u128(
@@ -544,76 +721,8 @@ pub unsafe extern "C" fn compact_fhe_uint256_list_try_encrypt_with_compact_publi
     })
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn compact_fhe_uint256_list_try_encrypt_with_compact_public_key_u256(
-    input: *const U256,
-    input_len: usize,
-    public_key: *const CompactPublicKey,
-    result: *mut *mut CompactFheUint256List,
-) -> c_int {
-    catch_panic(|| {
-        let public_key = get_ref_checked(public_key).unwrap();
-
-        let slc = ::std::slice::from_raw_parts(input, input_len);
-        let values = slc
-            .iter()
-            .copied()
-            .map(crate::integer::U256::from)
-            .collect::<Vec<_>>();
-        let inner =
-            <crate::high_level_api::CompactFheUint256List>::try_encrypt(&values, &public_key.0)
-                .unwrap();
-
-        *result = Box::into_raw(Box::new(CompactFheUint256List(inner)));
-    })
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn compact_fhe_int128_list_try_encrypt_with_compact_public_key_i128(
-    input: *const I128,
-    input_len: usize,
-    public_key: *const CompactPublicKey,
-    result: *mut *mut CompactFheInt128List,
-) -> c_int {
-    catch_panic(|| {
-        let public_key = get_ref_checked(public_key).unwrap();
-
-        let slc = ::std::slice::from_raw_parts(input, input_len);
-        let values = slc.iter().copied().map(i128::from).collect::<Vec<_>>();
-        let inner =
-            <crate::high_level_api::CompactFheInt128List>::try_encrypt(&values, &public_key.0)
-                .unwrap();
-
-        *result = Box::into_raw(Box::new(CompactFheInt128List(inner)));
-    })
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn compact_fhe_int256_list_try_encrypt_with_compact_public_key_i256(
-    input: *const I256,
-    input_len: usize,
-    public_key: *const CompactPublicKey,
-    result: *mut *mut CompactFheInt256List,
-) -> c_int {
-    catch_panic(|| {
-        let public_key = get_ref_checked(public_key).unwrap();
-
-        let slc = ::std::slice::from_raw_parts(input, input_len);
-        let values = slc
-            .iter()
-            .copied()
-            .map(crate::integer::I256::from)
-            .collect::<Vec<_>>();
-        let inner =
-            <crate::high_level_api::CompactFheInt256List>::try_encrypt(&values, &public_key.0)
-                .unwrap();
-
-        *result = Box::into_raw(Box::new(CompactFheInt256List(inner)));
-    })
-}
-
 macro_rules! define_casting_operation(
-    ($from:ty => $($to:ty),*) => {
+    ($from:ty => [$($to:ty),*]) => {
         $(
             ::paste::paste!{
                 #[no_mangle]
@@ -630,37 +739,21 @@ macro_rules! define_casting_operation(
                 }
             }
         )*
-    }
+    };
+    ($($from:ty),* => $to_list:tt) => {
+        $(
+            define_casting_operation!($from => $to_list);
+        )*
+    };
 );
 
-define_casting_operation!(FheUint8 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheUint10 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheUint12 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheUint14 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheUint16 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheUint32 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheUint64 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheUint128 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheUint256 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-
-define_casting_operation!(FheInt8 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheInt16 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheInt32 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheInt64 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheInt128 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
-define_casting_operation!(FheInt256 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256,
-    FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256);
+define_casting_operation!(
+    FheBool, FheUint2, FheUint4, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint24,
+    FheUint32, FheUint48, FheUint64, FheUint128, FheUint256, FheUint512, FheInt8, FheInt16,
+    FheInt32, FheInt64, FheInt128, FheInt256
+    => [
+        FheUint2, FheUint4, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint24,
+        FheUint32, FheUint48, FheUint64, FheUint128, FheUint256, FheUint512, FheInt8, FheInt16,
+        FheInt32, FheInt64, FheInt128, FheInt256
+    ]
+);
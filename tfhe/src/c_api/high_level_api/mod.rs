@@ -2,6 +2,8 @@
 mod utils;
 #[cfg(feature = "boolean")]
 pub mod booleans;
+#[cfg(feature = "integer")]
+pub mod compact_list;
 pub mod config;
 #[cfg(feature = "integer")]
 pub mod i128;
@@ -14,3 +16,5 @@ pub mod keys;
 pub mod u128;
 #[cfg(feature = "integer")]
 pub mod u256;
+#[cfg(feature = "integer")]
+pub mod u512;
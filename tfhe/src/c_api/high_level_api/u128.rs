@@ -1,3 +1,6 @@
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct U128 {
@@ -18,3 +21,67 @@ impl From<U128> for u128 {
         ((value.w1 as Self) << 64u128) | value.w0 as Self
     }
 }
+
+/// Creates a U128 from little endian bytes
+///
+/// len must be 16
+#[no_mangle]
+pub unsafe extern "C" fn u128_from_little_endian_bytes(
+    input: *const u8,
+    len: usize,
+    result: *mut U128,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let input = std::slice::from_raw_parts(input, len);
+        let inner = u128::from_le_bytes(input.try_into().unwrap());
+
+        *result = U128::from(inner);
+    })
+}
+
+/// Creates a U128 from big endian bytes
+///
+/// len must be 16
+#[no_mangle]
+pub unsafe extern "C" fn u128_from_big_endian_bytes(
+    input: *const u8,
+    len: usize,
+    result: *mut U128,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let input = std::slice::from_raw_parts(input, len);
+        let inner = u128::from_be_bytes(input.try_into().unwrap());
+
+        *result = U128::from(inner);
+    })
+}
+
+/// len must be 16
+#[no_mangle]
+pub unsafe extern "C" fn u128_little_endian_bytes(
+    input: U128,
+    result: *mut u8,
+    len: usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let bytes = std::slice::from_raw_parts_mut(result, len);
+        bytes.copy_from_slice(&u128::from(input).to_le_bytes());
+    })
+}
+
+/// len must be 16
+#[no_mangle]
+pub unsafe extern "C" fn u128_big_endian_bytes(input: U128, result: *mut u8, len: usize) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let bytes = std::slice::from_raw_parts_mut(result, len);
+        bytes.copy_from_slice(&u128::from(input).to_be_bytes());
+    })
+}
@@ -0,0 +1,102 @@
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct U512 {
+    pub w0: u64,
+    pub w1: u64,
+    pub w2: u64,
+    pub w3: u64,
+    pub w4: u64,
+    pub w5: u64,
+    pub w6: u64,
+    pub w7: u64,
+}
+
+impl From<crate::integer::U512> for U512 {
+    fn from(value: crate::integer::U512) -> Self {
+        Self {
+            w0: value.0[0],
+            w1: value.0[1],
+            w2: value.0[2],
+            w3: value.0[3],
+            w4: value.0[4],
+            w5: value.0[5],
+            w6: value.0[6],
+            w7: value.0[7],
+        }
+    }
+}
+
+impl From<U512> for crate::integer::U512 {
+    fn from(value: U512) -> Self {
+        Self([
+            value.w0, value.w1, value.w2, value.w3, value.w4, value.w5, value.w6, value.w7,
+        ])
+    }
+}
+
+/// Creates a U512 from little endian bytes
+///
+/// len must be 64
+#[no_mangle]
+pub unsafe extern "C" fn u512_from_little_endian_bytes(
+    input: *const u8,
+    len: usize,
+    result: *mut U512,
+) -> c_int {
+    catch_panic(|| {
+        let mut inner = crate::integer::U512::default();
+
+        let input = std::slice::from_raw_parts(input, len);
+        inner.copy_from_le_byte_slice(input);
+
+        *result = U512::from(inner);
+    })
+}
+
+/// Creates a U512 from big endian bytes
+///
+/// len must be 64
+#[no_mangle]
+pub unsafe extern "C" fn u512_from_big_endian_bytes(
+    input: *const u8,
+    len: usize,
+    result: *mut U512,
+) -> c_int {
+    catch_panic(|| {
+        let mut inner = crate::integer::U512::default();
+
+        let input = std::slice::from_raw_parts(input, len);
+        inner.copy_from_be_byte_slice(input);
+
+        *result = U512::from(inner);
+    })
+}
+
+/// len must be 64
+#[no_mangle]
+pub unsafe extern "C" fn u512_little_endian_bytes(
+    input: U512,
+    result: *mut u8,
+    len: usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let bytes = std::slice::from_raw_parts_mut(result, len);
+        crate::integer::U512::from(input).copy_to_le_byte_slice(bytes);
+    })
+}
+
+/// len must be 64
+#[no_mangle]
+pub unsafe extern "C" fn u512_big_endian_bytes(input: U512, result: *mut u8, len: usize) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let bytes = std::slice::from_raw_parts_mut(result, len);
+        crate::integer::U512::from(input).copy_to_be_byte_slice(bytes);
+    })
+}
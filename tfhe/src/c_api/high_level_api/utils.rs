@@ -41,6 +41,7 @@ impl_c_api_integer_type!(i64);
 impl_c_api_integer_type!(crate::c_api::high_level_api::u128::U128 => u128);
 impl_c_api_integer_type!(crate::c_api::high_level_api::i128::I128 => i128);
 impl_c_api_integer_type!(crate::c_api::high_level_api::u256::U256 => crate::integer::U256);
+impl_c_api_integer_type!(crate::c_api::high_level_api::u512::U512 => crate::integer::U512);
 impl_c_api_integer_type!(crate::c_api::high_level_api::i256::I256 => crate::integer::I256);
 
 macro_rules! impl_destroy_on_type {
@@ -143,7 +144,12 @@ macro_rules! impl_try_encrypt_list_with_compact_public_key_on_type {
                 $crate::c_api::utils::catch_panic(|| {
                     let public_key = $crate::c_api::utils::get_ref_checked(public_key).unwrap();
                     let slc = ::std::slice::from_raw_parts(input, input_len);
-                    let inner = <$wrapped_type>::try_encrypt(slc, &public_key.0).unwrap();
+                    let values = slc
+                        .iter()
+                        .copied()
+                        .map(<$input_type as $crate::c_api::high_level_api::utils::CApiIntegerType>::to_rust)
+                        .collect::<Vec<_>>();
+                    let inner = <$wrapped_type>::try_encrypt(values.as_slice(), &public_key.0).unwrap();
 
                     *result = Box::into_raw(Box::new($wrapper_type(inner)));
                 })
@@ -264,6 +270,7 @@ macro_rules! impl_serialize_deserialize_on_type {
 macro_rules! impl_safe_serialize_on_type {
     ($wrapper_type:ty) => {
         ::paste::paste! {
+            #[cfg(feature = "safe-deserialization")]
             #[no_mangle]
             pub unsafe extern "C" fn [<$wrapper_type:snake _safe_serialize>](
                 sself: *const $wrapper_type,
@@ -290,6 +297,7 @@ macro_rules! impl_safe_serialize_on_type {
 macro_rules! impl_safe_deserialize_conformant_integer {
     ($wrapper_type:ty, $function_name:path) => {
         ::paste::paste! {
+            #[cfg(feature = "safe-deserialization")]
             #[no_mangle]
             pub unsafe extern "C" fn [<$wrapper_type:snake _safe_deserialize_conformant>](
                 buffer_view: crate::c_api::buffer::BufferView,
@@ -545,3 +553,62 @@ macro_rules! impl_scalar_binary_assign_fn_on_type {
         )*
     };
 }
+
+// Overflowing and checked operations return a FheBool in addition
+// to the result, so we use a specialized macro for them
+#[cfg(feature = "integer")]
+macro_rules! impl_binary_fn_with_bool_output_on_type {
+    ($wrapper_type:ty => $($binary_fn_name:ident),* $(,)?) => {
+        $(
+           ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<$wrapper_type:snake _ $binary_fn_name>](
+                    lhs: *const $wrapper_type,
+                    rhs: *const $wrapper_type,
+                    result: *mut *mut $wrapper_type,
+                    result_bool: *mut *mut $crate::c_api::high_level_api::booleans::FheBool,
+                ) -> ::std::os::raw::c_int {
+                    $crate::c_api::utils::catch_panic(|| {
+                        $crate::c_api::utils::check_ptr_is_non_null_and_aligned(result).unwrap();
+                        $crate::c_api::utils::check_ptr_is_non_null_and_aligned(result_bool).unwrap();
+
+                        let lhs = $crate::c_api::utils::get_ref_checked(lhs).unwrap();
+                        let rhs = $crate::c_api::utils::get_ref_checked(rhs).unwrap();
+
+                        let (inner, inner_bool) = (&lhs.0).$binary_fn_name(&rhs.0);
+
+                        *result = Box::into_raw(Box::new($wrapper_type(inner)));
+                        *result_bool = Box::into_raw(Box::new(
+                            $crate::c_api::high_level_api::booleans::FheBool(inner_bool)
+                        ));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+// Some unary operations (e.g. leading_zeros) return a different type
+// than the input type
+#[cfg(feature = "integer")]
+macro_rules! impl_unary_fn_with_output_type_on_type {
+    ($wrapper_type:ty => $output_type:ty: $($unary_fn_name:ident),* $(,)?) => {
+        $(
+           ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<$wrapper_type:snake _ $unary_fn_name>](
+                    lhs: *const $wrapper_type,
+                    result: *mut *mut $output_type,
+                ) -> ::std::os::raw::c_int {
+                    $crate::c_api::utils::catch_panic(|| {
+                        let lhs = $crate::c_api::utils::get_ref_checked(lhs).unwrap();
+
+                        let inner = (&lhs.0).$unary_fn_name();
+
+                        *result = Box::into_raw(Box::new($output_type(inner)));
+                    })
+                }
+            }
+        )*
+    };
+}
@@ -0,0 +1,84 @@
+use crate::c_api::buffer::*;
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
+use crate::integer;
+
+pub struct IntegerRadixCiphertext(pub(in crate::c_api) integer::RadixCiphertext);
+pub struct IntegerSignedRadixCiphertext(pub(in crate::c_api) integer::SignedRadixCiphertext);
+pub struct IntegerBooleanBlock(pub(in crate::c_api) integer::BooleanBlock);
+
+macro_rules! impl_serialize_deserialize_ciphertext {
+    ($wrapper_type:ident => $name:ident) => {
+        ::paste::paste! {
+            #[no_mangle]
+            pub unsafe extern "C" fn [<integer_serialize_ $name>](
+                ciphertext: *const $wrapper_type,
+                result: *mut Buffer,
+            ) -> c_int {
+                catch_panic(|| {
+                    check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    let ciphertext = get_ref_checked(ciphertext).unwrap();
+
+                    let buffer: Buffer = bincode::serialize(&ciphertext.0).unwrap().into();
+
+                    *result = buffer;
+                })
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn [<integer_deserialize_ $name>](
+                buffer_view: BufferView,
+                result: *mut *mut $wrapper_type,
+            ) -> c_int {
+                catch_panic(|| {
+                    check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    // First fill the result with a null ptr so that if we fail and the return code
+                    // is not checked, then any access to the result pointer will segfault (mimics
+                    // malloc on failure)
+                    *result = std::ptr::null_mut();
+
+                    let ciphertext = bincode::deserialize(buffer_view.into()).unwrap();
+
+                    let heap_allocated_ciphertext = Box::new($wrapper_type(ciphertext));
+
+                    *result = Box::into_raw(heap_allocated_ciphertext);
+                })
+            }
+        }
+    };
+}
+
+impl_serialize_deserialize_ciphertext!(IntegerRadixCiphertext => radix_ciphertext);
+impl_serialize_deserialize_ciphertext!(IntegerSignedRadixCiphertext => signed_radix_ciphertext);
+impl_serialize_deserialize_ciphertext!(IntegerBooleanBlock => boolean_block);
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_radix_ciphertext_num_blocks(
+    ciphertext: *const IntegerRadixCiphertext,
+    result: *mut usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let ciphertext = get_ref_checked(ciphertext).unwrap();
+
+        *result = ciphertext.0.blocks.len();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_signed_radix_ciphertext_num_blocks(
+    ciphertext: *const IntegerSignedRadixCiphertext,
+    result: *mut usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let ciphertext = get_ref_checked(ciphertext).unwrap();
+
+        *result = ciphertext.0.blocks.len();
+    })
+}
@@ -0,0 +1,259 @@
+use crate::c_api::buffer::*;
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
+use crate::integer;
+
+use super::{IntegerBooleanBlock, IntegerRadixCiphertext, IntegerSignedRadixCiphertext};
+
+pub struct IntegerClientKey(pub(in crate::c_api) integer::ClientKey);
+
+/// Maximum number of 64 bits words accepted by the `*_words` functions
+const MAX_NUM_WORDS: usize = 8;
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_gen_client_key(
+    shortint_parameters: crate::c_api::shortint::parameters::ShortintPBSParameters,
+    result_client_key: *mut *mut IntegerClientKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result_client_key).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result_client_key = std::ptr::null_mut();
+
+        let shortint_parameters: crate::shortint::parameters::ClassicPBSParameters =
+            shortint_parameters.try_into().unwrap();
+
+        let client_key = integer::ClientKey::new(shortint_parameters);
+
+        let heap_allocated_client_key = Box::new(IntegerClientKey(client_key));
+
+        *result_client_key = Box::into_raw(heap_allocated_client_key);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_client_key_encrypt_radix(
+    client_key: *const IntegerClientKey,
+    value_to_encrypt: u64,
+    num_blocks: usize,
+    result: *mut *mut IntegerRadixCiphertext,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let heap_allocated_ciphertext = Box::new(IntegerRadixCiphertext(
+            client_key.0.encrypt_radix(value_to_encrypt, num_blocks),
+        ));
+
+        *result = Box::into_raw(heap_allocated_ciphertext);
+    })
+}
+
+/// Encrypts a value given as 64 bits words in little endian order (at most 8 words)
+///
+/// If the words hold more bits than the ciphertext, the value is truncated.
+#[no_mangle]
+pub unsafe extern "C" fn integer_client_key_encrypt_radix_words(
+    client_key: *const IntegerClientKey,
+    words: *const u64,
+    num_words: usize,
+    num_blocks: usize,
+    result: *mut *mut IntegerRadixCiphertext,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        assert!(
+            num_words <= MAX_NUM_WORDS,
+            "at most {MAX_NUM_WORDS} words can be encrypted, got {num_words}"
+        );
+
+        check_ptr_is_non_null_and_aligned(words).unwrap();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+        let words = std::slice::from_raw_parts(words, num_words);
+
+        let mut value = integer::U512::ZERO;
+        value.0[..num_words].copy_from_slice(words);
+
+        let heap_allocated_ciphertext = Box::new(IntegerRadixCiphertext(
+            client_key.0.encrypt_radix(value, num_blocks),
+        ));
+
+        *result = Box::into_raw(heap_allocated_ciphertext);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_client_key_decrypt_radix(
+    client_key: *const IntegerClientKey,
+    ciphertext_to_decrypt: *const IntegerRadixCiphertext,
+    result: *mut u64,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+        let ciphertext_to_decrypt = get_ref_checked(ciphertext_to_decrypt).unwrap();
+
+        *result = client_key.0.decrypt_radix(&ciphertext_to_decrypt.0);
+    })
+}
+
+/// Decrypts into 64 bits words in little endian order (at most 8 words)
+///
+/// Words that are beyond the width of the ciphertext are set to 0.
+#[no_mangle]
+pub unsafe extern "C" fn integer_client_key_decrypt_radix_words(
+    client_key: *const IntegerClientKey,
+    ciphertext_to_decrypt: *const IntegerRadixCiphertext,
+    result: *mut u64,
+    num_words: usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        assert!(
+            num_words <= MAX_NUM_WORDS,
+            "at most {MAX_NUM_WORDS} words can be decrypted, got {num_words}"
+        );
+
+        let client_key = get_ref_checked(client_key).unwrap();
+        let ciphertext_to_decrypt = get_ref_checked(ciphertext_to_decrypt).unwrap();
+
+        let value: integer::U512 = client_key.0.decrypt_radix(&ciphertext_to_decrypt.0);
+
+        let result = std::slice::from_raw_parts_mut(result, num_words);
+        result.copy_from_slice(&value.0[..num_words]);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_client_key_encrypt_signed_radix(
+    client_key: *const IntegerClientKey,
+    value_to_encrypt: i64,
+    num_blocks: usize,
+    result: *mut *mut IntegerSignedRadixCiphertext,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let heap_allocated_ciphertext = Box::new(IntegerSignedRadixCiphertext(
+            client_key
+                .0
+                .encrypt_signed_radix(value_to_encrypt, num_blocks),
+        ));
+
+        *result = Box::into_raw(heap_allocated_ciphertext);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_client_key_decrypt_signed_radix(
+    client_key: *const IntegerClientKey,
+    ciphertext_to_decrypt: *const IntegerSignedRadixCiphertext,
+    result: *mut i64,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+        let ciphertext_to_decrypt = get_ref_checked(ciphertext_to_decrypt).unwrap();
+
+        *result = client_key.0.decrypt_signed_radix(&ciphertext_to_decrypt.0);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_client_key_encrypt_bool(
+    client_key: *const IntegerClientKey,
+    value_to_encrypt: bool,
+    result: *mut *mut IntegerBooleanBlock,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let heap_allocated_ciphertext = Box::new(IntegerBooleanBlock(
+            client_key.0.encrypt_bool(value_to_encrypt),
+        ));
+
+        *result = Box::into_raw(heap_allocated_ciphertext);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_client_key_decrypt_bool(
+    client_key: *const IntegerClientKey,
+    ciphertext_to_decrypt: *const IntegerBooleanBlock,
+    result: *mut bool,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+        let ciphertext_to_decrypt = get_ref_checked(ciphertext_to_decrypt).unwrap();
+
+        *result = client_key.0.decrypt_bool(&ciphertext_to_decrypt.0);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_serialize_client_key(
+    client_key: *const IntegerClientKey,
+    result: *mut Buffer,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let buffer: Buffer = bincode::serialize(&client_key.0).unwrap().into();
+
+        *result = buffer;
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_deserialize_client_key(
+    buffer_view: BufferView,
+    result: *mut *mut IntegerClientKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let client_key: integer::ClientKey = bincode::deserialize(buffer_view.into()).unwrap();
+
+        let heap_allocated_client_key = Box::new(IntegerClientKey(client_key));
+
+        *result = Box::into_raw(heap_allocated_client_key);
+    })
+}
@@ -0,0 +1,73 @@
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
+use super::{
+    IntegerBooleanBlock, IntegerClientKey, IntegerRadixCiphertext, IntegerServerKey,
+    IntegerSignedRadixCiphertext,
+};
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_destroy_client_key(client_key: *mut IntegerClientKey) -> c_int {
+    if client_key.is_null() {
+        return 0;
+    }
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(client_key).unwrap();
+
+        drop(Box::from_raw(client_key));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_destroy_server_key(server_key: *mut IntegerServerKey) -> c_int {
+    if server_key.is_null() {
+        return 0;
+    }
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(server_key).unwrap();
+
+        drop(Box::from_raw(server_key));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_destroy_radix_ciphertext(
+    ciphertext: *mut IntegerRadixCiphertext,
+) -> c_int {
+    if ciphertext.is_null() {
+        return 0;
+    }
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(ciphertext).unwrap();
+
+        drop(Box::from_raw(ciphertext));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_destroy_signed_radix_ciphertext(
+    ciphertext: *mut IntegerSignedRadixCiphertext,
+) -> c_int {
+    if ciphertext.is_null() {
+        return 0;
+    }
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(ciphertext).unwrap();
+
+        drop(Box::from_raw(ciphertext));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_destroy_boolean_block(
+    boolean_block: *mut IntegerBooleanBlock,
+) -> c_int {
+    if boolean_block.is_null() {
+        return 0;
+    }
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(boolean_block).unwrap();
+
+        drop(Box::from_raw(boolean_block));
+    })
+}
@@ -0,0 +1,49 @@
+//! C bindings for the radix representation of the integer layer
+//!
+//! Unlike the high-level API, the number of blocks of each ciphertext is chosen at encryption
+//! time, allowing any width that is a multiple of the message bits of the parameters.
+//!
+//! Unless stated otherwise, server key operations use the `*_parallelized` implementations
+//! and require both operands to have the same number of blocks.
+pub mod ciphertext;
+pub mod client_key;
+pub mod destroy;
+pub mod server_key;
+
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
+use crate::integer;
+
+pub use ciphertext::{IntegerBooleanBlock, IntegerRadixCiphertext, IntegerSignedRadixCiphertext};
+pub use client_key::IntegerClientKey;
+pub use server_key::IntegerServerKey;
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_gen_keys_with_parameters(
+    shortint_parameters: crate::c_api::shortint::parameters::ShortintPBSParameters,
+    result_client_key: *mut *mut IntegerClientKey,
+    result_server_key: *mut *mut IntegerServerKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result_client_key).unwrap();
+        check_ptr_is_non_null_and_aligned(result_server_key).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result_client_key = std::ptr::null_mut();
+        *result_server_key = std::ptr::null_mut();
+
+        let shortint_parameters: crate::shortint::parameters::ClassicPBSParameters =
+            shortint_parameters.try_into().unwrap();
+
+        let client_key = integer::ClientKey::new(shortint_parameters);
+        let server_key = integer::ServerKey::new_radix_server_key(&client_key);
+
+        let heap_allocated_client_key = Box::new(IntegerClientKey(client_key));
+        let heap_allocated_server_key = Box::new(IntegerServerKey(server_key));
+
+        *result_client_key = Box::into_raw(heap_allocated_client_key);
+        *result_server_key = Box::into_raw(heap_allocated_server_key);
+    })
+}
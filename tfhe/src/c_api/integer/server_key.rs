@@ -0,0 +1,426 @@
+use crate::c_api::buffer::*;
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
+use crate::integer;
+use crate::integer::IntegerCiphertext;
+
+use super::{
+    IntegerBooleanBlock, IntegerClientKey, IntegerRadixCiphertext, IntegerSignedRadixCiphertext,
+};
+
+pub struct IntegerServerKey(pub(in crate::c_api) integer::ServerKey);
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_gen_server_key(
+    client_key: *const IntegerClientKey,
+    result_server_key: *mut *mut IntegerServerKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result_server_key).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result_server_key = std::ptr::null_mut();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let server_key = integer::ServerKey::new_radix_server_key(&client_key.0);
+
+        let heap_allocated_server_key = Box::new(IntegerServerKey(server_key));
+
+        *result_server_key = Box::into_raw(heap_allocated_server_key);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_serialize_server_key(
+    server_key: *const IntegerServerKey,
+    result: *mut Buffer,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let server_key = get_ref_checked(server_key).unwrap();
+
+        let buffer: Buffer = bincode::serialize(&server_key.0).unwrap().into();
+
+        *result = buffer;
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_deserialize_server_key(
+    buffer_view: BufferView,
+    result: *mut *mut IntegerServerKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let server_key: integer::ServerKey = bincode::deserialize(buffer_view.into()).unwrap();
+
+        let heap_allocated_server_key = Box::new(IntegerServerKey(server_key));
+
+        *result = Box::into_raw(heap_allocated_server_key);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_server_key_create_trivial_radix(
+    server_key: *const IntegerServerKey,
+    value_to_trivially_encrypt: u64,
+    num_blocks: usize,
+    result: *mut *mut IntegerRadixCiphertext,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let server_key = get_ref_checked(server_key).unwrap();
+
+        let res = server_key
+            .0
+            .create_trivial_radix(value_to_trivially_encrypt, num_blocks);
+
+        *result = Box::into_raw(Box::new(IntegerRadixCiphertext(res)));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_server_key_create_trivial_signed_radix(
+    server_key: *const IntegerServerKey,
+    value_to_trivially_encrypt: i64,
+    num_blocks: usize,
+    result: *mut *mut IntegerSignedRadixCiphertext,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let server_key = get_ref_checked(server_key).unwrap();
+
+        let res = server_key
+            .0
+            .create_trivial_radix(value_to_trivially_encrypt, num_blocks);
+
+        *result = Box::into_raw(Box::new(IntegerSignedRadixCiphertext(res)));
+    })
+}
+
+macro_rules! impl_binary_ops {
+    ($wrapper_type:ident => $name:ident: $($op:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<integer_server_key_ $name _ $op>](
+                    server_key: *const IntegerServerKey,
+                    ct_left: *const $wrapper_type,
+                    ct_right: *const $wrapper_type,
+                    result: *mut *mut $wrapper_type,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                        let server_key = get_ref_checked(server_key).unwrap();
+                        let ct_left = get_ref_checked(ct_left).unwrap();
+                        let ct_right = get_ref_checked(ct_right).unwrap();
+
+                        let res = server_key.0.[<$op _parallelized>](&ct_left.0, &ct_right.0);
+
+                        *result = Box::into_raw(Box::new($wrapper_type(res)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_shift_ops {
+    ($wrapper_type:ident => $name:ident: $($op:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                /// The shift amount is always an unsigned ciphertext
+                #[no_mangle]
+                pub unsafe extern "C" fn [<integer_server_key_ $name _ $op>](
+                    server_key: *const IntegerServerKey,
+                    ct: *const $wrapper_type,
+                    amount: *const IntegerRadixCiphertext,
+                    result: *mut *mut $wrapper_type,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                        let server_key = get_ref_checked(server_key).unwrap();
+                        let ct = get_ref_checked(ct).unwrap();
+                        let amount = get_ref_checked(amount).unwrap();
+
+                        let res = server_key.0.[<$op _parallelized>](&ct.0, &amount.0);
+
+                        *result = Box::into_raw(Box::new($wrapper_type(res)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_unary_ops {
+    ($wrapper_type:ident => $name:ident: $($op:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<integer_server_key_ $name _ $op>](
+                    server_key: *const IntegerServerKey,
+                    ct: *const $wrapper_type,
+                    result: *mut *mut $wrapper_type,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                        let server_key = get_ref_checked(server_key).unwrap();
+                        let ct = get_ref_checked(ct).unwrap();
+
+                        let res = server_key.0.[<$op _parallelized>](&ct.0);
+
+                        *result = Box::into_raw(Box::new($wrapper_type(res)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_comparison_ops {
+    ($wrapper_type:ident => $name:ident: $($op:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<integer_server_key_ $name _ $op>](
+                    server_key: *const IntegerServerKey,
+                    ct_left: *const $wrapper_type,
+                    ct_right: *const $wrapper_type,
+                    result: *mut *mut IntegerBooleanBlock,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                        let server_key = get_ref_checked(server_key).unwrap();
+                        let ct_left = get_ref_checked(ct_left).unwrap();
+                        let ct_right = get_ref_checked(ct_right).unwrap();
+
+                        let res = server_key.0.[<$op _parallelized>](&ct_left.0, &ct_right.0);
+
+                        *result = Box::into_raw(Box::new(IntegerBooleanBlock(res)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_scalar_ops {
+    ($wrapper_type:ident => $name:ident, $clear_type:ty: $($op:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<integer_server_key_ $name _ $op>](
+                    server_key: *const IntegerServerKey,
+                    ct: *const $wrapper_type,
+                    scalar: $clear_type,
+                    result: *mut *mut $wrapper_type,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                        let server_key = get_ref_checked(server_key).unwrap();
+                        let ct = get_ref_checked(ct).unwrap();
+
+                        let res = server_key.0.[<$op _parallelized>](&ct.0, scalar);
+
+                        *result = Box::into_raw(Box::new($wrapper_type(res)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_scalar_comparison_ops {
+    ($wrapper_type:ident => $name:ident, $clear_type:ty: $($op:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<integer_server_key_ $name _ $op>](
+                    server_key: *const IntegerServerKey,
+                    ct: *const $wrapper_type,
+                    scalar: $clear_type,
+                    result: *mut *mut IntegerBooleanBlock,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                        let server_key = get_ref_checked(server_key).unwrap();
+                        let ct = get_ref_checked(ct).unwrap();
+
+                        let res = server_key.0.[<$op _parallelized>](&ct.0, scalar);
+
+                        *result = Box::into_raw(Box::new(IntegerBooleanBlock(res)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_if_then_else {
+    ($wrapper_type:ident => $name:ident) => {
+        ::paste::paste! {
+            #[no_mangle]
+            pub unsafe extern "C" fn [<integer_server_key_ $name _if_then_else>](
+                server_key: *const IntegerServerKey,
+                condition: *const IntegerBooleanBlock,
+                ct_then: *const $wrapper_type,
+                ct_else: *const $wrapper_type,
+                result: *mut *mut $wrapper_type,
+            ) -> c_int {
+                catch_panic(|| {
+                    check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    let server_key = get_ref_checked(server_key).unwrap();
+                    let condition = get_ref_checked(condition).unwrap();
+                    let ct_then = get_ref_checked(ct_then).unwrap();
+                    let ct_else = get_ref_checked(ct_else).unwrap();
+
+                    let res =
+                        server_key
+                            .0
+                            .if_then_else_parallelized(&condition.0, &ct_then.0, &ct_else.0);
+
+                    *result = Box::into_raw(Box::new($wrapper_type(res)));
+                })
+            }
+        }
+    };
+}
+
+macro_rules! impl_radix_operations {
+    ($wrapper_type:ident => $name:ident, $clear_type:ty) => {
+        impl_binary_ops!($wrapper_type => $name:
+            add, sub, mul, div, rem, bitand, bitor, bitxor, min, max
+        );
+        impl_shift_ops!($wrapper_type => $name:
+            left_shift, right_shift, rotate_left, rotate_right
+        );
+        impl_unary_ops!($wrapper_type => $name: neg, bitnot);
+        impl_comparison_ops!($wrapper_type => $name: eq, ne, lt, le, gt, ge);
+        impl_scalar_ops!($wrapper_type => $name, $clear_type:
+            scalar_add, scalar_sub, scalar_mul, scalar_min, scalar_max
+        );
+        impl_scalar_ops!($wrapper_type => $name, u64:
+            scalar_left_shift, scalar_right_shift, scalar_rotate_left, scalar_rotate_right
+        );
+        impl_scalar_comparison_ops!($wrapper_type => $name, $clear_type:
+            scalar_eq, scalar_ne, scalar_lt, scalar_le, scalar_gt, scalar_ge
+        );
+        impl_if_then_else!($wrapper_type => $name);
+    };
+}
+
+impl_radix_operations!(IntegerRadixCiphertext => radix, u64);
+impl_radix_operations!(IntegerSignedRadixCiphertext => signed_radix, i64);
+impl_unary_ops!(IntegerSignedRadixCiphertext => signed_radix: abs);
+
+/// Trims or extends the blocks of `ct` to reach `target_num_blocks`,
+/// extending with the sign bit if the input is signed
+fn cast_into<From, Into>(
+    server_key: &integer::ServerKey,
+    ct: &From,
+    target_num_blocks: usize,
+) -> Into
+where
+    From: integer::IntegerRadixCiphertext,
+    Into: integer::IntegerRadixCiphertext,
+{
+    let current_num_blocks = ct.blocks().len();
+    let blocks = if target_num_blocks > current_num_blocks {
+        let num_blocks_to_add = target_num_blocks - current_num_blocks;
+        if From::IS_SIGNED {
+            let mut ct = integer::SignedRadixCiphertext::from_blocks(ct.blocks().to_vec());
+            server_key.extend_radix_with_sign_msb_assign(&mut ct, num_blocks_to_add);
+            ct.blocks
+        } else {
+            let mut ct = integer::RadixCiphertext::from_blocks(ct.blocks().to_vec());
+            server_key.extend_radix_with_trivial_zero_blocks_msb_assign(&mut ct, num_blocks_to_add);
+            ct.blocks
+        }
+    } else {
+        let mut ct = integer::RadixCiphertext::from_blocks(ct.blocks().to_vec());
+        server_key.trim_radix_blocks_msb_assign(&mut ct, current_num_blocks - target_num_blocks);
+        ct.blocks
+    };
+
+    Into::from_blocks(blocks)
+}
+
+macro_rules! impl_cast_into {
+    ($($from_type:ident => $from_name:ident),* ; $to_list:tt) => {
+        $(
+            impl_cast_into!(@from $from_type => $from_name; $to_list);
+        )*
+    };
+    (@from $from_type:ident => $from_name:ident; [$($to_type:ident => $to_name:ident),*]) => {
+        $(
+            ::paste::paste! {
+                /// Casts the ciphertext to `target_num_blocks` blocks,
+                /// with the same semantics as `as` casts between Rust integers
+                #[no_mangle]
+                pub unsafe extern "C" fn [<integer_server_key_ $from_name _cast_into_ $to_name>](
+                    server_key: *const IntegerServerKey,
+                    ct: *const $from_type,
+                    target_num_blocks: usize,
+                    result: *mut *mut $to_type,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                        let server_key = get_ref_checked(server_key).unwrap();
+                        let ct = get_ref_checked(ct).unwrap();
+
+                        let res = cast_into(&server_key.0, &ct.0, target_num_blocks);
+
+                        *result = Box::into_raw(Box::new($to_type(res)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_cast_into!(
+    IntegerRadixCiphertext => radix, IntegerSignedRadixCiphertext => signed_radix;
+    [IntegerRadixCiphertext => radix, IntegerSignedRadixCiphertext => signed_radix]
+);
+
+#[no_mangle]
+pub unsafe extern "C" fn integer_server_key_boolean_block_into_radix(
+    server_key: *const IntegerServerKey,
+    boolean_block: *const IntegerBooleanBlock,
+    num_blocks: usize,
+    result: *mut *mut IntegerRadixCiphertext,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let server_key = get_ref_checked(server_key).unwrap();
+        let boolean_block = get_ref_checked(boolean_block).unwrap();
+
+        let res = boolean_block
+            .0
+            .clone()
+            .into_radix(num_blocks, &server_key.0);
+
+        *result = Box::into_raw(Box::new(IntegerRadixCiphertext(res)));
+    })
+}
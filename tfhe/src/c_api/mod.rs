@@ -5,6 +5,8 @@ pub mod buffer;
 pub mod core_crypto;
 #[cfg(feature = "high-level-c-api")]
 pub mod high_level_api;
+#[cfg(feature = "integer-c-api")]
+pub mod integer;
 #[cfg(feature = "shortint-c-api")]
 pub mod shortint;
 pub(crate) mod utils;
@@ -23,14 +23,44 @@ impl From<ShortintEncryptionKeyChoice> for crate::shortint::parameters::Encrypti
     }
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum ShortintSecretKeyDistribution {
+    ShortintSecretKeyDistributionBinary,
+    ShortintSecretKeyDistributionTernary,
+}
+
+impl From<ShortintSecretKeyDistribution> for SecretKeyDistribution {
+    fn from(value: ShortintSecretKeyDistribution) -> Self {
+        match value {
+            ShortintSecretKeyDistribution::ShortintSecretKeyDistributionBinary => Self::Binary,
+            ShortintSecretKeyDistribution::ShortintSecretKeyDistributionTernary => Self::Ternary,
+        }
+    }
+}
+
+impl ShortintSecretKeyDistribution {
+    // TryFrom::try_from cannot be marked as const, so we have to have
+    // our own function
+    const fn try_convert(rust_distribution: SecretKeyDistribution) -> Result<Self, &'static str> {
+        match rust_distribution {
+            SecretKeyDistribution::Binary => Ok(Self::ShortintSecretKeyDistributionBinary),
+            SecretKeyDistribution::Ternary => Ok(Self::ShortintSecretKeyDistributionTernary),
+            SecretKeyDistribution::BoundedGaussian { .. } => {
+                Err("Bounded gaussian secret keys are not supported by the C API")
+            }
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ShortintPBSParameters {
     pub lwe_dimension: usize,
     pub glwe_dimension: usize,
     pub polynomial_size: usize,
-    pub lwe_modular_std_dev: f64,
-    pub glwe_modular_std_dev: f64,
+    pub lwe_noise_distribution: crate::c_api::core_crypto::DynamicDistribution,
+    pub glwe_noise_distribution: crate::c_api::core_crypto::DynamicDistribution,
     pub pbs_base_log: usize,
     pub pbs_level: usize,
     pub ks_base_log: usize,
@@ -39,6 +69,7 @@ pub struct ShortintPBSParameters {
     pub carry_modulus: usize,
     pub modulus_power_of_2_exponent: usize,
     pub encryption_key_choice: ShortintEncryptionKeyChoice,
+    pub secret_key_distribution: ShortintSecretKeyDistribution,
 }
 
 impl TryFrom<ShortintPBSParameters> for crate::shortint::ClassicPBSParameters {
@@ -49,8 +80,8 @@ impl TryFrom<ShortintPBSParameters> for crate::shortint::ClassicPBSParameters {
             lwe_dimension: LweDimension(c_params.lwe_dimension),
             glwe_dimension: GlweDimension(c_params.glwe_dimension),
             polynomial_size: PolynomialSize(c_params.polynomial_size),
-            lwe_modular_std_dev: StandardDev(c_params.lwe_modular_std_dev),
-            glwe_modular_std_dev: StandardDev(c_params.glwe_modular_std_dev),
+            lwe_noise_distribution: c_params.lwe_noise_distribution.into(),
+            glwe_noise_distribution: c_params.glwe_noise_distribution.into(),
             pbs_base_log: DecompositionBaseLog(c_params.pbs_base_log),
             pbs_level: DecompositionLevelCount(c_params.pbs_level),
             ks_base_log: DecompositionBaseLog(c_params.ks_base_log),
@@ -61,13 +92,16 @@ impl TryFrom<ShortintPBSParameters> for crate::shortint::ClassicPBSParameters {
                 c_params.modulus_power_of_2_exponent,
             )?,
             encryption_key_choice: c_params.encryption_key_choice.into(),
+            secret_key_distribution: c_params.secret_key_distribution.into(),
         })
     }
 }
 
-impl From<crate::shortint::ClassicPBSParameters> for ShortintPBSParameters {
-    fn from(rust_params: crate::shortint::ClassicPBSParameters) -> Self {
-        Self::convert(rust_params)
+impl TryFrom<crate::shortint::ClassicPBSParameters> for ShortintPBSParameters {
+    type Error = &'static str;
+
+    fn try_from(rust_params: crate::shortint::ClassicPBSParameters) -> Result<Self, Self::Error> {
+        Self::try_convert(rust_params)
     }
 }
 
@@ -95,13 +129,34 @@ const fn convert_modulus(rust_modulus: crate::shortint::CiphertextModulus) -> us
 }
 
 impl ShortintPBSParameters {
+    // Used for the exposed parameter sets, so an unsupported set
+    // fails to compile instead of panicking at runtime
     const fn convert(rust_params: crate::shortint::ClassicPBSParameters) -> Self {
-        Self {
+        match Self::try_convert(rust_params) {
+            Ok(c_params) => c_params,
+            Err(msg) => panic!("{}", msg),
+        }
+    }
+
+    const fn try_convert(
+        rust_params: crate::shortint::ClassicPBSParameters,
+    ) -> Result<Self, &'static str> {
+        let secret_key_distribution =
+            match ShortintSecretKeyDistribution::try_convert(rust_params.secret_key_distribution) {
+                Ok(distribution) => distribution,
+                Err(msg) => return Err(msg),
+            };
+
+        Ok(Self {
             lwe_dimension: rust_params.lwe_dimension.0,
             glwe_dimension: rust_params.glwe_dimension.0,
             polynomial_size: rust_params.polynomial_size.0,
-            lwe_modular_std_dev: rust_params.lwe_modular_std_dev.0,
-            glwe_modular_std_dev: rust_params.glwe_modular_std_dev.0,
+            lwe_noise_distribution: crate::c_api::core_crypto::DynamicDistribution::convert(
+                rust_params.lwe_noise_distribution,
+            ),
+            glwe_noise_distribution: crate::c_api::core_crypto::DynamicDistribution::convert(
+                rust_params.glwe_noise_distribution,
+            ),
             pbs_base_log: rust_params.pbs_base_log.0,
             pbs_level: rust_params.pbs_level.0,
             ks_base_log: rust_params.ks_base_log.0,
@@ -112,7 +167,8 @@ impl ShortintPBSParameters {
             encryption_key_choice: ShortintEncryptionKeyChoice::convert(
                 rust_params.encryption_key_choice,
             ),
-        }
+            secret_key_distribution,
+        })
     }
 }
 
@@ -143,7 +199,7 @@ macro_rules! expose_as_shortint_pbs_parameters(
                 // 1 scope for each parameters
                 {
                     let rust_params = crate::shortint::parameters::$param_name;
-                    let c_params = ShortintPBSParameters::from(rust_params);
+                    let c_params = ShortintPBSParameters::try_from(rust_params).unwrap();
                     let rust_params_from_c = crate::shortint::parameters::ClassicPBSParameters::try_from(c_params).unwrap();
                     assert_eq!(rust_params, rust_params_from_c);
                 }
@@ -344,7 +400,7 @@ pub unsafe extern "C" fn shortint_get_parameters(
         };
 
         if let Some(params) = params {
-            *result = params.into();
+            *result = params.try_into().unwrap();
         }
     })
 }
@@ -366,7 +422,7 @@ pub unsafe extern "C" fn shortint_get_parameters_small(
         };
 
         if let Some(params) = params {
-            *result = params.into();
+            *result = params.try_into().unwrap();
         }
     })
 }
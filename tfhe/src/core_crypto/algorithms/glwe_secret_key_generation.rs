@@ -1,8 +1,11 @@
 //! Module containing primitives pertaining to the generation of
 //! [`GLWE secret keys`](`GlweSecretKey`).
 
+use crate::core_crypto::commons::dispersion::StandardDev;
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
-use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
+use crate::core_crypto::commons::math::random::{
+    BoundedGaussian, RandomGenerable, UniformBinary, UniformTernary,
+};
 use crate::core_crypto::commons::numeric::Numeric;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
@@ -69,3 +72,111 @@ pub fn generate_binary_glwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(glwe_secret_key.as_mut());
 }
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with uniformly random ternary coefficients.
+///
+/// The coefficients are in {-1, 0, 1}, -1 being represented as `Scalar::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut glwe_secret_key =
+///     GlweSecretKey::new_empty_key(0u64, glwe_size.to_glwe_dimension(), polynomial_size);
+///
+/// generate_ternary_glwe_secret_key(&mut glwe_secret_key, &mut secret_generator);
+///
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// ```
+pub fn generate_ternary_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformTernary>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_uniform_ternary(glwe_secret_key.as_mut());
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with bounded rounded gaussian coefficients.
+///
+/// The coefficients follow a rounded gaussian distribution centered on zero, their absolute value
+/// is at most `bound`. Negative coefficients are represented in two's complement.
+pub fn generate_bounded_gaussian_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    std_dev: StandardDev,
+    bound: u32,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<BoundedGaussian>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_bounded_gaussian(glwe_secret_key.as_mut(), std_dev.0, bound);
+}
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with coefficients following
+/// the given [`SecretKeyDistribution`].
+pub fn allocate_and_generate_new_glwe_secret_key<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformBinary>
+        + RandomGenerable<UniformTernary>
+        + RandomGenerable<BoundedGaussian>
+        + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, glwe_dimension, polynomial_size);
+
+    generate_glwe_secret_key(&mut glwe_secret_key, distribution, generator);
+
+    glwe_secret_key
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with coefficients following the given
+/// [`SecretKeyDistribution`].
+pub fn generate_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformBinary>
+        + RandomGenerable<UniformTernary>
+        + RandomGenerable<BoundedGaussian>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    match distribution {
+        SecretKeyDistribution::Binary => {
+            generate_binary_glwe_secret_key(glwe_secret_key, generator);
+        }
+        SecretKeyDistribution::Ternary => {
+            generate_ternary_glwe_secret_key(glwe_secret_key, generator);
+        }
+        SecretKeyDistribution::BoundedGaussian { std_dev, bound } => {
+            generate_bounded_gaussian_glwe_secret_key(glwe_secret_key, std_dev, bound, generator);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Module containing primitives pertaining to the binary expansion of non binary
+//! [`LWE secret keys`](`LweSecretKey`) and of the [`LWE ciphertexts`](`LweCiphertext`) encrypted
+//! under them.
+//!
+//! The blind rotation of a bootstrap requires a binary input key. A key whose coefficients are
+//! bounded by $2^{b}$ in absolute value can be written as $s = \sum\_{j<b} 2^{j} (s^{+}\_{j} -
+//! s^{-}\_{j})$ with binary $s^{+}\_{j}$ and $s^{-}\_{j}$, so a ciphertext $(\vec{a}, b)$ encrypted
+//! under $\vec{s}$ can be turned into a ciphertext encrypting the same plaintext under the binary
+//! key made of the $s^{+}\_{j}$ and $s^{-}\_{j}$, at the cost of a $2b$ times larger dimension.
+//! A bootstrapping key generated for the expanded key can then bootstrap the expanded ciphertexts.
+
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with the binary expansion of the
+/// input key, whose coefficients absolute values must fit in `coefficient_bit_count` bits.
+///
+/// See [`expand_lwe_ciphertext_for_binary_key`] for usage.
+pub fn allocate_and_expand_lwe_secret_key_to_binary<Scalar, InputCont>(
+    input_lwe_secret_key: &LweSecretKey<InputCont>,
+    coefficient_bit_count: usize,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let mut output_lwe_secret_key = LweSecretKeyOwned::new_empty_key(
+        Scalar::ZERO,
+        LweDimension(input_lwe_secret_key.lwe_dimension().0 * 2 * coefficient_bit_count),
+    );
+
+    expand_lwe_secret_key_to_binary(input_lwe_secret_key, &mut output_lwe_secret_key);
+
+    output_lwe_secret_key
+}
+
+/// Fill the output [`LWE secret key`](`LweSecretKey`) with the binary expansion of the input
+/// key.
+///
+/// The number of bits used to write the absolute value of each input coefficient is given by the
+/// ratio between the dimensions of the keys, each input coefficient $s\_i$ is replaced by the bits
+/// of $\max(s\_i, 0)$ followed by the bits of $\max(-s\_i, 0)$, least significant bit first.
+///
+/// # Panics
+///
+/// Panics if the output dimension is not an even multiple of the input dimension, or if an input
+/// coefficient does not fit in the number of bits given by the dimensions.
+pub fn expand_lwe_secret_key_to_binary<Scalar, InputCont, OutputCont>(
+    input_lwe_secret_key: &LweSecretKey<InputCont>,
+    output_lwe_secret_key: &mut LweSecretKey<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let coefficient_bit_count = expansion_bit_count(
+        input_lwe_secret_key.lwe_dimension(),
+        output_lwe_secret_key.lwe_dimension(),
+    );
+
+    for (&coefficient, expanded) in input_lwe_secret_key.as_ref().iter().zip(
+        output_lwe_secret_key
+            .as_mut()
+            .chunks_exact_mut(2 * coefficient_bit_count),
+    ) {
+        let is_negative = (coefficient >> (Scalar::BITS - 1)) == Scalar::ONE;
+        let absolute_value = if is_negative {
+            coefficient.wrapping_neg()
+        } else {
+            coefficient
+        };
+        assert!(
+            coefficient_bit_count >= Scalar::BITS
+                || absolute_value >> coefficient_bit_count == Scalar::ZERO,
+            "Secret key coefficient does not fit in {coefficient_bit_count} bits"
+        );
+
+        let (positive_bits, negative_bits) = expanded.split_at_mut(coefficient_bit_count);
+        let (set_bits, cleared_bits) = if is_negative {
+            (negative_bits, positive_bits)
+        } else {
+            (positive_bits, negative_bits)
+        };
+        for (bit_index, bit) in set_bits.iter_mut().enumerate() {
+            *bit = (absolute_value >> bit_index) & Scalar::ONE;
+        }
+        cleared_bits.fill(Scalar::ZERO);
+    }
+}
+
+/// Allocate a new [`LWE ciphertext`](`LweCiphertext`) of the given dimension and fill it with the
+/// input ciphertext, re-encoded to encrypt the same plaintext under the binary expansion of its
+/// secret key.
+///
+/// See [`expand_lwe_ciphertext_for_binary_key`] for usage.
+pub fn allocate_and_expand_lwe_ciphertext_for_binary_key<Scalar, InputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_lwe_dimension: LweDimension,
+) -> LweCiphertextOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let mut output_lwe_ciphertext = LweCiphertextOwned::new(
+        Scalar::ZERO,
+        output_lwe_dimension.to_lwe_size(),
+        input_lwe_ciphertext.ciphertext_modulus(),
+    );
+
+    expand_lwe_ciphertext_for_binary_key(input_lwe_ciphertext, &mut output_lwe_ciphertext);
+
+    output_lwe_ciphertext
+}
+
+/// Fill the output [`LWE ciphertext`](`LweCiphertext`) with the input ciphertext, re-encoded for
+/// the binary expansion of its secret key.
+///
+/// The binary expansion of the key is the one computed by [`expand_lwe_secret_key_to_binary`]. The
+/// output body is the input body, and each input mask element $a\_i$ is replaced by
+/// $(2^{0} a\_i, \ldots, 2^{b-1} a\_i, -2^{0} a\_i, \ldots, -2^{b-1} a\_i)$.
+///
+/// # Panics
+///
+/// Panics if the output dimension is not an even multiple of the input dimension, or if the
+/// ciphertext modulus is not a power of two.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let secret_key_distribution = SecretKeyDistribution::Ternary;
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the ternary LweSecretKey and its binary expansion
+/// let lwe_secret_key = allocate_and_generate_new_lwe_secret_key(
+///     lwe_dimension,
+///     secret_key_distribution,
+///     &mut secret_generator,
+/// );
+/// let expanded_lwe_secret_key = allocate_and_expand_lwe_secret_key_to_binary(
+///     &lwe_secret_key,
+///     secret_key_distribution.coefficient_bit_count(),
+/// );
+/// assert!(expanded_lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1));
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut expanded_lwe = LweCiphertext::new(
+///     0u64,
+///     expanded_lwe_secret_key.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+/// expand_lwe_ciphertext_for_binary_key(&lwe, &mut expanded_lwe);
+///
+/// // Both ciphertexts decrypt to the same plaintext
+/// assert_eq!(
+///     decrypt_lwe_ciphertext(&lwe_secret_key, &lwe),
+///     decrypt_lwe_ciphertext(&expanded_lwe_secret_key, &expanded_lwe)
+/// );
+/// ```
+pub fn expand_lwe_ciphertext_for_binary_key<Scalar, InputCont, OutputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_lwe_ciphertext: &mut LweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input_lwe_ciphertext.ciphertext_modulus(),
+        output_lwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        Input CiphertextModulus {:?}, output CiphertextModulus {:?}.",
+        input_lwe_ciphertext.ciphertext_modulus(),
+        output_lwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        input_lwe_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+
+    let coefficient_bit_count = expansion_bit_count(
+        input_lwe_ciphertext.lwe_size().to_lwe_dimension(),
+        output_lwe_ciphertext.lwe_size().to_lwe_dimension(),
+    );
+
+    let (input_mask, input_body) = input_lwe_ciphertext.get_mask_and_body();
+    let (mut output_mask, output_body) = output_lwe_ciphertext.get_mut_mask_and_body();
+
+    for (&mask_element, expanded) in input_mask.as_ref().iter().zip(
+        output_mask
+            .as_mut()
+            .chunks_exact_mut(2 * coefficient_bit_count),
+    ) {
+        let (positive, negative) = expanded.split_at_mut(coefficient_bit_count);
+        for (bit_index, (positive, negative)) in
+            positive.iter_mut().zip(negative.iter_mut()).enumerate()
+        {
+            *positive = mask_element << bit_index;
+            *negative = positive.wrapping_neg();
+        }
+    }
+
+    *output_body.data = *input_body.data;
+}
+
+/// Returns the number of bits per coefficient of the expansion from `input_lwe_dimension` to
+/// `output_lwe_dimension`
+fn expansion_bit_count(
+    input_lwe_dimension: LweDimension,
+    output_lwe_dimension: LweDimension,
+) -> usize {
+    assert!(
+        input_lwe_dimension.0 != 0 && output_lwe_dimension.0 % (2 * input_lwe_dimension.0) == 0,
+        "The output LweDimension {output_lwe_dimension:?} must be an even multiple \
+        of the input LweDimension {input_lwe_dimension:?}"
+    );
+
+    output_lwe_dimension.0 / (2 * input_lwe_dimension.0)
+}
@@ -4,12 +4,14 @@
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulusKind;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+#[cfg(feature = "zk-pok-experimental")]
+use crate::zk::{CompactPkeProof, CompactPkePublicParams, ZkVerificationOutCome};
 use rayon::prelude::*;
 
 /// Convenience function to share the core logic of the LWE encryption between all functions needing
@@ -19,7 +21,7 @@ pub fn fill_lwe_mask_and_body_for_encryption<Scalar, KeyCont, OutputCont, Gen>(
     output_mask: &mut LweMask<OutputCont>,
     output_body: &mut LweBodyRefMut<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -68,7 +70,7 @@ pub fn fill_lwe_mask_and_body_for_encryption_native_mod_compatible<
     output_mask: &mut LweMask<OutputCont>,
     output_body: &mut LweBodyRefMut<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -121,7 +123,7 @@ pub fn fill_lwe_mask_and_body_for_encryption_other_mod<Scalar, KeyCont, OutputCo
     output_mask: &mut LweMask<OutputCont>,
     output_body: &mut LweBodyRefMut<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -224,7 +226,7 @@ pub fn encrypt_lwe_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut LweCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -311,7 +313,7 @@ pub fn encrypt_lwe_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
 pub fn allocate_and_encrypt_new_lwe_ciphertext<Scalar, KeyCont, Gen>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LweCiphertextOwned<Scalar>
@@ -668,7 +670,7 @@ pub fn encrypt_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont, Gen>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut LweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -777,7 +779,7 @@ pub fn par_encrypt_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont, G
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut LweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -1103,7 +1105,7 @@ pub fn encrypt_seeded_lwe_ciphertext_list_with_existing_generator<
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -1226,7 +1228,7 @@ pub fn encrypt_seeded_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -1262,7 +1264,7 @@ pub fn par_encrypt_seeded_lwe_ciphertext_list_with_existing_generator<
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -1386,7 +1388,7 @@ pub fn par_encrypt_seeded_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, Input
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -1416,7 +1418,7 @@ pub fn encrypt_seeded_lwe_ciphertext_with_existing_generator<Scalar, KeyCont, Ge
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertext<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -1505,7 +1507,7 @@ pub fn encrypt_seeded_lwe_ciphertext<Scalar, KeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertext<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -1587,7 +1589,7 @@ pub fn encrypt_seeded_lwe_ciphertext<Scalar, KeyCont, NoiseSeeder>(
 pub fn allocate_and_encrypt_new_seeded_lwe_ciphertext<Scalar, KeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLweCiphertext<Scalar>
@@ -1691,8 +1693,8 @@ pub fn encrypt_lwe_ciphertext_with_compact_public_key<
     lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
     output: &mut LweCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    mask_noise_parameters: impl DispersionParameter,
-    body_noise_parameters: impl DispersionParameter,
+    mask_noise_parameters: impl NoiseDistribution,
+    body_noise_parameters: impl NoiseDistribution,
     secret_generator: &mut SecretRandomGenerator<SecretGen>,
     encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
 ) where
@@ -1845,8 +1847,8 @@ pub fn encrypt_lwe_compact_ciphertext_list_with_compact_public_key<
     lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
     output: &mut LweCompactCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    mask_noise_parameters: impl DispersionParameter,
-    body_noise_parameters: impl DispersionParameter,
+    mask_noise_parameters: impl NoiseDistribution,
+    body_noise_parameters: impl NoiseDistribution,
     secret_generator: &mut SecretRandomGenerator<SecretGen>,
     encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
 ) where
@@ -2053,8 +2055,8 @@ pub fn par_encrypt_lwe_compact_ciphertext_list_with_compact_public_key<
     lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
     output: &mut LweCompactCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    mask_noise_parameters: impl DispersionParameter + Sync,
-    body_noise_parameters: impl DispersionParameter + Sync,
+    mask_noise_parameters: impl NoiseDistribution + Sync,
+    body_noise_parameters: impl NoiseDistribution + Sync,
     secret_generator: &mut SecretRandomGenerator<SecretGen>,
     encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
 ) where
@@ -2170,6 +2172,374 @@ pub fn par_encrypt_lwe_compact_ciphertext_list_with_compact_public_key<
         );
 }
 
+/// Sample a noise value in `[-2^bound_log2, 2^bound_log2[`, drawing again values that are out of
+/// the bound.
+#[cfg(feature = "zk-pok-experimental")]
+fn random_bounded_noise<Gen: ByteRandomGenerator>(
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    noise_parameters: impl NoiseDistribution,
+    bound_log2: u32,
+) -> i64 {
+    let bound = 1i64 << bound_log2;
+    loop {
+        let noise = generator.random_noise::<u64>(noise_parameters) as i64;
+        if (-bound..bound).contains(&noise) {
+            return noise;
+        }
+    }
+}
+
+/// Encrypt an input plaintext list and prove that the encryption is well formed.
+///
+/// The output [`LWE compact ciphertext list`](`LweCompactCiphertextList`) is encrypted using an
+/// [`LWE compact public key`](`LweCompactPublicKey`) and a zero-knowledge proof is returned.
+///
+/// The proof shows that each plaintext is a message smaller than the message modulus of the
+/// public parameters multiplied by their delta, that the noises are within the bounds of the
+/// public parameters and that the random vector is binary. It can be checked with
+/// [`verify_lwe_compact_ciphertext_list`].
+///
+/// Noise samples that are out of the bounds of the public parameters are drawn again, this is
+/// only expected to happen for bounded distributions not matching the public parameters.
+///
+/// # Panics
+///
+/// Panics if a plaintext is not a valid encoding for the public parameters, or if the list or the
+/// key do not match them.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+/// use tfhe::zk::CompactPkeCrs;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(256);
+/// let lwe_ciphertext_count = LweCiphertextCount(4);
+/// let noise_distribution = DynamicDistribution::new_t_uniform(20);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let message_modulus = 4u64;
+/// let carry_modulus = 4u64;
+/// let delta = (1u64 << 63) / (message_modulus * carry_modulus);
+///
+/// let crs = CompactPkeCrs::new(
+///     lwe_dimension,
+///     lwe_ciphertext_count.0,
+///     noise_distribution,
+///     noise_distribution,
+///     message_modulus,
+///     carry_modulus,
+/// );
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// let lwe_compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+///     &lwe_secret_key,
+///     noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let input_plaintext_list = PlaintextList::from_container(
+///     (0..lwe_ciphertext_count.0 as u64)
+///         .map(|m| (m % message_modulus) * delta)
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// // Create a new LweCompactCiphertextList
+/// let mut output_compact_ct_list = LweCompactCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     lwe_ciphertext_count,
+///     ciphertext_modulus,
+/// );
+///
+/// let proof = encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+///     &lwe_compact_public_key,
+///     &mut output_compact_ct_list,
+///     &input_plaintext_list,
+///     noise_distribution,
+///     noise_distribution,
+///     &mut secret_generator,
+///     &mut encryption_generator,
+///     crs.public_params(),
+/// );
+///
+/// // The proof can be checked without knowing the secret key
+/// assert!(verify_lwe_compact_ciphertext_list(
+///     &output_compact_ct_list,
+///     &lwe_compact_public_key,
+///     &proof,
+///     crs.public_params(),
+/// )
+/// .is_valid());
+///
+/// let mut output_plaintext_list = input_plaintext_list.clone();
+/// output_plaintext_list.as_mut().fill(0u64);
+///
+/// let lwe_ciphertext_list = output_compact_ct_list.expand_into_lwe_ciphertext_list();
+///
+/// decrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &lwe_ciphertext_list,
+///     &mut output_plaintext_list,
+/// );
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// // Round the plaintexts, keeping the padding bit, the carry bits and the message bits
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = signed_decomposer.closest_representable(*x.0));
+///
+/// // Check we recovered the original messages
+/// assert_eq!(input_plaintext_list, output_plaintext_list);
+/// ```
+#[cfg(feature = "zk-pok-experimental")]
+#[allow(clippy::too_many_arguments)]
+pub fn encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key<
+    KeyCont,
+    InputCont,
+    OutputCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    mask_noise_parameters: impl NoiseDistribution,
+    body_noise_parameters: impl NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+    public_params: &CompactPkePublicParams,
+) -> CompactPkeProof
+where
+    KeyCont: Container<Element = u64>,
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+{
+    assert!(
+        output.lwe_size().to_lwe_dimension() == lwe_compact_public_key.lwe_dimension(),
+        "Mismatch between LweDimension of output ciphertext and input public key. \
+    Got {:?} in output, and {:?} in public key.",
+        output.lwe_size().to_lwe_dimension(),
+        lwe_compact_public_key.lwe_dimension()
+    );
+
+    assert!(
+        lwe_compact_public_key.ciphertext_modulus() == output.ciphertext_modulus(),
+        "Mismatch between CiphertextModulus of output ciphertext and input public key. \
+    Got {:?} in output, and {:?} in public key.",
+        output.ciphertext_modulus(),
+        lwe_compact_public_key.ciphertext_modulus()
+    );
+
+    assert!(
+        output.lwe_ciphertext_count().0 == encoded.plaintext_count().0,
+        "Mismatch between LweCiphertextCount of output ciphertext and \
+        PlaintextCount of input list. Got {:?} in output, and {:?} in input plaintext list.",
+        output.lwe_ciphertext_count(),
+        encoded.plaintext_count()
+    );
+
+    assert!(
+        output.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    assert!(
+        lwe_compact_public_key.lwe_dimension().0 == public_params.lwe_dimension(),
+        "Mismatch between LweDimension of the public key and the public parameters. \
+    Got {:?} in public key, and {:?} in public parameters.",
+        lwe_compact_public_key.lwe_dimension(),
+        public_params.lwe_dimension()
+    );
+
+    assert!(
+        encoded.plaintext_count().0 <= public_params.max_num_messages(),
+        "The public parameters only allow proving lists of up to {} messages, got {:?}.",
+        public_params.max_num_messages(),
+        encoded.plaintext_count()
+    );
+
+    let delta_log2 = 63 - public_params.message_bits() - public_params.carry_bits();
+    let messages = encoded
+        .iter()
+        .map(|plaintext| {
+            let plaintext = *plaintext.0;
+            assert!(
+                plaintext.trailing_zeros() >= delta_log2,
+                "Plaintext {plaintext} is not a multiple of the delta of the public parameters"
+            );
+            plaintext >> delta_log2
+        })
+        .collect::<Vec<_>>();
+
+    let (mut output_mask_list, mut output_body_list) = output.get_mut_mask_and_body_list();
+    let (pk_mask, pk_body) = lwe_compact_public_key.get_mask_and_body();
+
+    let lwe_dimension = output_mask_list.lwe_dimension();
+
+    let mut binary_random_vector = vec![0u64; output_mask_list.lwe_mask_list_size()];
+    secret_generator.fill_slice_with_random_uniform_binary(&mut binary_random_vector);
+
+    // The noises are kept as they are part of the witness of the proof
+    let mask_noise = (0..output_mask_list.lwe_mask_list_size())
+        .map(|_| {
+            random_bounded_noise(
+                encryption_generator,
+                mask_noise_parameters,
+                public_params.mask_noise_bound_log2(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let body_noise = (0..encoded.plaintext_count().0)
+        .map(|_| {
+            random_bounded_noise(
+                encryption_generator,
+                body_noise_parameters,
+                public_params.body_noise_bound_log2(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let max_ciphertext_per_bin = lwe_dimension.0;
+
+    // Loop over the ciphertext "bins"
+    output_mask_list
+        .iter_mut()
+        .zip(
+            output_body_list
+                .chunks_mut(max_ciphertext_per_bin)
+                .zip(encoded.chunks(max_ciphertext_per_bin))
+                .zip(binary_random_vector.chunks(max_ciphertext_per_bin))
+                .zip(
+                    mask_noise
+                        .chunks(max_ciphertext_per_bin)
+                        .zip(body_noise.chunks(max_ciphertext_per_bin)),
+                ),
+        )
+        .for_each(
+            |(
+                mut output_mask,
+                (
+                    ((mut output_body_chunk, input_plaintext_chunk), binary_random_slice),
+                    (mask_noise_slice, body_noise_slice),
+                ),
+            )| {
+                let mut pk_body_convolved = vec![0u64; lwe_dimension.0];
+
+                slice_semi_reverse_negacyclic_convolution(
+                    output_mask.as_mut(),
+                    pk_mask.as_ref(),
+                    binary_random_slice,
+                );
+
+                slice_semi_reverse_negacyclic_convolution(
+                    pk_body_convolved.as_mut_slice(),
+                    pk_body.as_ref(),
+                    binary_random_slice,
+                );
+
+                output_mask
+                    .as_mut()
+                    .iter_mut()
+                    .zip(mask_noise_slice.iter())
+                    .for_each(|(dst, noise)| *dst = dst.wrapping_add(*noise as u64));
+
+                output_body_chunk
+                    .iter_mut()
+                    .zip(
+                        pk_body_convolved
+                            .iter()
+                            .zip(input_plaintext_chunk.iter())
+                            .zip(body_noise_slice.iter()),
+                    )
+                    .for_each(|(dst, ((&src, plaintext), noise))| {
+                        *dst.data = src.wrapping_add(*noise as u64).wrapping_add(*plaintext.0);
+                    });
+            },
+        );
+
+    let (public_commit, private_commit) = tfhe_zk_pok::proofs::pke::commit(
+        pk_mask.as_ref().to_vec(),
+        pk_body.as_ref().to_vec(),
+        output_mask_list.as_ref().to_vec(),
+        output_body_list.as_ref().to_vec(),
+        binary_random_vector,
+        mask_noise,
+        messages,
+        body_noise,
+    );
+
+    tfhe_zk_pok::proofs::pke::prove(
+        (public_params, &public_commit),
+        &private_commit,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Verify the zero-knowledge proof of a compact ciphertext list.
+///
+/// The [`LWE compact ciphertext list`](`LweCompactCiphertextList`) must have been encrypted with
+/// [`encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key`].
+///
+/// The verification only needs the [`LWE compact public key`](`LweCompactPublicKey`) used for
+/// the encryption and the public parameters, see the documentation of
+/// [`encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key`] for an example.
+#[cfg(feature = "zk-pok-experimental")]
+pub fn verify_lwe_compact_ciphertext_list<KeyCont, ListCont>(
+    lwe_compact_list: &LweCompactCiphertextList<ListCont>,
+    compact_public_key: &LweCompactPublicKey<KeyCont>,
+    proof: &CompactPkeProof,
+    public_params: &CompactPkePublicParams,
+) -> ZkVerificationOutCome
+where
+    KeyCont: Container<Element = u64>,
+    ListCont: Container<Element = u64>,
+{
+    if !lwe_compact_list.ciphertext_modulus().is_native_modulus()
+        || lwe_compact_list.ciphertext_modulus() != compact_public_key.ciphertext_modulus()
+        || lwe_compact_list.lwe_size().to_lwe_dimension() != compact_public_key.lwe_dimension()
+    {
+        return ZkVerificationOutCome::Invalid;
+    }
+
+    let (pk_mask, pk_body) = compact_public_key.get_mask_and_body();
+    let (mask_list, body_list) = lwe_compact_list.get_mask_and_body_list();
+
+    let (public_commit, _) = tfhe_zk_pok::proofs::pke::commit(
+        pk_mask.as_ref().to_vec(),
+        pk_body.as_ref().to_vec(),
+        mask_list.as_ref().to_vec(),
+        body_list.as_ref().to_vec(),
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+    );
+
+    match tfhe_zk_pok::proofs::pke::verify(proof, (public_params, &public_commit)) {
+        Ok(()) => ZkVerificationOutCome::Valid,
+        Err(()) => ZkVerificationOutCome::Invalid,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::core_crypto::commons::test_tools;
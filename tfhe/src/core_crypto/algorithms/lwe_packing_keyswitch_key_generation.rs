@@ -5,7 +5,7 @@
 use crate::core_crypto::algorithms::{
     encrypt_glwe_ciphertext_list, encrypt_seeded_glwe_ciphertext_list_with_existing_generator,
 };
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, ByteRandomGenerator};
@@ -75,7 +75,7 @@ pub fn generate_lwe_packing_keyswitch_key<Scalar, InputKeyCont, OutputKeyCont, K
     input_lwe_sk: &LweSecretKey<InputKeyCont>,
     output_glwe_sk: &GlweSecretKey<OutputKeyCont>,
     lwe_packing_keyswitch_key: &mut LwePackingKeyswitchKey<KSKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -165,7 +165,7 @@ pub fn allocate_and_generate_new_lwe_packing_keyswitch_key<
     output_glwe_sk: &GlweSecretKey<OutputKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LwePackingKeyswitchKeyOwned<Scalar>
@@ -262,7 +262,7 @@ pub fn generate_seeded_lwe_packing_keyswitch_key<
     input_lwe_sk: &LweSecretKey<InputKeyCont>,
     output_glwe_sk: &GlweSecretKey<OutputKeyCont>,
     lwe_packing_keyswitch_key: &mut SeededLwePackingKeyswitchKey<KSKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -355,7 +355,7 @@ pub fn allocate_and_generate_new_seeded_lwe_packing_keyswitch_key<
     output_glwe_sk: &GlweSecretKey<OutputKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLwePackingKeyswitchKeyOwned<Scalar>
@@ -1,8 +1,11 @@
 //! Module containing primitives pertaining to the generation of
 //! [`LWE secret keys`](`LweSecretKey`).
 
+use crate::core_crypto::commons::dispersion::StandardDev;
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
-use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
+use crate::core_crypto::commons::math::random::{
+    BoundedGaussian, RandomGenerable, UniformBinary, UniformTernary,
+};
 use crate::core_crypto::commons::numeric::Numeric;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
@@ -64,3 +67,172 @@ pub fn generate_binary_lwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(lwe_secret_key.as_mut());
 }
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with uniformly random ternary
+/// coefficients.
+pub fn allocate_and_generate_new_ternary_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformTernary> + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_ternary_lwe_secret_key(&mut lwe_secret_key, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with uniformly random ternary coefficients.
+///
+/// The coefficients are in {-1, 0, 1}, -1 being represented as `Scalar::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut lwe_secret_key = LweSecretKey::new_empty_key(0u64, lwe_dimension);
+///
+/// generate_ternary_lwe_secret_key(&mut lwe_secret_key, &mut secret_generator);
+///
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// ```
+pub fn generate_ternary_lwe_secret_key<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformTernary>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_uniform_ternary(lwe_secret_key.as_mut());
+}
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with coefficients following a
+/// rounded gaussian distribution bounded by `bound` in absolute value.
+pub fn allocate_and_generate_new_bounded_gaussian_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    std_dev: StandardDev,
+    bound: u32,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<BoundedGaussian> + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_bounded_gaussian_lwe_secret_key(&mut lwe_secret_key, std_dev, bound, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with bounded rounded gaussian coefficients.
+///
+/// The coefficients follow a rounded gaussian distribution centered on zero, their absolute value
+/// is at most `bound`. Negative coefficients are represented in two's complement.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut lwe_secret_key = LweSecretKey::new_empty_key(0u64, lwe_dimension);
+///
+/// generate_bounded_gaussian_lwe_secret_key(
+///     &mut lwe_secret_key,
+///     StandardDev(3.19),
+///     12,
+///     &mut secret_generator,
+/// );
+///
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| (elt as i64).abs() <= 12));
+/// ```
+pub fn generate_bounded_gaussian_lwe_secret_key<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    std_dev: StandardDev,
+    bound: u32,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<BoundedGaussian>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_bounded_gaussian(lwe_secret_key.as_mut(), std_dev.0, bound);
+}
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with coefficients following the
+/// given [`SecretKeyDistribution`].
+pub fn allocate_and_generate_new_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformBinary>
+        + RandomGenerable<UniformTernary>
+        + RandomGenerable<BoundedGaussian>
+        + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_lwe_secret_key(&mut lwe_secret_key, distribution, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with coefficients following the given
+/// [`SecretKeyDistribution`].
+pub fn generate_lwe_secret_key<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformBinary>
+        + RandomGenerable<UniformTernary>
+        + RandomGenerable<BoundedGaussian>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    match distribution {
+        SecretKeyDistribution::Binary => generate_binary_lwe_secret_key(lwe_secret_key, generator),
+        SecretKeyDistribution::Ternary => {
+            generate_ternary_lwe_secret_key(lwe_secret_key, generator);
+        }
+        SecretKeyDistribution::BoundedGaussian { std_dev, bound } => {
+            generate_bounded_gaussian_lwe_secret_key(lwe_secret_key, std_dev, bound, generator);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use super::*;
+
+fn is_small_signed<Scalar: UnsignedInteger>(value: Scalar, bound: u32) -> bool {
+    let bound = Scalar::cast_from(bound as u128);
+    value <= bound || value.wrapping_neg() <= bound
+}
+
+#[test]
+fn ternary_and_bounded_gaussian_secret_key_generation() {
+    let mut rsc = TestResources::new();
+
+    let lwe_secret_key = allocate_and_generate_new_lwe_secret_key::<u64, _>(
+        LweDimension(1024),
+        SecretKeyDistribution::Ternary,
+        &mut rsc.secret_random_generator,
+    );
+    assert!(lwe_secret_key
+        .as_ref()
+        .iter()
+        .all(|&coefficient| is_small_signed(coefficient, 1)));
+    // All three values are drawn with overwhelming probability
+    for value in [0u64, 1, u64::MAX] {
+        assert!(lwe_secret_key.as_ref().contains(&value));
+    }
+
+    let bound = 5;
+    let glwe_secret_key = allocate_and_generate_new_glwe_secret_key::<u32, _>(
+        GlweDimension(2),
+        PolynomialSize(512),
+        SecretKeyDistribution::BoundedGaussian {
+            std_dev: StandardDev(3.2),
+            bound,
+        },
+        &mut rsc.secret_random_generator,
+    );
+    assert!(glwe_secret_key
+        .as_ref()
+        .iter()
+        .all(|&coefficient| is_small_signed(coefficient, bound)));
+    assert!(glwe_secret_key
+        .as_ref()
+        .iter()
+        .any(|&coefficient| { !is_small_signed(coefficient, 1) }));
+}
+
+#[test]
+fn lwe_secret_key_binary_expansion() {
+    let lwe_secret_key =
+        LweSecretKey::from_container(vec![0u64, 1, u64::MAX, 3, 5u64.wrapping_neg()]);
+    let expanded = allocate_and_expand_lwe_secret_key_to_binary(&lwe_secret_key, 3);
+
+    assert_eq!(expanded.lwe_dimension(), LweDimension(30));
+    assert_eq!(
+        expanded.as_ref(),
+        &[
+            0, 0, 0, 0, 0, 0, // 0
+            1, 0, 0, 0, 0, 0, // 1
+            0, 0, 0, 1, 0, 0, // -1
+            1, 1, 0, 0, 0, 0, // 3
+            0, 0, 0, 1, 0, 1, // -5
+        ]
+    );
+}
+
+fn lwe_encrypt_pbs_decrypt_with_non_binary_key(distribution: SecretKeyDistribution) {
+    let params = TEST_PARAMS_4_BITS_NATIVE_U64;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let msg_modulus = 1u64 << params.message_modulus_log.0;
+    let delta = get_encoding_with_padding(ciphertext_modulus) / msg_modulus;
+
+    let mut rsc = TestResources::new();
+
+    let input_lwe_secret_key = allocate_and_generate_new_lwe_secret_key(
+        params.lwe_dimension,
+        distribution,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_glwe_secret_key(
+        params.glwe_dimension,
+        params.polynomial_size,
+        distribution,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    // The bootstrapping key is generated for the binary expansion of the input key
+    let expanded_lwe_secret_key = allocate_and_expand_lwe_secret_key_to_binary(
+        &input_lwe_secret_key,
+        distribution.coefficient_bit_count(),
+    );
+    assert_eq!(
+        expanded_lwe_secret_key.lwe_dimension().0,
+        params.lwe_dimension.0 * distribution.binary_expansion_factor()
+    );
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &expanded_lwe_secret_key,
+        &output_glwe_secret_key,
+        params.pbs_base_log,
+        params.pbs_level,
+        params.glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+    let mut fbsk = FourierLweBootstrapKey::new(
+        bsk.input_lwe_dimension(),
+        bsk.glwe_size(),
+        bsk.polynomial_size(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    let f = |x: u64| (x + 1) % msg_modulus;
+    let accumulator = generate_accumulator(
+        params.polynomial_size,
+        params.glwe_dimension.to_glwe_size(),
+        msg_modulus as usize,
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    for msg in 0..msg_modulus {
+        let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+            &input_lwe_secret_key,
+            Plaintext(msg * delta),
+            params.lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let expanded_lwe_ciphertext = allocate_and_expand_lwe_ciphertext_for_binary_key(
+            &lwe_ciphertext_in,
+            expanded_lwe_secret_key.lwe_dimension(),
+        );
+        assert_eq!(
+            decrypt_lwe_ciphertext(&input_lwe_secret_key, &lwe_ciphertext_in),
+            decrypt_lwe_ciphertext(&expanded_lwe_secret_key, &expanded_lwe_ciphertext)
+        );
+
+        let mut out_pbs_ct = LweCiphertext::new(
+            0u64,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        programmable_bootstrap_lwe_ciphertext(
+            &expanded_lwe_ciphertext,
+            &mut out_pbs_ct,
+            &accumulator,
+            &fbsk,
+        );
+
+        let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+        let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+        assert_eq!(decoded, f(msg));
+    }
+}
+
+#[test]
+fn lwe_encrypt_pbs_decrypt_ternary_key() {
+    lwe_encrypt_pbs_decrypt_with_non_binary_key(SecretKeyDistribution::Ternary);
+}
+
+#[test]
+fn lwe_encrypt_pbs_decrypt_bounded_gaussian_key() {
+    lwe_encrypt_pbs_decrypt_with_non_binary_key(SecretKeyDistribution::BoundedGaussian {
+        std_dev: StandardDev(1.0),
+        bound: 3,
+    });
+}
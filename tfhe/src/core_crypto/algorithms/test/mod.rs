@@ -12,6 +12,7 @@ mod ggsw_encryption;
 mod glwe_encryption;
 mod glwe_linear_algebra;
 mod glwe_sample_extraction;
+mod lwe_binary_expansion;
 mod lwe_bootstrap_key_generation;
 mod lwe_compact_public_key_generation;
 mod lwe_encryption;
@@ -434,4 +435,5 @@ macro_rules! create_parametrized_test_with_non_native_parameters {
     };
 }
 
-use {create_parametrized_test, create_parametrized_test_with_non_native_parameters};
+use create_parametrized_test;
+use create_parametrized_test_with_non_native_parameters;
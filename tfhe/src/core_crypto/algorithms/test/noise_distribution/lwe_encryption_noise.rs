@@ -260,3 +260,70 @@ create_parametrized_test!(random_noise_roundtrip {
     TEST_PARAMS_3_BITS_SOLINAS_U64,
     TEST_PARAMS_3_BITS_63_U64
 });
+
+fn t_uniform_noise_distribution<Scalar: UnsignedTorus + CastInto<usize>>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let mut rsc = TestResources::new();
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let encryption_rng = &mut rsc.encryption_random_generator;
+
+    let bound_log2 = 20;
+    let noise = TUniform::new(bound_log2);
+    let bound = Scalar::ONE << bound_log2 as usize;
+    let log2_modulus = if ciphertext_modulus.is_native_modulus() {
+        Scalar::BITS as u32
+    } else {
+        (ciphertext_modulus.get_custom_modulus() as f64)
+            .log2()
+            .ceil() as u32
+    };
+    let expected_variance = noise.variance(log2_modulus);
+
+    let num_ouptuts = 100_000;
+
+    let mut output: Vec<_> = vec![Scalar::ZERO; num_ouptuts];
+
+    encryption_rng.fill_slice_with_random_noise_custom_mod(&mut output, noise, ciphertext_modulus);
+
+    assert!(check_clear_content_respects_mod(
+        &output,
+        ciphertext_modulus
+    ));
+
+    for val in output.iter().copied() {
+        let abs_val = if ciphertext_modulus.is_native_modulus() {
+            modular_distance(Scalar::ZERO, val)
+        } else {
+            let custom_modulus_as_scalar: Scalar =
+                ciphertext_modulus.get_custom_modulus().cast_into();
+            modular_distance_custom_mod(Scalar::ZERO, val, custom_modulus_as_scalar)
+        };
+        assert!(
+            abs_val <= bound,
+            "val={val} exceeds the bound 2^{bound_log2}"
+        );
+    }
+
+    let output: Vec<_> = output
+        .into_iter()
+        .map(|x| torus_modular_diff(Scalar::ZERO, x, ciphertext_modulus))
+        .collect();
+
+    let measured_variance = variance(&output);
+    let var_abs_diff = (expected_variance.0 - measured_variance.0).abs();
+    let tolerance_threshold = RELATIVE_TOLERANCE * expected_variance.0;
+    assert!(
+        var_abs_diff < tolerance_threshold,
+        "Absolute difference for variance: {var_abs_diff}, \
+            tolerance threshold: {tolerance_threshold}, \
+            got variance: {measured_variance:?}, \
+            expected variance: {expected_variance:?}"
+    );
+}
+
+create_parametrized_test!(t_uniform_noise_distribution {
+    TEST_PARAMS_4_BITS_NATIVE_U64,
+    TEST_PARAMS_3_BITS_SOLINAS_U64,
+    TEST_PARAMS_3_BITS_63_U64
+});
@@ -12,7 +12,13 @@
 //! In any of those cases, the corresponding type implements the `DispersionParameter` trait,
 //! which makes if possible to use any of those representations generically when noise must be
 //! defined.
+//!
+//! The noise can also be drawn from a bounded [`TUniform`] distribution. The
+//! [`DynamicDistribution`] type allows to select either kind of distribution at runtime, and the
+//! [`NoiseDistribution`] trait is implemented by all the types which can be used to parametrize
+//! the noise of an encryption.
 
+pub use crate::core_crypto::commons::math::random::TUniform;
 use serde::{Deserialize, Serialize};
 
 /// A trait for types representing distribution parameters, for a given unsigned integer type.
@@ -194,3 +200,84 @@ impl DispersionParameter for Variance {
         log2_modulus as f64 + self.0.sqrt().log2()
     }
 }
+
+/// A noise distribution which can be selected at runtime, either a gaussian distribution or a
+/// bounded [`TUniform`] distribution.
+///
+/// # Example:
+///
+/// ```
+/// use tfhe::core_crypto::commons::dispersion::{DynamicDistribution, StandardDev, TUniform};
+/// let gaussian = DynamicDistribution::new_gaussian_from_std_dev(StandardDev(2_f64.powi(-25)));
+/// assert_eq!(
+///     gaussian.gaussian_std_dev(),
+///     Some(StandardDev(2_f64.powi(-25)))
+/// );
+/// assert_eq!(gaussian.variance(64).0, 2_f64.powi(-50));
+///
+/// let t_uniform = DynamicDistribution::new_t_uniform(3);
+/// assert_eq!(t_uniform, DynamicDistribution::TUniform(TUniform::new(3)));
+/// assert_eq!(t_uniform.gaussian_std_dev(), None);
+/// // (2 * 8^2 + 1) / 6 in the integer domain
+/// assert_eq!(t_uniform.variance(0).0, 21.5);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DynamicDistribution {
+    Gaussian(StandardDev),
+    TUniform(TUniform),
+}
+
+impl DynamicDistribution {
+    pub const fn new_gaussian_from_std_dev(std: StandardDev) -> Self {
+        Self::Gaussian(std)
+    }
+
+    pub const fn new_t_uniform(bound_log2: u32) -> Self {
+        Self::TUniform(TUniform::new(bound_log2))
+    }
+
+    /// Return the standard deviation of the distribution if it is a gaussian distribution.
+    pub const fn gaussian_std_dev(&self) -> Option<StandardDev> {
+        match self {
+            Self::Gaussian(std) => Some(*std),
+            Self::TUniform(_) => None,
+        }
+    }
+
+    /// Return the variance of the distribution, normalized for a modulus $2^{log2\_modulus}$.
+    ///
+    /// The modulus is only needed for [`TUniform`] distributions, whose bound is expressed in the
+    /// integer domain.
+    pub fn variance(&self, log2_modulus: u32) -> Variance {
+        match self {
+            Self::Gaussian(std) => Variance(std.get_variance()),
+            Self::TUniform(t_uniform) => t_uniform.variance(log2_modulus),
+        }
+    }
+}
+
+/// A trait for types which can be used as the noise distribution of an encryption.
+///
+/// It is implemented for all the [`DispersionParameter`] types (which describe a gaussian
+/// distribution), for [`TUniform`] and for [`DynamicDistribution`].
+pub trait NoiseDistribution: Copy {
+    fn to_dynamic_distribution(self) -> DynamicDistribution;
+}
+
+impl<T: DispersionParameter> NoiseDistribution for T {
+    fn to_dynamic_distribution(self) -> DynamicDistribution {
+        DynamicDistribution::Gaussian(StandardDev(self.get_standard_dev()))
+    }
+}
+
+impl NoiseDistribution for TUniform {
+    fn to_dynamic_distribution(self) -> DynamicDistribution {
+        DynamicDistribution::TUniform(self)
+    }
+}
+
+impl NoiseDistribution for DynamicDistribution {
+    fn to_dynamic_distribution(self) -> DynamicDistribution {
+        self
+    }
+}
@@ -4,7 +4,7 @@ pub(crate) mod mask_random_generator;
 pub(crate) mod noise_random_generator;
 #[cfg(test)]
 mod test;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::math::random::{
     ByteRandomGenerator, Gaussian, ParallelByteRandomGenerator, RandomGenerable, Seed, Seeder,
     Uniform,
@@ -255,69 +255,69 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     }
 
     // Sample a noise value, using the noise generator.
-    pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
+    pub(crate) fn random_noise<Scalar>(&mut self, noise: impl NoiseDistribution) -> Scalar
     where
         Scalar: UnsignedTorus + RandomGenerable<Gaussian<f64>>,
     {
-        self.noise.random_noise(std)
+        self.noise.random_noise(noise)
     }
 
     // Sample a noise value, using the noise generator.
     pub(crate) fn random_noise_custom_mod<Scalar>(
         &mut self,
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
         custom_modulus: CiphertextModulus<Scalar>,
     ) -> Scalar
     where
         Scalar: UnsignedTorus + RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
     {
-        self.noise.random_noise_custom_mod(std, custom_modulus)
+        self.noise.random_noise_custom_mod(noise, custom_modulus)
     }
 
     // Fills the input slice with random noise, using the noise generator.
     pub(crate) fn fill_slice_with_random_noise<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
-        self.noise.fill_slice_with_random_noise(output, std);
+        self.noise.fill_slice_with_random_noise(output, noise);
     }
 
     // Fills the input slice with random noise, using the noise generator.
     pub(crate) fn fill_slice_with_random_noise_custom_mod<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
     {
         self.noise
-            .fill_slice_with_random_noise_custom_mod(output, std, custom_modulus);
+            .fill_slice_with_random_noise_custom_mod(output, noise, custom_modulus);
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
         self.noise
-            .unsigned_torus_slice_wrapping_add_random_noise_assign(output, std);
+            .unsigned_torus_slice_wrapping_add_random_noise_assign(output, noise);
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
@@ -326,7 +326,7 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.noise
             .unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign(
                 output,
-                std,
+                noise,
                 custom_modulus,
             );
     }
@@ -1,12 +1,14 @@
 //! Module containing primitives pertaining to random noise generation in the context of encryption.
 
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::{
+    DispersionParameter, DynamicDistribution, NoiseDistribution,
+};
 use crate::core_crypto::commons::math::random::{
     ByteRandomGenerator, Gaussian, ParallelByteRandomGenerator, RandomGenerable, RandomGenerator,
-    Seed,
+    Seed, TUniform,
 };
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
-use crate::core_crypto::commons::numeric::{CastInto, UnsignedInteger};
+use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::parameters::{
     CiphertextModulus, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount, GlweSize,
     LweBskGroupingFactor, LweCiphertextCount, LweDimension, LweMaskCount, LweSize, PolynomialSize,
@@ -43,108 +45,168 @@ impl<G: ByteRandomGenerator> NoiseRandomGenerator<G> {
     }
 
     // Sample a noise value, using the random generator.
-    pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
+    pub(crate) fn random_noise<Scalar>(&mut self, noise: impl NoiseDistribution) -> Scalar
     where
-        Scalar: RandomGenerable<Gaussian<f64>>,
+        Scalar: UnsignedTorus,
     {
-        <Scalar>::generate_one(
-            &mut self.gen,
-            Gaussian {
-                std: std.get_standard_dev(),
-                mean: 0.,
-            },
-        )
+        match noise.to_dynamic_distribution() {
+            DynamicDistribution::Gaussian(std) => {
+                <Scalar as RandomGenerable<Gaussian<f64>>>::generate_one(
+                    &mut self.gen,
+                    Gaussian {
+                        std: std.get_standard_dev(),
+                        mean: 0.,
+                    },
+                )
+            }
+            DynamicDistribution::TUniform(t_uniform) => {
+                <Scalar as RandomGenerable<TUniform>>::generate_one(&mut self.gen, t_uniform)
+            }
+        }
     }
 
     // Sample a noise value, using the random generator.
     pub(crate) fn random_noise_custom_mod<Scalar>(
         &mut self,
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
         custom_modulus: CiphertextModulus<Scalar>,
     ) -> Scalar
     where
-        Scalar: UnsignedInteger + RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
+        Scalar: UnsignedTorus,
     {
         if custom_modulus.is_native_modulus() {
-            return self.random_noise(std);
+            return self.random_noise(noise);
         }
 
         let custom_modulus_as_scalar: Scalar = custom_modulus.get_custom_modulus().cast_into();
-        Scalar::generate_one_custom_modulus(
-            &mut self.gen,
-            Gaussian {
-                std: std.get_standard_dev(),
-                mean: 0.,
-            },
-            custom_modulus_as_scalar,
-        )
+        match noise.to_dynamic_distribution() {
+            DynamicDistribution::Gaussian(std) => {
+                <Scalar as RandomGenerable<Gaussian<f64>>>::generate_one_custom_modulus(
+                    &mut self.gen,
+                    Gaussian {
+                        std: std.get_standard_dev(),
+                        mean: 0.,
+                    },
+                    custom_modulus_as_scalar,
+                )
+            }
+            DynamicDistribution::TUniform(t_uniform) => {
+                <Scalar as RandomGenerable<TUniform>>::generate_one_custom_modulus(
+                    &mut self.gen,
+                    t_uniform,
+                    custom_modulus_as_scalar,
+                )
+            }
+        }
     }
 
     // Fills the input slice with random noise, using the random generator.
     pub(crate) fn fill_slice_with_random_noise<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
-        self.gen
-            .fill_slice_with_random_gaussian(output, 0., std.get_standard_dev());
+        match noise.to_dynamic_distribution() {
+            DynamicDistribution::Gaussian(std) => {
+                self.gen
+                    .fill_slice_with_random_gaussian(output, 0., std.get_standard_dev());
+            }
+            DynamicDistribution::TUniform(t_uniform) => {
+                self.gen
+                    .fill_slice_with_random_t_uniform(output, t_uniform.bound_log2());
+            }
+        }
     }
 
     // Fills the input slice with random noise, using the random generator.
     pub(crate) fn fill_slice_with_random_noise_custom_mod<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
     {
-        self.gen.fill_slice_with_random_gaussian_custom_mod(
-            output,
-            0.,
-            std.get_standard_dev(),
-            custom_modulus,
-        );
+        match noise.to_dynamic_distribution() {
+            DynamicDistribution::Gaussian(std) => {
+                self.gen.fill_slice_with_random_gaussian_custom_mod(
+                    output,
+                    0.,
+                    std.get_standard_dev(),
+                    custom_modulus,
+                );
+            }
+            DynamicDistribution::TUniform(t_uniform) => {
+                self.gen.fill_slice_with_random_t_uniform_custom_mod(
+                    output,
+                    t_uniform.bound_log2(),
+                    custom_modulus,
+                );
+            }
+        }
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
-        self.gen
-            .unsigned_torus_slice_wrapping_add_random_gaussian_assign(
-                output,
-                0.,
-                std.get_standard_dev(),
-            );
+        match noise.to_dynamic_distribution() {
+            DynamicDistribution::Gaussian(std) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_gaussian_assign(
+                        output,
+                        0.,
+                        std.get_standard_dev(),
+                    );
+            }
+            DynamicDistribution::TUniform(t_uniform) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_t_uniform_assign(
+                        output,
+                        t_uniform.bound_log2(),
+                    );
+            }
+        }
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
     {
-        self.gen
-            .unsigned_torus_slice_wrapping_add_random_gaussian_custom_mod_assign(
-                output,
-                0.,
-                std.get_standard_dev(),
-                custom_modulus,
-            );
+        match noise.to_dynamic_distribution() {
+            DynamicDistribution::Gaussian(std) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_gaussian_custom_mod_assign(
+                        output,
+                        0.,
+                        std.get_standard_dev(),
+                        custom_modulus,
+                    );
+            }
+            DynamicDistribution::TUniform(t_uniform) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_t_uniform_custom_mod_assign(
+                        output,
+                        t_uniform.bound_log2(),
+                        custom_modulus,
+                    );
+            }
+        }
     }
 
     // Forks the generator, when splitting a bootstrap key into ggsw ct.
@@ -2,7 +2,8 @@
 //! generation.
 
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary,
+    BoundedGaussian, ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, Uniform,
+    UniformBinary, UniformTernary,
 };
 
 /// A random number generator which can be used to generate secret keys.
@@ -25,4 +26,30 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
     {
         self.0.fill_slice_with_random_uniform_binary(slice);
     }
+
+    pub(crate) fn fill_slice_with_random_uniform_ternary<Scalar>(&mut self, slice: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformTernary>,
+    {
+        self.0.fill_slice_with_random_uniform_ternary(slice);
+    }
+
+    pub(crate) fn fill_slice_with_random_uniform<Scalar>(&mut self, slice: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<Uniform>,
+    {
+        self.0.fill_slice_with_random_uniform(slice);
+    }
+
+    pub(crate) fn fill_slice_with_random_bounded_gaussian<Scalar>(
+        &mut self,
+        slice: &mut [Scalar],
+        std: f64,
+        bound: u32,
+    ) where
+        Scalar: RandomGenerable<BoundedGaussian>,
+    {
+        self.0
+            .fill_slice_with_random_bounded_gaussian(slice, std, bound);
+    }
 }
@@ -0,0 +1,41 @@
+use super::*;
+
+/// A distribution type representing random sampling of signed integers following a rounded
+/// gaussian distribution centered on zero, where samples whose absolute value exceeds `bound` are
+/// rejected.
+///
+/// Negative values are represented in two's complement in the generated unsigned integers.
+#[derive(Clone, Copy)]
+pub struct BoundedGaussian {
+    /// The standard deviation of the distribution, before rounding.
+    pub std: f64,
+    /// The maximum absolute value of the samples.
+    pub bound: u32,
+}
+
+macro_rules! implement_bounded_gaussian {
+    ($T:ty) => {
+        impl RandomGenerable<BoundedGaussian> for $T {
+            type CustomModulus = $T;
+            fn generate_one<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                BoundedGaussian { std, bound }: BoundedGaussian,
+            ) -> Self {
+                loop {
+                    let (sample, _) =
+                        <(f64, f64)>::generate_one(generator, Gaussian { std, mean: 0.0 });
+                    let sample = sample.round();
+                    if sample.abs() <= f64::from(bound) {
+                        return (sample as i64) as $T;
+                    }
+                }
+            }
+        }
+    };
+}
+
+implement_bounded_gaussian!(u8);
+implement_bounded_gaussian!(u16);
+implement_bounded_gaussian!(u32);
+implement_bounded_gaussian!(u64);
+implement_bounded_gaussian!(u128);
@@ -1,6 +1,6 @@
 use crate::core_crypto::commons::math::random::{
-    Gaussian, RandomGenerable, Uniform, UniformBinary, UniformLsb, UniformMsb, UniformTernary,
-    UniformWithZeros,
+    BoundedGaussian, Gaussian, RandomGenerable, TUniform, Uniform, UniformBinary, UniformLsb,
+    UniformMsb, UniformTernary, UniformWithZeros,
 };
 use crate::core_crypto::commons::math::torus::{UnsignedInteger, UnsignedTorus};
 use crate::core_crypto::commons::numeric::{CastInto, FloatingPoint};
@@ -320,6 +320,52 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         Scalar::generate_one(self, UniformTernary)
     }
 
+    /// Fill a slice with random uniform ternary values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u32; 1000];
+    /// generator.fill_slice_with_random_uniform_ternary(&mut vec);
+    /// assert!(vec.iter().all(|&x| x == 0 || x == 1 || x == u32::MAX));
+    /// assert!(vec.iter().any(|&x| x == u32::MAX));
+    /// ```
+    pub fn fill_slice_with_random_uniform_ternary<Scalar>(&mut self, output: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformTernary>,
+    {
+        Scalar::fill_slice(self, UniformTernary, output);
+    }
+
+    /// Fill a slice with random signed integers following a rounded gaussian distribution
+    /// centered on zero, bounded by `bound` in absolute value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u32; 1000];
+    /// generator.fill_slice_with_random_bounded_gaussian(&mut vec, 3.19, 12);
+    /// assert!(vec.iter().all(|&x| (x as i32).abs() <= 12));
+    /// ```
+    pub fn fill_slice_with_random_bounded_gaussian<Scalar>(
+        &mut self,
+        output: &mut [Scalar],
+        std: f64,
+        bound: u32,
+    ) where
+        Scalar: RandomGenerable<BoundedGaussian>,
+    {
+        Scalar::fill_slice(self, BoundedGaussian { std, bound }, output);
+    }
+
     /// Generate an unsigned integer whose n least significant bits are uniformly random, and the
     /// other bits are zero.
     ///
@@ -581,6 +627,149 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
             }
         });
     }
+
+    /// Fill a slice with random values following a [`TUniform`] distribution bounded by
+    /// $2^{bound\_log2}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u32; 1000];
+    /// generator.fill_slice_with_random_t_uniform(&mut vec, 3);
+    /// assert!(vec.iter().all(|&x| (x as i32).abs() <= 8));
+    /// assert!(vec.iter().any(|&x| x != 0));
+    /// ```
+    pub fn fill_slice_with_random_t_uniform<Scalar>(
+        &mut self,
+        output: &mut [Scalar],
+        bound_log2: u32,
+    ) where
+        Scalar: RandomGenerable<TUniform>,
+    {
+        Scalar::fill_slice(self, TUniform::new(bound_log2), output);
+    }
+
+    /// Fill a slice with random values following a [`TUniform`] distribution bounded by
+    /// $2^{bound\_log2}$, for a given custom modulus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// use tfhe::core_crypto::commons::parameters::CiphertextModulus;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u32; 1000];
+    /// let modulus = (1 << 31) - 1;
+    /// generator.fill_slice_with_random_t_uniform_custom_mod(
+    ///     &mut vec,
+    ///     3,
+    ///     CiphertextModulus::try_new(modulus).unwrap(),
+    /// );
+    /// assert!(vec.iter().all(|&x| x <= 8 || x >= modulus as u32 - 8));
+    /// assert!(vec.iter().any(|&x| x != 0));
+    /// ```
+    pub fn fill_slice_with_random_t_uniform_custom_mod<Scalar>(
+        &mut self,
+        output: &mut [Scalar],
+        bound_log2: u32,
+        custom_modulus: CiphertextModulus<Scalar>,
+    ) where
+        Scalar: UnsignedInteger + RandomGenerable<TUniform, CustomModulus = Scalar>,
+    {
+        if custom_modulus.is_native_modulus() {
+            self.fill_slice_with_random_t_uniform(output, bound_log2);
+            return;
+        }
+
+        let custom_modulus_as_scalar: Scalar = custom_modulus.get_custom_modulus().cast_into();
+        Scalar::fill_slice_custom_mod(
+            self,
+            TUniform::new(bound_log2),
+            output,
+            custom_modulus_as_scalar,
+        );
+    }
+
+    /// Add a random value following a [`TUniform`] distribution bounded by $2^{bound\_log2}$ to
+    /// each element in a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![16u32; 1000];
+    /// generator.unsigned_torus_slice_wrapping_add_random_t_uniform_assign(&mut vec, 3);
+    /// assert!(vec.iter().all(|&x| (8..=24).contains(&x)));
+    /// ```
+    pub fn unsigned_torus_slice_wrapping_add_random_t_uniform_assign<Scalar>(
+        &mut self,
+        output: &mut [Scalar],
+        bound_log2: u32,
+    ) where
+        Scalar: UnsignedTorus,
+    {
+        let distribution = TUniform::new(bound_log2);
+        for elem in output.iter_mut() {
+            *elem = (*elem).wrapping_add(<Scalar as RandomGenerable<TUniform>>::generate_one(
+                self,
+                distribution,
+            ));
+        }
+    }
+
+    /// Add a random value following a [`TUniform`] distribution bounded by $2^{bound\_log2}$ to
+    /// each element in a slice, for a given custom modulus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// use tfhe::core_crypto::commons::parameters::CiphertextModulus;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u32; 1000];
+    /// generator.unsigned_torus_slice_wrapping_add_random_t_uniform_custom_mod_assign(
+    ///     &mut vec,
+    ///     3,
+    ///     CiphertextModulus::try_new_power_of_2(31).unwrap(),
+    /// );
+    /// assert!(vec.iter().any(|&x| x != 0));
+    /// ```
+    pub fn unsigned_torus_slice_wrapping_add_random_t_uniform_custom_mod_assign<Scalar>(
+        &mut self,
+        output: &mut [Scalar],
+        bound_log2: u32,
+        custom_modulus: CiphertextModulus<Scalar>,
+    ) where
+        Scalar: UnsignedTorus,
+    {
+        if custom_modulus.is_native_modulus() {
+            self.unsigned_torus_slice_wrapping_add_random_t_uniform_assign(output, bound_log2);
+            return;
+        }
+
+        let distribution = TUniform::new(bound_log2);
+        let custom_modulus_as_scalar: Scalar = custom_modulus.get_custom_modulus().cast_into();
+        for elem in output.iter_mut() {
+            *elem = (*elem).wrapping_add(
+                <Scalar as RandomGenerable<TUniform>>::generate_one_custom_modulus(
+                    self,
+                    distribution,
+                    custom_modulus_as_scalar,
+                ),
+            );
+        }
+    }
 }
 
 impl<G: ParallelByteRandomGenerator> RandomGenerator<G> {
@@ -17,8 +17,10 @@ use crate::core_crypto::commons::numeric::FloatingPoint;
 
 /// Convenience alias for the most efficient CSPRNG implementation available.
 pub use activated_random_generator::ActivatedRandomGenerator;
+pub use bounded_gaussian::*;
 pub use gaussian::*;
 pub use generator::*;
+pub use t_uniform::*;
 pub use uniform::*;
 pub use uniform_binary::*;
 pub use uniform_lsb::*;
@@ -30,8 +32,10 @@ pub use uniform_with_zeros::*;
 mod tests;
 
 mod activated_random_generator;
+mod bounded_gaussian;
 mod gaussian;
 mod generator;
+mod t_uniform;
 mod uniform;
 mod uniform_binary;
 mod uniform_lsb;
@@ -98,6 +102,8 @@ mod seal {
     impl Sealed for super::UniformWithZeros {}
     impl Sealed for super::UniformBinary {}
     impl Sealed for super::UniformTernary {}
+    impl Sealed for super::BoundedGaussian {}
+    impl Sealed for super::TUniform {}
     impl<T: FloatingPoint> Sealed for super::Gaussian<T> {}
 }
 impl Distribution for Uniform {}
@@ -106,4 +112,6 @@ impl Distribution for UniformLsb {}
 impl Distribution for UniformWithZeros {}
 impl Distribution for UniformBinary {}
 impl Distribution for UniformTernary {}
+impl Distribution for BoundedGaussian {}
+impl Distribution for TUniform {}
 impl<T: FloatingPoint> Distribution for Gaussian<T> {}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::core_crypto::commons::dispersion::Variance;
+use serde::{Deserialize, Serialize};
+
+/// A distribution type representing random sampling of signed integers in the bounded interval
+/// $[-2^b, 2^b]$, with $b$ the `bound_log2` of the distribution.
+///
+/// Each value in $]-2^b, 2^b[$ is drawn with probability $2^{-(b+1)}$, the two bounds $-2^b$ and
+/// $2^b$ are each drawn with probability $2^{-(b+2)}$. The bound is expressed in the integer
+/// domain of the ciphertext modulus, and negative values are represented in two's complement (or
+/// as $q - |x|$ for a custom modulus $q$) in the generated unsigned integers.
+///
+/// Contrary to a gaussian distribution, samples are guaranteed to never exceed the bound, which
+/// gives hard (rather than probabilistic) bounds on the noise of ciphertexts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TUniform {
+    bound_log2: u32,
+}
+
+impl TUniform {
+    /// Create a [`TUniform`] distribution over $[-2^{bound\_log2}, 2^{bound\_log2}]$.
+    pub const fn new(bound_log2: u32) -> Self {
+        Self { bound_log2 }
+    }
+
+    /// Return the base 2 logarithm of the bound of the distribution.
+    pub const fn bound_log2(&self) -> u32 {
+        self.bound_log2
+    }
+
+    /// Return the variance of the distribution, normalized for a modulus $2^{log2\_modulus}$,
+    /// i.e. $\frac{2^{2b+1} + 1}{6 \cdot 2^{2 \cdot log2\_modulus}}$.
+    pub fn variance(&self, log2_modulus: u32) -> Variance {
+        let integer_variance = (2.0f64.powi(2 * self.bound_log2 as i32 + 1) + 1.0) / 6.0;
+        Variance(integer_variance * 2.0f64.powi(-2 * log2_modulus as i32))
+    }
+}
+
+macro_rules! implement_t_uniform {
+    ($T:ty) => {
+        impl RandomGenerable<TUniform> for $T {
+            type CustomModulus = $T;
+            fn generate_one<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform,
+            ) -> Self {
+                let bound_log2 = distribution.bound_log2;
+                assert!(
+                    bound_log2 + 2 <= <$T>::BITS,
+                    "TUniform bound 2^{bound_log2} is too large for {}-bit integers",
+                    <$T>::BITS
+                );
+
+                // We need b + 2 uniform bits: the b + 1 upper bits give a value in [0, 2^(b+1)[
+                // and the lowest bit is added to it, which yields the expected probabilities for
+                // [0, 2^(b+1)], the result is then shifted to [-2^b, 2^b].
+                let random_bits = <$T>::generate_one(generator, Uniform);
+                let random_bits = random_bits >> (<$T>::BITS - (bound_log2 + 2));
+                let value = (random_bits >> 1) + (random_bits & 1);
+                value.wrapping_sub(1 << bound_log2)
+            }
+
+            fn generate_one_custom_modulus<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform,
+                custom_modulus: Self::CustomModulus,
+            ) -> Self {
+                let value = Self::generate_one(generator, distribution);
+                // Map negative values to [q - 2^b, q[
+                if value > (1 << distribution.bound_log2) {
+                    custom_modulus.wrapping_add(value)
+                } else {
+                    value
+                }
+            }
+        }
+    };
+}
+
+implement_t_uniform!(u8);
+implement_t_uniform!(u16);
+implement_t_uniform!(u32);
+implement_t_uniform!(u64);
+implement_t_uniform!(u128);
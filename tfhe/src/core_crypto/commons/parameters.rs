@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 
 pub use super::ciphertext_modulus::CiphertextModulus;
+use super::dispersion::StandardDev;
 
 /// The number plaintexts in a plaintext list.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
@@ -237,6 +238,46 @@ impl LweBskGroupingFactor {
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct GgswPerLweMultiBitBskElement(pub usize);
 
+/// The distribution of the coefficients of LWE and GLWE secret keys.
+///
+/// Non binary keys have more entropy than binary keys of the same dimension, but they increase the
+/// noise terms which depend on the secret key (e.g. in modulus switching and keyswitching). They
+/// can still be used as input keys of a bootstrap through their binary expansion, see
+/// [`expand_lwe_secret_key_to_binary`](`crate::core_crypto::algorithms::expand_lwe_secret_key_to_binary`),
+/// which makes the bootstrapping key and the blind rotation
+/// [`binary_expansion_factor`](`Self::binary_expansion_factor`) times larger.
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+pub enum SecretKeyDistribution {
+    /// Coefficients are uniformly sampled in {0, 1}
+    Binary,
+    /// Coefficients are uniformly sampled in {-1, 0, 1}
+    Ternary,
+    /// Coefficients follow a rounded gaussian distribution centered on zero, samples whose
+    /// absolute value is greater than `bound` being rejected
+    BoundedGaussian { std_dev: StandardDev, bound: u32 },
+}
+
+impl SecretKeyDistribution {
+    /// Returns the number of bits needed to write the absolute value of a coefficient.
+    pub fn coefficient_bit_count(&self) -> usize {
+        match self {
+            Self::Binary | Self::Ternary => 1,
+            Self::BoundedGaussian { bound, .. } => {
+                (u32::BITS - bound.leading_zeros()).max(1) as usize
+            }
+        }
+    }
+
+    /// Returns the factor between the dimension of a key following this distribution and the
+    /// dimension of its binary expansion, 1 for binary keys as they are not expanded.
+    pub fn binary_expansion_factor(&self) -> usize {
+        match self {
+            Self::Binary => 1,
+            Self::Ternary | Self::BoundedGaussian { .. } => 2 * self.coefficient_bit_count(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub enum EncryptionKeyChoice {
     Big,
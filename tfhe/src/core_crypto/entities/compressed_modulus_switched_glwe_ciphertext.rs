@@ -0,0 +1,336 @@
+//! Module containing the definition of the [`CompressedModulusSwitchedGlweCiphertext`].
+
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// A [`GLWE ciphertext`](`GlweCiphertext`) whose coefficients have been switched to a smaller
+/// modulus $2^{\ell}$ and bit-packed for storage and transport.
+///
+/// Only the first `bodies_count` coefficients of the body are kept, which allows to drop the
+/// unused body coefficients of a GLWE obtained by packing fewer LWEs than its polynomial size.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CompressedModulusSwitchedGlweCiphertext<Scalar: UnsignedInteger> {
+    packed_coefficients: Vec<Scalar>,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    bodies_count: LweCiphertextCount,
+    log_modulus: CiphertextModulusLog,
+    uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+/// Structure to store the expected properties of a compressed modulus switched GLWE ciphertext
+/// Can be used on a server to check if client inputs are well formed
+/// before running a computation on them
+#[derive(Copy, Clone)]
+pub struct CompressedModulusSwitchedGlweCiphertextParameters<Scalar: UnsignedInteger> {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub log_modulus: CiphertextModulusLog,
+    pub uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger> ParameterSetConformant
+    for CompressedModulusSwitchedGlweCiphertext<Scalar>
+{
+    type ParameterSet = CompressedModulusSwitchedGlweCiphertextParameters<Scalar>;
+
+    fn is_conformant(
+        &self,
+        params: &CompressedModulusSwitchedGlweCiphertextParameters<Scalar>,
+    ) -> bool {
+        self.is_well_formed()
+            && self.glwe_dimension == params.glwe_dimension
+            && self.polynomial_size == params.polynomial_size
+            && self.log_modulus == params.log_modulus
+            && self.uncompressed_ciphertext_modulus == params.uncompressed_ciphertext_modulus
+    }
+}
+
+impl<Scalar: UnsignedInteger> CompressedModulusSwitchedGlweCiphertext<Scalar> {
+    /// Compress a [`GlweCiphertext`] by switching its coefficients to the modulus
+    /// $2^{\texttt{log\_modulus}}$ and packing them, keeping only the first `bodies_count` body
+    /// coefficients.
+    ///
+    /// The modulus switch rounds to the closest multiple of $2^{w - \texttt{log\_modulus}}$ where
+    /// $w$ is the bit width of `Scalar`, it therefore adds noise which must be accounted for when
+    /// choosing `log_modulus`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    /// let log_modulus = CiphertextModulusLog(12);
+    /// let bodies_count = LweCiphertextCount(10);
+    ///
+    /// let mut seeder = new_seeder();
+    /// let seeder = seeder.as_mut();
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+    /// let mut secret_generator =
+    ///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    ///
+    /// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// // Encode 4 bits of message in the most significant bits
+    /// let delta = 1u64 << 60;
+    /// let messages: Vec<u64> = (0..polynomial_size.0 as u64).map(|i| i % 16).collect();
+    /// let plaintext_list =
+    ///     PlaintextList::from_container(messages.iter().map(|m| m * delta).collect::<Vec<_>>());
+    ///
+    /// let mut glwe = GlweCiphertext::new(
+    ///     0u64,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    /// encrypt_glwe_ciphertext(
+    ///     &glwe_secret_key,
+    ///     &mut glwe,
+    ///     &plaintext_list,
+    ///     glwe_modular_std_dev,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let compressed =
+    ///     CompressedModulusSwitchedGlweCiphertext::compress(&glwe, log_modulus, bodies_count);
+    ///
+    /// assert_eq!(compressed.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(compressed.polynomial_size(), polynomial_size);
+    /// assert_eq!(compressed.bodies_count(), bodies_count);
+    /// assert_eq!(compressed.log_modulus(), log_modulus);
+    ///
+    /// let extracted = compressed.extract();
+    ///
+    /// let mut decrypted = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+    /// decrypt_glwe_ciphertext(&glwe_secret_key, &extracted, &mut decrypted);
+    ///
+    /// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+    /// for (decrypted, expected) in decrypted.iter().zip(messages.iter()).take(bodies_count.0) {
+    ///     assert_eq!(
+    ///         decomposer.closest_representable(*decrypted.0) / delta,
+    ///         *expected
+    ///     );
+    /// }
+    /// ```
+    pub fn compress<Cont: Container<Element = Scalar>>(
+        ct: &GlweCiphertext<Cont>,
+        log_modulus: CiphertextModulusLog,
+        bodies_count: LweCiphertextCount,
+    ) -> Self {
+        let ciphertext_modulus = ct.ciphertext_modulus();
+        assert!(
+            ciphertext_modulus.is_native_modulus(),
+            "Modulus switch compression is only supported for the native modulus"
+        );
+        assert!(
+            log_modulus.0 > 0 && log_modulus.0 < Scalar::BITS,
+            "log_modulus must be in [1, {}), got {}",
+            Scalar::BITS,
+            log_modulus.0
+        );
+        assert!(
+            bodies_count.0 <= ct.polynomial_size().0,
+            "Cannot keep {} body coefficients from a GLWE of polynomial size {}",
+            bodies_count.0,
+            ct.polynomial_size().0
+        );
+
+        let mask = ct.get_mask();
+        let body = ct.get_body();
+
+        let kept_coefficients = mask
+            .as_ref()
+            .iter()
+            .chain(body.as_ref()[..bodies_count.0].iter())
+            .map(|coefficient| modulus_switch(*coefficient, log_modulus));
+
+        let packed_coefficients = pack(
+            kept_coefficients,
+            mask.as_ref().len() + bodies_count.0,
+            log_modulus,
+        );
+
+        Self {
+            packed_coefficients,
+            glwe_dimension: ct.glwe_size().to_glwe_dimension(),
+            polynomial_size: ct.polynomial_size(),
+            bodies_count,
+            log_modulus,
+            uncompressed_ciphertext_modulus: ciphertext_modulus,
+        }
+    }
+
+    /// Switch the coefficients back to the original modulus and return the corresponding
+    /// [`GlweCiphertext`], the body coefficients which were dropped during compression are set to
+    /// zero.
+    ///
+    /// See [`CompressedModulusSwitchedGlweCiphertext::compress`] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the packed data is inconsistent with the stored dimensions, which can only happen
+    /// for a ciphertext coming from an untrusted source: check it with
+    /// [`ParameterSetConformant::is_conformant`] first.
+    pub fn extract(&self) -> GlweCiphertextOwned<Scalar> {
+        assert!(
+            self.is_well_formed(),
+            "Malformed CompressedModulusSwitchedGlweCiphertext"
+        );
+
+        let mask_len = self.glwe_dimension.0 * self.polynomial_size.0;
+
+        let mut output = GlweCiphertext::new(
+            Scalar::ZERO,
+            self.glwe_dimension.to_glwe_size(),
+            self.polynomial_size,
+            self.uncompressed_ciphertext_modulus,
+        );
+
+        let unpacked = unpack(
+            &self.packed_coefficients,
+            mask_len + self.bodies_count.0,
+            self.log_modulus,
+        );
+
+        for (output, switched) in output.as_mut().iter_mut().zip(unpacked) {
+            *output = switched << (Scalar::BITS - self.log_modulus.0);
+        }
+
+        output
+    }
+
+    /// Return the [`GlweDimension`] of the compressed [`GlweCiphertext`].
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    /// Return the [`PolynomialSize`] of the compressed [`GlweCiphertext`].
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Return the number of body coefficients kept during compression.
+    pub fn bodies_count(&self) -> LweCiphertextCount {
+        self.bodies_count
+    }
+
+    /// Return the log2 of the modulus the coefficients were switched to.
+    pub fn log_modulus(&self) -> CiphertextModulusLog {
+        self.log_modulus
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`GlweCiphertext`] before compression.
+    pub fn uncompressed_ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.uncompressed_ciphertext_modulus
+    }
+
+    /// Return the packed coefficients.
+    pub fn packed_coefficients(&self) -> &[Scalar] {
+        &self.packed_coefficients
+    }
+
+    /// Return `true` if the packed data is consistent with the stored dimensions, i.e. if
+    /// [`CompressedModulusSwitchedGlweCiphertext::extract`] will not panic.
+    pub(crate) fn is_well_formed(&self) -> bool {
+        if self.log_modulus.0 == 0
+            || self.log_modulus.0 >= Scalar::BITS
+            || self.bodies_count.0 > self.polynomial_size.0
+        {
+            return false;
+        }
+
+        let expected_len = self
+            .glwe_dimension
+            .0
+            .checked_mul(self.polynomial_size.0)
+            .and_then(|mask_len| mask_len.checked_add(self.bodies_count.0))
+            .and_then(|count| count.checked_mul(self.log_modulus.0))
+            .map(|total_bits| {
+                total_bits / Scalar::BITS + usize::from(total_bits % Scalar::BITS != 0)
+            });
+
+        expected_len == Some(self.packed_coefficients.len())
+    }
+}
+
+// These accessors are used to create invalid objects and test the conformance functions
+// But these functions should not be used in other contexts, hence the `#[cfg(test)]`
+#[cfg(test)]
+#[allow(dead_code)]
+impl<Scalar: UnsignedInteger> CompressedModulusSwitchedGlweCiphertext<Scalar> {
+    pub(crate) fn get_mut_packed_coefficients(&mut self) -> &mut Vec<Scalar> {
+        &mut self.packed_coefficients
+    }
+
+    pub(crate) fn get_mut_bodies_count(&mut self) -> &mut LweCiphertextCount {
+        &mut self.bodies_count
+    }
+}
+
+/// Round a native modulus coefficient to the closest multiple of $2^{w - \ell}$ and return the
+/// corresponding value in $[0, 2^{\ell})$.
+fn modulus_switch<Scalar: UnsignedInteger>(
+    coefficient: Scalar,
+    log_modulus: CiphertextModulusLog,
+) -> Scalar {
+    let rounded = ((coefficient >> (Scalar::BITS - log_modulus.0 - 1)) + Scalar::ONE) >> 1;
+    rounded & ((Scalar::ONE << log_modulus.0) - Scalar::ONE)
+}
+
+/// Pack `count` values of `log_modulus` bits each contiguously in a vec of `Scalar`.
+fn pack<Scalar: UnsignedInteger>(
+    values: impl Iterator<Item = Scalar>,
+    count: usize,
+    log_modulus: CiphertextModulusLog,
+) -> Vec<Scalar> {
+    let total_bits = count * log_modulus.0;
+    let mut packed = vec![Scalar::ZERO; (total_bits + Scalar::BITS - 1) / Scalar::BITS];
+
+    for (index, value) in values.enumerate() {
+        let start = index * log_modulus.0;
+        let word = start / Scalar::BITS;
+        let offset = start % Scalar::BITS;
+
+        packed[word] |= value << offset;
+        if offset + log_modulus.0 > Scalar::BITS {
+            packed[word + 1] |= value >> (Scalar::BITS - offset);
+        }
+    }
+
+    packed
+}
+
+/// Unpack `count` values of `log_modulus` bits each from `packed`.
+fn unpack<Scalar: UnsignedInteger>(
+    packed: &[Scalar],
+    count: usize,
+    log_modulus: CiphertextModulusLog,
+) -> impl Iterator<Item = Scalar> + '_ {
+    let value_mask = (Scalar::ONE << log_modulus.0) - Scalar::ONE;
+
+    (0..count).map(move |index| {
+        let start = index * log_modulus.0;
+        let word = start / Scalar::BITS;
+        let offset = start % Scalar::BITS;
+
+        let mut value = packed[word] >> offset;
+        if offset + log_modulus.0 > Scalar::BITS {
+            value |= packed[word + 1] << (Scalar::BITS - offset);
+        }
+
+        value & value_mask
+    })
+}
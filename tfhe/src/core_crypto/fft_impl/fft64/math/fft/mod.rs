@@ -15,7 +15,7 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::mem::{align_of, size_of};
 use std::sync::{Arc, OnceLock, RwLock};
-#[cfg(not(feature = "experimental-force_fft_algo_dif4"))]
+#[cfg(not(any(feature = "experimental-force_fft_algo_dif4", target_arch = "wasm32")))]
 use std::time::Duration;
 
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
@@ -154,14 +154,22 @@ impl Fft {
 
             plan.map(|p| {
                 p.get_or_init(|| {
-                    #[cfg(not(feature = "experimental-force_fft_algo_dif4"))]
+                    #[cfg(not(any(
+                        feature = "experimental-force_fft_algo_dif4",
+                        target_arch = "wasm32"
+                    )))]
                     {
                         Arc::new((
                             Twisties::new(n / 2),
                             Plan::new(n / 2, Method::Measure(Duration::from_millis(10))),
                         ))
                     }
-                    #[cfg(feature = "experimental-force_fft_algo_dif4")]
+                    // There is no clock on wasm32-unknown-unknown, so the fastest algorithm cannot
+                    // be measured
+                    #[cfg(any(
+                        feature = "experimental-force_fft_algo_dif4",
+                        target_arch = "wasm32"
+                    ))]
                     {
                         Arc::new((
                             Twisties::new(n / 2),
@@ -0,0 +1,283 @@
+//! Encrypted arrays of integers.
+//!
+//! The [FheArray] type stores a list of encrypted integers and allows
+//! to read and write elements at an encrypted position (oblivious indexing).
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::high_level_api::integers::{GenericInteger, IntegerId};
+use crate::high_level_api::traits::{FheDecrypt, FheTryEncrypt};
+use crate::high_level_api::ClientKey;
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::FheBool;
+
+#[cfg(test)]
+mod tests;
+
+/// An array of encrypted integers.
+///
+/// Elements can be accessed using either a clear index (through [Index]/[IndexMut])
+/// or an encrypted index (through [FheArray::get] and [FheArray::set]).
+///
+/// When using an encrypted index, the server does not learn which element
+/// was accessed, as all the elements are touched.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, FheUint8Array};
+///
+/// let config = ConfigBuilder::default().build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let clear_values = [3u8, 7, 11, 13];
+/// let array = FheUint8Array::encrypt(clear_values.as_slice(), &client_key);
+///
+/// let index = FheUint8::encrypt(2u8, &client_key);
+/// let value = array.get(&index);
+///
+/// let decrypted: u8 = value.decrypt(&client_key);
+/// assert_eq!(decrypted, clear_values[2]);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "GenericInteger<Id>: Serialize",
+    deserialize = "GenericInteger<Id>: Deserialize<'de>"
+))]
+pub struct FheArray<Id: IntegerId> {
+    elements: Vec<GenericInteger<Id>>,
+}
+
+impl<Id> FheArray<Id>
+where
+    Id: IntegerId,
+{
+    pub fn new(elements: Vec<GenericInteger<Id>>) -> Self {
+        Self { elements }
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[GenericInteger<Id>] {
+        &self.elements
+    }
+
+    pub fn into_vec(self) -> Vec<GenericInteger<Id>> {
+        self.elements
+    }
+
+    /// Returns a new array containing a copy of the elements in the given (clear) range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice<R>(&self, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let bounds: (Bound<usize>, Bound<usize>) =
+            (range.start_bound().cloned(), range.end_bound().cloned());
+        Self::new(self.elements[bounds].to_vec())
+    }
+
+    /// Returns the element stored at the encrypted `index`.
+    ///
+    /// If `index` is greater than or equal to the length of the array,
+    /// the returned value will be an encryption of 0.
+    pub fn get<IndexId>(&self, index: &GenericInteger<IndexId>) -> GenericInteger<Id>
+    where
+        IndexId: IntegerId<InnerCiphertext = RadixCiphertext>,
+    {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let zero: Id::InnerCiphertext =
+                sks.create_trivial_zero_radix(Id::num_blocks(sks.message_modulus()));
+
+            let selectors = self.index_selectors(sks, &index.ciphertext);
+            // All but (at most) one element are zeroed out,
+            // so bitor-ing them together yields the selected element
+            let selected = self
+                .ciphertexts()
+                .par_iter()
+                .zip(selectors.par_iter())
+                .map(|(element, selector)| sks.if_then_else_parallelized(selector, *element, &zero))
+                .collect::<Vec<_>>();
+
+            tree_reduce(selected, |lhs, rhs| sks.bitor_parallelized(lhs, rhs)).unwrap_or(zero)
+        });
+
+        GenericInteger::new(ciphertext, Id::default())
+    }
+
+    /// Replaces the element stored at the encrypted `index` by `value`.
+    ///
+    /// If `index` is greater than or equal to the length of the array,
+    /// the array is left unchanged.
+    pub fn set<IndexId>(&mut self, index: &GenericInteger<IndexId>, value: &GenericInteger<Id>)
+    where
+        IndexId: IntegerId<InnerCiphertext = RadixCiphertext>,
+    {
+        let new_ciphertexts = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let value = &value.ciphertext;
+            let selectors = self.index_selectors(sks, &index.ciphertext);
+
+            self.ciphertexts()
+                .par_iter()
+                .zip(selectors.par_iter())
+                .map(|(element, selector)| sks.if_then_else_parallelized(selector, value, *element))
+                .collect::<Vec<_>>()
+        });
+
+        for (element, new_ciphertext) in self.elements.iter_mut().zip(new_ciphertexts) {
+            element.ciphertext = new_ciphertext;
+        }
+    }
+
+    /// Returns an encrypted boolean that is true if any element of the array
+    /// is equal to `value`.
+    pub fn contains(&self, value: &GenericInteger<Id>) -> FheBool {
+        let result = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let value = &value.ciphertext;
+            let comparisons = self
+                .ciphertexts()
+                .par_iter()
+                .map(|element| sks.eq_parallelized(*element, value))
+                .collect::<Vec<_>>();
+
+            tree_reduce(comparisons, |lhs, rhs| {
+                BooleanBlock::new_unchecked(sks.key.bitor(lhs.as_ref(), rhs.as_ref()))
+            })
+            .unwrap_or_else(|| sks.create_trivial_boolean_block(false))
+        });
+
+        FheBool::new(result)
+    }
+
+    fn ciphertexts(&self) -> Vec<&Id::InnerCiphertext> {
+        self.elements
+            .iter()
+            .map(|element| &element.ciphertext)
+            .collect()
+    }
+
+    /// Returns, for each position of the array, an encrypted boolean
+    /// telling whether `index` is equal to this position.
+    fn index_selectors(
+        &self,
+        sks: &crate::integer::ServerKey,
+        index: &RadixCiphertext,
+    ) -> Vec<BooleanBlock> {
+        (0..self.elements.len() as u64)
+            .into_par_iter()
+            .map(|position| sks.scalar_eq_parallelized(index, position))
+            .collect()
+    }
+}
+
+/// Reduces the values pairwise, level by level, so that the depth
+/// of the computation is logarithmic in the number of values.
+pub(in crate::high_level_api) fn tree_reduce<T, F>(mut values: Vec<T>, op: F) -> Option<T>
+where
+    T: Send + Sync,
+    F: Fn(&T, &T) -> T + Sync,
+{
+    while values.len() > 1 {
+        let odd_one_out = if values.len() % 2 == 1 {
+            values.pop()
+        } else {
+            None
+        };
+
+        let mut next_level = Vec::with_capacity((values.len() / 2) + 1);
+        values
+            .par_chunks_exact(2)
+            .map(|pair| op(&pair[0], &pair[1]))
+            .collect_into_vec(&mut next_level);
+        next_level.extend(odd_one_out);
+
+        values = next_level;
+    }
+
+    values.pop()
+}
+
+impl<Id> From<Vec<GenericInteger<Id>>> for FheArray<Id>
+where
+    Id: IntegerId,
+{
+    fn from(elements: Vec<GenericInteger<Id>>) -> Self {
+        Self::new(elements)
+    }
+}
+
+impl<Id> FromIterator<GenericInteger<Id>> for FheArray<Id>
+where
+    Id: IntegerId,
+{
+    fn from_iter<I: IntoIterator<Item = GenericInteger<Id>>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<Id> Index<usize> for FheArray<Id>
+where
+    Id: IntegerId,
+{
+    type Output = GenericInteger<Id>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.elements[index]
+    }
+}
+
+impl<Id> IndexMut<usize> for FheArray<Id>
+where
+    Id: IntegerId,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.elements[index]
+    }
+}
+
+impl<'a, Id, T> FheTryEncrypt<&'a [T], ClientKey> for FheArray<Id>
+where
+    Id: IntegerId,
+    T: Copy,
+    GenericInteger<Id>: FheTryEncrypt<T, ClientKey, Error = crate::high_level_api::errors::Error>,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(values: &'a [T], key: &ClientKey) -> Result<Self, Self::Error> {
+        values
+            .iter()
+            .map(|value| GenericInteger::<Id>::try_encrypt(*value, key))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+}
+
+impl<Id, ClearType> FheDecrypt<Vec<ClearType>> for FheArray<Id>
+where
+    Id: IntegerId,
+    GenericInteger<Id>: FheDecrypt<ClearType>,
+{
+    fn decrypt(&self, key: &ClientKey) -> Vec<ClearType> {
+        self.elements
+            .iter()
+            .map(|element| element.decrypt(key))
+            .collect()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ClientKey, ConfigBuilder, FheUint8};
+use crate::FheUint8Array;
+
+fn setup_default() -> ClientKey {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+    client_key
+}
+
+#[test]
+fn test_array_encrypted_get() {
+    let client_key = setup_default();
+
+    let clear_values = [17u8, 42, 255, 3, 128];
+    let array = FheUint8Array::encrypt(clear_values.as_slice(), &client_key);
+
+    for clear_index in [0u8, 2, 4, 7] {
+        let index = FheUint8::encrypt(clear_index, &client_key);
+        let value = array.get(&index);
+        let decrypted: u8 = value.decrypt(&client_key);
+        let expected = clear_values.get(clear_index as usize).copied().unwrap_or(0);
+        assert_eq!(decrypted, expected);
+    }
+}
+
+#[test]
+fn test_array_encrypted_set() {
+    let client_key = setup_default();
+
+    let mut clear_values = vec![1u8, 2, 3];
+    let mut array = FheUint8Array::encrypt(clear_values.as_slice(), &client_key);
+
+    let index = FheUint8::encrypt(1u8, &client_key);
+    let value = FheUint8::encrypt(99u8, &client_key);
+    array.set(&index, &value);
+    clear_values[1] = 99;
+
+    let decrypted: Vec<u8> = array.decrypt(&client_key);
+    assert_eq!(decrypted, clear_values);
+
+    // Out of range index leaves the array untouched
+    let index = FheUint8::encrypt(3u8, &client_key);
+    array.set(&index, &value);
+    let decrypted: Vec<u8> = array.decrypt(&client_key);
+    assert_eq!(decrypted, clear_values);
+}
+
+#[test]
+fn test_array_clear_index_and_slice() {
+    let client_key = setup_default();
+
+    let clear_values = [10u8, 20, 30, 40];
+    let mut array = FheUint8Array::encrypt(clear_values.as_slice(), &client_key);
+    assert_eq!(array.len(), clear_values.len());
+
+    let decrypted: u8 = array[2].decrypt(&client_key);
+    assert_eq!(decrypted, 30);
+
+    array[0] = FheUint8::encrypt(11u8, &client_key);
+    let decrypted: u8 = array[0].decrypt(&client_key);
+    assert_eq!(decrypted, 11);
+
+    let sub_array = array.slice(1..3);
+    let decrypted: Vec<u8> = sub_array.decrypt(&client_key);
+    assert_eq!(decrypted, vec![20, 30]);
+}
+
+#[test]
+fn test_array_contains() {
+    let client_key = setup_default();
+
+    let clear_values = [5u8, 6, 7];
+    let array = FheUint8Array::encrypt(clear_values.as_slice(), &client_key);
+
+    for clear_value in [6u8, 8] {
+        let value = FheUint8::encrypt(clear_value, &client_key);
+        let result = array.contains(&value);
+        assert_eq!(
+            result.decrypt(&client_key),
+            clear_values.contains(&clear_value)
+        );
+    }
+}
@@ -0,0 +1,669 @@
+//! Compact lists that can hold values of different types.
+//!
+//! Unlike [CompactFheUint32List](crate::CompactFheUint32List), which can only hold values
+//! of a single type, a [CompactCiphertextList] can mix booleans, unsigned and signed integers
+//! of different sizes while paying the cost of the compact encryption only once.
+use serde::{Deserialize, Serialize};
+
+use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+use crate::high_level_api::errors::{Error, Type};
+use crate::high_level_api::integers::{num_blocks_for_bits, GenericInteger, IntegerId};
+use crate::high_level_api::internal_traits::TypeIdentifier;
+use crate::integer::encryption::create_clear_radix_block_iterator;
+use crate::integer::{BooleanBlock, I256, U256};
+use crate::named::Named;
+use crate::shortint::parameters::CiphertextConformanceParams;
+use crate::shortint::{Ciphertext, MessageModulus};
+#[cfg(feature = "zk-pok-experimental")]
+use crate::zk::{CompactPkePublicParams, ZkVerificationOutCome};
+use crate::{CompactPublicKey, FheBool};
+
+/// Number of radix blocks used to store a value of the given type
+fn num_blocks_of(kind: Type, message_modulus: MessageModulus) -> usize {
+    if kind == Type::FheBool {
+        return 1;
+    }
+    num_blocks_for_bits(kind.num_bits(), message_modulus)
+}
+
+/// Clear values that can be pushed in a [CompactCiphertextListBuilder]
+pub trait Compactable {
+    /// The type of the ciphertext this value will be expanded to
+    fn kind() -> Type;
+
+    /// Appends the clear radix blocks of `self` to `messages`
+    fn compact_into(self, messages: &mut Vec<u64>, message_modulus: MessageModulus);
+}
+
+impl Compactable for bool {
+    fn kind() -> Type {
+        Type::FheBool
+    }
+
+    fn compact_into(self, messages: &mut Vec<u64>, _message_modulus: MessageModulus) {
+        messages.push(u64::from(self));
+    }
+}
+
+macro_rules! impl_compactable_for_clear_integer {
+    ($($clear_type:ty => $kind:ident),* $(,)?) => {
+        $(
+            impl Compactable for $clear_type {
+                fn kind() -> Type {
+                    Type::$kind
+                }
+
+                fn compact_into(self, messages: &mut Vec<u64>, message_modulus: MessageModulus) {
+                    let num_blocks = num_blocks_of(Type::$kind, message_modulus);
+                    messages.extend(create_clear_radix_block_iterator(
+                        self,
+                        message_modulus,
+                        num_blocks,
+                    ));
+                }
+            }
+        )*
+    };
+}
+
+impl_compactable_for_clear_integer!(
+    u8 => FheUint8,
+    u16 => FheUint16,
+    u32 => FheUint32,
+    u64 => FheUint64,
+    u128 => FheUint128,
+    U256 => FheUint256,
+    i8 => FheInt8,
+    i16 => FheInt16,
+    i32 => FheInt32,
+    i64 => FheInt64,
+    i128 => FheInt128,
+    I256 => FheInt256,
+);
+
+/// Ciphertext types that can be extracted from a [CompactCiphertextListExpander]
+pub trait Expandable: Sized {
+    /// The type this ciphertext is tagged with in a list
+    fn kind() -> Type;
+
+    /// Builds `Self` from the blocks of an entry that is tagged as `Self::kind()`
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>) -> Self;
+}
+
+impl Expandable for FheBool {
+    fn kind() -> Type {
+        Type::FheBool
+    }
+
+    /// The block of a compact list may hold any value of the message space,
+    /// so it goes through a PBS that maps it to 0 or 1 (any non-zero value is `true`).
+    fn from_expanded_blocks(mut blocks: Vec<Ciphertext>) -> Self {
+        let block = blocks.pop().unwrap();
+        let block = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let shortint_key = &keys.integer_key.pbs_key().key;
+            let lut = shortint_key.generate_lookup_table(|x| u64::from(x != 0));
+            shortint_key.apply_lookup_table(&block, &lut)
+        });
+        Self::new(BooleanBlock::new_unchecked(block))
+    }
+}
+
+impl<Id> Expandable for GenericInteger<Id>
+where
+    Id: IntegerId + TypeIdentifier,
+{
+    fn kind() -> Type {
+        Id::default().type_variant()
+    }
+
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>) -> Self {
+        Self::new(Id::InnerCiphertext::from(blocks), Id::default())
+    }
+}
+
+/// A compact list of encrypted values that may have different types
+///
+/// Each value is stored as its radix blocks in a single compact list,
+/// alongside a tag that records its type.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+/// use tfhe::{
+///     generate_keys, set_server_key, CompactCiphertextList, CompactPublicKey, ConfigBuilder,
+///     FheBool, FheInt64, FheUint32, FheUint8,
+/// };
+///
+/// let config = ConfigBuilder::default()
+///     .use_custom_parameters(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS, None)
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// let public_key = CompactPublicKey::new(&client_key);
+///
+/// let compact_list = CompactCiphertextList::builder(&public_key)
+///     .push(17u8)
+///     .push(u32::MAX)
+///     .push(-5i64)
+///     .push(true)
+///     .build();
+///
+/// set_server_key(server_key);
+/// let expander = compact_list.expand();
+/// assert_eq!(expander.len(), 4);
+///
+/// let a: FheUint8 = expander.get(0).unwrap().unwrap();
+/// let b: FheUint32 = expander.get(1).unwrap().unwrap();
+/// let c: FheInt64 = expander.get(2).unwrap().unwrap();
+/// let d: FheBool = expander.get(3).unwrap().unwrap();
+///
+/// // Asking for the wrong type is an error
+/// assert!(expander.get::<FheUint32>(0).unwrap().is_err());
+///
+/// let a: u8 = a.decrypt(&client_key);
+/// assert_eq!(a, 17);
+/// let b: u32 = b.decrypt(&client_key);
+/// assert_eq!(b, u32::MAX);
+/// let c: i64 = c.decrypt(&client_key);
+/// assert_eq!(c, -5);
+/// assert!(d.decrypt(&client_key));
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompactCiphertextList {
+    pub(in crate::high_level_api) inner: crate::integer::ciphertext::CompactCiphertextList,
+    pub(in crate::high_level_api) info: Vec<Type>,
+}
+
+impl CompactCiphertextList {
+    pub fn builder(public_key: &CompactPublicKey) -> CompactCiphertextListBuilder<'_> {
+        CompactCiphertextListBuilder::new(public_key)
+    }
+
+    /// Returns the number of values stored in the list
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+    }
+
+    /// Returns the type of the value at the given index
+    pub fn get_kind_of(&self, index: usize) -> Option<Type> {
+        self.info.get(index).copied()
+    }
+
+    /// Expands the list, so that the values can be extracted
+    pub fn expand(&self) -> CompactCiphertextListExpander {
+        CompactCiphertextListExpander {
+            blocks: self.inner.ct_list.expand(),
+            info: self.info.clone(),
+            message_modulus: self.inner.ct_list.message_modulus,
+        }
+    }
+}
+
+impl Named for CompactCiphertextList {
+    const NAME: &'static str = "high_level_api::CompactCiphertextList";
+}
+
+/// Structure to store the expected properties of a [CompactCiphertextList]
+pub struct CompactCiphertextListConformanceParams {
+    pub shortint_params: CiphertextConformanceParams,
+    pub num_elements_constraint: ListSizeConstraint,
+}
+
+/// Checks the shape of a compact list and that its type tags match its number of blocks
+fn is_conformant_list(
+    ct_list: &crate::shortint::ciphertext::CompactCiphertextList,
+    info: &[Type],
+    params: &CompactCiphertextListConformanceParams,
+) -> bool {
+    if !params.num_elements_constraint.is_valid(info.len()) {
+        return false;
+    }
+
+    let message_modulus = params.shortint_params.message_modulus;
+    let total_num_blocks: usize = info
+        .iter()
+        .map(|kind| num_blocks_of(*kind, message_modulus))
+        .sum();
+
+    ct_list.is_conformant(
+        &params
+            .shortint_params
+            .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(total_num_blocks)),
+    )
+}
+
+impl ParameterSetConformant for CompactCiphertextList {
+    type ParameterSet = CompactCiphertextListConformanceParams;
+
+    fn is_conformant(&self, params: &CompactCiphertextListConformanceParams) -> bool {
+        self.inner.num_blocks_per_integer == 1
+            && is_conformant_list(&self.inner.ct_list, &self.info, params)
+    }
+}
+
+/// Builder used to create a [CompactCiphertextList]
+///
+/// Values are encrypted all at once when [build](Self::build) is called.
+pub struct CompactCiphertextListBuilder<'a> {
+    messages: Vec<u64>,
+    info: Vec<Type>,
+    pk: &'a CompactPublicKey,
+}
+
+impl<'a> CompactCiphertextListBuilder<'a> {
+    pub fn new(pk: &'a CompactPublicKey) -> Self {
+        Self {
+            messages: vec![],
+            info: vec![],
+            pk,
+        }
+    }
+
+    pub fn push<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Compactable,
+    {
+        value.compact_into(&mut self.messages, self.pk.key.message_modulus());
+        self.info.push(T::kind());
+        self
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compactable,
+    {
+        for value in values {
+            self.push(value);
+        }
+        self
+    }
+
+    pub fn build(&self) -> CompactCiphertextList {
+        let ct_list = self
+            .pk
+            .key
+            .key
+            .key
+            .encrypt_iter(self.messages.iter().copied());
+        CompactCiphertextList {
+            inner: crate::integer::ciphertext::CompactCiphertextList {
+                ct_list,
+                num_blocks_per_integer: 1,
+            },
+            info: self.info.clone(),
+        }
+    }
+
+    /// Encrypts the values and proves that they were correctly encrypted
+    ///
+    /// The `public_params` must have been generated for the parameters of the public key and
+    /// allow at least as many messages as the total number of blocks of the pushed values.
+    #[cfg(feature = "zk-pok-experimental")]
+    pub fn build_with_proof(
+        &self,
+        public_params: &CompactPkePublicParams,
+    ) -> ProvenCompactCiphertextList {
+        let inner = self
+            .pk
+            .key
+            .key
+            .key
+            .encrypt_and_prove_slice(&self.messages, public_params);
+        ProvenCompactCiphertextList {
+            inner,
+            info: self.info.clone(),
+        }
+    }
+}
+
+/// A [CompactCiphertextList] with a zero-knowledge proof that its values were correctly encrypted
+///
+/// It is created with [CompactCiphertextListBuilder::build_with_proof], and the proof must be
+/// verified before the values can be extracted.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS;
+/// use tfhe::zk::CompactPkeCrs;
+/// use tfhe::{
+///     generate_keys, set_server_key, CompactCiphertextList, CompactPublicKey, ConfigBuilder,
+///     FheBool, FheUint8,
+/// };
+///
+/// let params = PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS;
+/// let config = ConfigBuilder::default()
+///     .use_custom_parameters(params, None)
+///     .build();
+/// // The public parameters are generated once, for lists of up to 8 blocks
+/// let crs = CompactPkeCrs::from_shortint_params(params, 8);
+/// let (client_key, server_key) = generate_keys(config);
+/// let public_key = CompactPublicKey::new(&client_key);
+///
+/// // Client side
+/// let proven_list = CompactCiphertextList::builder(&public_key)
+///     .push(17u8)
+///     .push(true)
+///     .build_with_proof(crs.public_params());
+///
+/// // Server side
+/// set_server_key(server_key);
+/// let expander = proven_list
+///     .verify_and_expand(crs.public_params(), &public_key)
+///     .unwrap();
+///
+/// let a: FheUint8 = expander.get(0).unwrap().unwrap();
+/// let b: FheBool = expander.get(1).unwrap().unwrap();
+///
+/// let a: u8 = a.decrypt(&client_key);
+/// assert_eq!(a, 17);
+/// assert!(b.decrypt(&client_key));
+/// ```
+#[cfg(feature = "zk-pok-experimental")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProvenCompactCiphertextList {
+    pub(in crate::high_level_api) inner: crate::shortint::ciphertext::ProvenCompactCiphertextList,
+    pub(in crate::high_level_api) info: Vec<Type>,
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl ProvenCompactCiphertextList {
+    /// Returns the number of values stored in the list
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+    }
+
+    /// Returns the type of the value at the given index
+    pub fn get_kind_of(&self, index: usize) -> Option<Type> {
+        self.info.get(index).copied()
+    }
+
+    /// Verifies the proof of the list against the public key used to encrypt it
+    pub fn verify(
+        &self,
+        public_params: &CompactPkePublicParams,
+        public_key: &CompactPublicKey,
+    ) -> ZkVerificationOutCome {
+        self.inner.verify(public_params, &public_key.key.key.key)
+    }
+
+    /// Verifies the proof of the list and expands it, so that the values can be extracted
+    pub fn verify_and_expand(
+        &self,
+        public_params: &CompactPkePublicParams,
+        public_key: &CompactPublicKey,
+    ) -> Result<CompactCiphertextListExpander, Error> {
+        let blocks = self
+            .inner
+            .verify_and_expand(public_params, &public_key.key.key.key)
+            .ok_or(Error::InvalidProof)?;
+
+        Ok(CompactCiphertextListExpander {
+            blocks,
+            info: self.info.clone(),
+            message_modulus: self.inner.ct_list.message_modulus,
+        })
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl Named for ProvenCompactCiphertextList {
+    const NAME: &'static str = "high_level_api::ProvenCompactCiphertextList";
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl ParameterSetConformant for ProvenCompactCiphertextList {
+    type ParameterSet = CompactCiphertextListConformanceParams;
+
+    fn is_conformant(&self, params: &CompactCiphertextListConformanceParams) -> bool {
+        is_conformant_list(&self.inner.ct_list, &self.info, params)
+    }
+}
+
+/// The expanded blocks of a [CompactCiphertextList]
+///
+/// Values are extracted by index with [get](Self::get).
+pub struct CompactCiphertextListExpander {
+    blocks: Vec<Ciphertext>,
+    info: Vec<Type>,
+    message_modulus: MessageModulus,
+}
+
+impl CompactCiphertextListExpander {
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+    }
+
+    pub fn get_kind_of(&self, index: usize) -> Option<Type> {
+        self.info.get(index).copied()
+    }
+
+    /// Returns the value at the given index
+    ///
+    /// - `None` if the index is out of bounds
+    /// - `Some(Err(_))` if the value stored at this index is not of type `T`, or if the list does
+    ///   not hold enough blocks for the values it is tagged with
+    pub fn get<T>(&self, index: usize) -> Option<Result<T, Error>>
+    where
+        T: Expandable,
+    {
+        let kind = *self.info.get(index)?;
+        if kind != T::kind() {
+            return Some(Err(Error::UnexpectedType {
+                expected: T::kind(),
+                got: kind,
+            }));
+        }
+
+        let start: usize = self.info[..index]
+            .iter()
+            .map(|kind| num_blocks_of(*kind, self.message_modulus))
+            .sum();
+        let end = start + num_blocks_of(kind, self.message_modulus);
+
+        let Some(blocks) = self.blocks.get(start..end) else {
+            return Some(Err(Error::InvalidCompactList));
+        };
+
+        Some(Ok(T::from_expanded_blocks(blocks.to_vec())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    use crate::{
+        generate_keys, set_server_key, ConfigBuilder, FheInt16, FheUint16, FheUint32, FheUint8,
+    };
+
+    #[test]
+    fn test_heterogeneous_compact_list() {
+        let config = ConfigBuilder::default()
+            .use_custom_parameters(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS, None)
+            .build();
+        let (client_key, server_key) = generate_keys(config);
+        set_server_key(server_key);
+        let public_key = CompactPublicKey::new(&client_key);
+
+        let compact_list = CompactCiphertextList::builder(&public_key)
+            .push(u32::MAX - 3)
+            .push(false)
+            .push(-1234i16)
+            .extend([7u8, 250u8].into_iter())
+            .push(true)
+            .build();
+
+        let serialized = bincode::serialize(&compact_list).unwrap();
+        let compact_list: CompactCiphertextList = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(compact_list.len(), 6);
+        assert_eq!(compact_list.get_kind_of(2), Some(Type::FheInt16));
+        assert_eq!(compact_list.get_kind_of(6), None);
+
+        let expander = compact_list.expand();
+
+        let a: FheUint32 = expander.get(0).unwrap().unwrap();
+        let b: FheBool = expander.get(1).unwrap().unwrap();
+        let c: FheInt16 = expander.get(2).unwrap().unwrap();
+        let d: FheUint8 = expander.get(3).unwrap().unwrap();
+        let e: FheUint8 = expander.get(4).unwrap().unwrap();
+        let f: FheBool = expander.get(5).unwrap().unwrap();
+        assert!(expander.get::<FheBool>(6).is_none());
+
+        // Values expanded from the list can be used in computations
+        let sum: u8 = (&d + &e).decrypt(&client_key);
+        assert_eq!(sum, 1);
+        assert!((&b | &f).decrypt(&client_key));
+
+        let a: u32 = a.decrypt(&client_key);
+        assert_eq!(a, u32::MAX - 3);
+        assert!(!b.decrypt(&client_key));
+        let c: i16 = c.decrypt(&client_key);
+        assert_eq!(c, -1234);
+        let d: u8 = d.decrypt(&client_key);
+        assert_eq!(d, 7);
+        let e: u8 = e.decrypt(&client_key);
+        assert_eq!(e, 250);
+        assert!(f.decrypt(&client_key));
+
+        assert_eq!(
+            expander.get::<FheUint8>(0).unwrap().err(),
+            Some(Error::UnexpectedType {
+                expected: Type::FheUint8,
+                got: Type::FheUint32
+            })
+        );
+        assert!(expander.get::<FheUint8>(1).unwrap().is_err());
+
+        // Blocks tagged as booleans are brought back to 0 or 1
+        let mut compact_list = CompactCiphertextList::builder(&public_key)
+            .push(2u8)
+            .push(true)
+            .build();
+        compact_list.info = vec![Type::FheBool; 5];
+        let expander = compact_list.expand();
+        let b: FheBool = expander.get(0).unwrap().unwrap();
+        let f: FheBool = expander.get(4).unwrap().unwrap();
+        assert!((&b & &f).decrypt(&client_key));
+
+        // The tags must not refer to more blocks than the list holds
+        compact_list.info = vec![Type::FheUint16];
+        let expander = compact_list.expand();
+        assert_eq!(
+            expander.get::<FheUint16>(0).unwrap().err(),
+            Some(Error::InvalidCompactList)
+        );
+    }
+
+    #[test]
+    fn test_invalid_heterogeneous_compact_list() {
+        let params = PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+        let config = ConfigBuilder::default()
+            .use_custom_parameters(params, None)
+            .build();
+        let (client_key, _server_key) = generate_keys(config);
+        let public_key = CompactPublicKey::new(&client_key);
+
+        let compact_list = CompactCiphertextList::builder(&public_key)
+            .push(1u8)
+            .push(true)
+            .build();
+
+        let conformance_params = CompactCiphertextListConformanceParams {
+            shortint_params: params.to_shortint_conformance_param(),
+            num_elements_constraint: ListSizeConstraint::exact_size(2),
+        };
+        assert!(compact_list.is_conformant(&conformance_params));
+
+        // The type tags must match the number of encrypted blocks
+        let mut list = compact_list.clone();
+        list.info[1] = Type::FheUint8;
+        assert!(!list.is_conformant(&conformance_params));
+
+        let mut list = compact_list.clone();
+        list.info.pop();
+        assert!(!list.is_conformant(&conformance_params));
+
+        let mut list = compact_list;
+        list.inner.num_blocks_per_integer = 4;
+        assert!(!list.is_conformant(&conformance_params));
+    }
+
+    #[cfg(feature = "zk-pok-experimental")]
+    #[test]
+    fn test_proven_heterogeneous_compact_list() {
+        use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS;
+        use crate::zk::CompactPkeCrs;
+
+        let params = PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS;
+        let config = ConfigBuilder::default()
+            .use_custom_parameters(params, None)
+            .build();
+        let crs = CompactPkeCrs::from_shortint_params(params, 16);
+        let (client_key, server_key) = generate_keys(config);
+        let public_key = CompactPublicKey::new(&client_key);
+
+        let proven_list = CompactCiphertextList::builder(&public_key)
+            .push(-3i16)
+            .push(true)
+            .push(200u8)
+            .build_with_proof(crs.public_params());
+
+        let serialized = bincode::serialize(&proven_list).unwrap();
+        let proven_list: ProvenCompactCiphertextList = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(proven_list.len(), 3);
+        assert_eq!(proven_list.get_kind_of(1), Some(Type::FheBool));
+
+        let conformance_params = CompactCiphertextListConformanceParams {
+            shortint_params: server_key.integer_key.pbs_key().key.conformance_params(),
+            num_elements_constraint: ListSizeConstraint::exact_size(3),
+        };
+        assert!(proven_list.is_conformant(&conformance_params));
+        let mut list = proven_list.clone();
+        list.info[1] = Type::FheUint8;
+        assert!(!list.is_conformant(&conformance_params));
+
+        set_server_key(server_key);
+        assert!(proven_list
+            .verify(crs.public_params(), &public_key)
+            .is_valid());
+        let expander = proven_list
+            .verify_and_expand(crs.public_params(), &public_key)
+            .unwrap();
+
+        let a: FheInt16 = expander.get(0).unwrap().unwrap();
+        let b: FheBool = expander.get(1).unwrap().unwrap();
+        let c: FheUint8 = expander.get(2).unwrap().unwrap();
+        assert!(expander.get::<FheUint8>(1).unwrap().is_err());
+
+        let a: i16 = a.decrypt(&client_key);
+        assert_eq!(a, -3);
+        assert!(b.decrypt(&client_key));
+        let c: u8 = c.decrypt(&client_key);
+        assert_eq!(c, 200);
+
+        // The proof does not hold for another public key
+        let other_public_key = CompactPublicKey::new(&client_key);
+        assert_eq!(
+            proven_list
+                .verify_and_expand(crs.public_params(), &other_public_key)
+                .err(),
+            Some(Error::InvalidProof)
+        );
+    }
+}
@@ -70,6 +70,42 @@ impl ConfigBuilder {
         self
     }
 
+    /// Makes the keys use the CRT representation with the given basis,
+    /// enabling the use of [FheUintCrt](crate::FheUintCrt).
+    ///
+    /// The integers will be represented modulo the product of the basis moduli,
+    /// each of them being stored in its own block, so the moduli must not be greater
+    /// than the message modulus of the parameters.
+    ///
+    /// The keys generated with this configuration can still be used with the radix
+    /// types (e.g. [FheUint8](crate::FheUint8)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the basis is empty, if its moduli are not pairwise coprime,
+    /// or if their product does not fit in a u64.
+    pub fn use_crt_representation(mut self, basis: Vec<u64>) -> Self {
+        self.config.inner.enable_crt_representation(&basis);
+        self
+    }
+
+    /// Sets the distribution of the coefficients of the secret keys.
+    ///
+    /// The block parameters keep their dimensions and noise, only the secret keys change, see
+    /// [SecretKeyDistribution](crate::shortint::SecretKeyDistribution) for the trade-offs of
+    /// non binary keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block parameters use the multi bit PBS and the distribution is not binary.
+    pub fn use_secret_key_distribution(
+        mut self,
+        distribution: crate::shortint::SecretKeyDistribution,
+    ) -> Self {
+        self.config.inner.set_secret_key_distribution(distribution);
+        self
+    }
+
     pub fn build(self) -> Config {
         self.config
     }
@@ -1,3 +1,5 @@
+use crate::core_crypto::commons::parameters::LweDimension;
+use crate::shortint::{CarryModulus, MessageModulus};
 use std::fmt::{Display, Formatter};
 
 /// Unwrap 'Extension' trait
@@ -25,7 +27,7 @@ where
 /// Enum that lists types available
 ///
 /// Mainly used to provide good errors.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Type {
     FheBool,
     FheUint2,
@@ -36,16 +38,81 @@ pub enum Type {
     FheUint12,
     FheUint14,
     FheUint16,
+    FheUint24,
     FheUint32,
+    FheUint48,
     FheUint64,
     FheUint128,
     FheUint256,
+    FheUint512,
     FheInt8,
     FheInt16,
     FheInt32,
     FheInt64,
     FheInt128,
     FheInt256,
+    /// An unsigned integer whose number of bits has no dedicated variant
+    FheUintN(usize),
+    /// A signed integer whose number of bits has no dedicated variant
+    FheIntN(usize),
+}
+
+impl Type {
+    pub(crate) fn unsigned(num_bits: usize) -> Self {
+        match num_bits {
+            2 => Self::FheUint2,
+            3 => Self::FheUint3,
+            4 => Self::FheUint4,
+            8 => Self::FheUint8,
+            10 => Self::FheUint10,
+            12 => Self::FheUint12,
+            14 => Self::FheUint14,
+            16 => Self::FheUint16,
+            24 => Self::FheUint24,
+            32 => Self::FheUint32,
+            48 => Self::FheUint48,
+            64 => Self::FheUint64,
+            128 => Self::FheUint128,
+            256 => Self::FheUint256,
+            512 => Self::FheUint512,
+            _ => Self::FheUintN(num_bits),
+        }
+    }
+
+    pub(crate) fn signed(num_bits: usize) -> Self {
+        match num_bits {
+            8 => Self::FheInt8,
+            16 => Self::FheInt16,
+            32 => Self::FheInt32,
+            64 => Self::FheInt64,
+            128 => Self::FheInt128,
+            256 => Self::FheInt256,
+            _ => Self::FheIntN(num_bits),
+        }
+    }
+
+    /// Number of bits of the type, booleans are stored on a single bit
+    pub(crate) fn num_bits(self) -> usize {
+        match self {
+            Self::FheBool => 1,
+            Self::FheUint2 => 2,
+            Self::FheUint3 => 3,
+            Self::FheUint4 => 4,
+            Self::FheUint8 | Self::FheInt8 => 8,
+            Self::FheUint10 => 10,
+            Self::FheUint12 => 12,
+            Self::FheUint14 => 14,
+            Self::FheUint16 | Self::FheInt16 => 16,
+            Self::FheUint24 => 24,
+            Self::FheUint32 | Self::FheInt32 => 32,
+            Self::FheUint48 => 48,
+            Self::FheUint64 | Self::FheInt64 => 64,
+            Self::FheUint128 | Self::FheInt128 => 128,
+            Self::FheUint256 | Self::FheInt256 => 256,
+            Self::FheUint512 => 512,
+            Self::FheUintN(num_bits) | Self::FheIntN(num_bits) => num_bits,
+        }
+    }
 }
 
 /// The server key was not initialized
@@ -78,11 +145,57 @@ impl Display for OutOfRangeError {
 
 impl std::error::Error for OutOfRangeError {}
 
+/// Error when creating or using a [KeySwitchingKey](crate::KeySwitchingKey)
+///
+/// Mismatches are reported as `(expected, got)`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum KeySwitchingError {
+    MessageModulusMismatch(MessageModulus, MessageModulus),
+    CarryModulusMismatch(CarryModulus, CarryModulus),
+    LweDimensionMismatch(LweDimension, LweDimension),
+}
+
+impl Display for KeySwitchingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MessageModulusMismatch(expected, got) => write!(
+                f,
+                "Message modulus mismatch, expected {}, got {}",
+                expected.0, got.0
+            ),
+            Self::CarryModulusMismatch(expected, got) => write!(
+                f,
+                "Carry modulus mismatch, expected {}, got {}",
+                expected.0, got.0
+            ),
+            Self::LweDimensionMismatch(expected, got) => write!(
+                f,
+                "Lwe dimension mismatch, expected {}, got {}",
+                expected.0, got.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KeySwitchingError {}
+
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
     OutOfRange,
     UninitializedServerKey,
+    CrtRepresentationNotEnabled,
+    UnsupportedCrtOperation {
+        operation: &'static str,
+    },
+    InvalidAsciiString,
+    InvalidCompactList,
+    UnexpectedType {
+        expected: Type,
+        got: Type,
+    },
+    #[cfg(feature = "zk-pok-experimental")]
+    InvalidProof,
 }
 
 impl From<OutOfRangeError> for Error {
@@ -100,6 +213,42 @@ impl Display for Error {
             Self::UninitializedServerKey => {
                 write!(f, "{UninitializedServerKey}")
             }
+            Self::CrtRepresentationNotEnabled => {
+                write!(
+                    f,
+                    "The key was not generated for the CRT representation, \
+                     did you forget to call `use_crt_representation` on the config ?"
+                )
+            }
+            Self::UnsupportedCrtOperation { operation } => {
+                write!(
+                    f,
+                    "The `{operation}` operation is not supported by the CRT representation"
+                )
+            }
+            Self::InvalidAsciiString => {
+                write!(
+                    f,
+                    "The string contains non ASCII or null characters, \
+                     which cannot be encrypted in a FheAsciiString"
+                )
+            }
+            Self::InvalidCompactList => {
+                write!(
+                    f,
+                    "The compact list does not hold enough blocks for the types it is tagged with"
+                )
+            }
+            Self::UnexpectedType { expected, got } => {
+                write!(f, "Expected a value of type {expected:?}, got {got:?}")
+            }
+            #[cfg(feature = "zk-pok-experimental")]
+            Self::InvalidProof => {
+                write!(
+                    f,
+                    "The zero-knowledge proof of the ciphertext list is not valid"
+                )
+            }
         }
     }
 }
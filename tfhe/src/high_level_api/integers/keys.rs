@@ -6,12 +6,33 @@ use crate::core_crypto::prelude::ActivatedRandomGenerator;
 use crate::integer::ciphertext::CompactCiphertextList;
 use crate::integer::public_key::CompactPublicKey;
 use crate::integer::CompressedCompactPublicKey;
-use crate::shortint::EncryptionKeyChoice;
+use crate::shortint::{EncryptionKeyChoice, MessageModulus, SecretKeyDistribution};
+
+/// Maximum number of moduli in a CRT basis
+///
+/// The moduli are pairwise coprime and at least 2, so a basis with more moduli
+/// would have a product that does not fit in a u64.
+const MAX_CRT_BASIS_LEN: usize = 15;
+
+/// The moduli of a CRT basis, stored inline so that the config stays `Copy`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct CrtBasis {
+    moduli: [u64; MAX_CRT_BASIS_LEN],
+    len: usize,
+}
+
+impl CrtBasis {
+    pub(crate) fn moduli(&self) -> &[u64] {
+        &self.moduli[..self.len]
+    }
+}
 
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerConfig {
     pub(crate) block_parameters: crate::shortint::PBSParameters,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    #[serde(default)]
+    pub(crate) crt_basis: Option<CrtBasis>,
 }
 
 impl IntegerConfig {
@@ -22,6 +43,7 @@ impl IntegerConfig {
         Self {
             block_parameters,
             wopbs_block_parameters,
+            crt_basis: None,
         }
     }
 
@@ -29,6 +51,7 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
             wopbs_block_parameters: None,
+            crt_basis: None,
         }
     }
 
@@ -36,6 +59,7 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS.into(),
             wopbs_block_parameters: None,
+            crt_basis: None,
         }
     }
 
@@ -47,12 +71,62 @@ impl IntegerConfig {
 
         self.wopbs_block_parameters = Some(wopbs_block_parameters);
     }
+
+    pub fn set_secret_key_distribution(&mut self, distribution: SecretKeyDistribution) {
+        match &mut self.block_parameters {
+            crate::shortint::PBSParameters::PBS(classic) => {
+                classic.secret_key_distribution = distribution;
+            }
+            crate::shortint::PBSParameters::MultiBitPBS(_) => {
+                assert!(
+                    distribution == SecretKeyDistribution::Binary,
+                    "Multi bit PBS parameters only support binary secret keys"
+                );
+            }
+        }
+    }
+
+    pub fn enable_crt_representation(&mut self, basis: &[u64]) {
+        assert!(!basis.is_empty(), "The CRT basis must not be empty");
+        for (i, &modulus) in basis.iter().enumerate() {
+            assert!(modulus >= 2, "The CRT basis moduli must be at least 2");
+            for &other in &basis[i + 1..] {
+                assert!(
+                    gcd(modulus, other) == 1,
+                    "The CRT basis moduli must be pairwise coprime, {modulus} and {other} are not"
+                );
+            }
+        }
+        assert!(
+            basis
+                .iter()
+                .try_fold(1u64, |acc, &modulus| acc.checked_mul(modulus))
+                .is_some(),
+            "The product of the CRT basis moduli must fit in a u64"
+        );
+
+        let mut moduli = [0; MAX_CRT_BASIS_LEN];
+        moduli[..basis.len()].copy_from_slice(basis);
+        self.crt_basis = Some(CrtBasis {
+            moduli,
+            len: basis.len(),
+        });
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerClientKey {
     pub(crate) key: crate::integer::ClientKey,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    #[serde(default)]
+    pub(crate) crt_basis: Option<CrtBasis>,
 }
 
 impl IntegerClientKey {
@@ -64,6 +138,7 @@ impl IntegerClientKey {
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            crt_basis: config.crt_basis,
         }
     }
 
@@ -79,6 +154,7 @@ impl From<IntegerConfig> for IntegerClientKey {
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            crt_basis: config.crt_basis,
         }
     }
 }
@@ -92,11 +168,12 @@ pub struct IntegerServerKey {
 impl IntegerServerKey {
     pub(in crate::high_level_api) fn new(client_key: &IntegerClientKey) -> Self {
         let cks = &client_key.key;
-        assert_eq!(
-            cks.parameters().message_modulus().0,
-            4,
-            "This API only supports integers with 2 bits per block (MessageModulus(4))",
-        );
+        if let Some(basis) = &client_key.crt_basis {
+            check_crt_basis_fits_parameters(basis.moduli(), cks.parameters().message_modulus());
+        }
+        // The radix key is also used for the CRT representation: its max degree is only
+        // more conservative than the one of a CRT key, so both kinds of integers
+        // can be used with the same key
         let base_integer_key = crate::integer::ServerKey::new_radix_server_key(cks);
         let wopbs_key = client_key
             .wopbs_block_parameters
@@ -115,6 +192,17 @@ impl IntegerServerKey {
     }
 }
 
+fn check_crt_basis_fits_parameters(basis: &[u64], message_modulus: MessageModulus) {
+    for &modulus in basis {
+        assert!(
+            modulus <= message_modulus.0 as u64,
+            "The CRT basis modulus {modulus} does not fit in a block, \
+             the parameters only support moduli up to {}",
+            message_modulus.0
+        );
+    }
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct IntegerCompressedServerKey {
     pub(crate) key: crate::integer::CompressedServerKey,
@@ -132,6 +220,12 @@ impl IntegerCompressedServerKey {
                    to create a CompressedServerKey.
                    "
         );
+        if let Some(basis) = &client_key.crt_basis {
+            check_crt_basis_fits_parameters(
+                basis.moduli(),
+                integer_key.parameters().message_modulus(),
+            );
+        }
         let key = crate::integer::CompressedServerKey::new_radix_compressed_server_key(integer_key);
         Self { key }
     }
@@ -162,6 +256,10 @@ impl IntegerCompactPublicKey {
         Some(Self { key })
     }
 
+    pub(in crate::high_level_api) fn message_modulus(&self) -> MessageModulus {
+        self.key.key.parameters.message_modulus()
+    }
+
     pub(in crate::high_level_api::integers) fn try_encrypt_compact<T>(
         &self,
         values: &[T],
@@ -1,16 +1,20 @@
 expand_pub_use_fhe_type!(
     pub use types{
-        FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128,
-        FheUint256, FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256
+        FheUint, FheInt, FheUint2, FheUint4, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16,
+        FheUint24, FheUint32, FheUint48, FheUint64, FheUint128, FheUint256, FheUint512, FheInt8,
+        FheInt16, FheInt32, FheInt64, FheInt128, FheInt256
     };
 );
 
+pub use types::FheUintCrt;
+
 pub(in crate::high_level_api) use keys::{
     IntegerClientKey, IntegerCompactPublicKey, IntegerCompressedCompactPublicKey,
     IntegerCompressedServerKey, IntegerConfig, IntegerServerKey,
 };
 
-pub(in crate::high_level_api) use parameters::IntegerId;
+pub(in crate::high_level_api) use parameters::{num_blocks_for_bits, IntegerId};
+pub use types::static_::{FheIntId, FheUintId};
 pub(in crate::high_level_api) use types::GenericInteger;
 
 mod client_key;
@@ -18,6 +22,8 @@ mod keys;
 mod parameters;
 mod server_key;
 #[cfg(test)]
+mod tests_crt;
+#[cfg(test)]
 mod tests_signed;
 #[cfg(test)]
 mod tests_unsigned;
@@ -26,11 +32,18 @@ mod types;
 #[cfg(feature = "safe-deserialization")]
 pub mod safe_serialize {
     use super::parameters::IntegerId;
-    use super::types::compact::GenericCompactInteger;
+    use super::types::compact::{GenericCompactInteger, GenericCompactIntegerList};
     use super::types::compressed::CompressedGenericInteger;
     use super::types::GenericInteger;
-    use crate::conformance::ParameterSetConformant;
-    use crate::integer::parameters::RadixCiphertextConformanceParams;
+    use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+    #[cfg(feature = "zk-pok-experimental")]
+    use crate::high_level_api::compact_list::ProvenCompactCiphertextList;
+    use crate::high_level_api::compact_list::{
+        CompactCiphertextList, CompactCiphertextListConformanceParams,
+    };
+    use crate::integer::parameters::{
+        RadixCiphertextConformanceParams, RadixCompactCiphertextListConformanceParams,
+    };
     use crate::named::Named;
     use crate::shortint::parameters::CiphertextConformanceParams;
     use crate::ServerKey;
@@ -79,7 +92,7 @@ pub mod safe_serialize {
     {
         let parameter_set = RadixCiphertextConformanceParams {
             shortint_params: sk.integer_key.pbs_key().key.conformance_params(),
-            num_blocks_per_integer: Id::num_blocks(),
+            num_blocks_per_integer: Id::num_blocks(sk.integer_key.pbs_key().message_modulus()),
         };
 
         crate::safe_deserialization::safe_deserialize_conformant(
@@ -101,7 +114,7 @@ pub mod safe_serialize {
     {
         let parameter_set = RadixCiphertextConformanceParams {
             shortint_params: sk.integer_key.pbs_key().key.conformance_params(),
-            num_blocks_per_integer: Id::num_blocks(),
+            num_blocks_per_integer: Id::num_blocks(sk.integer_key.pbs_key().message_modulus()),
         };
 
         crate::safe_deserialization::safe_deserialize_conformant(
@@ -121,7 +134,77 @@ pub mod safe_serialize {
     {
         let parameter_set = RadixCiphertextConformanceParams {
             shortint_params: sk.integer_key.pbs_key().key.conformance_params(),
-            num_blocks_per_integer: Id::num_blocks(),
+            num_blocks_per_integer: Id::num_blocks(sk.integer_key.pbs_key().message_modulus()),
+        };
+
+        crate::safe_deserialization::safe_deserialize_conformant(
+            reader,
+            serialized_size_limit,
+            &parameter_set,
+        )
+    }
+
+    /// Deserializes a compact list of a single integer type and checks its conformance
+    ///
+    /// The list (e.g. [CompactFheUint32List](crate::CompactFheUint32List)) must be conformant
+    /// with the server key and hold a number of integers satisfying `num_elements_constraint`
+    pub fn safe_deserialize_conformant_compact_integer_list<Id>(
+        reader: impl std::io::Read,
+        serialized_size_limit: u64,
+        sk: &ServerKey,
+        num_elements_constraint: ListSizeConstraint,
+    ) -> Result<GenericCompactIntegerList<Id>, String>
+    where
+        Id: IntegerId + DeserializeOwned,
+    {
+        let parameter_set = RadixCompactCiphertextListConformanceParams {
+            shortint_params: sk.integer_key.pbs_key().key.conformance_params(),
+            num_blocks_per_integer: Id::num_blocks(sk.integer_key.pbs_key().message_modulus()),
+            num_integers_constraint: num_elements_constraint,
+        };
+
+        crate::safe_deserialization::safe_deserialize_conformant(
+            reader,
+            serialized_size_limit,
+            &parameter_set,
+        )
+    }
+
+    /// Deserializes a [CompactCiphertextList] and checks that it is conformant
+    /// with the server key and holds a number of values satisfying `num_elements_constraint`
+    pub fn safe_deserialize_conformant_compact_list(
+        reader: impl std::io::Read,
+        serialized_size_limit: u64,
+        sk: &ServerKey,
+        num_elements_constraint: ListSizeConstraint,
+    ) -> Result<CompactCiphertextList, String> {
+        let parameter_set = CompactCiphertextListConformanceParams {
+            shortint_params: sk.integer_key.pbs_key().key.conformance_params(),
+            num_elements_constraint,
+        };
+
+        crate::safe_deserialization::safe_deserialize_conformant(
+            reader,
+            serialized_size_limit,
+            &parameter_set,
+        )
+    }
+
+    /// Deserializes a [ProvenCompactCiphertextList] and checks that it is conformant
+    /// with the server key and holds a number of values satisfying `num_elements_constraint`
+    ///
+    /// The proof itself is not checked, this is done when calling
+    /// [verify_and_expand](ProvenCompactCiphertextList::verify_and_expand).
+    #[cfg(feature = "zk-pok-experimental")]
+    pub fn safe_deserialize_conformant_proven_compact_list(
+        reader: impl std::io::Read,
+        serialized_size_limit: u64,
+        sk: &ServerKey,
+        num_elements_constraint: ListSizeConstraint,
+    ) -> Result<ProvenCompactCiphertextList, String> {
+        let parameter_set = CompactCiphertextListConformanceParams {
+            shortint_params: sk.integer_key.pbs_key().key.conformance_params(),
+            num_elements_constraint,
         };
 
         crate::safe_deserialization::safe_deserialize_conformant(
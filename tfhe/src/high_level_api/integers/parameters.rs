@@ -1,3 +1,5 @@
+use crate::shortint::MessageModulus;
+
 /// Meant to be implemented on the inner server key
 /// eg the crate::integer::ServerKey
 pub trait EvaluationIntegerKey<ClientKey> {
@@ -12,8 +14,31 @@ pub trait EvaluationIntegerKey<ClientKey> {
 
 /// Trait to mark Id type for integers
 pub trait IntegerId: Copy + Default {
-    type InnerCiphertext: crate::integer::ciphertext::IntegerRadixCiphertext;
+    type InnerCiphertext: crate::integer::ciphertext::IntegerRadixCiphertext + 'static;
     type InnerCompressedCiphertext;
 
-    fn num_blocks() -> usize;
+    /// Number of bits of the integer type
+    fn num_bits() -> usize;
+
+    /// Number of radix blocks needed to represent the integer type,
+    /// when each block stores a message of the given modulus.
+    ///
+    /// See [num_blocks_for_bits].
+    fn num_blocks(message_modulus: MessageModulus) -> usize {
+        num_blocks_for_bits(Self::num_bits(), message_modulus)
+    }
+}
+
+/// Number of radix blocks needed to store `num_bits` bits,
+/// when each block stores a message of the given modulus.
+///
+/// If the number of bits is not a multiple of the number of message bits of a block,
+/// the number of blocks is rounded up, the bits of the last block past `num_bits`
+/// are dropped when decrypting.
+pub(in crate::high_level_api) fn num_blocks_for_bits(
+    num_bits: usize,
+    message_modulus: MessageModulus,
+) -> usize {
+    let bits_per_block = message_modulus.0.ilog2() as usize;
+    (num_bits + bits_per_block - 1) / bits_per_block
 }
@@ -0,0 +1,135 @@
+use rand::Rng;
+
+use crate::high_level_api::errors::Error;
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheUint8, FheUintCrt};
+use crate::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+
+#[test]
+fn test_crt_operations() {
+    let basis = vec![5u64, 7, 8];
+    let modulus: u64 = basis.iter().product();
+
+    let config = ConfigBuilder::default()
+        .use_custom_parameters(PARAM_MESSAGE_3_CARRY_3_KS_PBS, None)
+        .use_crt_representation(basis)
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen_range(0..modulus);
+    let clear_b = rng.gen_range(0..modulus);
+    let scalar = rng.gen::<u64>();
+
+    let a = FheUintCrt::encrypt(clear_a, &client_key);
+    let b = FheUintCrt::encrypt(clear_b, &client_key);
+    assert_eq!(a.modulus(), modulus);
+
+    let c = &a + &b;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + clear_b) % modulus);
+
+    let c = &a - &b;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + modulus - clear_b) % modulus);
+
+    let c = &a * &b;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a * clear_b) % modulus);
+
+    let c = -&a;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (modulus - clear_a) % modulus);
+
+    let c = &a + scalar;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + scalar % modulus) % modulus);
+
+    let c = &a - scalar;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + modulus - scalar % modulus) % modulus);
+
+    let c = &a * scalar;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a * (scalar % modulus)) % modulus);
+
+    // Evaluate a * a * a + 3 * a * b + b, with dirty intermediate results
+    let mut c = &a * &a;
+    c *= &a;
+    c += &a * &b * 3u64;
+    c += &b;
+    let decrypted: u64 = c.decrypt(&client_key);
+    let expected =
+        (clear_a * clear_a % modulus * clear_a + 3 * (clear_a * clear_b % modulus) + clear_b)
+            % modulus;
+    assert_eq!(decrypted, expected);
+}
+
+#[test]
+fn test_crt_encrypt_errors() {
+    let config = ConfigBuilder::default()
+        .use_custom_parameters(PARAM_MESSAGE_3_CARRY_3_KS_PBS, None)
+        .use_crt_representation(vec![5, 7, 8])
+        .build();
+    let (client_key, _) = generate_keys(config);
+
+    let result = FheUintCrt::try_encrypt(280u64, &client_key);
+    assert!(matches!(result, Err(Error::OutOfRange)));
+
+    let config = ConfigBuilder::default().build();
+    let (client_key, _) = generate_keys(config);
+
+    let result = FheUintCrt::try_encrypt(1u64, &client_key);
+    assert!(matches!(result, Err(Error::CrtRepresentationNotEnabled)));
+}
+
+#[test]
+fn test_crt_unsupported_operations() {
+    let config = ConfigBuilder::default()
+        .use_custom_parameters(PARAM_MESSAGE_3_CARRY_3_KS_PBS, None)
+        .use_crt_representation(vec![5, 7, 8])
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let a = FheUintCrt::encrypt(27u64, &client_key);
+    let b = FheUintCrt::encrypt(13u64, &client_key);
+
+    assert!(matches!(
+        a.lt(&b),
+        Err(Error::UnsupportedCrtOperation { operation: "lt" })
+    ));
+    assert!(matches!(
+        &a & &b,
+        Err(Error::UnsupportedCrtOperation {
+            operation: "bitand"
+        })
+    ));
+    assert!(matches!(
+        &a / &b,
+        Err(Error::UnsupportedCrtOperation { operation: "div" })
+    ));
+
+    // The keys can also be used with the radix types
+    let c = FheUint8::encrypt(200u8, &client_key);
+    let d = FheUint8::encrypt(100u8, &client_key);
+    let decrypted: u8 = (&c + &d).decrypt(&client_key);
+    assert_eq!(decrypted, 200u8.wrapping_add(100));
+}
+
+#[test]
+fn test_crt_config_deserialization_without_basis() {
+    // Configs serialized before the CRT representation was added have no basis
+    let config = ConfigBuilder::default().build();
+    let mut value: serde_json::Value = serde_json::to_value(config).unwrap();
+    value["inner"].as_object_mut().unwrap().remove("crt_basis");
+    let config: crate::Config = serde_json::from_value(value).unwrap();
+    assert!(config.inner.crt_basis.is_none());
+}
+
+#[test]
+#[should_panic(expected = "pairwise coprime")]
+fn test_crt_basis_not_coprime() {
+    let _ = ConfigBuilder::default().use_crt_representation(vec![4, 6]);
+}
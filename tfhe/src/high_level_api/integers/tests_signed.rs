@@ -620,3 +620,99 @@ fn test_compact_public_key_list(config: Config) {
     let decrypted: i32 = a.decrypt(&client_key);
     assert_eq!(clear_xs[0], decrypted);
 }
+
+#[test]
+fn test_int16_leading_trailing_zeros_ilog2() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = thread_rng();
+    for clear_a in [rng.gen::<i16>(), rng.gen_range(1..=i16::MAX), -1i16] {
+        let a = FheInt16::encrypt(clear_a, &client_key);
+
+        let leading_zeros: u32 = a.leading_zeros().decrypt(&client_key);
+        assert_eq!(leading_zeros, clear_a.leading_zeros());
+
+        let trailing_zeros: u32 = a.trailing_zeros().decrypt(&client_key);
+        assert_eq!(trailing_zeros, clear_a.trailing_zeros());
+
+        let count_ones: u32 = a.count_ones().decrypt(&client_key);
+        assert_eq!(count_ones, clear_a.count_ones());
+
+        let (ilog2, is_ok) = a.checked_ilog2();
+        let is_ok = is_ok.decrypt(&client_key);
+        assert_eq!(is_ok, clear_a > 0);
+        if clear_a > 0 {
+            let ilog2: u32 = ilog2.decrypt(&client_key);
+            assert_eq!(ilog2, clear_a.ilog2());
+        }
+    }
+}
+
+#[test]
+fn test_int8_overflowing_add_sub() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = thread_rng();
+    for (clear_a, clear_b) in [
+        (rng.gen::<i8>(), rng.gen::<i8>()),
+        (-3i8, 40i8),
+        (i8::MAX, 1i8),
+        (i8::MIN, 1i8),
+    ] {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let (result, overflowed) = (&a).overflowing_add(&b);
+        let (expected_result, expected_overflowed) = clear_a.overflowing_add(clear_b);
+        let decrypted_result: i8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, expected_result);
+        assert_eq!(overflowed.decrypt(&client_key), expected_overflowed);
+
+        let (result, overflowed) = (&a).overflowing_sub(&b);
+        let (expected_result, expected_overflowed) = clear_a.overflowing_sub(clear_b);
+        let decrypted_result: i8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, expected_result);
+        assert_eq!(overflowed.decrypt(&client_key), expected_overflowed);
+    }
+}
+
+#[test]
+fn test_int8_overflowing_mul() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = thread_rng();
+    for (clear_a, clear_b) in [
+        (rng.gen::<i8>(), rng.gen::<i8>()),
+        (-3i8, 40i8),
+        (i8::MIN, -1i8),
+    ] {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let (result, overflowed) = (&a).overflowing_mul(&b);
+        let (expected_result, expected_overflowed) = clear_a.overflowing_mul(clear_b);
+        let decrypted_result: i8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, expected_result);
+        assert_eq!(overflowed.decrypt(&client_key), expected_overflowed);
+
+        let (result, is_ok) = a.checked_mul(&b);
+        let decrypted_result: i8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, expected_result);
+        assert_eq!(
+            is_ok.decrypt(&client_key),
+            clear_a.checked_mul(clear_b).is_some()
+        );
+    }
+}
@@ -2,11 +2,12 @@ use rand::Rng;
 
 use crate::high_level_api::prelude::*;
 use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
-use crate::integer::U256;
+use crate::integer::{U256, U512};
 use crate::{
-    CompactFheUint32, CompactFheUint32List, CompactPublicKey, CompressedFheUint16,
-    CompressedFheUint256, CompressedPublicKey, Config, FheInt16, FheInt32, FheInt8, FheUint128,
-    FheUint16, FheUint256, FheUint32, FheUint64,
+    CompactFheUint32, CompactFheUint32List, CompactFheUint4List, CompactPublicKey,
+    CompressedFheUint16, CompressedFheUint256, CompressedFheUint4, CompressedPublicKey, Config,
+    FheInt16, FheInt32, FheInt8, FheUint128, FheUint16, FheUint2, FheUint24, FheUint256, FheUint32,
+    FheUint4, FheUint48, FheUint512, FheUint64,
 };
 
 #[test]
@@ -809,3 +810,511 @@ fn test_if_then_else() {
         if clear_a <= clear_b { clear_b } else { clear_a }
     );
 }
+
+#[test]
+fn test_leading_trailing_zeros_ilog2() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..5 {
+        let clear_a = rng.gen::<u8>();
+        let a = FheUint8::encrypt(clear_a, &client_key);
+
+        let leading_zeros: u32 = a.leading_zeros().decrypt(&client_key);
+        assert_eq!(leading_zeros, clear_a.leading_zeros());
+
+        let trailing_zeros: u32 = a.trailing_zeros().decrypt(&client_key);
+        assert_eq!(trailing_zeros, clear_a.trailing_zeros());
+
+        let count_ones: u32 = a.count_ones().decrypt(&client_key);
+        assert_eq!(count_ones, clear_a.count_ones());
+
+        let (ilog2, is_ok) = a.checked_ilog2();
+        let is_ok = is_ok.decrypt(&client_key);
+        assert_eq!(is_ok, clear_a > 0);
+        if clear_a > 0 {
+            let ilog2: u32 = ilog2.decrypt(&client_key);
+            assert_eq!(ilog2, clear_a.ilog2());
+        }
+    }
+}
+
+#[test]
+fn test_overflowing_add_sub() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    for (clear_a, clear_b) in [
+        (rng.gen::<u8>(), rng.gen::<u8>()),
+        (3u8, 50u8),
+        (250u8, 7u8),
+    ] {
+        let a = FheUint8::encrypt(clear_a, &client_key);
+        let b = FheUint8::encrypt(clear_b, &client_key);
+
+        let (result, overflowed) = (&a).overflowing_add(&b);
+        let (expected_result, expected_overflowed) = clear_a.overflowing_add(clear_b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, expected_result);
+        assert_eq!(overflowed.decrypt(&client_key), expected_overflowed);
+
+        let (result, overflowed) = (&a).overflowing_sub(&b);
+        let (expected_result, expected_overflowed) = clear_a.overflowing_sub(clear_b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, expected_result);
+        assert_eq!(overflowed.decrypt(&client_key), expected_overflowed);
+    }
+}
+
+#[test]
+fn test_overflowing_mul() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    for (clear_a, clear_b) in [
+        (rng.gen::<u8>(), rng.gen::<u8>()),
+        (3u8, 50u8),
+        (129u8, 2u8),
+    ] {
+        let a = FheUint8::encrypt(clear_a, &client_key);
+        let b = FheUint8::encrypt(clear_b, &client_key);
+
+        let (result, overflowed) = (&a).overflowing_mul(&b);
+        let (expected_result, expected_overflowed) = clear_a.overflowing_mul(clear_b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, expected_result);
+        assert_eq!(overflowed.decrypt(&client_key), expected_overflowed);
+
+        let (result, is_ok) = a.checked_mul(&b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, expected_result);
+        assert_eq!(
+            is_ok.decrypt(&client_key),
+            clear_a.checked_mul(clear_b).is_some()
+        );
+    }
+}
+
+#[test]
+fn test_keyswitch() {
+    let config_a = ConfigBuilder::default()
+        .use_custom_parameters(
+            crate::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+            None,
+        )
+        .build();
+    let (client_key_a, server_key_a) = generate_keys(config_a);
+
+    let config_b = ConfigBuilder::default().build();
+    let (client_key_b, server_key_b) = generate_keys(config_b);
+
+    let ksk = crate::KeySwitchingKey::new(
+        (&client_key_a, &server_key_a),
+        (&client_key_b, &server_key_b),
+    );
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<u16>();
+    let clear_b = rng.gen::<u16>();
+
+    let a = FheUint16::encrypt(clear_a, &client_key_a);
+    let b = FheUint16::encrypt(clear_b, &client_key_b);
+
+    let a = a.keyswitch(&ksk);
+    let decrypted: u16 = a.decrypt(&client_key_b);
+    assert_eq!(decrypted, clear_a);
+
+    set_server_key(server_key_b);
+    let c = &a * &b;
+    let decrypted: u16 = c.decrypt(&client_key_b);
+    assert_eq!(decrypted, clear_a.wrapping_mul(clear_b));
+
+    // Keys with different moduli cannot be used together
+    let config_c = ConfigBuilder::default()
+        .use_custom_parameters(
+            crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_3_KS_PBS,
+            None,
+        )
+        .build();
+    let (client_key_c, server_key_c) = generate_keys(config_c);
+
+    // Ciphertexts not encrypted under the source keys are rejected
+    let d = FheUint16::encrypt(clear_a, &client_key_c);
+    assert!(matches!(
+        d.try_keyswitch(&ksk),
+        Err(crate::KeySwitchingError::CarryModulusMismatch(_, _))
+    ));
+
+    let error = crate::KeySwitchingKey::try_new(
+        (&client_key_c, &server_key_c),
+        (&client_key_a, &server_key_a),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        crate::KeySwitchingError::CarryModulusMismatch(_, _)
+    ));
+}
+
+#[test]
+fn test_sqrt_pow() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    for clear_a in [rng.gen::<u8>(), 0u8, 1u8, u8::MAX] {
+        let a = FheUint8::encrypt(clear_a, &client_key);
+
+        let result = a.sqrt();
+        let decrypted: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted, f64::from(clear_a).sqrt() as u8);
+    }
+
+    let clear_a = rng.gen::<u8>();
+    let clear_exponent = rng.gen_range(0u8..16);
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let exponent = FheUint8::encrypt(clear_exponent, &client_key);
+
+    let result = (&a).pow(u32::from(clear_exponent));
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_pow(u32::from(clear_exponent)));
+
+    let result = (&a).pow(&exponent);
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_pow(u32::from(clear_exponent)));
+}
+
+#[test]
+fn test_sort_argmax_top_k() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clears = (0..5).map(|_| rng.gen::<u32>()).collect::<Vec<_>>();
+    let values = clears
+        .iter()
+        .map(|&clear| FheUint32::encrypt(clear, &client_key))
+        .collect::<Vec<_>>();
+
+    let result = FheUint32::argmax(&values);
+    let decrypted: u32 = result.decrypt(&client_key);
+    let max = clears.iter().max().unwrap();
+    assert_eq!(clears[decrypted as usize], *max);
+
+    let result = FheUint32::argmin(&values);
+    let decrypted: u32 = result.decrypt(&client_key);
+    let min = clears.iter().min().unwrap();
+    assert_eq!(clears[decrypted as usize], *min);
+
+    let top = FheUint32::top_k(&values, 2);
+    let decrypted = top
+        .iter()
+        .map(|v| v.decrypt(&client_key))
+        .collect::<Vec<u32>>();
+    let mut expected = clears.clone();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(decrypted, expected[..2]);
+
+    let mut values = values;
+    FheUint32::sort(&mut values);
+    let decrypted = values
+        .iter()
+        .map(|v| v.decrypt(&client_key))
+        .collect::<Vec<u32>>();
+    expected.reverse();
+    assert_eq!(decrypted, expected);
+}
+
+#[test]
+fn test_small_uint2_uint4() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen_range(0u8..4);
+    let clear_b = rng.gen_range(0u8..4);
+
+    let a = FheUint2::encrypt(clear_a, &client_key);
+    let b = FheUint2::encrypt(clear_b, &client_key);
+
+    let c = &a + &b;
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + clear_b) % 4);
+
+    let c = &a * 3u8;
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a * 3) % 4);
+
+    let c = a.lt(&b);
+    assert_eq!(c.decrypt(&client_key), clear_a < clear_b);
+
+    let clear_a = rng.gen_range(0u8..16);
+    let clear_b = rng.gen_range(0u8..16);
+
+    let a = FheUint4::encrypt(clear_a, &client_key);
+    let b = FheUint4::encrypt(clear_b, &client_key);
+
+    let c = &a - &b;
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_sub(clear_b) % 16);
+
+    let c = &a ^ &b;
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a ^ clear_b);
+
+    // Casting from and to the small types
+    let a: FheUint8 = a.cast_into();
+    let decrypted: u8 = a.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a);
+
+    let a: FheUint2 = a.cast_into();
+    let decrypted: u8 = a.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a % 4);
+
+    let compressed = CompressedFheUint4::encrypt(clear_b, &client_key);
+    let decompressed = FheUint4::from(compressed);
+    let decrypted: u8 = decompressed.decrypt(&client_key);
+    assert_eq!(decrypted, clear_b);
+}
+
+#[test]
+fn test_non_power_of_two_uints() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let modulus_24 = 1u32 << 24;
+    let clear_a = rng.gen::<u32>() % modulus_24;
+    let clear_b = rng.gen::<u32>() % modulus_24;
+
+    let a = FheUint24::encrypt(clear_a, &client_key);
+    let b = FheUint24::encrypt(clear_b, &client_key);
+
+    let c = &a + &b;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + clear_b) % modulus_24);
+
+    let c = a.max(&b);
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.max(clear_b));
+
+    let modulus_48 = 1u64 << 48;
+    let clear_a = rng.gen::<u64>() % modulus_48;
+    let clear_b = rng.gen::<u64>() % modulus_48;
+
+    let a = FheUint48::encrypt(clear_a, &client_key);
+
+    let c = &a + clear_b;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + clear_b) % modulus_48);
+
+    let a: FheUint24 = a.cast_into();
+    let decrypted: u32 = a.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a % modulus_24 as u64) as u32);
+}
+
+#[test]
+fn test_custom_width_uint() {
+    use crate::FheUint;
+
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let modulus_20 = 1u32 << 20;
+    let clear_a = rng.gen::<u32>() % modulus_20;
+    let clear_b = rng.gen::<u32>() % modulus_20;
+
+    let a = FheUint::<20>::encrypt(clear_a, &client_key);
+    let b = FheUint::<20>::encrypt(clear_b, &client_key);
+
+    let c = &a * &b;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_mul(clear_b) % modulus_20);
+
+    // Built-in widths are aliases of the generic type
+    let a: FheUint<8> = a.cast_into();
+    let a: FheUint8 = a;
+    let decrypted: u8 = a.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a as u8);
+}
+
+#[test]
+fn test_width_not_multiple_of_block_size() {
+    use crate::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+
+    let config = ConfigBuilder::default()
+        .use_custom_parameters(PARAM_MESSAGE_3_CARRY_3_KS_PBS, None)
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    // 8 bits are stored in 3 blocks of 3 bits, the extra bit is dropped on decryption
+    let a = FheUint8::encrypt(200u8, &client_key);
+    let b = FheUint8::encrypt(100u8, &client_key);
+    let c = &a + &b;
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, 200u8.wrapping_add(100));
+
+    let a = FheUint24::try_encrypt(1234u32, &client_key).unwrap();
+    let decrypted: u32 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 1234);
+}
+
+#[test]
+fn test_uint512() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = U512::from((rng.gen::<u128>(), rng.gen(), rng.gen(), rng.gen()));
+    let clear_b = U512::from((rng.gen::<u128>(), rng.gen(), rng.gen(), rng.gen()));
+
+    let a = FheUint512::encrypt(clear_a, &client_key);
+    let b = FheUint512::encrypt(clear_b, &client_key);
+
+    let c = &a + &b;
+    let decrypted: U512 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a + clear_b);
+
+    let c = a.eq(&b);
+    assert_eq!(c.decrypt(&client_key), clear_a == clear_b);
+}
+
+#[test]
+fn test_compact_public_key_list_uint4() {
+    let config = ConfigBuilder::default()
+        .use_custom_parameters(
+            crate::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+            None,
+        )
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    let public_key = CompactPublicKey::new(&client_key);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_xs = (0..10).map(|_| rng.gen_range(0u8..16)).collect::<Vec<_>>();
+
+    let compacted_xs = CompactFheUint4List::encrypt(&clear_xs, &public_key);
+    assert_eq!(compacted_xs.len(), clear_xs.len());
+
+    let xs = compacted_xs.expand();
+    for (x, clear_x) in xs.iter().zip(clear_xs.iter().copied()) {
+        let decrypted: u8 = x.decrypt(&client_key);
+        assert_eq!(decrypted, clear_x);
+    }
+}
+
+#[cfg(feature = "safe-deserialization")]
+#[test]
+fn test_safe_serialize_uint4() {
+    use crate::high_level_api::{safe_deserialize_conformant_integer, safe_serialize};
+
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let clear = 13u8;
+    let ct = FheUint4::encrypt(clear, &client_key);
+
+    let mut buffer = vec![];
+    safe_serialize(&ct, &mut buffer, 1 << 20).unwrap();
+
+    let ct: FheUint4 =
+        safe_deserialize_conformant_integer(buffer.as_slice(), 1 << 20, &server_key).unwrap();
+    let decrypted: u8 = ct.decrypt(&client_key);
+    assert_eq!(decrypted, clear);
+
+    // A value with a different number of blocks is not conformant
+    let ct = FheUint8::encrypt(clear, &client_key);
+    let mut buffer = vec![];
+    safe_serialize(&ct, &mut buffer, 1 << 20).unwrap();
+    let result: Result<FheUint4, _> =
+        safe_deserialize_conformant_integer(buffer.as_slice(), 1 << 20, &server_key);
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "safe-deserialization")]
+#[test]
+fn test_safe_serialize_compact_list() {
+    use crate::conformance::ListSizeConstraint;
+    use crate::high_level_api::{safe_deserialize_conformant_compact_integer_list, safe_serialize};
+    use crate::{CompactFheUint16List, CompactFheUint8List};
+
+    let config = ConfigBuilder::default()
+        .use_custom_parameters(
+            crate::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+            None,
+        )
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    let public_key = CompactPublicKey::new(&client_key);
+
+    let clear_xs = [1u8, 2u8, 3u8];
+    let compacted_xs = CompactFheUint8List::encrypt(&clear_xs, &public_key);
+
+    let mut buffer = vec![];
+    safe_serialize(&compacted_xs, &mut buffer, 1 << 20).unwrap();
+
+    let compacted_xs: CompactFheUint8List = safe_deserialize_conformant_compact_integer_list(
+        buffer.as_slice(),
+        1 << 20,
+        &server_key,
+        ListSizeConstraint::exact_size(3),
+    )
+    .unwrap();
+    let xs = compacted_xs.expand();
+    for (x, clear_x) in xs.iter().zip(clear_xs.iter().copied()) {
+        let decrypted: u8 = x.decrypt(&client_key);
+        assert_eq!(decrypted, clear_x);
+    }
+
+    // The number of elements does not match the constraint
+    let result: Result<CompactFheUint8List, _> = safe_deserialize_conformant_compact_integer_list(
+        buffer.as_slice(),
+        1 << 20,
+        &server_key,
+        ListSizeConstraint::exact_size(2),
+    );
+    assert!(result.is_err());
+
+    // The elements are not of the expected type
+    let result: Result<CompactFheUint16List, _> = safe_deserialize_conformant_compact_integer_list(
+        buffer.as_slice(),
+        1 << 20,
+        &server_key,
+        ListSizeConstraint::exact_size(3),
+    );
+    assert!(result.is_err());
+}
@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::borrow::Borrow;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
@@ -6,21 +7,22 @@ use std::ops::{
 
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::CastFrom;
+use crate::high_level_api::errors::{KeySwitchingError, UnwrapResultExt};
 use crate::high_level_api::global_state::WithGlobalKey;
 use crate::high_level_api::integers::parameters::IntegerId;
 use crate::high_level_api::integers::IntegerServerKey;
 use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey};
-use crate::high_level_api::keys::CompressedPublicKey;
+use crate::high_level_api::keys::{CompressedPublicKey, KeySwitchingKey};
 use crate::high_level_api::traits::{
     DivRem, FheBootstrap, FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTrivialEncrypt,
-    FheTryEncrypt, FheTryTrivialEncrypt, RotateLeft, RotateLeftAssign, RotateRight,
-    RotateRightAssign,
+    FheTryEncrypt, FheTryTrivialEncrypt, OverflowingMul, Pow, RotateLeft, RotateLeftAssign,
+    RotateRight, RotateRightAssign,
 };
 use crate::high_level_api::{ClientKey, PublicKey};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::{IntegerRadixCiphertext, RadixCiphertext};
 use crate::integer::parameters::RadixCiphertextConformanceParams;
-use crate::integer::{IntegerCiphertext, SignedRadixCiphertext, I256, U256};
+use crate::integer::{BooleanBlock, IntegerCiphertext, SignedRadixCiphertext, I256, U256, U512};
 use crate::named::Named;
 use crate::{CompactPublicKey, FheBool};
 
@@ -112,6 +114,409 @@ where
 
         Self::new(ciphertext, self.id)
     }
+
+    /// Returns the number of leading zeros in the binary representation of self.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0b00111111_11111111u16, &client_key);
+    ///
+    /// let result = a.leading_zeros();
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 2);
+    /// ```
+    pub fn leading_zeros(&self) -> crate::FheUint32 {
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let result = sks.leading_zeros_parallelized(&self.ciphertext);
+            GenericInteger::from_unsigned_radix(sks, result)
+        })
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of self.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0b0000000_0101000u16, &client_key);
+    ///
+    /// let result = a.trailing_zeros();
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3);
+    /// ```
+    pub fn trailing_zeros(&self) -> crate::FheUint32 {
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let result = sks.trailing_zeros_parallelized(&self.ciphertext);
+            GenericInteger::from_unsigned_radix(sks, result)
+        })
+    }
+
+    /// Returns the number of ones in the binary representation of self.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0b0000000_0101011u16, &client_key);
+    ///
+    /// let result = a.count_ones();
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 4);
+    /// ```
+    pub fn count_ones(&self) -> crate::FheUint32 {
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let result = sks.count_ones_parallelized(&self.ciphertext);
+            GenericInteger::from_unsigned_radix(sks, result)
+        })
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// Result has no meaning if self encrypts a value <= 0. See [Self::checked_ilog2]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1200u16, &client_key);
+    ///
+    /// let result = a.ilog2();
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1200u16.ilog2());
+    /// ```
+    pub fn ilog2(&self) -> crate::FheUint32 {
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let result = sks.ilog2_parallelized(&self.ciphertext);
+            GenericInteger::from_unsigned_radix(sks, result)
+        })
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// Also returns a boolean flag that is true if the result is valid (i.e self was > 0)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-1i16, &client_key);
+    ///
+    /// let (_result, is_ok) = a.checked_ilog2();
+    /// let is_ok = is_ok.decrypt(&client_key);
+    /// assert!(!is_ok);
+    ///
+    /// let a = FheInt16::encrypt(1200i16, &client_key);
+    ///
+    /// let (result, is_ok) = a.checked_ilog2();
+    /// let is_ok = is_ok.decrypt(&client_key);
+    /// assert!(is_ok);
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1200i16.ilog2());
+    /// ```
+    pub fn checked_ilog2(&self) -> (crate::FheUint32, FheBool) {
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let (result, is_ok) = sks.checked_ilog2_parallelized(&self.ciphertext);
+            (
+                GenericInteger::from_unsigned_radix(sks, result),
+                FheBool::new(is_ok),
+            )
+        })
+    }
+
+    /// Sorts the values in ascending order.
+    ///
+    /// The sort is done using a sorting network,
+    /// so the sequence of operations does not depend on the encrypted values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let mut values = [1200u32, 7, 65536, 7]
+    ///     .iter()
+    ///     .map(|&v| FheUint32::encrypt(v, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// FheUint32::sort(&mut values);
+    ///
+    /// let decrypted = values
+    ///     .iter()
+    ///     .map(|v| v.decrypt(&client_key))
+    ///     .collect::<Vec<u32>>();
+    /// assert_eq!(decrypted, [7, 7, 1200, 65536]);
+    /// ```
+    pub fn sort(values: &mut [Self]) {
+        let mut ciphertexts = values
+            .iter()
+            .map(|value| value.ciphertext.clone())
+            .collect::<Vec<_>>();
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .sort_parallelized(&mut ciphertexts);
+        });
+        for (value, ciphertext) in values.iter_mut().zip(ciphertexts) {
+            value.ciphertext = ciphertext;
+        }
+    }
+
+    /// Returns the index of the maximum of the values.
+    ///
+    /// If the maximum appears multiple times, the lowest index is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values = [1200u32, 7, 65536, 7]
+    ///     .iter()
+    ///     .map(|&v| FheUint32::encrypt(v, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let result = FheUint32::argmax(&values);
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 2);
+    /// ```
+    pub fn argmax(values: &[Self]) -> crate::FheUint32 {
+        let ciphertexts = values
+            .iter()
+            .map(|value| value.ciphertext.clone())
+            .collect::<Vec<_>>();
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let result = sks.argmax_parallelized(&ciphertexts);
+            GenericInteger::from_unsigned_radix(sks, result)
+        })
+    }
+
+    /// Returns the index of the minimum of the values.
+    ///
+    /// If the minimum appears multiple times, the lowest index is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values = [1200u32, 7, 65536, 7]
+    ///     .iter()
+    ///     .map(|&v| FheUint32::encrypt(v, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let result = FheUint32::argmin(&values);
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1);
+    /// ```
+    pub fn argmin(values: &[Self]) -> crate::FheUint32 {
+        let ciphertexts = values
+            .iter()
+            .map(|value| value.ciphertext.clone())
+            .collect::<Vec<_>>();
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let result = sks.argmin_parallelized(&ciphertexts);
+            GenericInteger::from_unsigned_radix(sks, result)
+        })
+    }
+
+    /// Returns the `k` greatest values, in descending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the number of values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values = [1200u32, 7, 65536, 7]
+    ///     .iter()
+    ///     .map(|&v| FheUint32::encrypt(v, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let top = FheUint32::top_k(&values, 2);
+    ///
+    /// let decrypted = top
+    ///     .iter()
+    ///     .map(|v| v.decrypt(&client_key))
+    ///     .collect::<Vec<u32>>();
+    /// assert_eq!(decrypted, [65536, 1200]);
+    /// ```
+    pub fn top_k(values: &[Self], k: usize) -> Vec<Self> {
+        let ciphertexts = values
+            .iter()
+            .map(|value| value.ciphertext.clone())
+            .collect::<Vec<_>>();
+        let top = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .top_k_parallelized(&ciphertexts, k)
+        });
+        top.into_iter()
+            .map(|ciphertext| Self::new(ciphertext, Id::default()))
+            .collect()
+    }
+
+    /// Switches the keys of self using the given key switching key,
+    /// so that it becomes encrypted under the destination keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is not encrypted under the source keys of `ksk`,
+    /// see [Self::try_keyswitch].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, KeySwitchingKey};
+    ///
+    /// let config_a = ConfigBuilder::default()
+    ///     .use_custom_parameters(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS, None)
+    ///     .build();
+    /// let (client_key_a, server_key_a) = generate_keys(config_a);
+    ///
+    /// let config_b = ConfigBuilder::default().build();
+    /// let (client_key_b, server_key_b) = generate_keys(config_b);
+    ///
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&client_key_a, &server_key_a),
+    ///     (&client_key_b, &server_key_b),
+    /// );
+    ///
+    /// let a = FheUint8::encrypt(27u8, &client_key_a);
+    /// let b = a.keyswitch(&ksk);
+    ///
+    /// set_server_key(server_key_b);
+    /// let c = &b * 3u8;
+    ///
+    /// let decrypted: u8 = c.decrypt(&client_key_b);
+    /// assert_eq!(decrypted, 81u8);
+    /// ```
+    pub fn keyswitch(&self, ksk: &KeySwitchingKey) -> Self {
+        self.try_keyswitch(ksk).unwrap_display()
+    }
+
+    /// Switches the keys of self using the given key switching key,
+    /// so that it becomes encrypted under the destination keys.
+    ///
+    /// Returns an error if self is not encrypted under the source keys of `ksk`.
+    pub fn try_keyswitch(&self, ksk: &KeySwitchingKey) -> Result<Self, KeySwitchingError> {
+        let ciphertext = ksk.try_cast(&self.ciphertext)?;
+        Ok(Self::new(ciphertext, self.id))
+    }
+}
+
+impl<Id> GenericInteger<Id>
+where
+    Id: IntegerId<InnerCiphertext = RadixCiphertext>,
+{
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1200u16, &client_key);
+    ///
+    /// let result = a.sqrt();
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 34);
+    /// ```
+    pub fn sqrt(&self) -> Self {
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sks = keys.integer_key.pbs_key();
+            let result = sks.isqrt_parallelized(&self.ciphertext);
+            Self::new(result, Id::default())
+        })
+    }
+
+    /// Creates a value from an unsigned radix ciphertext,
+    /// trimming or extending its blocks to match the number of blocks of `Id`
+    pub(in crate::high_level_api) fn from_unsigned_radix(
+        integer_key: &crate::integer::ServerKey,
+        mut ciphertext: RadixCiphertext,
+    ) -> Self {
+        let current_num_blocks = ciphertext.blocks.len();
+        let target_num_blocks = Id::num_blocks(integer_key.message_modulus());
+        if target_num_blocks > current_num_blocks {
+            integer_key.extend_radix_with_trivial_zero_blocks_msb_assign(
+                &mut ciphertext,
+                target_num_blocks - current_num_blocks,
+            );
+        } else {
+            integer_key.trim_radix_blocks_msb_assign(
+                &mut ciphertext,
+                current_num_blocks - target_num_blocks,
+            );
+        }
+        Self::new(ciphertext, Id::default())
+    }
 }
 
 impl<FromId, IntoId> CastFrom<GenericInteger<FromId>> for GenericInteger<IntoId>
@@ -122,8 +527,8 @@ where
     fn cast_from(input: GenericInteger<FromId>) -> Self {
         crate::high_level_api::global_state::with_internal_keys(|keys| {
             let integer_key = keys.integer_key.pbs_key();
-            let current_num_blocks = FromId::num_blocks();
-            let target_num_blocks = IntoId::num_blocks();
+            let current_num_blocks = input.ciphertext.blocks().len();
+            let target_num_blocks = IntoId::num_blocks(integer_key.message_modulus());
 
             let blocks = if FromId::InnerCiphertext::IS_SIGNED {
                 if target_num_blocks > current_num_blocks {
@@ -166,7 +571,7 @@ where
 
             assert_eq!(
                 blocks.len(),
-                IntoId::num_blocks(),
+                target_num_blocks,
                 "internal error, wrong number of blocks after casting"
             );
             let new_ciphertext = IntoId::InnerCiphertext::from_blocks(blocks);
@@ -181,9 +586,10 @@ where
 {
     fn cast_from(input: FheBool) -> Self {
         let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
             input
                 .ciphertext
-                .into_radix(Id::num_blocks(), keys.integer_key.pbs_key())
+                .into_radix(Id::num_blocks(integer_key.message_modulus()), integer_key)
         });
 
         Self::new(ciphertext, Id::default())
@@ -196,14 +602,6 @@ where
 {
     type Error = GenericIntegerBlockError;
     fn try_from(other: RadixCiphertext) -> Result<Self, GenericIntegerBlockError> {
-        // Check number of blocks
-        if other.blocks.len() != Id::num_blocks() {
-            return Err(GenericIntegerBlockError::NumberOfBlocks(
-                Id::num_blocks(),
-                other.blocks.len(),
-            ));
-        }
-
         // Get correct carry modulus and message modulus from ServerKey
         let id = Id::default();
         let (correct_carry_mod, correct_message_mod) = id.with_unwrapped_global(|integer_key| {
@@ -213,6 +611,15 @@ where
             )
         });
 
+        // Check number of blocks
+        let expected_num_blocks = Id::num_blocks(correct_message_mod);
+        if other.blocks.len() != expected_num_blocks {
+            return Err(GenericIntegerBlockError::NumberOfBlocks(
+                expected_num_blocks,
+                other.blocks.len(),
+            ));
+        }
+
         // For each block, check that carry modulus and message modulus are valid
         for block in &other.blocks {
             let (input_carry_mod, input_message_mod) = (block.carry_modulus, block.message_modulus);
@@ -253,7 +660,29 @@ where
 {
     fn decrypt(&self, key: &ClientKey) -> ClearType {
         let key = &key.key.key;
-        key.decrypt(&self.ciphertext)
+
+        let message_modulus = key.parameters().message_modulus().0 as u64;
+        let bits_in_block = message_modulus.ilog2() as usize;
+        let num_bits_in_last_block = Id::num_bits() % bits_in_block;
+        if num_bits_in_last_block == 0 || self.ciphertext.blocks().is_empty() {
+            return key.decrypt(&self.ciphertext);
+        }
+
+        // The number of blocks was rounded up, the bits of the last block past the width
+        // of the type are dropped (replaced by the sign bit for signed types)
+        let mut blocks = self.ciphertext.blocks().to_vec();
+        let last_block = blocks.last_mut().unwrap();
+        let last_block_value = key.key.decrypt_message_and_carry(last_block) % message_modulus;
+        let low_bits_mask = (1u64 << num_bits_in_last_block) - 1;
+        let mut masked_value = last_block_value & low_bits_mask;
+        if Id::InnerCiphertext::IS_SIGNED
+            && (last_block_value >> (num_bits_in_last_block - 1)) & 1 == 1
+        {
+            masked_value |= (message_modulus - 1) & !low_bits_mask;
+        }
+        *last_block = key.key.encrypt(masked_value);
+
+        key.decrypt(&Id::InnerCiphertext::from_blocks(blocks))
     }
 }
 
@@ -268,9 +697,10 @@ where
         let id = Id::default();
 
         let integer_client_key = &key.key.key;
+        let num_blocks = Id::num_blocks(integer_client_key.parameters().message_modulus());
         let ciphertext = <crate::integer::ClientKey as EncryptionKey<_, _>>::encrypt(
             integer_client_key,
-            (value, Id::num_blocks()),
+            (value, num_blocks),
         );
         Ok(Self::new(ciphertext, id))
     }
@@ -286,9 +716,10 @@ where
     fn try_encrypt(value: T, key: &PublicKey) -> Result<Self, Self::Error> {
         let id = Id::default();
         let integer_public_key = &key.key;
+        let num_blocks = Id::num_blocks(integer_public_key.parameters().message_modulus());
         let ciphertext = <crate::integer::PublicKey as EncryptionKey<_, _>>::encrypt(
             integer_public_key,
-            (value, Id::num_blocks()),
+            (value, num_blocks),
         );
         Ok(Self::new(ciphertext, id))
     }
@@ -304,9 +735,10 @@ where
     fn try_encrypt(value: T, key: &CompressedPublicKey) -> Result<Self, Self::Error> {
         let id = Id::default();
         let integer_public_key = &key.key;
+        let num_blocks = Id::num_blocks(integer_public_key.parameters().message_modulus());
         let ciphertext = <crate::integer::CompressedPublicKey as EncryptionKey<_, _>>::encrypt(
             integer_public_key,
-            (value, Id::num_blocks()),
+            (value, num_blocks),
         );
         Ok(Self::new(ciphertext, id))
     }
@@ -321,11 +753,12 @@ where
 
     fn try_encrypt(value: T, key: &CompactPublicKey) -> Result<Self, Self::Error> {
         let id = Id::default();
+        let num_blocks = Id::num_blocks(key.key.message_modulus());
         let integer_public_key = &key.key.key;
         let ciphertext =
             <crate::integer::public_key::CompactPublicKey as EncryptionKey<_, _>>::encrypt(
                 integer_public_key,
-                (value, Id::num_blocks()),
+                (value, num_blocks),
             );
         Ok(Self::new(ciphertext, id))
     }
@@ -341,9 +774,8 @@ where
     fn try_encrypt_trivial(value: T) -> Result<Self, Self::Error> {
         let id = Id::default();
         let ciphertext: Id::InnerCiphertext = id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .create_trivial_radix(value, Id::num_blocks())
+            let integer_key = integer_key.pbs_key();
+            integer_key.create_trivial_radix(value, Id::num_blocks(integer_key.message_modulus()))
         });
         Ok(Self::new(ciphertext, id))
     }
@@ -356,7 +788,7 @@ where
 {
     #[track_caller]
     fn encrypt_trivial(value: T) -> Self {
-        Self::try_encrypt_trivial(value).unwrap()
+        Self::try_encrypt_trivial(value).unwrap_display()
     }
 }
 
@@ -707,6 +1139,280 @@ where
     }
 }
 
+impl<Id> GenericInteger<Id>
+where
+    Id: IntegerId + WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Adds two [GenericInteger] and returns a boolean indicating overflow.
+    ///
+    /// Only used by the C API.
+    pub(crate) fn overflowing_add(&self, rhs: &Self) -> (Self, FheBool) {
+        let (result, overflowed) = self.id.with_unwrapped_global(|integer_key| {
+            let pbs_key = integer_key.pbs_key();
+            dispatch_overflowing_op(
+                &self.ciphertext,
+                &rhs.ciphertext,
+                |lhs, rhs| pbs_key.unsigned_overflowing_add_parallelized(lhs, rhs),
+                |lhs, rhs| pbs_key.signed_overflowing_add_parallelized(lhs, rhs),
+            )
+        });
+        (
+            GenericInteger::<Id>::new(result, self.id),
+            FheBool::new(overflowed),
+        )
+    }
+
+    /// Subtracts two [GenericInteger] and returns a boolean indicating overflow.
+    ///
+    /// Only used by the C API.
+    pub(crate) fn overflowing_sub(&self, rhs: &Self) -> (Self, FheBool) {
+        let (result, overflowed) = self.id.with_unwrapped_global(|integer_key| {
+            let pbs_key = integer_key.pbs_key();
+            dispatch_overflowing_op(
+                &self.ciphertext,
+                &rhs.ciphertext,
+                |lhs, rhs| pbs_key.unsigned_overflowing_sub_parallelized(lhs, rhs),
+                |lhs, rhs| pbs_key.signed_overflowing_sub_parallelized(lhs, rhs),
+            )
+        });
+        (
+            GenericInteger::<Id>::new(result, self.id),
+            FheBool::new(overflowed),
+        )
+    }
+}
+
+/// Calls the unsigned or the signed version of an overflowing operation,
+/// depending on the concrete type of the inner ciphertexts.
+///
+/// The operands are borrowed as their concrete type, so no block is copied.
+fn dispatch_overflowing_op<T>(
+    lhs: &T,
+    rhs: &T,
+    unsigned_op: impl FnOnce(&RadixCiphertext, &RadixCiphertext) -> (RadixCiphertext, BooleanBlock),
+    signed_op: impl FnOnce(
+        &SignedRadixCiphertext,
+        &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, BooleanBlock),
+) -> (T, BooleanBlock)
+where
+    T: IntegerRadixCiphertext + 'static,
+{
+    let (lhs, rhs) = (lhs as &dyn Any, rhs as &dyn Any);
+    let (result_blocks, overflowed) = if let (Some(lhs), Some(rhs)) = (
+        lhs.downcast_ref::<RadixCiphertext>(),
+        rhs.downcast_ref::<RadixCiphertext>(),
+    ) {
+        let (result, overflowed) = unsigned_op(lhs, rhs);
+        (result.into_blocks(), overflowed)
+    } else if let (Some(lhs), Some(rhs)) = (
+        lhs.downcast_ref::<SignedRadixCiphertext>(),
+        rhs.downcast_ref::<SignedRadixCiphertext>(),
+    ) {
+        let (result, overflowed) = signed_op(lhs, rhs);
+        (result.into_blocks(), overflowed)
+    } else {
+        unreachable!("integer ciphertexts are either RadixCiphertext or SignedRadixCiphertext")
+    };
+    (T::from_blocks(result_blocks), overflowed)
+}
+
+impl<Id> OverflowingMul<Self> for GenericInteger<Id>
+where
+    Id: IntegerId + WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = Self;
+
+    fn overflowing_mul(self, rhs: Self) -> (Self::Output, FheBool) {
+        <&Self as OverflowingMul<&Self>>::overflowing_mul(&self, &rhs)
+    }
+}
+
+impl<Id> OverflowingMul<&Self> for GenericInteger<Id>
+where
+    Id: IntegerId + WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = Self;
+
+    fn overflowing_mul(self, rhs: &Self) -> (Self::Output, FheBool) {
+        <&Self as OverflowingMul<&Self>>::overflowing_mul(&self, rhs)
+    }
+}
+
+impl<Id> OverflowingMul<Self> for &GenericInteger<Id>
+where
+    Id: IntegerId + WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = GenericInteger<Id>;
+
+    /// Multiplies two [GenericInteger] and returns a boolean indicating overflow.
+    ///
+    /// * The operation is modular, i.e on overflow the result wraps around.
+    /// * On overflow the [FheBool] is true, otherwise false
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint16::encrypt(37849u16, &client_key);
+    ///
+    /// let (result, overflowed) = (&a).overflowing_mul(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 3u16.wrapping_mul(37849u16));
+    /// assert!(overflowed.decrypt(&client_key));
+    /// ```
+    fn overflowing_mul(self, rhs: Self) -> (Self::Output, FheBool) {
+        let (result, overflowed) = self.id.with_unwrapped_global(|integer_key| {
+            let pbs_key = integer_key.pbs_key();
+            dispatch_overflowing_op(
+                &self.ciphertext,
+                &rhs.ciphertext,
+                |lhs, rhs| pbs_key.unsigned_overflowing_mul_parallelized(lhs, rhs),
+                |lhs, rhs| pbs_key.signed_overflowing_mul_parallelized(lhs, rhs),
+            )
+        });
+        (
+            GenericInteger::<Id>::new(result, self.id),
+            FheBool::new(overflowed),
+        )
+    }
+}
+
+impl<Id> GenericInteger<Id>
+where
+    Id: IntegerId + WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Multiplies two [GenericInteger], checking for overflow.
+    ///
+    /// Returns the wrapped result, and a [FheBool] that is true
+    /// if the result is valid, i.e. the multiplication did not overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    /// let b = FheInt16::encrypt(1000i16, &client_key);
+    ///
+    /// let (result, is_ok) = a.checked_mul(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, -3000i16);
+    /// assert!(is_ok.decrypt(&client_key));
+    /// ```
+    pub fn checked_mul(&self, rhs: &Self) -> (Self, FheBool) {
+        let (result, overflowed) = <&Self as OverflowingMul<&Self>>::overflowing_mul(self, rhs);
+        (result, !overflowed)
+    }
+}
+
+impl<Id> Pow<u32> for GenericInteger<Id>
+where
+    Id: IntegerId + WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = Self;
+
+    fn pow(self, exponent: u32) -> Self::Output {
+        <&Self as Pow<u32>>::pow(&self, exponent)
+    }
+}
+
+impl<Id> Pow<u32> for &GenericInteger<Id>
+where
+    Id: IntegerId + WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = GenericInteger<Id>;
+
+    /// Raises a [GenericInteger] to the power of a clear exponent.
+    ///
+    /// The operation is modular, i.e on overflow the result wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(7u16, &client_key);
+    ///
+    /// let result = (&a).pow(5);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 7u16.pow(5));
+    /// ```
+    fn pow(self, exponent: u32) -> Self::Output {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .scalar_pow_parallelized(&self.ciphertext, exponent)
+        });
+        GenericInteger::<Id>::new(ciphertext, self.id)
+    }
+}
+
+impl<Id, Id2> Pow<&GenericInteger<Id2>> for GenericInteger<Id>
+where
+    Id: IntegerId + WithGlobalKey<Key = IntegerServerKey>,
+    Id2: IntegerId<InnerCiphertext = RadixCiphertext>,
+{
+    type Output = Self;
+
+    fn pow(self, exponent: &GenericInteger<Id2>) -> Self::Output {
+        <&Self as Pow<&GenericInteger<Id2>>>::pow(&self, exponent)
+    }
+}
+
+impl<Id, Id2> Pow<&GenericInteger<Id2>> for &GenericInteger<Id>
+where
+    Id: IntegerId + WithGlobalKey<Key = IntegerServerKey>,
+    Id2: IntegerId<InnerCiphertext = RadixCiphertext>,
+{
+    type Output = GenericInteger<Id>;
+
+    /// Raises a [GenericInteger] to the power of an encrypted exponent.
+    ///
+    /// * The exponent is required to be an unsigned integer type, but it does not need to have the
+    ///   same number of bits.
+    /// * The operation is modular, i.e on overflow the result wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(7u16, &client_key);
+    /// let b = FheUint8::encrypt(5u8, &client_key);
+    ///
+    /// let result = (&a).pow(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 7u16.pow(5));
+    /// ```
+    fn pow(self, exponent: &GenericInteger<Id2>) -> Self::Output {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .pow_parallelized(&self.ciphertext, &exponent.ciphertext)
+        });
+        GenericInteger::<Id>::new(ciphertext, self.id)
+    }
+}
+
 // Shifts and rotations are special cases where the right hand side
 // is for now, required to be a unsigned integer type.
 // And its constraints are a bit relaxed: rhs does not needs to have the same
@@ -906,15 +1612,20 @@ macro_rules! generic_integer_impl_scalar_div_rem {
 generic_integer_impl_scalar_div_rem!(
     key_method: scalar_div_rem_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
 );
 generic_integer_impl_scalar_div_rem!(
     key_method: signed_scalar_div_rem_parallelized,
@@ -1023,15 +1734,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: Add(add),
     key_method: scalar_add_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1043,15 +1759,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: Sub(sub),
     key_method: scalar_sub_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1063,15 +1784,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: Mul(mul),
     key_method: scalar_mul_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1083,15 +1809,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: BitAnd(bitand),
     key_method: scalar_bitand_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1103,15 +1834,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: BitOr(bitor),
     key_method: scalar_bitor_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1123,15 +1859,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: BitXor(bitxor),
     key_method: scalar_bitxor_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1143,15 +1884,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: Shl(shl),
     key_method: scalar_left_shift_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8, u16, u32, u64, u128),
+        (super::FheUint4, u8, u16, u32, u64, u128),
         (super::FheUint8, u8, u16, u32, u64, u128),
         (super::FheUint10, u8, u16, u32, u64, u128),
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U256),
         (super::FheInt8, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
@@ -1163,15 +1909,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: Shr(shr),
     key_method: scalar_right_shift_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8, u16, u32, u64, u128),
+        (super::FheUint4, u8, u16, u32, u64, u128),
         (super::FheUint8, u8, u16, u32, u64, u128),
         (super::FheUint10, u8, u16, u32, u64, u128),
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U256),
         (super::FheInt8, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
@@ -1183,15 +1934,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: RotateLeft(rotate_left),
     key_method: scalar_rotate_left_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8, u16, u32, u64, u128),
+        (super::FheUint4, u8, u16, u32, u64, u128),
         (super::FheUint8, u8, u16, u32, u64, u128),
         (super::FheUint10, u8, u16, u32, u64, u128),
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U256),
         (super::FheInt8, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
@@ -1203,15 +1959,20 @@ generic_integer_impl_scalar_operation!(
     rust_trait: RotateRight(rotate_right),
     key_method: scalar_rotate_right_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8, u16, u32, u64, u128),
+        (super::FheUint4, u8, u16, u32, u64, u128),
         (super::FheUint8, u8, u16, u32, u64, u128),
         (super::FheUint10, u8, u16, u32, u64, u128),
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U256),
         (super::FheInt8, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
@@ -1223,29 +1984,39 @@ generic_integer_impl_scalar_operation!(
     rust_trait: Div(div),
     key_method: scalar_div_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Rem(rem),
     key_method: scalar_rem_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Div(div),
@@ -1274,15 +2045,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: AddAssign(add_assign),
     key_method: scalar_add_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1294,15 +2070,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: SubAssign(sub_assign),
     key_method: scalar_sub_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1314,15 +2095,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: MulAssign(mul_assign),
     key_method: scalar_mul_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1334,15 +2120,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitAndAssign(bitand_assign),
     key_method: scalar_bitand_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1354,15 +2145,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitOrAssign(bitor_assign),
     key_method: scalar_bitor_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1374,15 +2170,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitXorAssign(bitxor_assign),
     key_method: scalar_bitxor_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
         (super::FheInt8, i8),
         (super::FheInt16, i16),
         (super::FheInt32, i32),
@@ -1394,15 +2195,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: ShlAssign(shl_assign),
     key_method: scalar_left_shift_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8, u16, u32, u64, u128),
+        (super::FheUint4, u8, u16, u32, u64, u128),
         (super::FheUint8, u8, u16, u32, u64, u128),
         (super::FheUint10, u8, u16, u32, u64, u128),
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U256),
         (super::FheInt8, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
@@ -1414,15 +2220,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: ShrAssign(shr_assign),
     key_method: scalar_right_shift_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8, u16, u32, u64, u128),
+        (super::FheUint4, u8, u16, u32, u64, u128),
         (super::FheUint8, u8, u16, u32, u64, u128),
         (super::FheUint10, u8, u16, u32, u64, u128),
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U256),
         (super::FheInt8, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
@@ -1434,15 +2245,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: RotateLeftAssign(rotate_left_assign),
     key_method: scalar_rotate_left_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8, u16, u32, u64, u128),
+        (super::FheUint4, u8, u16, u32, u64, u128),
         (super::FheUint8, u8, u16, u32, u64, u128),
         (super::FheUint10, u8, u16, u32, u64, u128),
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U256),
         (super::FheInt8, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
@@ -1454,15 +2270,20 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: RotateRightAssign(rotate_right_assign),
     key_method: scalar_rotate_right_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8, u16, u32, u64, u128),
+        (super::FheUint4, u8, u16, u32, u64, u128),
         (super::FheUint8, u8, u16, u32, u64, u128),
         (super::FheUint10, u8, u16, u32, u64, u128),
         (super::FheUint12, u8, u16, u32, u64, u128),
         (super::FheUint14, u8, u16, u32, u64, u128),
         (super::FheUint16, u8, u16, u32, u64, u128),
+        (super::FheUint24, u8, u16, u32, u64, u128),
         (super::FheUint32, u8, u16, u32, u64, u128),
+        (super::FheUint48, u8, u16, u32, u64, u128),
         (super::FheUint64, u8, u16, u32, u64, u128),
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U256),
         (super::FheInt8, u8, u16, u32, u64, u128),
         (super::FheInt16, u8, u16, u32, u64, u128),
         (super::FheInt32, u8, u16, u32, u64, u128),
@@ -1474,29 +2295,39 @@ generic_integer_impl_scalar_operation_assign!(
     rust_trait: DivAssign(div_assign),
     key_method: scalar_div_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RemAssign(rem_assign),
     key_method: scalar_rem_assign_parallelized,
     fhe_and_scalar_type:
+        (super::FheUint2, u8),
+        (super::FheUint4, u8),
         (super::FheUint8, u8),
         (super::FheUint10, u16),
         (super::FheUint12, u16),
         (super::FheUint14, u16),
         (super::FheUint16, u16),
+        (super::FheUint24, u32),
         (super::FheUint32, u32),
+        (super::FheUint48, u64),
         (super::FheUint64, u64),
         (super::FheUint128, u128),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: DivAssign(div_assign),
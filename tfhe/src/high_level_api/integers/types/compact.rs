@@ -59,7 +59,9 @@ where
 
     fn try_encrypt(value: T, key: &CompactPublicKey) -> Result<Self, Self::Error> {
         let id = Id::default();
-        let ciphertext = key.key.try_encrypt_compact(&[value], Id::num_blocks());
+        let ciphertext = key
+            .key
+            .try_encrypt_compact(&[value], Id::num_blocks(key.key.message_modulus()));
         Ok(Self {
             list: ciphertext,
             id,
@@ -76,7 +78,9 @@ where
 
     fn try_encrypt(values: &'a [T], key: &CompactPublicKey) -> Result<Self, Self::Error> {
         let id = Id::default();
-        let ciphertext = key.key.try_encrypt_compact(values, Id::num_blocks());
+        let ciphertext = key
+            .key
+            .try_encrypt_compact(values, Id::num_blocks(key.key.message_modulus()));
         Ok(Self {
             list: ciphertext,
             id,
@@ -78,9 +78,10 @@ where
     fn try_encrypt(value: T, key: &ClientKey) -> Result<Self, Self::Error> {
         let id = Id::default();
         let integer_client_key = &key.key.key;
+        let num_blocks = Id::num_blocks(integer_client_key.parameters().message_modulus());
         let inner = <crate::integer::ClientKey as EncryptionKey<_, _>>::encrypt(
             integer_client_key,
-            (value, Id::num_blocks()),
+            (value, num_blocks),
         );
         Ok(Self::new(inner, id))
     }
@@ -0,0 +1,280 @@
+use std::borrow::Borrow;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitOr, BitXor, Div, Mul, MulAssign, Neg, Rem, Shl, Shr, Sub, SubAssign,
+};
+
+use crate::high_level_api::errors::Error;
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::ClientKey;
+use crate::high_level_api::traits::{FheDecrypt, FheTryEncrypt};
+use crate::integer::CrtCiphertext;
+use crate::named::Named;
+use crate::FheBool;
+
+/// A FHE unsigned integer using the CRT representation
+///
+/// The integer is represented by its residues modulo each modulus of the basis
+/// given to [ConfigBuilder::use_crt_representation](crate::ConfigBuilder::use_crt_representation),
+/// thus computations wrap around the product of the basis moduli.
+///
+/// Additions, subtractions, negations and multiplications (by another [FheUintCrt] or by a
+/// clear `u64`) are done independently on each residue, which makes them (especially the
+/// multiplication) much cheaper than with the radix types.
+///
+/// Operations that need the integer as a whole (comparisons, bitwise operations, shifts,
+/// divisions) are not supported by this representation, they return an
+/// [Error::UnsupportedCrtOperation].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUintCrt};
+///
+/// let config = ConfigBuilder::default()
+///     .use_custom_parameters(PARAM_MESSAGE_3_CARRY_3_KS_PBS, None)
+///     .use_crt_representation(vec![5, 7, 8])
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// // Computations are done modulo 5 * 7 * 8 = 280
+/// let a = FheUintCrt::encrypt(27u64, &client_key);
+/// let b = FheUintCrt::encrypt(13u64, &client_key);
+///
+/// // 27 * 27 + 3 * 13 + 1
+/// let result = &a * &a + &b * 3u64 + 1u64;
+/// let decrypted: u64 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, (27 * 27 + 3 * 13 + 1) % 280);
+/// ```
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct FheUintCrt {
+    pub(in crate::high_level_api) ciphertext: CrtCiphertext,
+}
+
+impl Named for FheUintCrt {
+    const NAME: &'static str = "high_level_api::FheUintCrt";
+}
+
+impl FheUintCrt {
+    pub(in crate::high_level_api) fn new(ciphertext: CrtCiphertext) -> Self {
+        Self { ciphertext }
+    }
+
+    /// Returns the product of the moduli of the CRT basis,
+    /// i.e. the modulus of the computations.
+    pub fn modulus(&self) -> u64 {
+        self.ciphertext.moduli.iter().product()
+    }
+}
+
+impl FheTryEncrypt<u64, ClientKey> for FheUintCrt {
+    type Error = Error;
+
+    fn try_encrypt(value: u64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let basis = key
+            .key
+            .crt_basis
+            .as_ref()
+            .ok_or(Error::CrtRepresentationNotEnabled)?;
+        let modulus: u64 = basis.moduli().iter().product();
+        if value >= modulus {
+            return Err(Error::OutOfRange);
+        }
+
+        let integer_client_key = &key.key.key;
+        let ciphertext = integer_client_key.encrypt_crt(value, basis.moduli().to_vec());
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl FheDecrypt<u64> for FheUintCrt {
+    fn decrypt(&self, key: &ClientKey) -> u64 {
+        let integer_client_key = &key.key.key;
+        integer_client_key.decrypt_crt(&self.ciphertext)
+    }
+}
+
+macro_rules! fhe_uint_crt_impl_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+        impl<B> $rust_trait_name<B> for FheUintCrt
+        where
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<B> $rust_trait_name<B> for &FheUintCrt
+        where
+            B: Borrow<FheUintCrt>,
+        {
+            type Output = FheUintCrt;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let ciphertext = with_internal_keys(|keys| {
+                    let mut lhs = self.ciphertext.clone();
+                    let mut rhs = rhs.borrow().ciphertext.clone();
+                    keys.integer_key.pbs_key().$key_method(&mut lhs, &mut rhs)
+                });
+                FheUintCrt::new(ciphertext)
+            }
+        }
+    }
+);
+
+macro_rules! fhe_uint_crt_impl_operation_assign (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+        impl<B> $rust_trait_name<B> for FheUintCrt
+        where
+            B: Borrow<Self>,
+        {
+            fn $rust_trait_method(&mut self, rhs: B) {
+                with_internal_keys(|keys| {
+                    let mut rhs = rhs.borrow().ciphertext.clone();
+                    keys.integer_key
+                        .pbs_key()
+                        .$key_method(&mut self.ciphertext, &mut rhs)
+                })
+            }
+        }
+    }
+);
+
+macro_rules! fhe_uint_crt_impl_scalar_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+        impl $rust_trait_name<u64> for FheUintCrt {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: u64) -> Self::Output {
+                <&Self as $rust_trait_name<u64>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl $rust_trait_name<u64> for &FheUintCrt {
+            type Output = FheUintCrt;
+
+            fn $rust_trait_method(self, rhs: u64) -> Self::Output {
+                let ciphertext = with_internal_keys(|keys| {
+                    let mut lhs = self.ciphertext.clone();
+                    keys.integer_key.pbs_key().$key_method(&mut lhs, rhs)
+                });
+                FheUintCrt::new(ciphertext)
+            }
+        }
+    }
+);
+
+macro_rules! fhe_uint_crt_impl_scalar_operation_assign (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+        impl $rust_trait_name<u64> for FheUintCrt {
+            fn $rust_trait_method(&mut self, rhs: u64) {
+                with_internal_keys(|keys| {
+                    keys.integer_key
+                        .pbs_key()
+                        .$key_method(&mut self.ciphertext, rhs)
+                })
+            }
+        }
+    }
+);
+
+fhe_uint_crt_impl_operation!(Add(add) => smart_crt_add_parallelized);
+fhe_uint_crt_impl_operation!(Sub(sub) => smart_crt_sub_parallelized);
+fhe_uint_crt_impl_operation!(Mul(mul) => smart_crt_mul_parallelized);
+
+fhe_uint_crt_impl_operation_assign!(AddAssign(add_assign) => smart_crt_add_assign_parallelized);
+fhe_uint_crt_impl_operation_assign!(SubAssign(sub_assign) => smart_crt_sub_assign_parallelized);
+fhe_uint_crt_impl_operation_assign!(MulAssign(mul_assign) => smart_crt_mul_assign_parallelized);
+
+fhe_uint_crt_impl_scalar_operation!(Add(add) => smart_crt_scalar_add_parallelized);
+fhe_uint_crt_impl_scalar_operation!(Sub(sub) => smart_crt_scalar_sub_parallelized);
+fhe_uint_crt_impl_scalar_operation!(Mul(mul) => smart_crt_scalar_mul_parallelized);
+
+fhe_uint_crt_impl_scalar_operation_assign!(AddAssign(add_assign) => smart_crt_scalar_add_assign_parallelized);
+fhe_uint_crt_impl_scalar_operation_assign!(SubAssign(sub_assign) => smart_crt_scalar_sub_assign_parallelized);
+fhe_uint_crt_impl_scalar_operation_assign!(MulAssign(mul_assign) => smart_crt_scalar_mul_assign_parallelized);
+
+impl Neg for FheUintCrt {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        <&Self as Neg>::neg(&self)
+    }
+}
+
+impl Neg for &FheUintCrt {
+    type Output = FheUintCrt;
+
+    fn neg(self) -> Self::Output {
+        let ciphertext = with_internal_keys(|keys| {
+            let mut ct = self.ciphertext.clone();
+            keys.integer_key
+                .pbs_key()
+                .smart_crt_neg_parallelized(&mut ct)
+        });
+        FheUintCrt::new(ciphertext)
+    }
+}
+
+macro_rules! fhe_uint_crt_impl_unsupported_comparison (
+    ($($method:ident),* $(,)?) => {
+        impl FheUintCrt {
+            $(
+                #[doc = concat!("The `", stringify!($method), "` comparison is not supported by the CRT representation,")]
+                /// it always returns an [Error::UnsupportedCrtOperation].
+                pub fn $method<B: Borrow<Self>>(&self, _rhs: B) -> Result<FheBool, Error> {
+                    Err(Error::UnsupportedCrtOperation {
+                        operation: stringify!($method),
+                    })
+                }
+            )*
+        }
+    }
+);
+
+macro_rules! fhe_uint_crt_impl_unsupported_operation (
+    ($($rust_trait_name:ident($rust_trait_method:ident)),* $(,)?) => {
+        $(
+            impl<B> $rust_trait_name<B> for FheUintCrt
+            where
+                B: Borrow<Self>,
+            {
+                type Output = Result<Self, Error>;
+
+                fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                    <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+                }
+            }
+
+            impl<B> $rust_trait_name<B> for &FheUintCrt
+            where
+                B: Borrow<FheUintCrt>,
+            {
+                type Output = Result<FheUintCrt, Error>;
+
+                fn $rust_trait_method(self, _rhs: B) -> Self::Output {
+                    Err(Error::UnsupportedCrtOperation {
+                        operation: stringify!($rust_trait_method),
+                    })
+                }
+            }
+        )*
+    }
+);
+
+fhe_uint_crt_impl_unsupported_comparison!(eq, ne, lt, le, gt, ge);
+
+fhe_uint_crt_impl_unsupported_operation!(
+    BitAnd(bitand),
+    BitOr(bitor),
+    BitXor(bitxor),
+    Shl(shl),
+    Shr(shr),
+    Div(div),
+    Rem(rem),
+);
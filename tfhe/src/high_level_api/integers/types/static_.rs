@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::base::GenericInteger;
+use crate::high_level_api::array::FheArray;
+use crate::high_level_api::errors::Type;
+use crate::high_level_api::global_state::WithGlobalKey;
 use crate::high_level_api::integers::parameters::{EvaluationIntegerKey, IntegerId};
 use crate::high_level_api::integers::types::compact::{
     GenericCompactInteger, GenericCompactIntegerList,
@@ -12,160 +15,178 @@ use crate::integer::keycache::{KEY_CACHE, KEY_CACHE_WOPBS};
 use crate::integer::wopbs::WopbsKey;
 use paste::paste;
 
-macro_rules! define_static_integer_parameters {
+macro_rules! define_generic_integer_type {
     (
-        UnsignedRadix {
-            num_bits: $num_bits:literal,
-            num_block: $num_block:literal,
+        $(#[$outer:meta])*
+        $name:ident {
+            id: $id:ident,
+            inner_ciphertext: $inner_ciphertext:ty,
+            inner_compressed_ciphertext: $inner_compressed_ciphertext:ty,
+            type_variant: $type_variant:path,
         }
     ) => {
         paste! {
-            #[doc = concat!("Id for the [FheUint", stringify!($num_bits), "] data type.")]
+            #[doc = concat!("Id for the [", stringify!($name), "] data types.")]
             #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
-            pub struct [<FheUint $num_bits Id>];
+            pub struct $id<const N: usize>;
 
-            impl IntegerId for [<FheUint $num_bits Id>] {
-                type InnerCiphertext = crate::integer::RadixCiphertext;
-                type InnerCompressedCiphertext = crate::integer::ciphertext::CompressedRadixCiphertext;
+            impl<const N: usize> $id<N> {
+                const NUM_BITS: usize = {
+                    assert!(N > 0, "An integer type must have at least one bit");
+                    N
+                };
+            }
 
-                fn num_blocks() -> usize {
-                    $num_block
+            impl<const N: usize> IntegerId for $id<N> {
+                type InnerCiphertext = $inner_ciphertext;
+                type InnerCompressedCiphertext = $inner_compressed_ciphertext;
+
+                fn num_bits() -> usize {
+                    Self::NUM_BITS
                 }
             }
 
-            impl TypeIdentifier for [<FheUint $num_bits Id>] {
-                fn type_variant(&self) -> $crate::high_level_api::errors::Type {
-                    $crate::high_level_api::errors::Type::[<FheUint $num_bits>]
+            impl<const N: usize> TypeIdentifier for $id<N> {
+                fn type_variant(&self) -> Type {
+                    $type_variant(N)
                 }
             }
-        }
-    };
-    (
-        SignedRadix {
-            num_bits: $num_bits:literal,
-            num_block: $num_block:literal,
-        }
-    ) => {
-        paste! {
-            #[doc = concat!("Id for the [FheInt", stringify!($num_bits), "] data type.")]
-            #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
-            pub struct [<FheInt $num_bits Id>];
 
-            impl IntegerId for [<FheInt $num_bits Id>] {
-                type InnerCiphertext = crate::integer::SignedRadixCiphertext;
-                type InnerCompressedCiphertext = crate::integer::ciphertext::CompressedSignedRadixCiphertext;
+            impl<const N: usize> WithGlobalKey for $id<N> {
+                type Key = crate::high_level_api::integers::IntegerServerKey;
 
-                fn num_blocks() -> usize {
-                    $num_block
-                }
+                fn with_unwrapped_global<R, F>(self, func: F) -> R
+                where
+                    F: FnOnce(&Self::Key) -> R {
+                    crate::high_level_api::global_state::with_internal_keys(|keys| {
+                            func(&keys.integer_key)
+                        })
+                    }
             }
 
-            impl TypeIdentifier for [<FheInt $num_bits Id>] {
-                fn type_variant(&self) -> $crate::high_level_api::errors::Type {
-                    $crate::high_level_api::errors::Type::[<FheInt $num_bits>]
-                }
-            }
+            $(#[$outer])*
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type $name<const N: usize> = GenericInteger<$id<N>>;
+
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<Compressed $name>]<const N: usize> = CompressedGenericInteger<$id<N>>;
+
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<Compact $name>]<const N: usize> = GenericCompactInteger<$id<N>>;
+
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<Compact $name List>]<const N: usize> = GenericCompactIntegerList<$id<N>>;
+
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<$name Array>]<const N: usize> = FheArray<$id<N>>;
         }
     };
 }
 
+define_generic_integer_type!(
+    /// An unsigned integer type with `N` bits
+    ///
+    /// When `N` is not a multiple of the number of message bits of the blocks
+    /// used by the keys (2 bits with the default parameters), the number of blocks
+    /// is rounded up and the extra bits of the last block are dropped on decryption.
+    ///
+    /// Types for the most common widths are also provided (e.g. [FheUint8](crate::FheUint8)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// // 6 bits are stored in 3 blocks, instead of 4 for a FheUint8
+    /// type FheUint6 = FheUint<6>;
+    ///
+    /// let a = FheUint6::encrypt(60u8, &client_key);
+    /// let b = FheUint6::encrypt(10u8, &client_key);
+    ///
+    /// let result = &a + &b;
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (60 + 10) % 64);
+    /// ```
+    FheUint {
+        id: FheUintId,
+        inner_ciphertext: crate::integer::RadixCiphertext,
+        inner_compressed_ciphertext: crate::integer::ciphertext::CompressedRadixCiphertext,
+        type_variant: Type::unsigned,
+    }
+);
+
+define_generic_integer_type!(
+    /// A signed integer type with `N` bits
+    ///
+    /// When `N` is not a multiple of the number of message bits of the blocks
+    /// used by the keys (2 bits with the default parameters), the number of blocks
+    /// is rounded up and the extra bits of the last block are dropped on decryption.
+    ///
+    /// Types for the most common widths are also provided (e.g. [FheInt8](crate::FheInt8)).
+    FheInt {
+        id: FheIntId,
+        inner_ciphertext: crate::integer::SignedRadixCiphertext,
+        inner_compressed_ciphertext: crate::integer::ciphertext::CompressedSignedRadixCiphertext,
+        type_variant: Type::signed,
+    }
+);
+
 macro_rules! static_int_type {
-    // This rule generates the types specialization
-    // as well as call the macros
-    // that implement necessary traits for the ClientKey and ServerKey
-    //
-    // This is not meant to be used directly, instead see the other rules below
+    // Defines the aliases of a static integer type
+    // of the given width, in the `Radix` representation
     (
-        @impl_types_and_key_traits,
         $(#[$outer:meta])*
-        $name:ident {
+        Unsigned {
             num_bits: $num_bits:literal,
         }
     ) => {
-         paste! {
-            #[doc = concat!("An unsigned integer type with", stringify!($num_bits), "bits")]
+        paste! {
+            #[doc = concat!("An unsigned integer type with ", stringify!($num_bits), " bits")]
             $(#[$outer])*
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
-            pub type $name = GenericInteger<[<$name Id>]>;
+            pub type [<FheUint $num_bits>] = FheUint<$num_bits>;
 
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
-            pub type [<Compressed $name>] = CompressedGenericInteger<[<$name Id>]>;
+            pub type [<CompressedFheUint $num_bits>] = CompressedFheUint<$num_bits>;
 
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
-            pub type [<Compact $name>] = GenericCompactInteger<[<$name Id>]>;
+            pub type [<CompactFheUint $num_bits>] = CompactFheUint<$num_bits>;
 
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
-            pub type [<Compact $name List>] = GenericCompactIntegerList<[<$name Id>]>;
-
-            impl $crate::high_level_api::global_state::WithGlobalKey for [<$name Id>] {
-                type Key = crate::high_level_api::integers::IntegerServerKey;
-
-                fn with_unwrapped_global<R, F>(self, func: F) -> R
-                where
-                    F: FnOnce(&Self::Key) -> R {
-                    $crate::high_level_api::global_state::with_internal_keys(|keys| {
-                            func(&keys.integer_key)
-                        })
-                    }
-            }
-        }
-    };
+            pub type [<CompactFheUint $num_bits List>] = CompactFheUintList<$num_bits>;
 
-    // Defines a static integer type that uses
-    // the `Radix` representation
-    (
-        $(#[$outer:meta])*
-        Unsigned {
-            num_bits: $num_bits:literal,
-            parameters: Radix {
-                num_block: $num_block:literal,
-            },
-        }
-    ) => {
-        define_static_integer_parameters!(
-            UnsignedRadix {
-                num_bits: $num_bits,
-                num_block: $num_block,
-            }
-        );
-
-        ::paste::paste!{
-            static_int_type!(
-                @impl_types_and_key_traits,
-                $(#[$outer])*
-                [<FheUint $num_bits>] {
-                    num_bits: $num_bits,
-                }
-            );
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<FheUint $num_bits Array>] = FheUintArray<$num_bits>;
         }
     };
 
-    // Defines a static integer type that uses
-    // the `Radix` representation
     (
         $(#[$outer:meta])*
         Signed {
             num_bits: $num_bits:literal,
-            parameters: Radix {
-                num_block: $num_block:literal,
-            },
         }
     ) => {
-        define_static_integer_parameters!(
-            SignedRadix {
-                num_bits: $num_bits,
-                num_block: $num_block,
-            }
-        );
-
-        ::paste::paste!{
-            static_int_type!(
-                @impl_types_and_key_traits,
-                $(#[$outer])*
-                [<FheInt $num_bits>] {
-                    num_bits: $num_bits,
-                }
-            );
+        paste! {
+            #[doc = concat!("A signed integer type with ", stringify!($num_bits), " bits")]
+            $(#[$outer])*
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<FheInt $num_bits>] = FheInt<$num_bits>;
+
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<CompressedFheInt $num_bits>] = CompressedFheInt<$num_bits>;
+
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<CompactFheInt $num_bits>] = CompactFheInt<$num_bits>;
+
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<CompactFheInt $num_bits List>] = CompactFheIntList<$num_bits>;
+
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<FheInt $num_bits Array>] = FheIntArray<$num_bits>;
         }
     };
 }
@@ -208,137 +229,122 @@ where
     }
 }
 
+static_int_type! {
+    Unsigned {
+        num_bits: 2,
+    }
+}
+
+static_int_type! {
+    Unsigned {
+        num_bits: 4,
+    }
+}
+
 static_int_type! {
     Unsigned {
         num_bits: 8,
-        parameters: Radix {
-            num_block: 4,
-        },
     }
 }
 
 static_int_type! {
     Unsigned {
         num_bits: 10,
-        parameters: Radix {
-            num_block: 5,
-        },
     }
 }
 
 static_int_type! {
     Unsigned {
         num_bits: 12,
-        parameters: Radix {
-            num_block: 6,
-        },
     }
 }
 
 static_int_type! {
     Unsigned {
         num_bits: 14,
-        parameters: Radix {
-            num_block: 7,
-        },
     }
 }
 
 static_int_type! {
     Unsigned {
         num_bits: 16,
-        parameters: Radix {
-            num_block: 8,
-        },
+    }
+}
+
+static_int_type! {
+    Unsigned {
+        num_bits: 24,
     }
 }
 
 static_int_type! {
     Unsigned {
         num_bits: 32,
-        parameters: Radix {
-            num_block: 16,
-        },
+    }
+}
+
+static_int_type! {
+    Unsigned {
+        num_bits: 48,
     }
 }
 
 static_int_type! {
     Unsigned {
         num_bits: 64,
-        parameters: Radix {
-            num_block: 32,
-        },
     }
 }
 
 static_int_type! {
     Unsigned {
         num_bits: 128,
-        parameters: Radix {
-            num_block: 64,
-        },
     }
 }
 
 static_int_type! {
     Unsigned {
         num_bits: 256,
-        parameters: Radix {
-            num_block: 128,
-        },
     }
 }
 
 static_int_type! {
-   Signed {
+    Unsigned {
+        num_bits: 512,
+    }
+}
+
+static_int_type! {
+    Signed {
         num_bits: 8,
-        parameters: Radix {
-            num_block: 4,
-        },
     }
 }
 
 static_int_type! {
-   Signed {
+    Signed {
         num_bits: 16,
-        parameters: Radix {
-            num_block: 8,
-        },
     }
 }
 
 static_int_type! {
-   Signed {
+    Signed {
         num_bits: 32,
-        parameters: Radix {
-            num_block: 16,
-        },
     }
 }
 
 static_int_type! {
-   Signed {
+    Signed {
         num_bits: 64,
-        parameters: Radix {
-            num_block: 32,
-        },
     }
 }
 
 static_int_type! {
-   Signed {
+    Signed {
         num_bits: 128,
-        parameters: Radix {
-            num_block: 64,
-        },
     }
 }
 
 static_int_type! {
-   Signed {
+    Signed {
         num_bits: 256,
-        parameters: Radix {
-            num_block: 128,
-        },
     }
 }
@@ -0,0 +1,179 @@
+//! Chunked serialization of the high level API server keys.
+//!
+//! The keys are written with the format described in
+//! [crate::shortint::server_key::chunked_serialization], using the [Named](crate::named::Named)
+//! type name of the high level API keys.
+//!
+//! The WoPBS key used for function evaluation on integers is not part of the format,
+//! so keys generated from a [Config] enabling it are not supported.
+
+use super::{CompressedServerKey, ServerKey};
+use crate::high_level_api::config::Config;
+use crate::high_level_api::integers::{IntegerCompressedServerKey, IntegerServerKey};
+use crate::shortint::server_key::chunked_serialization as shortint_chunked;
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+const WOPBS_NOT_SUPPORTED: &str =
+    "Chunked serialization does not support server keys with function evaluation on integers";
+
+/// Serializes a [ServerKey] into a [writer](std::io::Write) using the chunked format.
+///
+/// Returns an error if the key was generated with function evaluation on integers enabled.
+pub fn safe_serialize_chunked_server_key(
+    server_key: &ServerKey,
+    writer: impl Write,
+) -> Result<(), String> {
+    if server_key.integer_key.wopbs_key.is_some() {
+        return Err(WOPBS_NOT_SUPPORTED.to_owned());
+    }
+    shortint_chunked::serialize_server_key_as::<ServerKey>(&server_key.integer_key.key.key, writer)
+        .map_err(|err| err.to_string())
+}
+
+/// Serializes a [CompressedServerKey] into a [writer](std::io::Write) using the chunked format.
+pub fn safe_serialize_chunked_compressed_server_key(
+    server_key: &CompressedServerKey,
+    writer: impl Write,
+) -> Result<(), String> {
+    shortint_chunked::serialize_compressed_server_key_as::<CompressedServerKey>(
+        &server_key.integer_key.key.key,
+        writer,
+    )
+    .map_err(|err| err.to_string())
+}
+
+/// Deserializes a [ServerKey] serialized by [safe_serialize_chunked_server_key]
+/// from a [reader](std::io::Read), checking it was generated with the parameters of the
+/// given [Config].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{
+///     generate_keys, safe_deserialize_chunked_server_key, safe_serialize_chunked_server_key,
+///     set_server_key, ConfigBuilder, FheUint8,
+/// };
+///
+/// let config = ConfigBuilder::default().build();
+/// let (client_key, server_key) = generate_keys(config.clone());
+///
+/// let mut buffer = vec![];
+/// safe_serialize_chunked_server_key(&server_key, &mut buffer).unwrap();
+///
+/// let server_key = safe_deserialize_chunked_server_key(buffer.as_slice(), &config).unwrap();
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(100u8, &client_key);
+/// let c: u8 = (a + b).decrypt(&client_key);
+/// assert_eq!(c, 127);
+/// ```
+pub fn safe_deserialize_chunked_server_key(
+    reader: impl Read,
+    config: &Config,
+) -> Result<ServerKey, String> {
+    if config.inner.wopbs_block_parameters.is_some() {
+        return Err(WOPBS_NOT_SUPPORTED.to_owned());
+    }
+    let key = shortint_chunked::deserialize_server_key_as::<ServerKey>(
+        reader,
+        &config.inner.block_parameters,
+    )?;
+    Ok(ServerKey {
+        integer_key: Arc::new(IntegerServerKey {
+            key: crate::integer::ServerKey { key },
+            wopbs_key: None,
+        }),
+    })
+}
+
+/// Deserializes a [CompressedServerKey] serialized by
+/// [safe_serialize_chunked_compressed_server_key] from a [reader](std::io::Read),
+/// checking it was generated with the parameters of the given [Config].
+pub fn safe_deserialize_chunked_compressed_server_key(
+    reader: impl Read,
+    config: &Config,
+) -> Result<CompressedServerKey, String> {
+    let key = shortint_chunked::deserialize_compressed_server_key_as::<CompressedServerKey>(
+        reader,
+        &config.inner.block_parameters,
+    )?;
+    Ok(CompressedServerKey {
+        integer_key: IntegerCompressedServerKey {
+            key: crate::integer::CompressedServerKey { key },
+        },
+    })
+}
+
+/// Deserializes a [CompressedServerKey] serialized by
+/// [safe_serialize_chunked_compressed_server_key] from a [reader](std::io::Read),
+/// and decompresses it into a [ServerKey].
+///
+/// See [shortint_chunked::safe_deserialize_chunked_compressed_server_key_and_decompress] for the
+/// memory usage of the decompression.
+pub fn safe_deserialize_chunked_compressed_server_key_and_decompress(
+    reader: impl Read,
+    config: &Config,
+) -> Result<ServerKey, String> {
+    let key = shortint_chunked::deserialize_compressed_server_key_and_decompress_as::<
+        CompressedServerKey,
+    >(reader, &config.inner.block_parameters)?;
+    Ok(ServerKey {
+        integer_key: Arc::new(IntegerServerKey {
+            key: crate::integer::ServerKey { key },
+            wopbs_key: None,
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high_level_api::ClientKey;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use crate::ConfigBuilder;
+
+    #[test]
+    fn test_chunked_hl_server_key_serialization() {
+        let config = ConfigBuilder::default()
+            .use_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS, None)
+            .build();
+        let client_key = ClientKey::generate(config.clone());
+        let compressed_sks = CompressedServerKey::new(&client_key);
+        let sks = compressed_sks.clone().decompress();
+
+        let mut buffer = vec![];
+        safe_serialize_chunked_server_key(&sks, &mut buffer).unwrap();
+        let deserialized = safe_deserialize_chunked_server_key(buffer.as_slice(), &config).unwrap();
+        assert_eq!(deserialized.integer_key.key.key, sks.integer_key.key.key);
+
+        // A high level API key is not an integer key
+        let result =
+            crate::integer::server_key::chunked_serialization::safe_deserialize_chunked_server_key(
+                buffer.as_slice(),
+                &config.inner.block_parameters,
+            );
+        let Err(err) = result else {
+            panic!("A high level API key was deserialized as an integer key")
+        };
+        assert!(err.contains("expected type"), "{err}");
+
+        let mut buffer = vec![];
+        safe_serialize_chunked_compressed_server_key(&compressed_sks, &mut buffer).unwrap();
+        let deserialized =
+            safe_deserialize_chunked_compressed_server_key(buffer.as_slice(), &config).unwrap();
+        assert_eq!(
+            deserialized.integer_key.key.key,
+            compressed_sks.integer_key.key.key
+        );
+
+        let decompressed = safe_deserialize_chunked_compressed_server_key_and_decompress(
+            buffer.as_slice(),
+            &config,
+        )
+        .unwrap();
+        assert_eq!(decompressed.integer_key.key.key, sks.integer_key.key.key);
+    }
+}
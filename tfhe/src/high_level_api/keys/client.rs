@@ -5,7 +5,9 @@
 use concrete_csprng::seeders::Seed;
 
 use crate::high_level_api::config::Config;
-use crate::high_level_api::integers::IntegerClientKey;
+use crate::high_level_api::integers::{GenericInteger, IntegerClientKey, IntegerId};
+use crate::high_level_api::traits::FheDecrypt;
+use crate::integer::ciphertext::IntegerCiphertext;
 
 use super::{CompressedServerKey, ServerKey};
 
@@ -36,6 +38,52 @@ impl ClientKey {
         }
     }
 
+    /// Decrypts an integer and measures the noise of each of its blocks.
+    ///
+    /// The noise of each block is given as the base 2 logarithm of its absolute value, see
+    /// [`crate::shortint::ClientKey::decrypt_with_noise`]. This is meant to debug circuits whose
+    /// results are wrong, by telling whether the noise of some blocks grew too large.
+    ///
+    /// Trivial blocks (e.g. of a [trivially encrypted](crate::prelude::FheTryTrivialEncrypt)
+    /// integer) have no noise, their noise is [`f64::NEG_INFINITY`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(27u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    /// let c = a + b;
+    ///
+    /// let (decrypted, noise_bits): (u8, _) = client_key.decrypt_with_noise(&c);
+    /// assert_eq!(decrypted, 127);
+    /// assert!(noise_bits.iter().all(|&bits| bits < 58.0));
+    ///
+    /// let d = FheUint8::encrypt_trivial(27u8);
+    /// let (decrypted, noise_bits): (u8, _) = client_key.decrypt_with_noise(&d);
+    /// assert_eq!(decrypted, 27);
+    /// assert!(noise_bits.iter().all(|&bits| bits == f64::NEG_INFINITY));
+    /// ```
+    pub fn decrypt_with_noise<Id, ClearType>(
+        &self,
+        ciphertext: &GenericInteger<Id>,
+    ) -> (ClearType, Vec<f64>)
+    where
+        Id: IntegerId,
+        GenericInteger<Id>: FheDecrypt<ClearType>,
+    {
+        let noise_bits = self
+            .key
+            .key
+            .blocks_noise_bits(ciphertext.ciphertext.blocks());
+        (ciphertext.decrypt(self), noise_bits)
+    }
+
     /// Generates a new ServerKey
     ///
     /// The `ServerKey` generated is meant to be used to initialize the global state
@@ -0,0 +1,193 @@
+//! This module defines KeySwitchingKey
+//!
+//! - [KeySwitchingKey] allows switching ciphertexts from one set of keys to another, possibly using
+//!   different parameters.
+
+use crate::high_level_api::errors::{KeySwitchingError, UnwrapResultExt};
+use crate::high_level_api::keys::{ClientKey, ServerKey};
+use crate::integer::IntegerCiphertext;
+use crate::shortint::parameters::ShortintKeySwitchingParameters;
+
+use rayon::prelude::*;
+
+/// Key used to switch ciphertexts from one set of keys to another.
+///
+/// The key is generated using the client and server keys of both the source
+/// and the destination, it is then meant to be sent to the server.
+///
+/// Both sets of keys must use the same message modulus and carry modulus,
+/// but the other parameters (e.g. the LWE dimension) may differ, which allows
+/// e.g. moving ciphertexts encrypted with small compact public key parameters
+/// to bigger computation parameters.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct KeySwitchingKey {
+    key: crate::integer::key_switching_key::KeySwitchingKey,
+}
+
+impl KeySwitchingKey {
+    /// Creates a new key switching key, from the key pair `key_pair_from`
+    /// to the key pair `key_pair_to`.
+    ///
+    /// The key switching parameters of the destination keys are used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key pairs are not compatible, see [Self::try_new].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, KeySwitchingKey};
+    ///
+    /// let config_a = ConfigBuilder::default()
+    ///     .use_custom_parameters(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS, None)
+    ///     .build();
+    /// let (client_key_a, server_key_a) = generate_keys(config_a);
+    ///
+    /// let config_b = ConfigBuilder::default().build();
+    /// let (client_key_b, server_key_b) = generate_keys(config_b);
+    ///
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&client_key_a, &server_key_a),
+    ///     (&client_key_b, &server_key_b),
+    /// );
+    ///
+    /// let a = FheUint8::encrypt(142u8, &client_key_a);
+    /// let b = a.keyswitch(&ksk);
+    ///
+    /// set_server_key(server_key_b);
+    /// let c = &b + &b;
+    ///
+    /// let decrypted: u8 = c.decrypt(&client_key_b);
+    /// assert_eq!(decrypted, 142u8.wrapping_add(142u8));
+    /// ```
+    pub fn new(
+        key_pair_from: (&ClientKey, &ServerKey),
+        key_pair_to: (&ClientKey, &ServerKey),
+    ) -> Self {
+        Self::try_new(key_pair_from, key_pair_to).unwrap_display()
+    }
+
+    /// Creates a new key switching key, from the key pair `key_pair_from`
+    /// to the key pair `key_pair_to`.
+    ///
+    /// The key switching parameters of the destination keys are used.
+    ///
+    /// Returns an error if the key pairs do not use the same message modulus
+    /// and carry modulus.
+    pub fn try_new(
+        key_pair_from: (&ClientKey, &ServerKey),
+        key_pair_to: (&ClientKey, &ServerKey),
+    ) -> Result<Self, KeySwitchingError> {
+        let params_to = key_pair_to.0.key.key.parameters();
+        let params =
+            ShortintKeySwitchingParameters::new(params_to.ks_base_log(), params_to.ks_level());
+        Self::try_new_with_parameters(key_pair_from, key_pair_to, params)
+    }
+
+    /// Creates a new key switching key, from the key pair `key_pair_from`
+    /// to the key pair `key_pair_to`, using the given key switching parameters.
+    ///
+    /// Returns an error if the key pairs do not use the same message modulus
+    /// and carry modulus.
+    ///
+    /// # Warning
+    ///
+    /// Failing to fix the parameters properly would yield incorrect and insecure computation.
+    pub fn try_new_with_parameters(
+        key_pair_from: (&ClientKey, &ServerKey),
+        key_pair_to: (&ClientKey, &ServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Result<Self, KeySwitchingError> {
+        let params_from = key_pair_from.0.key.key.parameters();
+        let params_to = key_pair_to.0.key.key.parameters();
+
+        if params_from.message_modulus() != params_to.message_modulus() {
+            return Err(KeySwitchingError::MessageModulusMismatch(
+                params_from.message_modulus(),
+                params_to.message_modulus(),
+            ));
+        }
+        if params_from.carry_modulus() != params_to.carry_modulus() {
+            return Err(KeySwitchingError::CarryModulusMismatch(
+                params_from.carry_modulus(),
+                params_to.carry_modulus(),
+            ));
+        }
+
+        let key = crate::integer::key_switching_key::KeySwitchingKey::new(
+            (&key_pair_from.0.key.key, &key_pair_from.1.integer_key.key),
+            (&key_pair_to.0.key.key, &key_pair_to.1.integer_key.key),
+            params,
+        );
+
+        Ok(Self { key })
+    }
+
+    /// Checks that the ciphertext can be key switched with this key,
+    /// i.e. that it is encrypted under the source key.
+    fn check_ciphertext<T>(&self, ct: &T) -> Result<(), KeySwitchingError>
+    where
+        T: IntegerCiphertext,
+    {
+        let shortint_key = &self.key.key;
+        let expected_lwe_dimension = shortint_key.key_switching_key.input_key_lwe_dimension();
+        let expected_message_modulus = shortint_key.src_server_key.message_modulus;
+        let expected_carry_modulus = shortint_key.src_server_key.carry_modulus;
+
+        for block in ct.blocks() {
+            if block.message_modulus != expected_message_modulus {
+                return Err(KeySwitchingError::MessageModulusMismatch(
+                    expected_message_modulus,
+                    block.message_modulus,
+                ));
+            }
+            if block.carry_modulus != expected_carry_modulus {
+                return Err(KeySwitchingError::CarryModulusMismatch(
+                    expected_carry_modulus,
+                    block.carry_modulus,
+                ));
+            }
+            let lwe_dimension = block.ct.lwe_size().to_lwe_dimension();
+            if lwe_dimension != expected_lwe_dimension {
+                return Err(KeySwitchingError::LweDimensionMismatch(
+                    expected_lwe_dimension,
+                    lwe_dimension,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Switches the keys of an integer ciphertext,
+    /// after checking it is encrypted under the source key.
+    ///
+    /// The blocks are bootstrapped once switched, as the noise added by the key switch
+    /// is too large for them to be used in bivariate operations.
+    pub(in crate::high_level_api) fn try_cast<T>(&self, ct: &T) -> Result<T, KeySwitchingError>
+    where
+        T: IntegerCiphertext,
+    {
+        self.check_ciphertext(ct)?;
+
+        let mut result = self.key.cast(ct);
+
+        // The key switch does not change the encoding, so the switched blocks
+        // keep the degrees of the input blocks
+        let dest_server_key = &self.key.key.dest_server_key;
+        let identity_lut = dest_server_key.generate_lookup_table(|x| x);
+        result
+            .blocks_mut()
+            .par_iter_mut()
+            .zip(ct.blocks().par_iter())
+            .for_each(|(block, input_block)| {
+                dest_server_key.apply_lookup_table_assign(block, &identity_lut);
+                block.degree = input_block.degree;
+            });
+
+        Ok(result)
+    }
+}
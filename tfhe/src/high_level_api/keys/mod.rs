@@ -1,11 +1,24 @@
+#[cfg(feature = "safe-deserialization")]
+mod chunked_serialization;
 #[macro_use]
 mod client;
 #[macro_use]
 mod public;
+mod key_switching_key;
 mod server;
+#[cfg(feature = "threshold-decryption-experimental")]
+pub mod threshold;
 
 use crate::high_level_api::config::Config;
+#[cfg(feature = "safe-deserialization")]
+pub use chunked_serialization::{
+    safe_deserialize_chunked_compressed_server_key,
+    safe_deserialize_chunked_compressed_server_key_and_decompress,
+    safe_deserialize_chunked_server_key, safe_serialize_chunked_compressed_server_key,
+    safe_serialize_chunked_server_key,
+};
 pub use client::ClientKey;
+pub use key_switching_key::KeySwitchingKey;
 pub use public::{CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey};
 pub use server::{CompressedServerKey, ServerKey};
 
@@ -1,4 +1,5 @@
 use crate::high_level_api::integers::{IntegerCompressedServerKey, IntegerServerKey};
+use crate::named::Named;
 
 use std::sync::Arc;
 
@@ -19,6 +20,10 @@ pub struct ServerKey {
     pub(crate) integer_key: Arc<IntegerServerKey>,
 }
 
+impl Named for ServerKey {
+    const NAME: &'static str = "high_level_api::ServerKey";
+}
+
 impl ServerKey {
     pub fn new(keys: &ClientKey) -> Self {
         Self {
@@ -84,6 +89,10 @@ pub struct CompressedServerKey {
     pub(crate) integer_key: IntegerCompressedServerKey,
 }
 
+impl Named for CompressedServerKey {
+    const NAME: &'static str = "high_level_api::CompressedServerKey";
+}
+
 impl CompressedServerKey {
     pub fn new(keys: &ClientKey) -> Self {
         Self {
@@ -0,0 +1,182 @@
+//! This module defines threshold decryption for the high level API.
+//!
+//! - [ClientKey::generate_threshold_keys] generates a [NoiseSquashingKey] and splits its secret key
+//!   in one [ThresholdClientKeyShare] per party;
+//! - the server squashes the noise of a ciphertext with [NoiseSquashingKey::squash];
+//! - each participating party computes a [PartialDecryptionShare] of the [SquashedNoiseFheUint]
+//!   with [ThresholdClientKeyShare::partial_decrypt];
+//! - [combine_partial_decryptions] recovers the clear value from the shares of all participants.
+//!
+//! See [crate::shortint::threshold] for the description of the secret sharing scheme and of the
+//! smudging noise.
+
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::integers::{GenericInteger, IntegerId};
+use crate::integer::block_decomposition::RecomposableFrom;
+use crate::integer::noise_squashing::{
+    NoiseSquashingKey as IntegerNoiseSquashingKey, SquashedNoiseRadixCiphertext,
+};
+use crate::integer::threshold::{
+    combine_radix_partial_decryptions, RadixPartialDecryptionShare, ThresholdSecretKeyShare,
+};
+use crate::integer::RadixCiphertext;
+use crate::shortint::parameters::NoiseSquashingParameters;
+pub use crate::shortint::threshold::{PartyId, ThresholdParameters};
+use serde::{Deserialize, Serialize};
+
+use super::ClientKey;
+
+/// A key to squash the noise of ciphertexts before their threshold decryption.
+///
+/// It is generated by the client and is meant to be published.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingKey {
+    pub(crate) key: IntegerNoiseSquashingKey,
+}
+
+/// An unsigned integer ciphertext whose noise was squashed by a [NoiseSquashingKey], it can only
+/// be decrypted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SquashedNoiseFheUint {
+    pub(crate) inner: SquashedNoiseRadixCiphertext,
+}
+
+/// The share of the secret key of a [NoiseSquashingKey] held by one party, it must be kept
+/// secret.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThresholdClientKeyShare {
+    pub(crate) key: ThresholdSecretKeyShare,
+}
+
+/// The contribution of one party to the decryption of a ciphertext.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialDecryptionShare {
+    pub(crate) share: RadixPartialDecryptionShare,
+}
+
+impl ClientKey {
+    /// Generate a [NoiseSquashingKey] for the keys of this client key and secret share its secret
+    /// key among the parties described by `params`, returning the share of each party in order.
+    ///
+    /// The shares are generated by the holder of the client key acting as a trusted dealer, the
+    /// client key must be discarded once the shares have been distributed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::threshold::{combine_partial_decryptions, PartyId, ThresholdParameters};
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(27u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    /// let c = a + b;
+    ///
+    /// // 2 out of 3 parties are needed to decrypt
+    /// let (noise_squashing_key, key_shares) = client_key.generate_threshold_keys(
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+    ///     ThresholdParameters::new(3, 2),
+    /// );
+    /// drop(client_key);
+    ///
+    /// let squashed = noise_squashing_key.squash(&c);
+    ///
+    /// let participants = [PartyId(0), PartyId(1)];
+    /// let partial_decryptions: Vec<_> = participants
+    ///     .iter()
+    ///     .map(|party| key_shares[party.0].partial_decrypt(&squashed, &participants))
+    ///     .collect();
+    ///
+    /// let decrypted: u8 = combine_partial_decryptions(&squashed, &partial_decryptions);
+    /// assert_eq!(decrypted, 127);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// See [crate::shortint::ClientKey::new_noise_squashing_private_key].
+    pub fn generate_threshold_keys(
+        &self,
+        noise_squashing_params: NoiseSquashingParameters,
+        params: ThresholdParameters,
+    ) -> (NoiseSquashingKey, Vec<ThresholdClientKeyShare>) {
+        let client_key = &self.key.key;
+        let private_key = client_key.new_noise_squashing_private_key(noise_squashing_params);
+        let noise_squashing_key = NoiseSquashingKey {
+            key: client_key.new_noise_squashing_key(&private_key),
+        };
+        let key_shares = private_key
+            .generate_threshold_key_shares(params)
+            .into_iter()
+            .map(|key| ThresholdClientKeyShare { key })
+            .collect();
+
+        (noise_squashing_key, key_shares)
+    }
+}
+
+impl NoiseSquashingKey {
+    /// Squash the noise of `ciphertext`, using the server key set in the current thread.
+    ///
+    /// See [ClientKey::generate_threshold_keys] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no server key is set, or if the noise squashing key was not generated for the
+    /// keys of the server key.
+    pub fn squash<Id>(&self, ciphertext: &GenericInteger<Id>) -> SquashedNoiseFheUint
+    where
+        Id: IntegerId<InnerCiphertext = RadixCiphertext>,
+    {
+        with_internal_keys(|keys| SquashedNoiseFheUint {
+            inner: self
+                .key
+                .squash_radix_ciphertext_noise(&ciphertext.ciphertext, keys.integer_key.pbs_key()),
+        })
+    }
+}
+
+impl ThresholdClientKeyShare {
+    pub fn party(&self) -> PartyId {
+        self.key.party()
+    }
+
+    /// Compute the contribution of this party to the decryption of `ciphertext` by the
+    /// `participants`.
+    ///
+    /// See [ClientKey::generate_threshold_keys] for usage.
+    pub fn partial_decrypt(
+        &self,
+        ciphertext: &SquashedNoiseFheUint,
+        participants: &[PartyId],
+    ) -> PartialDecryptionShare {
+        PartialDecryptionShare {
+            share: self
+                .key
+                .partial_decrypt_radix(&ciphertext.inner, participants),
+        }
+    }
+}
+
+/// Combine the partial decryptions of all the participants to recover the clear value encrypted
+/// in `ciphertext`.
+///
+/// See [ClientKey::generate_threshold_keys] for usage.
+///
+/// # Panics
+///
+/// Panics if the shares do not come from exactly the participants they were computed for.
+pub fn combine_partial_decryptions<ClearType>(
+    ciphertext: &SquashedNoiseFheUint,
+    shares: &[PartialDecryptionShare],
+) -> ClearType
+where
+    ClearType: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    let shares: Vec<_> = shares.iter().map(|share| share.share.clone()).collect();
+    combine_radix_partial_decryptions(&ciphertext.inner, &shares)
+}
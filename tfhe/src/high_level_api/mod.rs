@@ -15,6 +15,7 @@ macro_rules! expand_pub_use_fhe_type(
                     [<Compressed $fhe_type_name>],
                     [<Compact $fhe_type_name>],
                     [<Compact $fhe_type_name List>],
+                    [<$fhe_type_name Array>],
                 )*
             };
 
@@ -23,27 +24,49 @@ macro_rules! expand_pub_use_fhe_type(
 );
 
 pub use crate::core_crypto::commons::math::random::Seed;
+#[cfg(feature = "zk-pok-experimental")]
+pub use compact_list::ProvenCompactCiphertextList;
+pub use compact_list::{
+    CompactCiphertextList, CompactCiphertextListBuilder, CompactCiphertextListConformanceParams,
+    CompactCiphertextListExpander, Compactable, Expandable,
+};
 pub use config::{Config, ConfigBuilder};
-pub use errors::{Error, OutOfRangeError};
+pub use errors::{Error, KeySwitchingError, OutOfRangeError};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
+#[cfg(feature = "threshold-decryption-experimental")]
+pub use keys::threshold;
 pub use keys::{
     generate_keys, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
-    CompressedServerKey, PublicKey, ServerKey,
+    CompressedServerKey, KeySwitchingKey, PublicKey, ServerKey,
+};
+#[cfg(feature = "safe-deserialization")]
+pub use keys::{
+    safe_deserialize_chunked_compressed_server_key,
+    safe_deserialize_chunked_compressed_server_key_and_decompress,
+    safe_deserialize_chunked_server_key, safe_serialize_chunked_compressed_server_key,
+    safe_serialize_chunked_server_key,
 };
 
 #[cfg(test)]
 mod tests;
 
+pub use crate::high_level_api::array::FheArray;
 pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool};
 expand_pub_use_fhe_type!(
     pub use crate::high_level_api::integers{
-        FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128,
-        FheUint256, FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256
+        FheUint, FheInt, FheUint2, FheUint4, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16,
+        FheUint24, FheUint32, FheUint48, FheUint64, FheUint128, FheUint256, FheUint512, FheInt8,
+        FheInt16, FheInt32, FheInt64, FheInt128, FheInt256
     };
 );
+pub use crate::high_level_api::integers::{FheIntId, FheUintCrt, FheUintId};
+pub use crate::high_level_api::strings::{FheAsciiString, StringPattern};
+#[cfg(all(feature = "safe-deserialization", feature = "zk-pok-experimental"))]
+pub use integers::safe_serialize::safe_deserialize_conformant_proven_compact_list;
 #[cfg(feature = "safe-deserialization")]
 pub use integers::safe_serialize::{
     safe_deserialize_conformant, safe_deserialize_conformant_compact_integer,
+    safe_deserialize_conformant_compact_integer_list, safe_deserialize_conformant_compact_list,
     safe_deserialize_conformant_compressed_integer, safe_deserialize_conformant_integer,
     safe_serialize,
 };
@@ -56,9 +79,12 @@ mod config;
 mod internal_traits;
 mod traits;
 
+mod array;
 mod booleans;
+mod compact_list;
 pub mod errors;
 mod integers;
+mod strings;
 
 /// The tfhe prelude.
 pub mod prelude;
@@ -8,8 +8,8 @@
 pub use crate::high_level_api::traits::{
     DivRem, DynamicFheEncryptor, DynamicFheTrivialEncryptor, DynamicFheTryEncryptor, FheBootstrap,
     FheDecrypt, FheEncrypt, FheEq, FheMax, FheMin, FheNumberConstant, FheOrd, FheTrivialEncrypt,
-    FheTryEncrypt, FheTryTrivialEncrypt, RotateLeft, RotateLeftAssign, RotateRight,
-    RotateRightAssign,
+    FheTryEncrypt, FheTryTrivialEncrypt, OverflowingMul, Pow, RotateLeft, RotateLeftAssign,
+    RotateRight, RotateRightAssign,
 };
 
 pub use crate::core_crypto::prelude::{CastFrom, CastInto};
@@ -0,0 +1,957 @@
+//! Encrypted ASCII strings.
+//!
+//! The [FheAsciiString] type stores a string as a list of encrypted bytes ([FheUint8]),
+//! and supports searching, comparing and transforming it without decrypting it.
+//!
+//! To hide the length of the string, it can be encrypted with padding:
+//! the string is followed by encrypted null characters, so the server only learns
+//! an upper bound of the length.
+use std::ops::Add;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::high_level_api::array::tree_reduce;
+use crate::high_level_api::errors::Error;
+use crate::high_level_api::integers::{FheUintId, IntegerId};
+use crate::high_level_api::traits::{FheDecrypt, FheEq, FheOrd, FheTryEncrypt};
+use crate::high_level_api::ClientKey;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use crate::{FheBool, FheUint32, FheUint8};
+
+#[cfg(test)]
+mod tests;
+
+/// An encrypted ASCII string.
+///
+/// Each character is encrypted in a [FheUint8]. A string may be *padded*,
+/// in which case its characters are followed by an unknown number of encrypted
+/// null characters (`'\0'`), which are not part of the string.
+/// Because of this, strings to encrypt must not contain null characters.
+///
+/// Operations that take a pattern accept both encrypted strings and clear strings,
+/// see [StringPattern].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let string = FheAsciiString::try_encrypt_with_padding("Hello", 2, &client_key).unwrap();
+/// let pattern = FheAsciiString::encrypt("ell", &client_key);
+///
+/// let contains = string.contains(&pattern);
+/// assert!(contains.decrypt(&client_key));
+///
+/// let starts_with = string.starts_with("Hel");
+/// assert!(starts_with.decrypt(&client_key));
+///
+/// let upper = string.to_uppercase();
+/// let decrypted: String = upper.decrypt(&client_key);
+/// assert_eq!(decrypted, "HELLO");
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheAsciiString {
+    chars: Vec<FheUint8>,
+    padded: bool,
+}
+
+/// A pattern used to search in or compare with an [FheAsciiString].
+///
+/// It is either an encrypted string or a clear string.
+#[derive(Copy, Clone)]
+pub enum StringPattern<'a> {
+    Clear(&'a str),
+    Encrypted(&'a FheAsciiString),
+}
+
+impl<'a> From<&'a str> for StringPattern<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Clear(value)
+    }
+}
+
+impl<'a> From<&'a String> for StringPattern<'a> {
+    fn from(value: &'a String) -> Self {
+        Self::Clear(value.as_str())
+    }
+}
+
+impl<'a> From<&'a FheAsciiString> for StringPattern<'a> {
+    fn from(value: &'a FheAsciiString) -> Self {
+        Self::Encrypted(value)
+    }
+}
+
+/// A character of a pattern, as seen by the server
+enum PatternChar<'a> {
+    Clear(u8),
+    Encrypted(&'a RadixCiphertext),
+}
+
+impl FheAsciiString {
+    /// Encrypts the string, followed by `padding` null characters.
+    ///
+    /// Returns an error if the string contains non ASCII or null characters.
+    pub fn try_encrypt_with_padding(
+        value: &str,
+        padding: usize,
+        key: &ClientKey,
+    ) -> Result<Self, Error> {
+        if !value.is_ascii() || value.contains('\0') {
+            return Err(Error::InvalidAsciiString);
+        }
+
+        let chars = value
+            .bytes()
+            .chain(std::iter::repeat(0).take(padding))
+            .map(|byte| FheUint8::try_encrypt(byte, key))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            chars,
+            padded: padding > 0,
+        })
+    }
+
+    /// Returns the encrypted characters, including the padding ones.
+    pub fn chars(&self) -> &[FheUint8] {
+        &self.chars
+    }
+
+    /// Returns whether the string may be followed by padding null characters.
+    ///
+    /// Results of operations that change the length of the string by an encrypted
+    /// amount (e.g. [FheAsciiString::trim]) are padded.
+    pub fn is_padded(&self) -> bool {
+        self.padded
+    }
+
+    /// Returns the encrypted length of the string, padding excluded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt_with_padding("tfhe", 3, &client_key).unwrap();
+    ///
+    /// let len = string.len();
+    /// let decrypted: u32 = len.decrypt(&client_key);
+    /// assert_eq!(decrypted, 4);
+    /// ```
+    pub fn len(&self) -> FheUint32 {
+        with_server_key(|sks| {
+            let num_blocks = FheUintId::<32>::num_blocks(sks.message_modulus());
+            let num_chars = FheUint32::new(
+                sks.create_trivial_radix(self.chars.len() as u64, num_blocks),
+                FheUintId::<32>,
+            );
+            if !self.padded {
+                return num_chars;
+            }
+
+            // The length is the position of the first padding character, if any
+            let zeros = self
+                .ciphertexts()
+                .par_iter()
+                .map(|c| sks.scalar_eq_parallelized(*c, 0u8))
+                .collect::<Vec<_>>();
+            let (index, found) = first_true_index(sks, zeros);
+            let index = FheUint32::from_unsigned_radix(sks, index);
+            let length =
+                sks.if_then_else_parallelized(&found, &index.ciphertext, &num_chars.ciphertext);
+            FheUint32::new(length, FheUintId::<32>)
+        })
+    }
+
+    /// Returns an encrypted boolean that is true if the string is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt_with_padding("", 2, &client_key).unwrap();
+    ///
+    /// let is_empty = string.is_empty();
+    /// assert!(is_empty.decrypt(&client_key));
+    /// ```
+    pub fn is_empty(&self) -> FheBool {
+        let result = with_server_key(|sks| match self.chars.first() {
+            Some(first) if self.padded => sks.scalar_eq_parallelized(&first.ciphertext, 0u8),
+            Some(_) => sks.create_trivial_boolean_block(false),
+            None => sks.create_trivial_boolean_block(true),
+        });
+        FheBool::new(result)
+    }
+
+    /// Returns an encrypted boolean that is true if the string starts with the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::encrypt("homomorphic", &client_key);
+    ///
+    /// assert!(string.starts_with("homo").decrypt(&client_key));
+    /// assert!(!string.starts_with("morph").decrypt(&client_key));
+    /// ```
+    pub fn starts_with<'a>(&self, pattern: impl Into<StringPattern<'a>>) -> FheBool {
+        let pattern = pattern.into();
+        let result = with_server_key(|sks| {
+            let chars = self.ciphertexts();
+            let pattern = PreparedPattern::new(sks, pattern);
+            pattern.matches_at(sks, &chars, 0)
+        });
+        FheBool::new(result)
+    }
+
+    /// Returns an encrypted boolean that is true if the string ends with the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt_with_padding("morphic", 2, &client_key).unwrap();
+    /// let pattern = FheAsciiString::encrypt("phic", &client_key);
+    ///
+    /// assert!(string.ends_with(&pattern).decrypt(&client_key));
+    /// ```
+    pub fn ends_with<'a>(&self, pattern: impl Into<StringPattern<'a>>) -> FheBool {
+        let pattern = pattern.into();
+        let result = with_server_key(|sks| {
+            // Ending with the pattern is starting with the reversed pattern,
+            // once the string is reversed
+            let chars = reverse(sks, &self.ciphertexts(), self.padded);
+            let chars = chars.iter().collect::<Vec<_>>();
+            match pattern {
+                StringPattern::Clear(clear) => {
+                    let reversed = clear.bytes().rev().collect::<Vec<_>>();
+                    PreparedPattern::new_clear(&reversed).matches_at(sks, &chars, 0)
+                }
+                StringPattern::Encrypted(encrypted) => {
+                    let reversed = reverse(sks, &encrypted.ciphertexts(), encrypted.padded);
+                    PreparedPattern::new_encrypted(sks, &reversed, encrypted.padded)
+                        .matches_at(sks, &chars, 0)
+                }
+            }
+        });
+        FheBool::new(result)
+    }
+
+    /// Returns an encrypted boolean that is true if the string contains the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::encrypt("homomorphic", &client_key);
+    ///
+    /// assert!(string.contains("morph").decrypt(&client_key));
+    /// assert!(!string.contains("graph").decrypt(&client_key));
+    /// ```
+    pub fn contains<'a>(&self, pattern: impl Into<StringPattern<'a>>) -> FheBool {
+        let pattern = pattern.into();
+        let result = with_server_key(|sks| {
+            let matches = self.match_positions(sks, pattern);
+            any(sks, matches)
+        });
+        FheBool::new(result)
+    }
+
+    /// Returns the encrypted index of the first occurrence of the pattern in the string,
+    /// and an encrypted boolean that is true if the pattern was found.
+    ///
+    /// If the pattern is not found, the returned index is an encryption of 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::encrypt("homomorphic", &client_key);
+    ///
+    /// let (index, found) = string.find("morph");
+    /// let index: u32 = index.decrypt(&client_key);
+    /// assert!(found.decrypt(&client_key));
+    /// assert_eq!(index, 4);
+    /// ```
+    pub fn find<'a>(&self, pattern: impl Into<StringPattern<'a>>) -> (FheUint32, FheBool) {
+        let pattern = pattern.into();
+        with_server_key(|sks| {
+            let matches = self.match_positions(sks, pattern);
+            let (index, found) = first_true_index(sks, matches);
+            (
+                FheUint32::from_unsigned_radix(sks, index),
+                FheBool::new(found),
+            )
+        })
+    }
+
+    /// Returns the string with ASCII uppercase letters converted to lowercase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::encrypt("Hello, World!", &client_key);
+    ///
+    /// let lower: String = string.to_lowercase().decrypt(&client_key);
+    /// assert_eq!(lower, "hello, world!");
+    /// ```
+    pub fn to_lowercase(&self) -> Self {
+        self.map_letters(b'A'..=b'Z')
+    }
+
+    /// Returns the string with ASCII lowercase letters converted to uppercase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::encrypt("Hello, World!", &client_key);
+    ///
+    /// let upper: String = string.to_uppercase().decrypt(&client_key);
+    /// assert_eq!(upper, "HELLO, WORLD!");
+    /// ```
+    pub fn to_uppercase(&self) -> Self {
+        self.map_letters(b'a'..=b'z')
+    }
+
+    /// Returns the string with leading and trailing whitespace removed.
+    ///
+    /// The result is padded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::encrypt(" \t tfhe\n", &client_key);
+    ///
+    /// let trimmed = string.trim();
+    /// assert!(trimmed.is_padded());
+    /// let trimmed: String = trimmed.decrypt(&client_key);
+    /// assert_eq!(trimmed, "tfhe");
+    /// ```
+    pub fn trim(&self) -> Self {
+        self.trim_end().trim_start()
+    }
+
+    /// Returns the string with leading whitespace removed.
+    ///
+    /// The result is padded.
+    pub fn trim_start(&self) -> Self {
+        let chars = with_server_key(|sks| {
+            let chars = self.ciphertexts();
+            let is_whitespace = chars
+                .par_iter()
+                .map(|c| is_whitespace(sks, c))
+                .collect::<Vec<_>>();
+            shift_left(sks, &chars, &is_whitespace)
+        });
+        Self::from_ciphertexts(chars, true)
+    }
+
+    /// Returns the string with trailing whitespace removed.
+    ///
+    /// The result is padded.
+    pub fn trim_end(&self) -> Self {
+        let chars = with_server_key(|sks| {
+            let chars = self.ciphertexts();
+            // A character is removed if it and all the characters after it
+            // are whitespace (or padding)
+            let removable = chars
+                .par_iter()
+                .map(|c| {
+                    let (whitespace, zero) = rayon::join(
+                        || is_whitespace(sks, c),
+                        || sks.scalar_eq_parallelized(*c, 0u8),
+                    );
+                    or(sks, &whitespace, &zero)
+                })
+                .collect::<Vec<_>>();
+            let mut to_remove = Vec::with_capacity(removable.len());
+            let mut all_removable = sks.create_trivial_boolean_block(true);
+            for flag in removable.iter().rev() {
+                all_removable = and(sks, &all_removable, flag);
+                to_remove.push(all_removable.clone());
+            }
+            to_remove.reverse();
+
+            chars
+                .par_iter()
+                .zip(to_remove.par_iter())
+                .map(|(c, remove)| zero_out_if(sks, c, remove))
+                .collect()
+        });
+        Self::from_ciphertexts(chars, true)
+    }
+
+    /// Returns the concatenation of the string and the pattern.
+    ///
+    /// The result is padded if either of the strings is padded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt_with_padding("Hello", 2, &client_key).unwrap();
+    /// let other = FheAsciiString::encrypt(", World", &client_key);
+    ///
+    /// let result = string.concat(&other).concat("!");
+    /// let decrypted: String = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, "Hello, World!");
+    /// ```
+    pub fn concat<'a>(&self, other: impl Into<StringPattern<'a>>) -> Self {
+        let other = other.into();
+        with_server_key(|sks| {
+            let (other_chars, other_padded) = match other {
+                StringPattern::Clear(clear) => {
+                    let chars = clear
+                        .bytes()
+                        .map(|byte| trivial_char(sks, byte))
+                        .collect::<Vec<_>>();
+                    (chars, false)
+                }
+                StringPattern::Encrypted(encrypted) => (
+                    encrypted
+                        .chars
+                        .iter()
+                        .map(|c| c.ciphertext.clone())
+                        .collect(),
+                    encrypted.padded,
+                ),
+            };
+
+            let mut chars = self
+                .chars
+                .iter()
+                .map(|c| c.ciphertext.clone())
+                .collect::<Vec<_>>();
+
+            if !self.padded {
+                chars.extend(other_chars);
+                return Self::from_ciphertexts(chars, other_padded);
+            }
+
+            // The other string has to be moved right after the last non padding
+            // character, whose position is encrypted
+            let num_chars = chars.len();
+            let mut shifted = other_chars;
+            shifted.resize_with(num_chars + shifted.len(), || trivial_char(sks, 0));
+            let is_not_zero = chars
+                .par_iter()
+                .map(|c| sks.scalar_ne_parallelized(c, 0u8))
+                .collect::<Vec<_>>();
+            let shifted = shift_right(sks, &shifted, &is_not_zero);
+
+            chars.resize_with(shifted.len(), || trivial_char(sks, 0));
+            let chars = chars
+                .par_iter()
+                .zip(shifted.par_iter())
+                .map(|(lhs, rhs)| sks.bitor_parallelized(lhs, rhs))
+                .collect();
+            Self::from_ciphertexts(chars, true)
+        })
+    }
+
+    fn from_ciphertexts(chars: Vec<RadixCiphertext>, padded: bool) -> Self {
+        Self {
+            chars: chars
+                .into_iter()
+                .map(|c| FheUint8::new(c, FheUintId::<8>))
+                .collect(),
+            padded,
+        }
+    }
+
+    fn ciphertexts(&self) -> Vec<&RadixCiphertext> {
+        self.chars.iter().map(|c| &c.ciphertext).collect()
+    }
+
+    /// Flips the case of the letters in the given range
+    fn map_letters(&self, letters: std::ops::RangeInclusive<u8>) -> Self {
+        let chars = with_server_key(|sks| {
+            let case_bit_position = 5;
+            self.ciphertexts()
+                .par_iter()
+                .map(|c| {
+                    let (lower_bound, upper_bound) = rayon::join(
+                        || sks.scalar_ge_parallelized(*c, *letters.start()),
+                        || sks.scalar_le_parallelized(*c, *letters.end()),
+                    );
+                    let is_letter = and(sks, &lower_bound, &upper_bound);
+                    let case_bit: RadixCiphertext = is_letter.into_radix(c.blocks.len(), sks);
+                    let case_bit = sks.scalar_left_shift_parallelized(&case_bit, case_bit_position);
+                    sks.bitxor_parallelized(*c, &case_bit)
+                })
+                .collect()
+        });
+        Self::from_ciphertexts(chars, self.padded)
+    }
+
+    /// Returns, for each position where the pattern could start,
+    /// an encrypted boolean that is true if the pattern starts there
+    fn match_positions(&self, sks: &ServerKey, pattern: StringPattern<'_>) -> Vec<BooleanBlock> {
+        let chars = self.ciphertexts();
+        let pattern = PreparedPattern::new(sks, pattern);
+
+        let min_len = pattern.min_len();
+        if min_len > chars.len() {
+            return vec![];
+        }
+        // A pattern that may be empty matches at 0, so there is no need
+        // to look for it past the last character
+        let last_position = chars.len() - min_len.max(1).min(chars.len());
+
+        (0..=last_position)
+            .into_par_iter()
+            .map(|position| pattern.matches_at(sks, &chars, position))
+            .collect()
+    }
+
+    /// Returns the characters of the string and of the other one,
+    /// as two radix ciphertexts of the same size in which the first character
+    /// is the most significant, so that comparing them compares the strings
+    /// in lexicographic order.
+    fn as_comparable_radix(
+        &self,
+        sks: &ServerKey,
+        other: StringPattern<'_>,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let other_chars = match other {
+            StringPattern::Clear(clear) => clear
+                .bytes()
+                .map(|byte| trivial_char(sks, byte))
+                .collect::<Vec<_>>(),
+            StringPattern::Encrypted(encrypted) => encrypted
+                .chars
+                .iter()
+                .map(|c| c.ciphertext.clone())
+                .collect(),
+        };
+        let chars = self
+            .chars
+            .iter()
+            .map(|c| c.ciphertext.clone())
+            .collect::<Vec<_>>();
+
+        // Missing characters are null characters, which are lower than all the others
+        let num_chars = chars.len().max(other_chars.len()).max(1);
+        let to_radix = |mut chars: Vec<RadixCiphertext>| {
+            chars.resize_with(num_chars, || trivial_char(sks, 0));
+            let blocks = chars
+                .into_iter()
+                .rev()
+                .flat_map(|c| c.blocks)
+                .collect::<Vec<_>>();
+            RadixCiphertext::from(blocks)
+        };
+
+        (to_radix(chars), to_radix(other_chars))
+    }
+}
+
+/// A pattern prepared for matching
+struct PreparedPattern<'a> {
+    chars: Vec<PatternChar<'a>>,
+    /// For padded encrypted patterns, whether each character is a padding one
+    is_padding: Option<Vec<BooleanBlock>>,
+}
+
+impl<'a> PreparedPattern<'a> {
+    fn new(sks: &ServerKey, pattern: StringPattern<'a>) -> Self {
+        match pattern {
+            StringPattern::Clear(clear) => Self::new_clear(clear.as_bytes()),
+            StringPattern::Encrypted(encrypted) => Self {
+                chars: encrypted
+                    .chars
+                    .iter()
+                    .map(|c| PatternChar::Encrypted(&c.ciphertext))
+                    .collect(),
+                is_padding: encrypted.padded.then(|| {
+                    encrypted
+                        .chars
+                        .par_iter()
+                        .map(|c| sks.scalar_eq_parallelized(&c.ciphertext, 0u8))
+                        .collect()
+                }),
+            },
+        }
+    }
+
+    fn new_clear(bytes: &[u8]) -> Self {
+        Self {
+            chars: bytes.iter().map(|byte| PatternChar::Clear(*byte)).collect(),
+            is_padding: None,
+        }
+    }
+
+    fn new_encrypted(sks: &ServerKey, chars: &'a [RadixCiphertext], padded: bool) -> Self {
+        Self {
+            chars: chars.iter().map(PatternChar::Encrypted).collect(),
+            is_padding: padded.then(|| {
+                chars
+                    .par_iter()
+                    .map(|c| sks.scalar_eq_parallelized(c, 0u8))
+                    .collect()
+            }),
+        }
+    }
+
+    /// The minimum length of the pattern
+    fn min_len(&self) -> usize {
+        if self.is_padding.is_some() {
+            0
+        } else {
+            self.chars.len()
+        }
+    }
+
+    /// Returns an encrypted boolean that is true if the pattern
+    /// is found in `chars`, starting at `position`
+    fn matches_at(
+        &self,
+        sks: &ServerKey,
+        chars: &[&RadixCiphertext],
+        position: usize,
+    ) -> BooleanBlock {
+        if position + self.min_len() > chars.len() {
+            return sks.create_trivial_boolean_block(false);
+        }
+
+        let char_matches = self
+            .chars
+            .par_iter()
+            .enumerate()
+            .map(|(i, pattern_char)| {
+                let is_equal = chars.get(position + i).map(|c| match pattern_char {
+                    PatternChar::Clear(clear) => sks.scalar_eq_parallelized(*c, *clear),
+                    PatternChar::Encrypted(encrypted) => sks.eq_parallelized(*c, *encrypted),
+                });
+                let is_padding = self.is_padding.as_ref().map(|flags| &flags[i]);
+                match (is_equal, is_padding) {
+                    (Some(is_equal), Some(is_padding)) => or(sks, &is_equal, is_padding),
+                    (Some(is_equal), None) => is_equal,
+                    (None, Some(is_padding)) => is_padding.clone(),
+                    (None, None) => sks.create_trivial_boolean_block(false),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        all(sks, char_matches)
+    }
+}
+
+fn with_server_key<R>(func: impl FnOnce(&ServerKey) -> R) -> R {
+    crate::high_level_api::global_state::with_internal_keys(|keys| func(keys.integer_key.pbs_key()))
+}
+
+fn trivial_char(sks: &ServerKey, value: u8) -> RadixCiphertext {
+    let num_blocks = FheUintId::<8>::num_blocks(sks.message_modulus());
+    sks.create_trivial_radix(value, num_blocks)
+}
+
+fn and(sks: &ServerKey, lhs: &BooleanBlock, rhs: &BooleanBlock) -> BooleanBlock {
+    BooleanBlock::new_unchecked(sks.key.bitand(lhs.as_ref(), rhs.as_ref()))
+}
+
+fn or(sks: &ServerKey, lhs: &BooleanBlock, rhs: &BooleanBlock) -> BooleanBlock {
+    BooleanBlock::new_unchecked(sks.key.bitor(lhs.as_ref(), rhs.as_ref()))
+}
+
+fn not(sks: &ServerKey, value: &BooleanBlock) -> BooleanBlock {
+    BooleanBlock::new_unchecked(sks.key.scalar_bitxor(value.as_ref(), 1))
+}
+
+fn all(sks: &ServerKey, values: Vec<BooleanBlock>) -> BooleanBlock {
+    tree_reduce(values, |lhs, rhs| and(sks, lhs, rhs))
+        .unwrap_or_else(|| sks.create_trivial_boolean_block(true))
+}
+
+fn any(sks: &ServerKey, values: Vec<BooleanBlock>) -> BooleanBlock {
+    tree_reduce(values, |lhs, rhs| or(sks, lhs, rhs))
+        .unwrap_or_else(|| sks.create_trivial_boolean_block(false))
+}
+
+/// Whitespace as defined by [str::trim]
+fn is_whitespace(sks: &ServerKey, c: &RadixCiphertext) -> BooleanBlock {
+    let ((lower_bound, upper_bound), is_space) = rayon::join(
+        || {
+            rayon::join(
+                || sks.scalar_ge_parallelized(c, b'\t'),
+                || sks.scalar_le_parallelized(c, b'\r'),
+            )
+        },
+        || sks.scalar_eq_parallelized(c, b' '),
+    );
+    or(sks, &and(sks, &lower_bound, &upper_bound), &is_space)
+}
+
+fn zero_out_if(sks: &ServerKey, c: &RadixCiphertext, condition: &BooleanBlock) -> RadixCiphertext {
+    let zero = trivial_char(sks, 0);
+    sks.if_then_else_parallelized(condition, &zero, c)
+}
+
+/// Returns the encrypted index of the first true value, and an encrypted boolean
+/// that is true if there is one.
+fn first_true_index(sks: &ServerKey, values: Vec<BooleanBlock>) -> (RadixCiphertext, BooleanBlock) {
+    if values.is_empty() {
+        return (
+            sks.create_trivial_radix(0u64, 1),
+            sks.create_trivial_boolean_block(false),
+        );
+    }
+
+    let as_radix = values
+        .iter()
+        .map(|value| value.clone().into_radix::<RadixCiphertext>(1, sks))
+        .collect::<Vec<_>>();
+    rayon::join(|| sks.argmax_parallelized(&as_radix), || any(sks, values))
+}
+
+/// Returns, for each amount in `0..=predicate.len()`, an encrypted boolean that is true
+/// if the number of leading true values in `predicate` is equal to this amount.
+fn leading_count_selectors(sks: &ServerKey, predicate: &[BooleanBlock]) -> Vec<BooleanBlock> {
+    let mut selectors = Vec::with_capacity(predicate.len() + 1);
+    let mut all_before = sks.create_trivial_boolean_block(true);
+    for value in predicate {
+        selectors.push(and(sks, &all_before, &not(sks, value)));
+        all_before = and(sks, &all_before, value);
+    }
+    selectors.push(all_before);
+    selectors
+}
+
+/// Returns the bits (least significant first) of the amount selected by the one-hot
+/// `selectors`
+fn selected_amount_bits(sks: &ServerKey, selectors: &[BooleanBlock]) -> Vec<BooleanBlock> {
+    let num_bits = usize::BITS - (selectors.len() - 1).leading_zeros();
+    (0..num_bits)
+        .into_par_iter()
+        .map(|bit| {
+            let selected = selectors
+                .iter()
+                .enumerate()
+                .filter(|(amount, _)| (amount >> bit) & 1 == 1)
+                .map(|(_, selector)| selector.clone())
+                .collect();
+            any(sks, selected)
+        })
+        .collect()
+}
+
+/// Shifts the characters to the left by the (encrypted) number of leading true values
+/// in `predicate`, filling with null characters
+fn shift_left(
+    sks: &ServerKey,
+    chars: &[&RadixCiphertext],
+    predicate: &[BooleanBlock],
+) -> Vec<RadixCiphertext> {
+    let selectors = leading_count_selectors(sks, predicate);
+    let bits = selected_amount_bits(sks, &selectors);
+
+    let mut chars = chars.iter().map(|c| (*c).clone()).collect::<Vec<_>>();
+    for (bit, condition) in bits.iter().enumerate() {
+        let shift = 1 << bit;
+        chars = (0..chars.len())
+            .into_par_iter()
+            .map(|i| {
+                chars.get(i + shift).map_or_else(
+                    || zero_out_if(sks, &chars[i], condition),
+                    |shifted| sks.if_then_else_parallelized(condition, shifted, &chars[i]),
+                )
+            })
+            .collect();
+    }
+    chars
+}
+
+/// Shifts the characters to the right by the (encrypted) number of leading true values
+/// in `predicate`, filling with null characters
+fn shift_right(
+    sks: &ServerKey,
+    chars: &[RadixCiphertext],
+    predicate: &[BooleanBlock],
+) -> Vec<RadixCiphertext> {
+    let selectors = leading_count_selectors(sks, predicate);
+    let bits = selected_amount_bits(sks, &selectors);
+
+    let mut chars = chars.to_vec();
+    for (bit, condition) in bits.iter().enumerate() {
+        let shift = 1 << bit;
+        chars = (0..chars.len())
+            .into_par_iter()
+            .map(|i| {
+                i.checked_sub(shift).map_or_else(
+                    || zero_out_if(sks, &chars[i], condition),
+                    |j| sks.if_then_else_parallelized(condition, &chars[j], &chars[i]),
+                )
+            })
+            .collect();
+    }
+    chars
+}
+
+/// Reverses the characters, padding characters are kept at the end
+fn reverse(sks: &ServerKey, chars: &[&RadixCiphertext], padded: bool) -> Vec<RadixCiphertext> {
+    let reversed = chars.iter().rev().copied().collect::<Vec<_>>();
+    if !padded {
+        return reversed.into_iter().cloned().collect();
+    }
+
+    let is_zero = reversed
+        .par_iter()
+        .map(|c| sks.scalar_eq_parallelized(*c, 0u8))
+        .collect::<Vec<_>>();
+    shift_left(sks, &reversed, &is_zero)
+}
+
+impl<'a> FheTryEncrypt<&'a str, ClientKey> for FheAsciiString {
+    type Error = Error;
+
+    fn try_encrypt(value: &'a str, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt_with_padding(value, 0, key)
+    }
+}
+
+impl FheDecrypt<String> for FheAsciiString {
+    fn decrypt(&self, key: &ClientKey) -> String {
+        self.chars
+            .iter()
+            .map(|c| {
+                let byte: u8 = c.decrypt(key);
+                byte
+            })
+            .take_while(|byte| *byte != 0)
+            .map(char::from)
+            .collect()
+    }
+}
+
+impl<'a, P> FheEq<P> for FheAsciiString
+where
+    P: Into<StringPattern<'a>>,
+{
+    fn eq(&self, other: P) -> FheBool {
+        let result = with_server_key(|sks| {
+            let (lhs, rhs) = self.as_comparable_radix(sks, other.into());
+            sks.eq_parallelized(&lhs, &rhs)
+        });
+        FheBool::new(result)
+    }
+
+    fn ne(&self, other: P) -> FheBool {
+        let result = with_server_key(|sks| {
+            let (lhs, rhs) = self.as_comparable_radix(sks, other.into());
+            sks.ne_parallelized(&lhs, &rhs)
+        });
+        FheBool::new(result)
+    }
+}
+
+impl<'a, P> FheOrd<P> for FheAsciiString
+where
+    P: Into<StringPattern<'a>>,
+{
+    fn lt(&self, other: P) -> FheBool {
+        let result = with_server_key(|sks| {
+            let (lhs, rhs) = self.as_comparable_radix(sks, other.into());
+            sks.lt_parallelized(&lhs, &rhs)
+        });
+        FheBool::new(result)
+    }
+
+    fn le(&self, other: P) -> FheBool {
+        let result = with_server_key(|sks| {
+            let (lhs, rhs) = self.as_comparable_radix(sks, other.into());
+            sks.le_parallelized(&lhs, &rhs)
+        });
+        FheBool::new(result)
+    }
+
+    fn gt(&self, other: P) -> FheBool {
+        let result = with_server_key(|sks| {
+            let (lhs, rhs) = self.as_comparable_radix(sks, other.into());
+            sks.gt_parallelized(&lhs, &rhs)
+        });
+        FheBool::new(result)
+    }
+
+    fn ge(&self, other: P) -> FheBool {
+        let result = with_server_key(|sks| {
+            let (lhs, rhs) = self.as_comparable_radix(sks, other.into());
+            sks.ge_parallelized(&lhs, &rhs)
+        });
+        FheBool::new(result)
+    }
+}
+
+impl Add<&FheAsciiString> for &FheAsciiString {
+    type Output = FheAsciiString;
+
+    fn add(self, rhs: &FheAsciiString) -> Self::Output {
+        self.concat(rhs)
+    }
+}
+
+impl Add<&str> for &FheAsciiString {
+    type Output = FheAsciiString;
+
+    fn add(self, rhs: &str) -> Self::Output {
+        self.concat(rhs)
+    }
+}
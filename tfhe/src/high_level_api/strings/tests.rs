@@ -0,0 +1,122 @@
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ClientKey, ConfigBuilder};
+use crate::FheAsciiString;
+
+fn setup_default() -> ClientKey {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+    client_key
+}
+
+#[test]
+fn test_string_encrypt_decrypt() {
+    let client_key = setup_default();
+
+    let string = FheAsciiString::encrypt("tfhe-rs", &client_key);
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe-rs");
+    assert!(!string.is_padded());
+
+    let padded = FheAsciiString::try_encrypt_with_padding("abc", 2, &client_key).unwrap();
+    assert_eq!(padded.chars().len(), 5);
+    let decrypted: String = padded.decrypt(&client_key);
+    assert_eq!(decrypted, "abc");
+    let len: u32 = padded.len().decrypt(&client_key);
+    assert_eq!(len, 3);
+
+    let empty = FheAsciiString::try_encrypt_with_padding("", 2, &client_key).unwrap();
+    assert!(empty.is_empty().decrypt(&client_key));
+    assert!(!padded.is_empty().decrypt(&client_key));
+
+    assert!(FheAsciiString::try_encrypt("caf\u{e9}", &client_key).is_err());
+    assert!(FheAsciiString::try_encrypt("a\0b", &client_key).is_err());
+}
+
+#[test]
+fn test_string_search() {
+    let client_key = setup_default();
+
+    let clear_string = "abcab";
+    let string = FheAsciiString::try_encrypt_with_padding(clear_string, 1, &client_key).unwrap();
+
+    for clear_pattern in ["ab", "ca", "b", "ba", ""] {
+        let pattern =
+            FheAsciiString::try_encrypt_with_padding(clear_pattern, 1, &client_key).unwrap();
+
+        let contains = string.contains(&pattern);
+        assert_eq!(
+            contains.decrypt(&client_key),
+            clear_string.contains(clear_pattern)
+        );
+
+        let starts_with = string.starts_with(clear_pattern);
+        assert_eq!(
+            starts_with.decrypt(&client_key),
+            clear_string.starts_with(clear_pattern)
+        );
+
+        let ends_with = string.ends_with(&pattern);
+        assert_eq!(
+            ends_with.decrypt(&client_key),
+            clear_string.ends_with(clear_pattern)
+        );
+
+        let (index, found) = string.find(clear_pattern);
+        let index: u32 = index.decrypt(&client_key);
+        let expected = clear_string.find(clear_pattern);
+        assert_eq!(found.decrypt(&client_key), expected.is_some());
+        assert_eq!(index as usize, expected.unwrap_or(0));
+    }
+}
+
+#[test]
+fn test_string_comparisons() {
+    let client_key = setup_default();
+
+    let clear_lhs = "abc";
+    let lhs = FheAsciiString::try_encrypt_with_padding(clear_lhs, 1, &client_key).unwrap();
+
+    for clear_rhs in ["abc", "ab", "abd", "b"] {
+        let rhs = FheAsciiString::encrypt(clear_rhs, &client_key);
+
+        assert_eq!(lhs.eq(&rhs).decrypt(&client_key), clear_lhs == clear_rhs);
+        assert_eq!(
+            lhs.ne(clear_rhs).decrypt(&client_key),
+            clear_lhs != clear_rhs
+        );
+        assert_eq!(lhs.lt(&rhs).decrypt(&client_key), clear_lhs < clear_rhs);
+        assert_eq!(
+            lhs.ge(clear_rhs).decrypt(&client_key),
+            clear_lhs >= clear_rhs
+        );
+    }
+}
+
+#[test]
+fn test_string_transformations() {
+    let client_key = setup_default();
+
+    let string = FheAsciiString::try_encrypt_with_padding(" Ab1\t", 1, &client_key).unwrap();
+
+    let lower: String = string.to_lowercase().decrypt(&client_key);
+    assert_eq!(lower, " ab1\t");
+    let upper: String = string.to_uppercase().decrypt(&client_key);
+    assert_eq!(upper, " AB1\t");
+
+    let trimmed = string.trim();
+    assert!(trimmed.is_padded());
+    let decrypted: String = trimmed.decrypt(&client_key);
+    assert_eq!(decrypted, "Ab1");
+
+    let concatenated = &trimmed + "-x";
+    let decrypted: String = concatenated.decrypt(&client_key);
+    assert_eq!(decrypted, "Ab1-x");
+
+    let other = FheAsciiString::encrypt("yz", &client_key);
+    let concatenated = &other + &trimmed;
+    let decrypted: String = concatenated.decrypt(&client_key);
+    assert_eq!(decrypted, "yzAb1");
+}
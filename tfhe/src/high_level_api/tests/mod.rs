@@ -93,6 +93,51 @@ fn test_server_key_decompression() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_ternary_secret_keys() -> Result<(), Box<dyn std::error::Error>> {
+    use crate::set_server_key;
+    use crate::shortint::SecretKeyDistribution;
+
+    for config in [
+        ConfigBuilder::default_with_big_encryption(),
+        ConfigBuilder::default_with_small_encryption(),
+    ] {
+        let config = config
+            .use_secret_key_distribution(SecretKeyDistribution::Ternary)
+            .build();
+
+        let cks = ClientKey::generate(config);
+        let sks = CompressedServerKey::new(&cks).decompress();
+        set_server_key(sks);
+
+        let clear_a = 123u8;
+        let clear_b = 201u8;
+        let a = FheUint8::try_encrypt(clear_a, &cks)?;
+        let b = FheUint8::try_encrypt(clear_b, &cks)?;
+
+        let c = &a * &b + 17u8;
+        let decrypted: u8 = c.decrypt(&cks);
+        assert_eq!(decrypted, clear_a.wrapping_mul(clear_b).wrapping_add(17));
+
+        let d = a.gt(&b);
+        assert_eq!(d.decrypt(&cks), clear_a > clear_b);
+    }
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "Multi bit PBS parameters only support binary secret keys")]
+fn test_ternary_secret_keys_multi_bit_panics() {
+    use crate::shortint::SecretKeyDistribution;
+
+    let _ = ConfigBuilder::with_custom_parameters(
+        crate::shortint::parameters::PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+        None,
+    )
+    .use_secret_key_distribution(SecretKeyDistribution::Ternary);
+}
+
 #[test]
 fn test_with_seed() {
     use crate::Seed;
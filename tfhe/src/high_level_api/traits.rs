@@ -167,3 +167,19 @@ pub trait DivRem<Rhs = Self> {
 
     fn div_rem(self, amount: Rhs) -> Self::Output;
 }
+
+/// Trait for multiplication that also returns whether
+/// the result overflowed (wrapped around).
+pub trait OverflowingMul<Rhs = Self> {
+    type Output;
+
+    fn overflowing_mul(self, rhs: Rhs) -> (Self::Output, FheBool);
+}
+
+/// Trait for exponentiation, where the result wraps around
+/// if it does not fit in the type.
+pub trait Pow<Rhs> {
+    type Output;
+
+    fn pow(self, exponent: Rhs) -> Self::Output;
+}
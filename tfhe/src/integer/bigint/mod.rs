@@ -3,6 +3,7 @@ pub mod i256;
 pub mod i512;
 pub mod static_signed;
 pub mod static_unsigned;
+pub mod u1024;
 pub mod u256;
 pub mod u512;
 
@@ -0,0 +1,48 @@
+use crate::core_crypto::prelude::CastFrom;
+
+pub type U1024 = super::static_unsigned::StaticUnsignedBigInt<16>;
+
+impl CastFrom<crate::integer::U512> for U1024 {
+    fn cast_from(input: crate::integer::U512) -> Self {
+        let mut converted = [0u64; 16];
+        converted[..8].copy_from_slice(input.0.as_slice());
+        Self(converted)
+    }
+}
+
+impl CastFrom<U1024> for crate::integer::U512 {
+    fn cast_from(input: U1024) -> Self {
+        let mut converted = [0u64; 8];
+        converted.copy_from_slice(&input.0[..8]);
+        Self(converted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::U512;
+
+    #[test]
+    fn test_const() {
+        assert_eq!(U1024::BITS, 1024);
+    }
+
+    #[test]
+    fn test_cast_u512() {
+        let a = U512::MAX;
+        let b = U1024::cast_from(a);
+        assert_eq!(b, (U1024::ONE << 512u32) - U1024::ONE);
+        assert_eq!(U512::cast_from(b), a);
+
+        let c = U1024::MAX;
+        assert_eq!(U512::cast_from(c), U512::MAX);
+    }
+
+    #[test]
+    fn test_mul_does_not_overflow_u512_square() {
+        let a = U1024::cast_from(U512::MAX);
+        let expected = U1024::MAX - (U1024::ONE << 513u32) + U1024::from(2u32);
+        assert_eq!(a * a, expected);
+    }
+}
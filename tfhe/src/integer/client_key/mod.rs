@@ -247,6 +247,50 @@ impl ClientKey {
         )
     }
 
+    /// Decrypts a ciphertext encrypting an radix integer, and measures the noise of each block
+    ///
+    /// The noise of each block is given as the base 2 logarithm of its absolute value, see
+    /// [`crate::shortint::ClientKey::decrypt_with_noise`]. Trivial blocks have no noise, their
+    /// noise is [`f64::NEG_INFINITY`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let num_block = 4;
+    ///
+    /// let msg = 191_u64;
+    ///
+    /// // Encryption
+    /// let ct = cks.encrypt_radix(msg, num_block);
+    ///
+    /// // Decryption
+    /// let (dec, noise_bits): (u64, _) = cks.decrypt_radix_with_noise(&ct);
+    /// assert_eq!(msg, dec);
+    /// assert_eq!(noise_bits.len(), num_block);
+    /// assert!(noise_bits.iter().all(|&bits| bits < 58.0));
+    /// ```
+    pub fn decrypt_radix_with_noise<T>(&self, ctxt: &RadixCiphertext) -> (T, Vec<f64>)
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        (
+            self.decrypt_radix(ctxt),
+            self.blocks_noise_bits(&ctxt.blocks),
+        )
+    }
+
+    /// Measures the noise of each block, see [`crate::shortint::ClientKey::decrypt_with_noise`]
+    pub(crate) fn blocks_noise_bits(&self, blocks: &[crate::shortint::Ciphertext]) -> Vec<f64> {
+        blocks
+            .iter()
+            .map(|block| self.key.decrypt_with_noise(block).1)
+            .collect()
+    }
+
     /// Decrypts a ciphertext encrypting an radix integer encrypted without padding
     ///
     /// # Example
@@ -371,6 +415,18 @@ impl ClientKey {
         self.decrypt_signed_radix_impl(ctxt, crate::shortint::ClientKey::decrypt_message_and_carry)
     }
 
+    /// Decrypts a ciphertext encrypting a signed radix integer, and measures the noise of each
+    /// block, see [`Self::decrypt_radix_with_noise`]
+    pub fn decrypt_signed_radix_with_noise<T>(&self, ctxt: &SignedRadixCiphertext) -> (T, Vec<f64>)
+    where
+        T: RecomposableSignedInteger,
+    {
+        (
+            self.decrypt_signed_radix(ctxt),
+            self.blocks_noise_bits(&ctxt.blocks),
+        )
+    }
+
     pub fn decrypt_signed_radix_impl<T, F>(
         &self,
         ctxt: &SignedRadixCiphertext,
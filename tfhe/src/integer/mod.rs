@@ -58,9 +58,13 @@ pub mod client_key;
 pub mod key_switching_key;
 #[cfg(any(test, feature = "internal-keycache"))]
 pub mod keycache;
+pub mod noise_squashing;
 pub mod parameters;
 pub mod public_key;
+pub mod regex;
 pub mod server_key;
+#[cfg(feature = "threshold-decryption-experimental")]
+pub mod threshold;
 pub mod wopbs;
 
 pub use bigint::i256::I256;
@@ -110,8 +114,14 @@ where
             lwe_dimension: wopbs_params.lwe_dimension,
             glwe_dimension: wopbs_params.glwe_dimension,
             polynomial_size: wopbs_params.polynomial_size,
-            lwe_modular_std_dev: wopbs_params.lwe_modular_std_dev,
-            glwe_modular_std_dev: wopbs_params.glwe_modular_std_dev,
+            lwe_noise_distribution:
+                crate::shortint::parameters::DynamicDistribution::new_gaussian_from_std_dev(
+                    wopbs_params.lwe_modular_std_dev,
+                ),
+            glwe_noise_distribution:
+                crate::shortint::parameters::DynamicDistribution::new_gaussian_from_std_dev(
+                    wopbs_params.glwe_modular_std_dev,
+                ),
             pbs_base_log: wopbs_params.pbs_base_log,
             pbs_level: wopbs_params.pbs_level,
             ks_base_log: wopbs_params.ks_base_log,
@@ -120,6 +130,7 @@ where
             carry_modulus: wopbs_params.carry_modulus,
             ciphertext_modulus: wopbs_params.ciphertext_modulus,
             encryption_key_choice: wopbs_params.encryption_key_choice,
+            secret_key_distribution: crate::shortint::parameters::SecretKeyDistribution::Binary,
         };
 
         crate::shortint::parameters::ShortintParameterSet::try_new_pbs_and_wopbs_param_set((
@@ -0,0 +1,166 @@
+//! This module defines the noise squashing of radix ciphertexts.
+//!
+//! The noise of each block of a radix ciphertext is squashed with the shortint noise squashing,
+//! see [crate::shortint::noise_squashing].
+
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::integer::ciphertext::IntegerCiphertext;
+use crate::integer::{ClientKey, ServerKey};
+use crate::shortint::noise_squashing::{
+    NoiseSquashingKey as ShortintNoiseSquashingKey,
+    NoiseSquashingPrivateKey as ShortintNoiseSquashingPrivateKey, SquashedNoiseCiphertext,
+};
+use crate::shortint::parameters::NoiseSquashingParameters;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The secret key of the squashed radix ciphertexts, it must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingPrivateKey {
+    pub(crate) key: ShortintNoiseSquashingPrivateKey,
+}
+
+/// A key to squash the noise of radix ciphertexts into [SquashedNoiseRadixCiphertext]s.
+///
+/// It is generated by the client and is meant to be published.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingKey {
+    pub(crate) key: ShortintNoiseSquashingKey,
+}
+
+/// A radix ciphertext whose noise was squashed by a [NoiseSquashingKey], one squashed block per
+/// block of the input ciphertext.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SquashedNoiseRadixCiphertext {
+    pub(crate) blocks: Vec<SquashedNoiseCiphertext>,
+}
+
+impl SquashedNoiseRadixCiphertext {
+    pub fn blocks(&self) -> &[SquashedNoiseCiphertext] {
+        &self.blocks
+    }
+}
+
+impl AsRef<ShortintNoiseSquashingPrivateKey> for NoiseSquashingPrivateKey {
+    fn as_ref(&self) -> &ShortintNoiseSquashingPrivateKey {
+        &self.key
+    }
+}
+
+impl AsRef<ShortintNoiseSquashingKey> for NoiseSquashingKey {
+    fn as_ref(&self) -> &ShortintNoiseSquashingKey {
+        &self.key
+    }
+}
+
+impl ClientKey {
+    /// Generate the secret key of the squashed radix ciphertexts.
+    ///
+    /// See [NoiseSquashingKey::squash_radix_ciphertext_noise] for usage.
+    ///
+    /// # Panics
+    ///
+    /// See [crate::shortint::ClientKey::new_noise_squashing_private_key].
+    pub fn new_noise_squashing_private_key(
+        &self,
+        params: NoiseSquashingParameters,
+    ) -> NoiseSquashingPrivateKey {
+        NoiseSquashingPrivateKey {
+            key: self.key.new_noise_squashing_private_key(params),
+        }
+    }
+
+    /// Generate the [NoiseSquashingKey] from the secret key of the squashed ciphertexts.
+    ///
+    /// See [NoiseSquashingKey::squash_radix_ciphertext_noise] for usage.
+    pub fn new_noise_squashing_key(
+        &self,
+        private_key: &NoiseSquashingPrivateKey,
+    ) -> NoiseSquashingKey {
+        NoiseSquashingKey {
+            key: self.key.new_noise_squashing_key(&private_key.key),
+        }
+    }
+}
+
+impl NoiseSquashingKey {
+    pub fn params(&self) -> NoiseSquashingParameters {
+        self.key.params()
+    }
+
+    /// Squash the noise of each block of `ctxt`, which must have been computed on with
+    /// `server_key`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::{
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    /// let num_blocks = 4;
+    ///
+    /// let private_key = cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// let noise_squashing_key = cks.new_noise_squashing_key(&private_key);
+    ///
+    /// let ct1 = cks.encrypt_radix(120u64, num_blocks);
+    /// let ct2 = cks.encrypt_radix(71u64, num_blocks);
+    /// let ct = sks.add_parallelized(&ct1, &ct2);
+    /// let squashed = noise_squashing_key.squash_radix_ciphertext_noise(&ct, &sks);
+    ///
+    /// let dec: u64 = private_key.decrypt_radix(&squashed);
+    /// assert_eq!(dec, 191);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the noise squashing key was not generated for the keys of `server_key`.
+    pub fn squash_radix_ciphertext_noise<T>(
+        &self,
+        ctxt: &T,
+        server_key: &ServerKey,
+    ) -> SquashedNoiseRadixCiphertext
+    where
+        T: IntegerCiphertext,
+    {
+        SquashedNoiseRadixCiphertext {
+            blocks: ctxt
+                .blocks()
+                .par_iter()
+                .map(|block| self.key.squash_ciphertext_noise(block, &server_key.key))
+                .collect(),
+        }
+    }
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Decrypt a squashed radix ciphertext.
+    ///
+    /// See [NoiseSquashingKey::squash_radix_ciphertext_noise] for usage.
+    pub fn decrypt_radix<T>(&self, ctxt: &SquashedNoiseRadixCiphertext) -> T
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        if ctxt.blocks.is_empty() {
+            return T::ZERO;
+        }
+
+        let bits_in_block = ctxt.blocks[0].message_modulus().0.ilog2();
+        let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+
+        for block in &ctxt.blocks {
+            let decrypted_block = self.key.decrypt_message_and_carry(block);
+            if !recomposer.add_unmasked(decrypted_block) {
+                // End of T::BITS reached no need to try more
+                // recomposition
+                break;
+            }
+        }
+
+        recomposer.value()
+    }
+}
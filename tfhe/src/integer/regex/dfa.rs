@@ -0,0 +1,324 @@
+//! Compilation of a parsed pattern into a deterministic finite automaton.
+//!
+//! The expression is first turned into a non deterministic automaton (Thompson construction),
+//! which is then determinized with the subset construction. Transitions are not given for
+//! each byte, but for each *byte class*: a set of bytes that no part of the pattern
+//! distinguishes, so that the server only has to test the membership of each character
+//! in a few classes.
+use std::collections::{BTreeSet, HashMap};
+
+use super::parser::{ByteSet, ParsedPattern, RegExpr};
+use super::RegexError;
+
+/// Maximum number of states of the automata, to bound the cost of the evaluation
+pub(super) const MAX_STATES: usize = 4096;
+
+/// Index of the state from which no accepting state can be reached
+pub(super) const DEAD_STATE: usize = 0;
+
+/// A set of bytes for which all the transitions of the automaton are the same,
+/// stored as a list of inclusive ranges
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct ByteClass {
+    pub(super) ranges: Vec<(u8, u8)>,
+}
+
+impl ByteClass {
+    #[cfg(test)]
+    pub(super) fn contains(&self, byte: u8) -> bool {
+        self.ranges
+            .iter()
+            .any(|(from, to)| (*from..=*to).contains(&byte))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct Dfa {
+    pub(super) classes: Vec<ByteClass>,
+    /// `transitions[state][class]` is the state reached from `state`
+    /// when reading a byte of `classes[class]`
+    pub(super) transitions: Vec<Vec<usize>>,
+    pub(super) accepting: Vec<bool>,
+    pub(super) start: usize,
+}
+
+impl Dfa {
+    pub(super) fn num_states(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Returns the state reached from `state` when reading `byte`
+    #[cfg(test)]
+    pub(super) fn next_state(&self, state: usize, byte: u8) -> usize {
+        self.classes
+            .iter()
+            .position(|class| class.contains(byte))
+            .map_or(DEAD_STATE, |class| self.transitions[state][class])
+    }
+}
+
+#[derive(Default)]
+struct NfaState {
+    epsilon: Vec<usize>,
+    transition: Option<(ByteSet, usize)>,
+}
+
+#[derive(Default)]
+struct Nfa {
+    states: Vec<NfaState>,
+}
+
+impl Nfa {
+    fn add_state(&mut self) -> Result<usize, RegexError> {
+        if self.states.len() >= MAX_STATES * 16 {
+            return Err(RegexError::new("pattern is too large"));
+        }
+        self.states.push(NfaState::default());
+        Ok(self.states.len() - 1)
+    }
+
+    /// Adds the states recognizing `re`, returns the start and end states
+    fn add_expression(&mut self, re: &RegExpr) -> Result<(usize, usize), RegexError> {
+        match re {
+            RegExpr::Set(set) => {
+                let start = self.add_state()?;
+                let end = self.add_state()?;
+                self.states[start].transition = Some((*set, end));
+                Ok((start, end))
+            }
+            RegExpr::Seq(re_xs) => {
+                let start = self.add_state()?;
+                let mut end = start;
+                for re_x in re_xs {
+                    let (x_start, x_end) = self.add_expression(re_x)?;
+                    self.states[end].epsilon.push(x_start);
+                    end = x_end;
+                }
+                Ok((start, end))
+            }
+            RegExpr::Either(re_xs) => {
+                let start = self.add_state()?;
+                let end = self.add_state()?;
+                for re_x in re_xs {
+                    let (x_start, x_end) = self.add_expression(re_x)?;
+                    self.states[start].epsilon.push(x_start);
+                    self.states[x_end].epsilon.push(end);
+                }
+                Ok((start, end))
+            }
+            RegExpr::Repeated {
+                repeat_re,
+                at_least,
+                at_most,
+            } => {
+                let start = self.add_state()?;
+                let mut end = start;
+                for _ in 0..*at_least {
+                    let (x_start, x_end) = self.add_expression(repeat_re)?;
+                    self.states[end].epsilon.push(x_start);
+                    end = x_end;
+                }
+                if let Some(at_most) = at_most {
+                    // Each optional repetition can be skipped to the end
+                    let final_end = self.add_state()?;
+                    for _ in *at_least..*at_most {
+                        let (x_start, x_end) = self.add_expression(repeat_re)?;
+                        self.states[end].epsilon.push(x_start);
+                        self.states[end].epsilon.push(final_end);
+                        end = x_end;
+                    }
+                    self.states[end].epsilon.push(final_end);
+                    Ok((start, final_end))
+                } else {
+                    let (x_start, x_end) = self.add_expression(repeat_re)?;
+                    let loop_state = self.add_state()?;
+                    self.states[end].epsilon.push(loop_state);
+                    self.states[loop_state].epsilon.push(x_start);
+                    self.states[x_end].epsilon.push(loop_state);
+                    Ok((start, loop_state))
+                }
+            }
+        }
+    }
+
+    fn epsilon_closure(&self, states: impl IntoIterator<Item = usize>) -> BTreeSet<usize> {
+        let mut closure = BTreeSet::new();
+        let mut stack = states.into_iter().collect::<Vec<_>>();
+        while let Some(state) = stack.pop() {
+            if closure.insert(state) {
+                stack.extend(self.states[state].epsilon.iter().copied());
+            }
+        }
+        closure
+    }
+}
+
+/// Splits the non null bytes into the classes of bytes that belong to the same sets
+fn byte_classes(nfa: &Nfa) -> Vec<ByteClass> {
+    let sets = nfa
+        .states
+        .iter()
+        .filter_map(|state| state.transition.map(|(set, _)| set))
+        .collect::<BTreeSet<_>>();
+
+    let mut class_of_signature = HashMap::new();
+    let mut classes: Vec<Vec<u8>> = vec![];
+    for byte in 1..=u8::MAX {
+        let signature = sets
+            .iter()
+            .map(|set| set.contains(byte))
+            .collect::<Vec<_>>();
+        if !signature.contains(&true) {
+            // Bytes matched by no set always lead to the dead state
+            continue;
+        }
+        let class = *class_of_signature.entry(signature).or_insert_with(|| {
+            classes.push(vec![]);
+            classes.len() - 1
+        });
+        classes[class].push(byte);
+    }
+
+    classes.iter().map(|bytes| to_ranges(bytes)).collect()
+}
+
+fn to_ranges(bytes: &[u8]) -> ByteClass {
+    let mut ranges: Vec<(u8, u8)> = vec![];
+    for &byte in bytes {
+        match ranges.last_mut() {
+            Some((_, to)) if u16::from(*to) + 1 == u16::from(byte) => *to = byte,
+            _ => ranges.push((byte, byte)),
+        }
+    }
+    ByteClass { ranges }
+}
+
+pub(super) fn compile(pattern: &ParsedPattern) -> Result<Dfa, RegexError> {
+    let mut nfa = Nfa::default();
+    let (nfa_start, nfa_end) = nfa.add_expression(&pattern.re)?;
+
+    let classes = byte_classes(&nfa);
+    // A representative byte of each class, to compute its transitions
+    let representatives = classes
+        .iter()
+        .map(|class| class.ranges[0].0)
+        .collect::<Vec<_>>();
+
+    let mut state_ids: HashMap<BTreeSet<usize>, usize> = HashMap::new();
+    let mut subsets = vec![BTreeSet::new()];
+    state_ids.insert(BTreeSet::new(), DEAD_STATE);
+
+    let start_subset = nfa.epsilon_closure([nfa_start]);
+    state_ids.insert(start_subset.clone(), 1);
+    subsets.push(start_subset);
+
+    let mut transitions = vec![];
+    let mut next_to_process = 0;
+    while next_to_process < subsets.len() {
+        let subset = subsets[next_to_process].clone();
+        let mut state_transitions = Vec::with_capacity(classes.len());
+        for &byte in &representatives {
+            let targets = subset.iter().filter_map(|state| {
+                nfa.states[*state]
+                    .transition
+                    .filter(|(set, _)| set.contains(byte))
+                    .map(|(_, target)| target)
+            });
+            let target_subset = nfa.epsilon_closure(targets);
+            let target = if let Some(target) = state_ids.get(&target_subset) {
+                *target
+            } else {
+                if subsets.len() >= MAX_STATES {
+                    return Err(RegexError::new(format!(
+                        "pattern needs more than {MAX_STATES} states"
+                    )));
+                }
+                state_ids.insert(target_subset.clone(), subsets.len());
+                subsets.push(target_subset);
+                subsets.len() - 1
+            };
+            state_transitions.push(target);
+        }
+        transitions.push(state_transitions);
+        next_to_process += 1;
+    }
+
+    let accepting = subsets
+        .iter()
+        .map(|subset| subset.contains(&nfa_end))
+        .collect();
+
+    let mut dfa = Dfa {
+        classes,
+        transitions,
+        accepting,
+        start: 1,
+    };
+    redirect_dead_states(&mut dfa);
+
+    Ok(merge_equivalent_classes(dfa))
+}
+
+/// Redirects the transitions to states from which no accepting state can be reached
+/// to the dead state
+fn redirect_dead_states(dfa: &mut Dfa) {
+    let mut live = dfa.accepting.clone();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for state in 0..dfa.num_states() {
+            if !live[state] && dfa.transitions[state].iter().any(|target| live[*target]) {
+                live[state] = true;
+                changed = true;
+            }
+        }
+    }
+
+    for row in &mut dfa.transitions {
+        for target in row.iter_mut() {
+            if !live[*target] {
+                *target = DEAD_STATE;
+            }
+        }
+    }
+}
+
+/// Merges the classes that lead to the same states from every state
+fn merge_equivalent_classes(dfa: Dfa) -> Dfa {
+    let mut merged_classes: Vec<ByteClass> = vec![];
+    let mut merged_columns: Vec<Vec<usize>> = vec![];
+    for (class_index, class) in dfa.classes.into_iter().enumerate() {
+        let column = dfa
+            .transitions
+            .iter()
+            .map(|row| row[class_index])
+            .collect::<Vec<_>>();
+        if column.iter().all(|target| *target == DEAD_STATE) {
+            continue;
+        }
+        if let Some(index) = merged_columns.iter().position(|merged| *merged == column) {
+            let mut bytes = merged_classes[index]
+                .ranges
+                .iter()
+                .chain(class.ranges.iter())
+                .flat_map(|(from, to)| *from..=*to)
+                .collect::<Vec<_>>();
+            bytes.sort_unstable();
+            merged_classes[index] = to_ranges(&bytes);
+        } else {
+            merged_columns.push(column);
+            merged_classes.push(class);
+        }
+    }
+
+    let transitions = (0..dfa.transitions.len())
+        .map(|state| merged_columns.iter().map(|column| column[state]).collect())
+        .collect();
+
+    Dfa {
+        classes: merged_classes,
+        transitions,
+        accepting: dfa.accepting,
+        start: dfa.start,
+    }
+}
@@ -0,0 +1,402 @@
+//! Regular expression matching on encrypted content.
+//!
+//! A [Regex] is compiled from a pattern into a deterministic finite automaton (DFA),
+//! which the server evaluates on content given as a list of encrypted bytes
+//! (one [RadixCiphertext] per byte, usually made of 8 bits of message).
+//!
+//! The automaton is evaluated in a single pass over the content, going from its end to
+//! its start: for each position and each state of the automaton, the server computes whether
+//! reading the content from this position, starting in this state, leads to a match.
+//! Results for a position are shared by all the states and start positions that need them,
+//! so the cost of the evaluation is linear in the length of the content and in the size
+//! of the automaton.
+//!
+//! # Patterns
+//!
+//! Patterns are written between slashes, optionally followed by the `i` modifier
+//! for case insensitive matching. The following constructs are supported:
+//!
+//! | Construct | Syntax | Example |
+//! |-----------|--------|---------|
+//! | Character | the character itself | `/a/` |
+//! | Escaped character | `\<char>` | `/\./` |
+//! | Any character | `.` | `/a.c/` |
+//! | Character class | `[<chars and ranges>]` | `/[a-z_]/` |
+//! | Negated character class | `[^<chars and ranges>]` | `/[^0-9]/` |
+//! | Group | `(<regex>)` | `/(ab)*/` |
+//! | Alternation | `<regex>\|<regex>` | `/ab\|cd/` |
+//! | Optional | `<atom>?` | `/ab?/` |
+//! | Zero or more | `<atom>*` | `/ab*/` |
+//! | One or more | `<atom>+` | `/ab+/` |
+//! | Bounded repetition | `<atom>{n}`, `<atom>{n,}`, `<atom>{,m}`, `<atom>{n,m}` | `/a{2,4}/` |
+//! | Start of content | `^` at the start of the pattern | `/^ab/` |
+//! | End of content | `$` at the end of the pattern | `/ab$/` |
+//!
+//! The null character is never matched: it marks the end of the content,
+//! so that padded content (e.g. a padded [FheAsciiString](crate::FheAsciiString))
+//! can be matched without revealing its length.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::integer::regex::Regex;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//!
+//! // 4 blocks of 2 bits to store a byte
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 4);
+//!
+//! let content = b"tfhe-rs"
+//!     .iter()
+//!     .map(|byte| cks.encrypt(*byte as u64))
+//!     .collect::<Vec<_>>();
+//!
+//! let regex = Regex::new("/[a-z]-r/").unwrap();
+//! let (position, is_match) = sks.regex_find_parallelized(&content, &regex);
+//!
+//! assert!(cks.decrypt_bool(&is_match));
+//! let position: u64 = cks.decrypt(&position);
+//! assert_eq!(position, 3);
+//! ```
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use rayon::prelude::*;
+
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+
+mod dfa;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use dfa::{Dfa, DEAD_STATE};
+
+/// Error returned when a pattern cannot be compiled
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegexError {
+    message: String,
+}
+
+impl RegexError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for RegexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid regex pattern: {}", self.message)
+    }
+}
+
+impl std::error::Error for RegexError {}
+
+/// A compiled regex pattern.
+///
+/// See the [module documentation](self) for the supported syntax.
+#[derive(Clone, Debug)]
+pub struct Regex {
+    pattern: String,
+    dfa: Dfa,
+    anchored_start: bool,
+    anchored_end: bool,
+    /// For each state, the (non dead) target states, with the index of the group
+    /// of classes leading to them
+    targets: Vec<Vec<(usize, usize)>>,
+    /// Groups of classes, as indices into `dfa.classes`
+    class_groups: Vec<Vec<usize>>,
+}
+
+impl Regex {
+    /// Parses and compiles the pattern.
+    pub fn new(pattern: &str) -> Result<Self, RegexError> {
+        let parsed = parser::parse(pattern)?;
+        let dfa = dfa::compile(&parsed)?;
+
+        let mut group_ids: HashMap<Vec<usize>, usize> = HashMap::new();
+        let mut class_groups = vec![];
+        let targets = dfa
+            .transitions
+            .iter()
+            .map(|row| {
+                let mut classes_of_target: Vec<(usize, Vec<usize>)> = vec![];
+                for (class, target) in row.iter().enumerate() {
+                    if *target == DEAD_STATE {
+                        continue;
+                    }
+                    match classes_of_target.iter_mut().find(|(t, _)| t == target) {
+                        Some((_, classes)) => classes.push(class),
+                        None => classes_of_target.push((*target, vec![class])),
+                    }
+                }
+                classes_of_target
+                    .into_iter()
+                    .map(|(target, classes)| {
+                        let group = *group_ids.entry(classes.clone()).or_insert_with(|| {
+                            class_groups.push(classes);
+                            class_groups.len() - 1
+                        });
+                        (target, group)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            pattern: pattern.to_owned(),
+            dfa,
+            anchored_start: parsed.anchored_start,
+            anchored_end: parsed.anchored_end,
+            targets,
+            class_groups,
+        })
+    }
+
+    /// Returns the pattern the regex was compiled from.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the number of states of the compiled automaton.
+    pub fn num_states(&self) -> usize {
+        self.dfa.num_states()
+    }
+
+    /// Returns, for each position of the clear content (and the position after its end),
+    /// whether a match starts there
+    #[cfg(test)]
+    fn clear_match_starts(&self, content: &[u8]) -> Vec<bool> {
+        let mut reaches_match = self.dfa.accepting.clone();
+        let mut match_starts = vec![reaches_match[self.dfa.start]; content.len() + 1];
+        for (i, byte) in content.iter().enumerate().rev() {
+            reaches_match = (0..self.dfa.num_states())
+                .map(|state| {
+                    let accepts_here =
+                        self.dfa.accepting[state] && (!self.anchored_end || *byte == 0);
+                    accepts_here || reaches_match[self.dfa.next_state(state, *byte)]
+                })
+                .collect();
+            match_starts[i] = reaches_match[self.dfa.start];
+        }
+        if self.anchored_start {
+            match_starts.truncate(1);
+        }
+        match_starts
+    }
+}
+
+/// A boolean that is either known by the server, or encrypted
+#[derive(Clone)]
+enum Flag {
+    Clear(bool),
+    Encrypted(BooleanBlock),
+}
+
+impl Flag {
+    fn and(&self, other: &Self, sks: &ServerKey) -> Self {
+        match (self, other) {
+            (Self::Clear(false), _) | (_, Self::Clear(false)) => Self::Clear(false),
+            (Self::Clear(true), value) | (value, Self::Clear(true)) => value.clone(),
+            (Self::Encrypted(lhs), Self::Encrypted(rhs)) => Self::Encrypted(
+                BooleanBlock::new_unchecked(sks.key.bitand(lhs.as_ref(), rhs.as_ref())),
+            ),
+        }
+    }
+
+    fn or(&self, other: &Self, sks: &ServerKey) -> Self {
+        match (self, other) {
+            (Self::Clear(true), _) | (_, Self::Clear(true)) => Self::Clear(true),
+            (Self::Clear(false), value) | (value, Self::Clear(false)) => value.clone(),
+            (Self::Encrypted(lhs), Self::Encrypted(rhs)) => Self::Encrypted(
+                BooleanBlock::new_unchecked(sks.key.bitor(lhs.as_ref(), rhs.as_ref())),
+            ),
+        }
+    }
+
+    fn any(flags: Vec<Self>, sks: &ServerKey) -> Self {
+        flags
+            .into_par_iter()
+            .reduce(|| Self::Clear(false), |lhs, rhs| lhs.or(&rhs, sks))
+    }
+
+    fn into_boolean_block(self, sks: &ServerKey) -> BooleanBlock {
+        match self {
+            Self::Clear(value) => sks.create_trivial_boolean_block(value),
+            Self::Encrypted(value) => value,
+        }
+    }
+}
+
+impl ServerKey {
+    /// Returns an encrypted boolean that is true if the regex matches the content.
+    ///
+    /// Each element of `content` is an encrypted byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::regex::Regex;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 4);
+    ///
+    /// let content = b"Ab"
+    ///     .iter()
+    ///     .map(|byte| cks.encrypt(*byte as u64))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let regex = Regex::new("/^ab$/i").unwrap();
+    /// let is_match = sks.regex_is_match_parallelized(&content, &regex);
+    /// assert!(cks.decrypt_bool(&is_match));
+    /// ```
+    pub fn regex_is_match_parallelized(
+        &self,
+        content: &[RadixCiphertext],
+        regex: &Regex,
+    ) -> BooleanBlock {
+        let match_starts = self.regex_match_starts(content, regex);
+        Flag::any(match_starts, self).into_boolean_block(self)
+    }
+
+    /// Returns the encrypted position of the start of the leftmost match of the regex
+    /// in the content, and an encrypted boolean that is true if there is a match.
+    ///
+    /// Each element of `content` is an encrypted byte. If there is no match,
+    /// the returned position is an encryption of 0. The position has enough blocks
+    /// to store `content.len()`.
+    pub fn regex_find_parallelized(
+        &self,
+        content: &[RadixCiphertext],
+        regex: &Regex,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        let num_bits = (usize::BITS - content.len().leading_zeros()) as usize;
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        let num_blocks = ((num_bits + bits_per_block - 1) / bits_per_block).max(1);
+
+        let match_starts = self.regex_match_starts(content, regex);
+        if match_starts.len() == 1 {
+            let is_match = match_starts.into_iter().next().unwrap();
+            return (
+                self.create_trivial_zero_radix(num_blocks),
+                is_match.into_boolean_block(self),
+            );
+        }
+
+        let as_radix = match_starts
+            .iter()
+            .map(|flag| {
+                flag.clone()
+                    .into_boolean_block(self)
+                    .into_radix::<RadixCiphertext>(1, self)
+            })
+            .collect::<Vec<_>>();
+        let (mut position, is_match) = rayon::join(
+            || self.argmax_parallelized(&as_radix),
+            || Flag::any(match_starts, self).into_boolean_block(self),
+        );
+        let current_num_blocks = position.blocks.len();
+        if current_num_blocks < num_blocks {
+            self.extend_radix_with_trivial_zero_blocks_msb_assign(
+                &mut position,
+                num_blocks - current_num_blocks,
+            );
+        }
+
+        (position, is_match)
+    }
+
+    /// Returns, for each position of the content (and the position after its end),
+    /// whether a match starts there.
+    ///
+    /// If the regex is anchored at the start, only the first position is returned.
+    fn regex_match_starts(&self, content: &[RadixCiphertext], regex: &Regex) -> Vec<Flag> {
+        let dfa = &regex.dfa;
+
+        // reaches_match[state]: reading the content from the current position,
+        // starting in `state`, leads to a match
+        let mut reaches_match = dfa
+            .accepting
+            .iter()
+            .map(|accepting| Flag::Clear(*accepting))
+            .collect::<Vec<_>>();
+        let mut match_starts = vec![Flag::Clear(false); content.len() + 1];
+        match_starts[content.len()] = reaches_match[dfa.start].clone();
+
+        for (i, byte) in content.iter().enumerate().rev() {
+            let (group_flags, is_end) = rayon::join(
+                || self.regex_class_group_flags(byte, regex),
+                || {
+                    regex
+                        .anchored_end
+                        .then(|| Flag::Encrypted(self.scalar_eq_parallelized(byte, 0u8)))
+                },
+            );
+
+            reaches_match = (0..dfa.num_states())
+                .into_par_iter()
+                .map(|state| {
+                    let accepts_here = match (&is_end, dfa.accepting[state]) {
+                        (_, false) => Flag::Clear(false),
+                        (None, true) => Flag::Clear(true),
+                        (Some(is_end), true) => is_end.clone(),
+                    };
+                    regex.targets[state]
+                        .iter()
+                        .fold(accepts_here, |result, (target, group)| {
+                            let step = group_flags[*group].and(&reaches_match[*target], self);
+                            result.or(&step, self)
+                        })
+                })
+                .collect();
+            match_starts[i] = reaches_match[dfa.start].clone();
+        }
+
+        if regex.anchored_start {
+            match_starts.truncate(1);
+        }
+        match_starts
+    }
+
+    /// Returns, for each group of classes of the regex, whether the byte belongs
+    /// to one of the classes of the group
+    fn regex_class_group_flags(&self, byte: &RadixCiphertext, regex: &Regex) -> Vec<Flag> {
+        let in_class = regex
+            .dfa
+            .classes
+            .par_iter()
+            .map(|class| {
+                let in_ranges = class
+                    .ranges
+                    .par_iter()
+                    .map(|(from, to)| {
+                        if from == to {
+                            return Flag::Encrypted(self.scalar_eq_parallelized(byte, *from));
+                        }
+                        let (lower_bound, upper_bound) = rayon::join(
+                            || Flag::Encrypted(self.scalar_ge_parallelized(byte, *from)),
+                            || Flag::Encrypted(self.scalar_le_parallelized(byte, *to)),
+                        );
+                        lower_bound.and(&upper_bound, self)
+                    })
+                    .collect();
+                Flag::any(in_ranges, self)
+            })
+            .collect::<Vec<_>>();
+
+        regex
+            .class_groups
+            .par_iter()
+            .map(|group| {
+                Flag::any(
+                    group.iter().map(|class| in_class[*class].clone()).collect(),
+                    self,
+                )
+            })
+            .collect()
+    }
+}
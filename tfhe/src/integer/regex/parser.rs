@@ -0,0 +1,404 @@
+//! Parsing of regex patterns.
+//!
+//! Patterns are written between slashes, optionally followed by the `i` modifier for case
+//! insensitive matching, e.g. `/^ab|cd$/i`.
+//!
+//! Grammar of the expression between the slashes:
+//!
+//! ```text
+//! <pattern> ::= [ '^' ] <regex> [ '$' ]
+//! <regex>   ::= <term> { '|' <term> }
+//! <term>    ::= { <factor> }
+//! <factor>  ::= <atom> [ '?' | '*' | '+' | '{' <repetition> '}' ]
+//! <atom>    ::= <char> | '\' <any char> | '.' | '[' [ '^' ] <class items> ']' | '(' <regex> ')'
+//! ```
+use super::RegexError;
+
+/// Maximum number of repetitions allowed in a `{m,n}` quantifier
+pub(super) const MAX_REPETITIONS: usize = 1000;
+
+const META_CHARACTERS: &[u8] = b".\\[]()|?*+{}^$/";
+
+/// A set of bytes, as a bitmap
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(super) struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    pub(super) const fn empty() -> Self {
+        Self([0; 4])
+    }
+
+    pub(super) fn single(byte: u8) -> Self {
+        let mut set = Self::empty();
+        set.insert(byte);
+        set
+    }
+
+    pub(super) fn range(from: u8, to: u8) -> Self {
+        let mut set = Self::empty();
+        for byte in from..=to {
+            set.insert(byte);
+        }
+        set
+    }
+
+    /// All the bytes, except the null byte which is reserved to mark the end of the content
+    pub(super) fn any() -> Self {
+        Self::range(1, u8::MAX)
+    }
+
+    pub(super) fn insert(&mut self, byte: u8) {
+        self.0[usize::from(byte) / 64] |= 1 << (byte % 64);
+    }
+
+    pub(super) fn contains(&self, byte: u8) -> bool {
+        (self.0[usize::from(byte) / 64] >> (byte % 64)) & 1 == 1
+    }
+
+    pub(super) fn union(&self, other: &Self) -> Self {
+        let mut result = *self;
+        for (lhs, rhs) in result.0.iter_mut().zip(other.0.iter()) {
+            *lhs |= rhs;
+        }
+        result
+    }
+
+    pub(super) fn complement(&self) -> Self {
+        let mut result = Self::empty();
+        for byte in 1..=u8::MAX {
+            if !self.contains(byte) {
+                result.insert(byte);
+            }
+        }
+        result
+    }
+
+    fn case_insensitive(&self) -> Self {
+        let mut result = *self;
+        for byte in 0..=u8::MAX {
+            if self.contains(byte) {
+                result.insert(byte.to_ascii_lowercase());
+                result.insert(byte.to_ascii_uppercase());
+            }
+        }
+        result
+    }
+}
+
+/// The parsed expression
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum RegExpr {
+    /// Matches one byte of the set
+    Set(ByteSet),
+    Seq(Vec<Self>),
+    Either(Vec<Self>),
+    Repeated {
+        repeat_re: Box<Self>,
+        at_least: usize,
+        /// If None, there is no upper limit
+        at_most: Option<usize>,
+    },
+}
+
+impl RegExpr {
+    fn case_insensitive(self) -> Self {
+        match self {
+            Self::Set(set) => Self::Set(set.case_insensitive()),
+            Self::Seq(re_xs) => Self::Seq(re_xs.into_iter().map(Self::case_insensitive).collect()),
+            Self::Either(re_xs) => {
+                Self::Either(re_xs.into_iter().map(Self::case_insensitive).collect())
+            }
+            Self::Repeated {
+                repeat_re,
+                at_least,
+                at_most,
+            } => Self::Repeated {
+                repeat_re: Box::new(repeat_re.case_insensitive()),
+                at_least,
+                at_most,
+            },
+        }
+    }
+}
+
+/// A parsed pattern
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct ParsedPattern {
+    pub(super) re: RegExpr,
+    /// The pattern starts with `^`
+    pub(super) anchored_start: bool,
+    /// The pattern ends with `$`
+    pub(super) anchored_end: bool,
+}
+
+pub(super) fn parse(pattern: &str) -> Result<ParsedPattern, RegexError> {
+    let bytes = pattern.as_bytes();
+    if bytes.first() != Some(&b'/') {
+        return Err(RegexError::new("pattern must start with '/'"));
+    }
+    let closing = bytes
+        .iter()
+        .rposition(|byte| *byte == b'/')
+        .filter(|position| *position > 0)
+        .ok_or_else(|| RegexError::new("pattern must end with '/', optionally followed by 'i'"))?;
+
+    let case_insensitive = match &bytes[closing + 1..] {
+        [] => false,
+        [b'i'] => true,
+        modifiers => {
+            return Err(RegexError::new(format!(
+                "unknown modifiers: {}",
+                String::from_utf8_lossy(modifiers)
+            )))
+        }
+    };
+
+    let mut parser = Parser {
+        input: &bytes[1..closing],
+        position: 0,
+    };
+    let anchored_start = parser.eat(b'^');
+    let re = parser.regex()?;
+    let anchored_end = parser.eat(b'$');
+    if let Some(byte) = parser.peek() {
+        return Err(parser.unexpected(byte));
+    }
+
+    Ok(ParsedPattern {
+        re: if case_insensitive {
+            re.case_insensitive()
+        } else {
+            re
+        },
+        anchored_start,
+        anchored_end,
+    })
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Some(byte)
+    }
+
+    fn eat(&mut self, expected: u8) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), RegexError> {
+        match self.peek() {
+            Some(byte) if byte == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            Some(byte) => Err(self.unexpected(byte)),
+            None => Err(RegexError::new(format!(
+                "unexpected end of pattern, expected '{}'",
+                char::from(expected)
+            ))),
+        }
+    }
+
+    fn unexpected(&self, byte: u8) -> RegexError {
+        RegexError::new(format!(
+            "unexpected token '{}' at position {}",
+            char::from(byte),
+            self.position
+        ))
+    }
+
+    fn regex(&mut self) -> Result<RegExpr, RegexError> {
+        let mut alternatives = vec![self.term()?];
+        while self.eat(b'|') {
+            alternatives.push(self.term()?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.pop().unwrap()
+        } else {
+            RegExpr::Either(alternatives)
+        })
+    }
+
+    fn term(&mut self) -> Result<RegExpr, RegexError> {
+        let mut re_xs = vec![];
+        while let Some(byte) = self.peek() {
+            if matches!(byte, b'|' | b')')
+                || (byte == b'$' && self.position + 1 == self.input.len())
+            {
+                break;
+            }
+            re_xs.push(self.factor()?);
+        }
+        Ok(if re_xs.len() == 1 {
+            re_xs.pop().unwrap()
+        } else {
+            RegExpr::Seq(re_xs)
+        })
+    }
+
+    fn factor(&mut self) -> Result<RegExpr, RegexError> {
+        let atom = self.atom()?;
+        let (at_least, at_most) = if self.eat(b'?') {
+            (0, Some(1))
+        } else if self.eat(b'*') {
+            (0, None)
+        } else if self.eat(b'+') {
+            (1, None)
+        } else if self.eat(b'{') {
+            let repetition = self.repetition()?;
+            self.expect(b'}')?;
+            repetition
+        } else {
+            return Ok(atom);
+        };
+
+        Ok(RegExpr::Repeated {
+            repeat_re: Box::new(atom),
+            at_least,
+            at_most,
+        })
+    }
+
+    fn repetition(&mut self) -> Result<(usize, Option<usize>), RegexError> {
+        let at_least = self.number()?;
+        let at_most = if self.eat(b',') {
+            self.number()?
+        } else {
+            // `{n}`
+            Some(at_least.ok_or_else(|| RegexError::new("empty repetition count"))?)
+        };
+        let at_least = at_least.unwrap_or(0);
+
+        if at_most.map_or(at_least, |at_most| at_most.max(at_least)) > MAX_REPETITIONS {
+            return Err(RegexError::new(format!(
+                "repetition count must not exceed {MAX_REPETITIONS}"
+            )));
+        }
+        if at_most.is_some_and(|at_most| at_most < at_least) {
+            return Err(RegexError::new(format!(
+                "invalid repetition range, {at_least} is greater than {}",
+                at_most.unwrap()
+            )));
+        }
+
+        Ok((at_least, at_most))
+    }
+
+    fn number(&mut self) -> Result<Option<usize>, RegexError> {
+        let start = self.position;
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Ok(None);
+        }
+        std::str::from_utf8(&self.input[start..self.position])
+            .unwrap()
+            .parse()
+            .map(Some)
+            .map_err(|_| RegexError::new("repetition count is too large"))
+    }
+
+    fn atom(&mut self) -> Result<RegExpr, RegexError> {
+        let Some(byte) = self.next() else {
+            return Err(RegexError::new("unexpected end of pattern"));
+        };
+        match byte {
+            b'.' => Ok(RegExpr::Set(ByteSet::any())),
+            b'\\' => self
+                .escaped()
+                .map(|byte| RegExpr::Set(ByteSet::single(byte))),
+            b'[' => {
+                let set = self.class()?;
+                self.expect(b']')?;
+                Ok(RegExpr::Set(set))
+            }
+            b'(' => {
+                let re = self.regex()?;
+                self.expect(b')')?;
+                Ok(re)
+            }
+            byte if is_literal(byte) => Ok(RegExpr::Set(ByteSet::single(byte))),
+            byte => {
+                self.position -= 1;
+                Err(self.unexpected(byte))
+            }
+        }
+    }
+
+    fn escaped(&mut self) -> Result<u8, RegexError> {
+        match self.next() {
+            Some(0) => Err(RegexError::new("null characters cannot be matched")),
+            Some(byte) => Ok(byte),
+            None => Err(RegexError::new("unexpected end of pattern after '\\'")),
+        }
+    }
+
+    fn class(&mut self) -> Result<ByteSet, RegexError> {
+        let negated = self.eat(b'^');
+        let mut set = ByteSet::empty();
+        loop {
+            let from = match self.peek() {
+                Some(b']') | None => break,
+                Some(b'\\') => {
+                    self.position += 1;
+                    self.escaped()?
+                }
+                Some(0) => return Err(RegexError::new("null characters cannot be matched")),
+                Some(byte) => {
+                    self.position += 1;
+                    byte
+                }
+            };
+
+            let is_range = self.peek() == Some(b'-')
+                && self
+                    .input
+                    .get(self.position + 1)
+                    .is_some_and(|byte| *byte != b']');
+            if is_range {
+                self.position += 1;
+                let to = match self.next() {
+                    Some(b'\\') => self.escaped()?,
+                    Some(byte) => byte,
+                    None => unreachable!(),
+                };
+                if to < from {
+                    return Err(RegexError::new(format!(
+                        "invalid class range {}-{}",
+                        char::from(from),
+                        char::from(to)
+                    )));
+                }
+                set = set.union(&ByteSet::range(from, to));
+            } else {
+                set.insert(from);
+            }
+        }
+
+        if set == ByteSet::empty() {
+            return Err(RegexError::new("empty character class"));
+        }
+
+        Ok(if negated { set.complement() } else { set })
+    }
+}
+
+fn is_literal(byte: u8) -> bool {
+    (byte.is_ascii_graphic() || byte == b' ') && !META_CHARACTERS.contains(&byte)
+}
@@ -0,0 +1,124 @@
+use test_case::test_case;
+
+use super::parser::{parse, ByteSet, RegExpr};
+use super::Regex;
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+const NB_CTXT: usize = 4;
+
+fn char_re(c: u8) -> RegExpr {
+    RegExpr::Set(ByteSet::single(c))
+}
+
+#[test_case("/h/", &char_re(b'h'); "char")]
+#[test_case("/&/", &char_re(b'&'); "not necessary to escape ampersand")]
+#[test_case("/ /", &char_re(b' '); "space")]
+#[test_case("/\\./", &char_re(b'.'); "escaped dot")]
+#[test_case("/./", &RegExpr::Set(ByteSet::any()); "any")]
+#[test_case("/[a-c]/", &RegExpr::Set(ByteSet::range(b'a', b'c')); "range")]
+#[test_case("/ab/", &RegExpr::Seq(vec![char_re(b'a'), char_re(b'b')]); "seq")]
+#[test_case("/a|b|c/", &RegExpr::Either(vec![char_re(b'a'), char_re(b'b'), char_re(b'c')]); "either")]
+#[test_case("/a{2,}/",
+    &RegExpr::Repeated { repeat_re: Box::new(char_re(b'a')), at_least: 2, at_most: None };
+    "at least")]
+#[test_case("/a{,3}/",
+    &RegExpr::Repeated { repeat_re: Box::new(char_re(b'a')), at_least: 0, at_most: Some(3) };
+    "at most")]
+#[test_case("/(ab)?/",
+    &RegExpr::Repeated {
+        repeat_re: Box::new(RegExpr::Seq(vec![char_re(b'a'), char_re(b'b')])),
+        at_least: 0,
+        at_most: Some(1),
+    };
+    "optional group")]
+fn test_parser(pattern: &str, expected: &RegExpr) {
+    let parsed = parse(pattern).unwrap();
+    assert_eq!(&parsed.re, expected);
+}
+
+#[test_case("ab"; "missing slashes")]
+#[test_case("/ab/x"; "unknown modifier")]
+#[test_case("/(ab/"; "unclosed group")]
+#[test_case("/ab)/"; "unopened group")]
+#[test_case("/a{3,2}/"; "invalid repetition range")]
+#[test_case("/a{1001}/"; "too many repetitions")]
+#[test_case("/a$b/"; "end anchor in the middle")]
+#[test_case("/*a/"; "quantifier without atom")]
+#[test_case("/[]/"; "empty class")]
+fn test_parser_errors(pattern: &str) {
+    assert!(Regex::new(pattern).is_err());
+}
+
+#[test_case("ab", "/ab/", Some(0))]
+#[test_case("b", "/ab/", None)]
+#[test_case("ab", "/a?b/", Some(0))]
+#[test_case("b", "/a?b/", Some(0))]
+#[test_case("ab", "/^ab|cd$/", Some(0))]
+#[test_case(" ab", "/^ab|cd$/", None)]
+#[test_case(" cd", "/^ab|cd$/", None)]
+#[test_case("cd", "/^ab|cd$/", Some(0))]
+#[test_case("abcd", "/^ab|cd$/", None)]
+#[test_case("abcd", "/ab|cd$/", Some(2))]
+#[test_case("123abc456", "/abc/", Some(3))]
+#[test_case("123abdc456", "/abc/", None)]
+#[test_case("cdaabc", "/a*bc/", Some(2))]
+#[test_case("cdbc", "/a+bc/", None)]
+#[test_case("Ab", "/ab/i", Some(0) ; "ab case insensitive")]
+#[test_case("Ab", "/ab/", None ; "ab case sensitive")]
+#[test_case("cD", "/ab|cd/i", Some(0))]
+#[test_case("test a num 8", "/8/", Some(11))]
+#[test_case("test a num 8", "/^8/", None)]
+#[test_case("4453", "/^[0-9]*$/", Some(0))]
+#[test_case("4453", "/^[09]*$/", None)]
+#[test_case("09009", "/^[09]*$/", Some(0))]
+#[test_case(" de", "/^ab|cd|de$/", None ; "multiple or nests below ^")]
+#[test_case("xaaay", "/a{2,3}y/", Some(1))]
+#[test_case("xaaaay", "/xa{2,3}y/", None)]
+#[test_case("ab1_", "/^[a-z0-9_]{4}$/", Some(0))]
+#[test_case("ab-", "/[^a-z]/", Some(2))]
+#[test_case("", "/a*$/", Some(0) ; "empty content")]
+#[test_case("ba", "/b*$/", Some(2) ; "empty match at the end")]
+#[test_case("ab\0\0", "/b$/", Some(1) ; "padded content")]
+#[test_case("a\0b", "/a.b/", None ; "null is never matched")]
+fn test_clear_evaluation(content: &str, pattern: &str, expected: Option<usize>) {
+    let regex = Regex::new(pattern).unwrap();
+    let match_starts = regex.clear_match_starts(content.as_bytes());
+    assert_eq!(match_starts.iter().position(|m| *m), expected);
+}
+
+#[test]
+fn test_dfa_is_small() {
+    // Bounded repetitions of a class share the classes of the automaton
+    let regex = Regex::new("/[a-z]{3}@[a-z]{3}/").unwrap();
+    assert_eq!(regex.dfa.classes.len(), 2);
+    assert_eq!(regex.num_states(), 9);
+}
+
+#[test]
+fn test_regex_find() {
+    let (cks, sks) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    for (content, pattern, expected) in [
+        ("xaby", "/a[a-z]/", Some(1u64)),
+        ("xaby", "/^ab/", None),
+        ("ab\0", "/B$/i", Some(1)),
+    ] {
+        let ct_content = content
+            .bytes()
+            .map(|byte| cks.encrypt(u64::from(byte)))
+            .collect::<Vec<_>>();
+        let regex = Regex::new(pattern).unwrap();
+
+        let (position, is_match) = sks.regex_find_parallelized(&ct_content, &regex);
+        let position: u64 = cks.decrypt(&position);
+        assert_eq!(cks.decrypt_bool(&is_match), expected.is_some());
+        assert_eq!(position, expected.unwrap_or(0));
+
+        let is_match = sks.regex_is_match_parallelized(&ct_content, &regex);
+        assert_eq!(cks.decrypt_bool(&is_match), expected.is_some());
+    }
+}
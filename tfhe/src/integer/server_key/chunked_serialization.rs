@@ -0,0 +1,139 @@
+//! Chunked serialization of the integer server keys.
+//!
+//! The keys are written with the format described in
+//! [crate::shortint::server_key::chunked_serialization], using the [Named](crate::named::Named)
+//! type name of the integer keys, so that an integer key can not be read as a shortint key (or the
+//! other way around).
+
+use crate::integer::{CompressedServerKey, ServerKey};
+use crate::shortint::server_key::chunked_serialization as shortint_chunked;
+use crate::shortint::PBSParameters;
+use std::io::{Read, Write};
+
+/// Serializes a [ServerKey] into a [writer](std::io::Write) using the chunked format.
+pub fn safe_serialize_chunked_server_key(
+    server_key: &ServerKey,
+    writer: impl Write,
+) -> bincode::Result<()> {
+    shortint_chunked::serialize_server_key_as::<ServerKey>(&server_key.key, writer)
+}
+
+/// Serializes a [CompressedServerKey] into a [writer](std::io::Write) using the chunked format.
+pub fn safe_serialize_chunked_compressed_server_key(
+    server_key: &CompressedServerKey,
+    writer: impl Write,
+) -> bincode::Result<()> {
+    shortint_chunked::serialize_compressed_server_key_as::<CompressedServerKey>(
+        &server_key.key,
+        writer,
+    )
+}
+
+/// Deserializes a [ServerKey] serialized by [safe_serialize_chunked_server_key]
+/// from a [reader](std::io::Read).
+///
+/// The same checks as [shortint_chunked::safe_deserialize_chunked_server_key] are done.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::integer::server_key::chunked_serialization::{
+///     safe_deserialize_chunked_server_key, safe_serialize_chunked_server_key,
+/// };
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let num_blocks = 4;
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+///
+/// let mut buffer = vec![];
+/// safe_serialize_chunked_server_key(&sks, &mut buffer).unwrap();
+///
+/// let sks = safe_deserialize_chunked_server_key(
+///     buffer.as_slice(),
+///     &PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
+/// )
+/// .unwrap();
+///
+/// let ct = cks.encrypt(12u64);
+/// let ct_res = sks.add_parallelized(&ct, &ct);
+/// let dec: u64 = cks.decrypt(&ct_res);
+/// assert_eq!(dec, 24);
+/// ```
+pub fn safe_deserialize_chunked_server_key(
+    reader: impl Read,
+    parameter_set: &PBSParameters,
+) -> Result<ServerKey, String> {
+    shortint_chunked::deserialize_server_key_as::<ServerKey>(reader, parameter_set)
+        .map(|key| ServerKey { key })
+}
+
+/// Deserializes a [CompressedServerKey] serialized by
+/// [safe_serialize_chunked_compressed_server_key] from a [reader](std::io::Read).
+pub fn safe_deserialize_chunked_compressed_server_key(
+    reader: impl Read,
+    parameter_set: &PBSParameters,
+) -> Result<CompressedServerKey, String> {
+    shortint_chunked::deserialize_compressed_server_key_as::<CompressedServerKey>(
+        reader,
+        parameter_set,
+    )
+    .map(|key| CompressedServerKey { key })
+}
+
+/// Deserializes a [CompressedServerKey] serialized by
+/// [safe_serialize_chunked_compressed_server_key] from a [reader](std::io::Read),
+/// and decompresses it into a [ServerKey].
+///
+/// See [shortint_chunked::safe_deserialize_chunked_compressed_server_key_and_decompress] for the
+/// memory usage of the decompression.
+pub fn safe_deserialize_chunked_compressed_server_key_and_decompress(
+    reader: impl Read,
+    parameter_set: &PBSParameters,
+) -> Result<ServerKey, String> {
+    shortint_chunked::deserialize_compressed_server_key_and_decompress_as::<CompressedServerKey>(
+        reader,
+        parameter_set,
+    )
+    .map(|key| ServerKey { key })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::ClientKey;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    #[test]
+    fn test_chunked_integer_server_key_serialization() {
+        let parameters = PBSParameters::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let cks = ClientKey::new(parameters);
+        let compressed_sks = CompressedServerKey::new_radix_compressed_server_key(&cks);
+        let sks = ServerKey::from(compressed_sks.clone());
+
+        let mut buffer = vec![];
+        safe_serialize_chunked_server_key(&sks, &mut buffer).unwrap();
+        let deserialized =
+            safe_deserialize_chunked_server_key(buffer.as_slice(), &parameters).unwrap();
+        assert_eq!(deserialized.key, sks.key);
+
+        // An integer key is not a shortint key
+        let err =
+            shortint_chunked::safe_deserialize_chunked_server_key(buffer.as_slice(), &parameters)
+                .unwrap_err();
+        assert!(err.contains("expected type"), "{err}");
+
+        let mut buffer = vec![];
+        safe_serialize_chunked_compressed_server_key(&compressed_sks, &mut buffer).unwrap();
+        let deserialized =
+            safe_deserialize_chunked_compressed_server_key(buffer.as_slice(), &parameters).unwrap();
+        assert_eq!(deserialized.key, compressed_sks.key);
+
+        let decompressed = safe_deserialize_chunked_compressed_server_key_and_decompress(
+            buffer.as_slice(),
+            &parameters,
+        )
+        .unwrap();
+        assert_eq!(decompressed.key, sks.key);
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! This module implements the generation of the server public key, together with all the
 //! available homomorphic integer operations.
+#[cfg(feature = "safe-deserialization")]
+pub mod chunked_serialization;
 pub mod comparator;
 mod crt;
 mod crt_parallel;
@@ -28,6 +30,10 @@ pub struct ServerKey {
     pub(crate) key: crate::shortint::ServerKey,
 }
 
+impl crate::named::Named for ServerKey {
+    const NAME: &'static str = "integer::ServerKey";
+}
+
 impl From<ServerKey> for crate::shortint::ServerKey {
     fn from(key: ServerKey) -> Self {
         key.key
@@ -197,6 +203,10 @@ pub struct CompressedServerKey {
     pub(crate) key: crate::shortint::CompressedServerKey,
 }
 
+impl crate::named::Named for CompressedServerKey {
+    const NAME: &'static str = "integer::CompressedServerKey";
+}
+
 impl CompressedServerKey {
     pub fn new_radix_compressed_server_key(client_key: &ClientKey) -> Self {
         let max_degree = integer_radix_server_key_max_degree(client_key.key.parameters);
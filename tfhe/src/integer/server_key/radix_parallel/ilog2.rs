@@ -0,0 +1,416 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use crate::shortint::server_key::BivariateLookupTableOwned;
+use crate::shortint::Ciphertext;
+
+use rayon::prelude::*;
+use std::borrow::Cow;
+
+/// Tells in which direction consecutive zero bits are counted
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Direction {
+    /// Counting starts from the most significant bit
+    Leading,
+    /// Counting starts from the least significant bit
+    Trailing,
+}
+
+impl ServerKey {
+    /// Returns the number of blocks needed to represent the number of bits
+    /// of a ciphertext made of `num_blocks` blocks, i.e. a value in
+    /// `[0, num_blocks * bits_per_block]`
+    fn num_blocks_for_bit_count(&self, num_blocks: usize) -> usize {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let num_bits = num_blocks * bits_per_block;
+        let num_bits_needed = num_bits.ilog2() as usize + 1;
+        (num_bits_needed + bits_per_block - 1) / bits_per_block
+    }
+
+    /// Returns `ct` if its blocks have empty carries,
+    /// or a copy of it whose carries were propagated otherwise
+    fn propagate_carries_if_needed<'a, T>(&self, ct: &'a T) -> Cow<'a, T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.block_carries_are_empty() {
+            Cow::Borrowed(ct)
+        } else {
+            let mut tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            Cow::Owned(tmp_ct)
+        }
+    }
+
+    /// Sums the `blocks`, each one encrypting a value that fits in the message space,
+    /// into a radix ciphertext of `num_result_blocks` blocks
+    fn sum_blocks_into_radix(
+        &self,
+        blocks: Vec<Ciphertext>,
+        num_result_blocks: usize,
+    ) -> RadixCiphertext {
+        let to_sum = blocks
+            .into_iter()
+            .map(|block| {
+                let mut ct: RadixCiphertext = self.create_trivial_zero_radix(num_result_blocks);
+                ct.blocks[0] = block;
+                ct
+            })
+            .collect::<Vec<_>>();
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(to_sum)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_result_blocks))
+    }
+
+    /// Computes the inclusive prefix of `blocks` with respect to the
+    /// associative operation encoded in `lut`, using the Hillis-Steele algorithm.
+    fn unchecked_block_prefix_scan_parallelized(
+        &self,
+        blocks: &mut [Ciphertext],
+        lut: &BivariateLookupTableOwned,
+    ) {
+        let num_blocks = blocks.len();
+        let mut space = 1;
+        while space < num_blocks {
+            let step_output = blocks[space..]
+                .par_iter()
+                .zip(blocks[..num_blocks - space].par_iter())
+                .map(|(current, previous)| {
+                    self.key
+                        .unchecked_apply_lookup_table_bivariate(current, previous, lut)
+                })
+                .collect::<Vec<_>>();
+
+            for (block, new_block) in blocks[space..].iter_mut().zip(step_output) {
+                *block = new_block;
+            }
+
+            space *= 2;
+        }
+    }
+
+    /// Counts the number of consecutive zero bits, starting from the direction given.
+    ///
+    /// The input ciphertext's blocks must have empty carries.
+    fn unchecked_count_consecutive_zeros_parallelized<T>(
+        &self,
+        ct: &T,
+        direction: Direction,
+    ) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_per_block = message_modulus.ilog2();
+        let num_result_blocks = self.num_blocks_for_bit_count(ct.blocks().len());
+
+        // Blocks are ordered such that counting starts from the first block
+        let mut blocks = ct.blocks().to_vec();
+        if direction == Direction::Leading {
+            blocks.reverse();
+        }
+
+        let zeros_in_block = |x: u64| {
+            let x = x % message_modulus;
+            if x == 0 {
+                u64::from(bits_per_block)
+            } else if direction == Direction::Leading {
+                u64::from(bits_per_block - 1 - x.ilog2())
+            } else {
+                u64::from(x.trailing_zeros())
+            }
+        };
+
+        // For each block, whether all blocks from the first one up to it
+        // (inclusive) are zeros
+        let is_zero_lut = self
+            .key
+            .generate_lookup_table(|x| u64::from(x % message_modulus == 0));
+        let mut all_zeros_so_far = blocks
+            .par_iter()
+            .map(|block| self.key.apply_lookup_table(block, &is_zero_lut))
+            .collect::<Vec<_>>();
+        let and_lut = self.key.generate_lookup_table_bivariate(|x, y| x & y);
+        self.unchecked_block_prefix_scan_parallelized(&mut all_zeros_so_far, &and_lut);
+
+        // A block contributes its count of zeros only if all the
+        // preceding blocks are zeros
+        let contribution_lut =
+            self.key
+                .generate_lookup_table_bivariate(|x, preceding_are_zeros| {
+                    if preceding_are_zeros == 1 {
+                        zeros_in_block(x)
+                    } else {
+                        0
+                    }
+                });
+        let first_block_lut = self.key.generate_lookup_table(zeros_in_block);
+        let contributions = blocks
+            .par_iter()
+            .enumerate()
+            .map(|(i, block)| {
+                if i == 0 {
+                    self.key.apply_lookup_table(block, &first_block_lut)
+                } else {
+                    self.key.unchecked_apply_lookup_table_bivariate(
+                        block,
+                        &all_zeros_so_far[i - 1],
+                        &contribution_lut,
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.sum_blocks_into_radix(contributions, num_result_blocks)
+    }
+
+    /// Returns the number of leading zeros in the binary representation of `ct`
+    ///
+    /// The returned ciphertext has enough blocks to represent the number
+    /// of bits of `ct`.
+    ///
+    /// The input ciphertext's blocks must have empty carries.
+    pub fn unchecked_leading_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.unchecked_count_consecutive_zeros_parallelized(ct, Direction::Leading)
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `ct`
+    ///
+    /// The returned ciphertext has enough blocks to represent the number
+    /// of bits of `ct`.
+    ///
+    /// The input ciphertext's blocks must have empty carries.
+    pub fn unchecked_trailing_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.unchecked_count_consecutive_zeros_parallelized(ct, Direction::Trailing)
+    }
+
+    /// Returns the number of ones in the binary representation of `ct`
+    ///
+    /// The returned ciphertext has enough blocks to represent the number
+    /// of bits of `ct`.
+    ///
+    /// The input ciphertext's blocks must have empty carries.
+    pub fn unchecked_count_ones_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let num_result_blocks = self.num_blocks_for_bit_count(ct.blocks().len());
+
+        let lut = self
+            .key
+            .generate_lookup_table(|x| u64::from((x % message_modulus).count_ones()));
+        let counts = ct
+            .blocks()
+            .par_iter()
+            .map(|block| self.key.apply_lookup_table(block, &lut))
+            .collect::<Vec<_>>();
+
+        self.sum_blocks_into_radix(counts, num_result_blocks)
+    }
+
+    /// Returns the base 2 logarithm of `ct`, rounded down.
+    ///
+    /// The returned ciphertext has enough blocks to represent the number
+    /// of bits of `ct`.
+    ///
+    /// The result is not meaningful if `ct` encrypts a value that is less than
+    /// or equal to zero, see [Self::checked_ilog2_parallelized].
+    ///
+    /// The input ciphertext's blocks must have empty carries.
+    pub fn unchecked_ilog2_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as u64;
+        let num_bits = bits_per_block * ct.blocks().len() as u64;
+
+        // ilog2(x) = (num_bits - 1) - leading_zeros(x)
+        let leading_zeros = self.unchecked_leading_zeros_parallelized(ct);
+        let mut result = self.neg_parallelized(&leading_zeros);
+        self.scalar_add_assign_parallelized(&mut result, num_bits - 1);
+        result
+    }
+
+    /// Returns the number of leading zeros in the binary representation of `ct`
+    ///
+    /// The returned ciphertext has enough blocks to represent the number
+    /// of bits of `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 5u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.leading_zeros_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.leading_zeros());
+    /// ```
+    pub fn leading_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let ct = self.propagate_carries_if_needed(ct);
+        self.unchecked_leading_zeros_parallelized(&*ct)
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of `ct`
+    ///
+    /// The returned ciphertext has enough blocks to represent the number
+    /// of bits of `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = -4i8;
+    ///
+    /// let ct = cks.encrypt_signed(msg);
+    ///
+    /// let ct_res = sks.trailing_zeros_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.trailing_zeros());
+    /// ```
+    pub fn trailing_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let ct = self.propagate_carries_if_needed(ct);
+        self.unchecked_trailing_zeros_parallelized(&*ct)
+    }
+
+    /// Returns the number of ones in the binary representation of `ct`
+    ///
+    /// The returned ciphertext has enough blocks to represent the number
+    /// of bits of `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 183u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.count_ones_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.count_ones());
+    /// ```
+    pub fn count_ones_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let ct = self.propagate_carries_if_needed(ct);
+        self.unchecked_count_ones_parallelized(&*ct)
+    }
+
+    /// Returns the base 2 logarithm of `ct`, rounded down.
+    ///
+    /// The returned ciphertext has enough blocks to represent the number
+    /// of bits of `ct`.
+    ///
+    /// The result is not meaningful if `ct` encrypts a value that is less than
+    /// or equal to zero, see [Self::checked_ilog2_parallelized].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 100u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.ilog2_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.ilog2());
+    /// ```
+    pub fn ilog2_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let ct = self.propagate_carries_if_needed(ct);
+        self.unchecked_ilog2_parallelized(&*ct)
+    }
+
+    /// Returns the base 2 logarithm of `ct`, rounded down,
+    /// and a boolean telling whether the result is valid, i.e. if `ct` encrypts
+    /// a value greater than zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 0u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let (_ct_res, is_ok) = sks.checked_ilog2_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let is_ok = cks.decrypt_bool(&is_ok);
+    /// assert!(!is_ok);
+    /// ```
+    pub fn checked_ilog2_parallelized<T>(&self, ct: &T) -> (RadixCiphertext, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let ct = self.propagate_carries_if_needed(ct);
+
+        rayon::join(
+            || self.unchecked_ilog2_parallelized(&*ct),
+            || {
+                if T::IS_SIGNED {
+                    self.unchecked_scalar_gt_parallelized(&*ct, 0)
+                } else {
+                    self.unchecked_scalar_ne_parallelized(&*ct, 0)
+                }
+            },
+        )
+    }
+}
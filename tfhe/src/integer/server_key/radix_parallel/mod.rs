@@ -5,8 +5,10 @@ mod bitwise_op;
 mod cmux;
 mod comparison;
 mod div_mod;
+mod ilog2;
 mod mul;
 mod neg;
+mod pow;
 mod rotate;
 mod scalar_add;
 mod scalar_bitwise_op;
@@ -17,6 +19,8 @@ mod scalar_rotate;
 mod scalar_shift;
 mod scalar_sub;
 mod shift;
+mod sort;
+mod sqrt;
 pub(crate) mod sub;
 
 #[cfg(test)]
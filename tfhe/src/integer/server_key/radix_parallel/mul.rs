@@ -1,5 +1,5 @@
-use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::ServerKey;
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
 use rayon::prelude::*;
 
 impl ServerKey {
@@ -588,4 +588,382 @@ impl ServerKey {
 
         self.unchecked_mul_assign_parallelized(lhs, rhs);
     }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting unsigned
+    /// integer values, and returns a boolean indicating if the multiplication overflowed.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_unsigned_overflowing_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "Left hand side must must have a number of blocks equal \
+            to the number of blocks of the right hand side: lhs {} blocks, rhs {} blocks",
+            lhs.blocks.len(),
+            rhs.blocks.len()
+        );
+        let num_blocks = lhs.blocks.len();
+        let message_modulus = self.key.message_modulus.0;
+
+        let lsb_block_mul_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, y| (x * y) % message_modulus as u64);
+        let msb_block_mul_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, y| (x * y) / message_modulus as u64);
+        let block_mul_is_not_zero_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, y| u64::from(x * y != 0));
+        let block_mul_msb_is_not_zero_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, y| u64::from(x * y >= message_modulus as u64));
+
+        // The partial products landing in the low num_blocks blocks sum to less than
+        // (2 * num_blocks - 1) * message_modulus^num_blocks, a few extra blocks keep the carry
+        // going out of the low blocks so that it can be checked for overflow
+        let mut num_extra_blocks = 1;
+        let mut extra_blocks_capacity = message_modulus;
+        while extra_blocks_capacity < 2 * num_blocks {
+            extra_blocks_capacity *= message_modulus;
+            num_extra_blocks += 1;
+        }
+
+        // Partial product of lhs with a block of rhs, placed at shift and truncated to the
+        // low num_blocks blocks
+        let partial_product =
+            |rhs_block: &crate::shortint::Ciphertext,
+             shift: usize,
+             lut: &crate::shortint::server_key::BivariateLookupTableOwned| {
+                let mut term: RadixCiphertext =
+                    self.create_trivial_zero_radix(num_blocks + num_extra_blocks);
+                term.blocks[shift..num_blocks]
+                    .par_iter_mut()
+                    .zip(lhs.blocks.par_iter())
+                    .filter(|(_, lhs_block)| lhs_block.degree.0 != 0)
+                    .for_each(|(term_block, lhs_block)| {
+                        *term_block = self
+                            .key
+                            .unchecked_apply_lookup_table_bivariate(lhs_block, rhs_block, lut);
+                    });
+                term
+            };
+
+        let non_zero_rhs_blocks = || {
+            rhs.blocks
+                .par_iter()
+                .enumerate()
+                .filter(|(_, block)| block.degree.0 != 0)
+        };
+
+        let (terms, mut overflow_flags) = rayon::join(
+            || {
+                let message_part_terms = non_zero_rhs_blocks()
+                    .map(|(i, rhs_block)| partial_product(rhs_block, i, &lsb_block_mul_lut));
+
+                if message_modulus > 2 {
+                    // Multiplying 2 blocks generates some part this is in the carry,
+                    // it goes one block further
+                    message_part_terms
+                        .chain(
+                            non_zero_rhs_blocks()
+                                .filter(|(i, _)| i + 1 < num_blocks)
+                                .map(|(i, rhs_block)| {
+                                    partial_product(rhs_block, i + 1, &msb_block_mul_lut)
+                                }),
+                        )
+                        .collect::<Vec<_>>()
+                } else {
+                    message_part_terms.collect::<Vec<_>>()
+                }
+            },
+            || {
+                // The partial products which do not fit in the low num_blocks blocks,
+                // the product of lhs block j and rhs block i lands at block i + j
+                // and its carry part at block i + j + 1
+                non_zero_rhs_blocks()
+                    .flat_map(|(i, rhs_block)| {
+                        lhs.blocks[num_blocks - 1 - i..]
+                            .par_iter()
+                            .enumerate()
+                            .filter(|(_, lhs_block)| lhs_block.degree.0 != 0)
+                            .map(|(offset, lhs_block)| {
+                                let lut = if offset == 0 {
+                                    &block_mul_msb_is_not_zero_lut
+                                } else {
+                                    &block_mul_is_not_zero_lut
+                                };
+                                self.key.unchecked_apply_lookup_table_bivariate(
+                                    lhs_block, rhs_block, lut,
+                                )
+                            })
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+
+        let mut result = self
+            .unchecked_sum_ciphertexts_vec_parallelized(terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks + num_extra_blocks));
+
+        // The multiplication overflowed if the sum of the low partial products carried
+        // out of the low blocks, or if any of the high partial products is not zero
+        let carry_blocks = RadixCiphertext::from(result.blocks.split_off(num_blocks));
+        overflow_flags.push(self.unchecked_scalar_ne_parallelized(&carry_blocks, 0).0);
+        let overflowed = BooleanBlock::new_unchecked(
+            self.is_at_least_one_comparisons_block_true(overflow_flags),
+        );
+
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting unsigned
+    /// integer values, and returns a boolean indicating if the multiplication overflowed.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg_1 = 128u8;
+    /// let msg_2 = 3u8;
+    ///
+    /// // Encrypt two messages:
+    /// let ctxt_1 = cks.encrypt(msg_1);
+    /// let ctxt_2 = cks.encrypt(msg_2);
+    ///
+    /// // Compute homomorphically a multiplication
+    /// let (result, overflowed) = sks.unsigned_overflowing_mul_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt:
+    /// let decrypted_result: u8 = cks.decrypt(&result);
+    /// let decrypted_overflow = cks.decrypt_bool(&overflowed);
+    ///
+    /// let (expected_result, expected_overflow) = msg_1.overflowing_mul(msg_2);
+    /// assert_eq!(expected_result, decrypted_result);
+    /// assert_eq!(expected_overflow, decrypted_overflow);
+    /// ```
+    pub fn unsigned_overflowing_mul_parallelized(
+        &self,
+        ctxt_left: &RadixCiphertext,
+        ctxt_right: &RadixCiphertext,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (
+            ctxt_left.block_carries_are_empty(),
+            ctxt_right.block_carries_are_empty(),
+        ) {
+            (true, true) => (ctxt_left, ctxt_right),
+            (true, false) => {
+                tmp_rhs = ctxt_right.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (ctxt_left, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = ctxt_left.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, ctxt_right)
+            }
+            (false, false) => {
+                tmp_lhs = ctxt_left.clone();
+                tmp_rhs = ctxt_right.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_unsigned_overflowing_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting signed
+    /// integer values, and returns a boolean indicating if the multiplication overflowed.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_signed_overflowing_mul_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, BooleanBlock) {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "Left hand side must must have a number of blocks equal \
+            to the number of blocks of the right hand side: lhs {} blocks, rhs {} blocks",
+            lhs.blocks.len(),
+            rhs.blocks.len()
+        );
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+
+        // The magnitude of the product is the product of the magnitudes,
+        // |i::MIN| is correctly represented when seen as an unsigned value
+        let ((abs_lhs, abs_rhs), is_result_negative) = rayon::join(
+            || {
+                rayon::join(
+                    || RadixCiphertext::from(self.unchecked_abs_parallelized(lhs).blocks),
+                    || RadixCiphertext::from(self.unchecked_abs_parallelized(rhs).blocks),
+                )
+            },
+            || {
+                BooleanBlock::new_unchecked(self.key.unchecked_evaluate_bivariate_function(
+                    lhs.blocks.last().unwrap(),
+                    rhs.blocks.last().unwrap(),
+                    |x, y| ((x ^ y) >> sign_bit_pos) & 1,
+                ))
+            },
+        );
+        let (abs_result, abs_overflowed) =
+            self.unchecked_unsigned_overflowing_mul_parallelized(&abs_lhs, &abs_rhs);
+
+        let ((result, is_magnitude_at_least_half), are_lower_bits_set) = rayon::join(
+            || {
+                rayon::join(
+                    || {
+                        let negated = self.neg_parallelized(&abs_result);
+                        let result = self.unchecked_if_then_else_parallelized(
+                            &is_result_negative,
+                            &negated,
+                            &abs_result,
+                        );
+                        SignedRadixCiphertext::from_blocks(result.blocks)
+                    },
+                    || {
+                        // The magnitude is at least 2^(num_bits - 1) if its top bit is set
+                        self.key.apply_lookup_table(
+                            abs_result.blocks.last().unwrap(),
+                            &self.key.generate_lookup_table(|x| (x >> sign_bit_pos) & 1),
+                        )
+                    },
+                )
+            },
+            || {
+                // Whether any bit but the top one of the magnitude is set
+                let mut lower_bits = abs_result.clone();
+                self.key.apply_lookup_table_assign(
+                    lower_bits.blocks.last_mut().unwrap(),
+                    &self
+                        .key
+                        .generate_lookup_table(|x| x % (message_modulus / 2)),
+                );
+                self.unchecked_scalar_ne_parallelized(&lower_bits, 0).0
+            },
+        );
+
+        // A positive result overflows if its magnitude is at least 2^(num_bits - 1),
+        // a negative one if its magnitude is more than 2^(num_bits - 1)
+        let is_bound_exceeded = self.key.unchecked_evaluate_bivariate_function(
+            &is_result_negative.0,
+            &are_lower_bits_set,
+            |is_negative, lower_bits_set| u64::from(is_negative == 0 || lower_bits_set != 0),
+        );
+        let sign_overflowed = self.key.unchecked_evaluate_bivariate_function(
+            &is_magnitude_at_least_half,
+            &is_bound_exceeded,
+            |x, y| x & y,
+        );
+        let overflowed = BooleanBlock::new_unchecked(
+            self.is_at_least_one_comparisons_block_true(vec![abs_overflowed.0, sign_overflowed]),
+        );
+
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting signed
+    /// integer values, and returns a boolean indicating if the multiplication overflowed.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg_1 = i8::MIN;
+    /// let msg_2 = -1i8;
+    ///
+    /// // Encrypt two messages:
+    /// let ctxt_1 = cks.encrypt_signed(msg_1);
+    /// let ctxt_2 = cks.encrypt_signed(msg_2);
+    ///
+    /// // Compute homomorphically a multiplication
+    /// let (result, overflowed) = sks.signed_overflowing_mul_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt:
+    /// let decrypted_result: i8 = cks.decrypt_signed(&result);
+    /// let decrypted_overflow = cks.decrypt_bool(&overflowed);
+    ///
+    /// let (expected_result, expected_overflow) = msg_1.overflowing_mul(msg_2);
+    /// assert_eq!(expected_result, decrypted_result);
+    /// assert_eq!(expected_overflow, decrypted_overflow);
+    /// ```
+    pub fn signed_overflowing_mul_parallelized(
+        &self,
+        ctxt_left: &SignedRadixCiphertext,
+        ctxt_right: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, BooleanBlock) {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (
+            ctxt_left.block_carries_are_empty(),
+            ctxt_right.block_carries_are_empty(),
+        ) {
+            (true, true) => (ctxt_left, ctxt_right),
+            (true, false) => {
+                tmp_rhs = ctxt_right.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (ctxt_left, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = ctxt_left.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, ctxt_right)
+            }
+            (false, false) => {
+                tmp_lhs = ctxt_left.clone();
+                tmp_rhs = ctxt_right.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_signed_overflowing_mul_parallelized(lhs, rhs)
+    }
 }
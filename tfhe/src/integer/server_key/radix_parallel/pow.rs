@@ -0,0 +1,175 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::server_key::radix_parallel::bit_extractor::BitExtractor;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically `base` raised to the power of the clear `exponent`.
+    ///
+    /// The result wraps around if it does not fit in the number of blocks of `base`.
+    ///
+    /// The input ciphertext's blocks must have empty carries.
+    pub fn unchecked_scalar_pow_parallelized<T>(&self, base: &T, exponent: u32) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if exponent == 0 {
+            return self.create_trivial_radix(1u64, base.blocks().len());
+        }
+
+        // Left-to-right square and multiply: starting from the most significant
+        // bit of the exponent (which is the `base` itself), we square the result
+        // and multiply it by the base if the current bit is set.
+        let mut result = base.clone();
+        for i in (0..exponent.ilog2()).rev() {
+            result = self.unchecked_mul_parallelized(&result, &result);
+            if (exponent >> i) & 1 == 1 {
+                self.unchecked_mul_assign_parallelized(&mut result, base);
+            }
+        }
+        result
+    }
+
+    /// Computes homomorphically `base` raised to the power of the encrypted `exponent`.
+    ///
+    /// The result wraps around if it does not fit in the number of blocks of `base`.
+    ///
+    /// The input ciphertexts' blocks must have empty carries.
+    pub fn unchecked_pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let num_bits = exponent.blocks.len() * bits_per_block;
+        let exponent_bits = BitExtractor::new(self, bits_per_block)
+            .extract_all_bits(&exponent.blocks)
+            .into_par_iter()
+            .map(BooleanBlock::new_unchecked)
+            .collect::<Vec<_>>();
+
+        // Right-to-left square and multiply: at step i, `power` is base^(2^i)
+        // and is multiplied into the result if the i-th bit of the exponent is set.
+        let mut result: T = self.create_trivial_radix(1u64, base.blocks().len());
+        let mut power = base.clone();
+        for (i, bit) in exponent_bits.iter().enumerate() {
+            let is_last_bit = i == num_bits - 1;
+            let (multiplied, next_power) = rayon::join(
+                || self.unchecked_mul_parallelized(&result, &power),
+                || {
+                    if is_last_bit {
+                        None
+                    } else {
+                        Some(self.unchecked_mul_parallelized(&power, &power))
+                    }
+                },
+            );
+            result = self.unchecked_if_then_else_parallelized(bit, &multiplied, &result);
+            if let Some(next_power) = next_power {
+                power = next_power;
+            }
+        }
+        result
+    }
+
+    /// Computes homomorphically `base` raised to the power of the clear `exponent`.
+    ///
+    /// The result wraps around if it does not fit in the number of blocks of `base`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 3u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_pow_parallelized(&ct, 5);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.pow(5));
+    /// ```
+    pub fn scalar_pow_parallelized<T>(&self, base: &T, exponent: u32) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_base;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp_base = base.clone();
+            self.full_propagate_parallelized(&mut tmp_base);
+            &tmp_base
+        };
+        self.unchecked_scalar_pow_parallelized(base, exponent)
+    }
+
+    /// Computes homomorphically `base` raised to the power of the encrypted `exponent`.
+    ///
+    /// The result wraps around if it does not fit in the number of blocks of `base`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let ct_exponent = cks.encrypt(exponent);
+    ///
+    /// let ct_res = sks.pow_parallelized(&ct, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.pow(u32::from(exponent)));
+    /// ```
+    pub fn pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_base;
+        let mut tmp_exponent;
+
+        let (base, exponent) = match (
+            base.block_carries_are_empty(),
+            exponent.block_carries_are_empty(),
+        ) {
+            (true, true) => (base, exponent),
+            (true, false) => {
+                tmp_exponent = exponent.clone();
+                self.full_propagate_parallelized(&mut tmp_exponent);
+                (base, &tmp_exponent)
+            }
+            (false, true) => {
+                tmp_base = base.clone();
+                self.full_propagate_parallelized(&mut tmp_base);
+                (&tmp_base, exponent)
+            }
+            (false, false) => {
+                tmp_base = base.clone();
+                tmp_exponent = exponent.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_base),
+                    || self.full_propagate_parallelized(&mut tmp_exponent),
+                );
+                (&tmp_base, &tmp_exponent)
+            }
+        };
+
+        self.unchecked_pow_parallelized(base, exponent)
+    }
+}
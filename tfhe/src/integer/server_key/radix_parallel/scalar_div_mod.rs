@@ -13,6 +13,7 @@
 use std::ops::{Add, AddAssign, BitAnd, Div, Mul, Neg, Shl, Shr, Sub};
 
 use crate::core_crypto::prelude::{CastFrom, CastInto, Numeric, SignedNumeric, UnsignedInteger};
+use crate::integer::bigint::u1024::U1024;
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
 use crate::integer::server_key::radix::scalar_mul::ScalarMultiplier;
@@ -89,6 +90,20 @@ impl MiniUnsignedInteger for U512 {
     }
 }
 
+impl MiniUnsignedInteger for U1024 {
+    fn ceil_ilog2(self) -> u32 {
+        self.ceil_ilog2()
+    }
+
+    fn ilog2(self) -> u32 {
+        self.ilog2()
+    }
+
+    fn is_power_of_two(self) -> bool {
+        self.is_power_of_two()
+    }
+}
+
 pub trait Reciprocable: MiniUnsignedInteger {
     // We need the double precision to compute and store the reciprocal
     // u8 -> u16, u32 -> u64
@@ -123,6 +138,10 @@ impl Reciprocable for U256 {
     type DoublePrecision = U512;
 }
 
+impl Reciprocable for U512 {
+    type DoublePrecision = U1024;
+}
+
 pub trait SignedReciprocable:
     DecomposableInto<u64>
     + DecomposableInto<u8>
@@ -0,0 +1,388 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{RadixCiphertext, ServerKey};
+
+use rayon::prelude::*;
+
+/// Returns the layers of comparators of Batcher's odd-even merge sort network for `n` elements.
+///
+/// Comparators of the same layer work on disjoint pairs of indices `(i, j)` with `i < j`,
+/// so they can be evaluated in parallel.
+fn odd_even_merge_sort_layers(n: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut layers = Vec::new();
+
+    let mut p = 1;
+    while p < n {
+        let mut k = p;
+        while k >= 1 {
+            let mut layer = Vec::new();
+            let mut j = k % p;
+            while j + k < n {
+                for i in 0..k.min(n - j - k) {
+                    if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                        layer.push((i + j, i + j + k));
+                    }
+                }
+                j += 2 * k;
+            }
+            if !layer.is_empty() {
+                layers.push(layer);
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+
+    layers
+}
+
+impl ServerKey {
+    /// Sorts the ciphertexts in ascending order.
+    ///
+    /// The input ciphertexts' blocks must have empty carries.
+    pub fn unchecked_sort_parallelized<T>(&self, cts: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        for layer in odd_even_merge_sort_layers(cts.len()) {
+            let swapped = layer
+                .par_iter()
+                .map(|&(i, j)| {
+                    // A single comparison selects both the min and the max
+                    let (lhs, rhs) = (&cts[i], &cts[j]);
+                    let is_greater = self.unchecked_gt_parallelized(lhs, rhs);
+                    rayon::join(
+                        || self.unchecked_if_then_else_parallelized(&is_greater, rhs, lhs),
+                        || self.unchecked_if_then_else_parallelized(&is_greater, lhs, rhs),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            for ((i, j), (min, max)) in layer.into_iter().zip(swapped) {
+                cts[i] = min;
+                cts[j] = max;
+            }
+        }
+    }
+
+    /// Sorts the ciphertexts in ascending order.
+    ///
+    /// The sort is done using Batcher's odd-even merge sort network,
+    /// so the sequence of operations does not depend on the encrypted values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [37u8, 3, 255, 128, 3];
+    ///
+    /// let mut cts = msgs.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    ///
+    /// sks.sort_parallelized(&mut cts);
+    ///
+    /// // Decrypt:
+    /// let dec = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u8>>();
+    /// assert_eq!(dec, [3, 3, 37, 128, 255]);
+    /// ```
+    pub fn sort_parallelized<T>(&self, cts: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_sort_parallelized(cts);
+    }
+
+    /// Returns the encrypted index of the maximum, or of the minimum
+    /// if `select_max` is false.
+    ///
+    /// In case of ties, the lowest index is returned.
+    fn unchecked_arg_extremum_parallelized<T>(&self, cts: &[T], select_max: bool) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            !cts.is_empty(),
+            "Cannot compute the index of an empty slice"
+        );
+
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let num_index_bits = usize::BITS - (cts.len() - 1).leading_zeros();
+        let num_index_blocks =
+            ((num_index_bits + bits_per_block - 1) / bits_per_block).max(1) as usize;
+
+        let mut candidates = cts
+            .iter()
+            .enumerate()
+            .map(|(index, ct)| {
+                (
+                    ct.clone(),
+                    self.create_trivial_radix::<u64, RadixCiphertext>(
+                        index as u64,
+                        num_index_blocks,
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Tournament where candidates are paired with their neighbour, the left one
+        // always having the lowest indices so that it wins ties.
+        while candidates.len() > 1 {
+            let odd_one = if candidates.len() % 2 == 1 {
+                candidates.pop()
+            } else {
+                None
+            };
+
+            candidates = candidates
+                .par_chunks_exact(2)
+                .map(|pair| {
+                    let (left_value, left_index) = &pair[0];
+                    let (right_value, right_index) = &pair[1];
+                    let right_wins = if select_max {
+                        self.unchecked_gt_parallelized(right_value, left_value)
+                    } else {
+                        self.unchecked_lt_parallelized(right_value, left_value)
+                    };
+                    rayon::join(
+                        || {
+                            self.unchecked_if_then_else_parallelized(
+                                &right_wins,
+                                right_value,
+                                left_value,
+                            )
+                        },
+                        || {
+                            self.unchecked_if_then_else_parallelized(
+                                &right_wins,
+                                right_index,
+                                left_index,
+                            )
+                        },
+                    )
+                })
+                .collect();
+
+            candidates.extend(odd_one);
+        }
+
+        candidates.pop().unwrap().1
+    }
+
+    /// Returns the encrypted index of the maximum of the ciphertexts.
+    ///
+    /// The input ciphertexts' blocks must have empty carries.
+    ///
+    /// See [Self::argmax_parallelized]
+    pub fn unchecked_argmax_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.unchecked_arg_extremum_parallelized(cts, true)
+    }
+
+    /// Returns the encrypted index of the minimum of the ciphertexts.
+    ///
+    /// The input ciphertexts' blocks must have empty carries.
+    ///
+    /// See [Self::argmin_parallelized]
+    pub fn unchecked_argmin_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.unchecked_arg_extremum_parallelized(cts, false)
+    }
+
+    /// Returns the encrypted index of the maximum of the ciphertexts.
+    ///
+    /// If the maximum appears multiple times, the lowest index is returned.
+    ///
+    /// The returned ciphertext has just enough blocks to store `cts.len() - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [37u8, 3, 255, 128, 255];
+    ///
+    /// let cts = msgs.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.argmax_parallelized(&cts);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 2);
+    /// ```
+    pub fn argmax_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_propagated_carries(cts, |cts| self.unchecked_argmax_parallelized(cts))
+    }
+
+    /// Returns the encrypted index of the minimum of the ciphertexts.
+    ///
+    /// If the minimum appears multiple times, the lowest index is returned.
+    ///
+    /// The returned ciphertext has just enough blocks to store `cts.len() - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [37u8, 3, 255, 128, 3];
+    ///
+    /// let cts = msgs.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.argmin_parallelized(&cts);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 1);
+    /// ```
+    pub fn argmin_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_propagated_carries(cts, |cts| self.unchecked_argmin_parallelized(cts))
+    }
+
+    /// Returns the `k` greatest ciphertexts, in descending order.
+    ///
+    /// The input ciphertexts' blocks must have empty carries.
+    ///
+    /// See [Self::top_k_parallelized]
+    pub fn unchecked_top_k_parallelized<T>(&self, cts: &[T], k: usize) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            k <= cts.len(),
+            "k ({k}) must not be greater than the number of ciphertexts ({})",
+            cts.len()
+        );
+
+        let mut sorted = cts.to_vec();
+        self.unchecked_sort_parallelized(&mut sorted);
+        sorted.into_iter().rev().take(k).collect()
+    }
+
+    /// Returns the `k` greatest ciphertexts, in descending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the number of ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [37u8, 3, 255, 128, 3];
+    ///
+    /// let cts = msgs.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    ///
+    /// let top = sks.top_k_parallelized(&cts, 2);
+    ///
+    /// // Decrypt:
+    /// let dec = top.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u8>>();
+    /// assert_eq!(dec, [255, 128]);
+    /// ```
+    pub fn top_k_parallelized<T>(&self, cts: &[T], k: usize) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_propagated_carries(cts, |cts| self.unchecked_top_k_parallelized(cts, k))
+    }
+
+    /// Calls `func` with the ciphertexts, propagating their carries first if needed.
+    fn with_propagated_carries<T, R>(&self, cts: &[T], func: impl FnOnce(&[T]) -> R) -> R
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if cts
+            .iter()
+            .all(IntegerRadixCiphertext::block_carries_are_empty)
+        {
+            func(cts)
+        } else {
+            let mut cloned = cts.to_vec();
+            cloned
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            func(&cloned)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::odd_even_merge_sort_layers;
+
+    #[test]
+    fn test_odd_even_merge_sort_network() {
+        for n in 0..=17usize {
+            let layers = odd_even_merge_sort_layers(n);
+
+            for layer in &layers {
+                let mut used = vec![false; n];
+                for &(i, j) in layer {
+                    assert!(i < j && j < n);
+                    assert!(
+                        !used[i] && !used[j],
+                        "comparators of a layer must be disjoint"
+                    );
+                    used[i] = true;
+                    used[j] = true;
+                }
+            }
+
+            // 0-1 principle: a comparator network sorts every input
+            // iff it sorts every sequence of 0s and 1s
+            for bits in 0u32..(1 << n) {
+                let mut values = (0..n).map(|i| (bits >> i) & 1).collect::<Vec<_>>();
+                for layer in &layers {
+                    for &(i, j) in layer {
+                        if values[i] > values[j] {
+                            values.swap(i, j);
+                        }
+                    }
+                }
+                assert!(values.windows(2).all(|w| w[0] <= w[1]), "n = {n}");
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::integer::{RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Sets the bit at `bit_index` in the clean ciphertext `ct`,
+    /// that bit must be known to be 0 beforehand.
+    fn set_clear_bit(&self, ct: &mut RadixCiphertext, bit_index: usize) {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let block_index = bit_index / bits_per_block;
+        let bit_in_block = bit_index % bits_per_block;
+        self.key
+            .unchecked_scalar_bitor_assign(&mut ct.blocks[block_index], 1 << bit_in_block);
+    }
+
+    /// Computes homomorphically the square root of `ct`, rounded down.
+    ///
+    /// The result has the same number of blocks as `ct`.
+    ///
+    /// The input ciphertext's blocks must have empty carries.
+    pub fn unchecked_isqrt_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let num_blocks = ct.blocks.len();
+        let num_bits = num_blocks * bits_per_block;
+        if num_bits == 0 {
+            return ct.clone();
+        }
+
+        // Digit-by-digit method, where each step tries the next power of four:
+        //
+        // if remainder >= result + bit {
+        //     remainder -= result + bit;
+        //     result = (result >> 1) + bit;
+        // } else {
+        //     result >>= 1;
+        // }
+        //
+        // Both `result` and `result >> 1` are always multiples of 2 * bit,
+        // so the additions of `bit` are done by setting that bit,
+        // which does not need any carry propagation.
+        let mut remainder = ct.clone();
+        let mut result: RadixCiphertext = self.create_trivial_zero_radix(num_blocks);
+        // Starts at the highest power of four that fits in num_bits
+        let mut bit_index = (num_bits - 1) & !1;
+        loop {
+            let (candidate, (shifted, shifted_with_bit)) = rayon::join(
+                || {
+                    let mut candidate = result.clone();
+                    self.set_clear_bit(&mut candidate, bit_index);
+                    candidate
+                },
+                || {
+                    let shifted = self.unchecked_scalar_right_shift_parallelized(&result, 1u32);
+                    let mut shifted_with_bit = shifted.clone();
+                    self.set_clear_bit(&mut shifted_with_bit, bit_index);
+                    (shifted, shifted_with_bit)
+                },
+            );
+
+            let (difference, is_less) =
+                self.unchecked_unsigned_overflowing_sub_parallelized(&remainder, &candidate);
+
+            (remainder, result) = rayon::join(
+                || self.unchecked_if_then_else_parallelized(&is_less, &remainder, &difference),
+                || self.unchecked_if_then_else_parallelized(&is_less, &shifted, &shifted_with_bit),
+            );
+
+            if bit_index < 2 {
+                break;
+            }
+            bit_index -= 2;
+        }
+
+        result
+    }
+
+    /// Computes homomorphically the square root of `ct`, rounded down.
+    ///
+    /// The result has the same number of blocks as `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 200u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.isqrt_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 14);
+    /// ```
+    pub fn isqrt_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_isqrt_parallelized(ct)
+    }
+}
@@ -75,6 +75,11 @@ fn overflowing_sub_under_modulus(lhs: u64, rhs: u64, modulus: u64) -> (u64, bool
     (result % modulus, overflowed)
 }
 
+fn overflowing_mul_under_modulus(lhs: u64, rhs: u64, modulus: u64) -> (u64, bool) {
+    let (result, overflowed) = lhs.overflowing_mul(rhs);
+    (result % modulus, overflowed || result >= modulus)
+}
+
 fn overflowing_add_under_modulus(lhs: u64, rhs: u64, modulus: u64) -> (u64, bool) {
     let (result, overflowed) = lhs.overflowing_add(rhs);
     (result % modulus, overflowed || result >= modulus)
@@ -2022,6 +2027,158 @@ where
     }
 }
 
+pub(crate) fn default_overflowing_mul_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, BooleanBlock),
+    >,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    executor.setup(&cks, sks.clone());
+
+    let block_modulus = cks.parameters().message_modulus().0 as u64;
+    // The last case only overflows through the carries of the partial products
+    // landing in the lower blocks
+    for (clear_0, clear_1) in [
+        (modulus - 1, 1),
+        (modulus - 1, modulus - 1),
+        (modulus / 2, 2),
+        (0, modulus - 1),
+        (modulus / block_modulus - 1, block_modulus + 1),
+    ] {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, result_overflowed) = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+
+        let (expected_result, expected_overflowed) =
+            overflowing_mul_under_modulus(clear_0, clear_1, modulus);
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let decrypted_overflowed = cks.decrypt_bool(&result_overflowed);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for mul, for ({clear_0} * {clear_1}) % {modulus} \
+             expected {expected_result}, got {decrypted_result}"
+        );
+        assert_eq!(
+            decrypted_overflowed,
+            expected_overflowed,
+            "Invalid overflow flag result for overflowing_mul for ({clear_0} * {clear_1}) % {modulus} \
+             expected overflow flag {expected_overflowed}, got {decrypted_overflowed}"
+        );
+    }
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, result_overflowed) = executor.execute((&ctxt_0, &ctxt_1));
+        let (tmp_ct, tmp_o) = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct, "Failed determinism check");
+        assert_eq!(tmp_o, result_overflowed, "Failed determinism check");
+
+        let (expected_result, expected_overflowed) =
+            overflowing_mul_under_modulus(clear_0, clear_1, modulus);
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let decrypted_overflowed = cks.decrypt_bool(&result_overflowed);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for mul, for ({clear_0} * {clear_1}) % {modulus} \
+             expected {expected_result}, got {decrypted_result}"
+        );
+        assert_eq!(
+            decrypted_overflowed,
+            expected_overflowed,
+            "Invalid overflow flag result for overflowing_mul for ({clear_0} * {clear_1}) % {modulus} \
+             expected overflow flag {expected_overflowed}, got {decrypted_overflowed}"
+        );
+
+        for _ in 0..NB_TESTS_SMALLER {
+            // Add non zero scalar to have non clean ciphertexts
+            let clear_2 = random_non_zero_value(&mut rng, modulus);
+            let clear_3 = random_non_zero_value(&mut rng, modulus);
+
+            let ctxt_0 = sks.unchecked_scalar_add(&ctxt_0, clear_2);
+            let ctxt_1 = sks.unchecked_scalar_add(&ctxt_1, clear_3);
+
+            let clear_lhs = clear_0.wrapping_add(clear_2) % modulus;
+            let clear_rhs = clear_1.wrapping_add(clear_3) % modulus;
+
+            let d0: u64 = cks.decrypt(&ctxt_0);
+            assert_eq!(d0, clear_lhs, "Failed sanity decryption check");
+            let d1: u64 = cks.decrypt(&ctxt_1);
+            assert_eq!(d1, clear_rhs, "Failed sanity decryption check");
+
+            let (ct_res, result_overflowed) = executor.execute((&ctxt_0, &ctxt_1));
+            assert!(ct_res.block_carries_are_empty());
+
+            let (expected_result, expected_overflowed) =
+                overflowing_mul_under_modulus(clear_lhs, clear_rhs, modulus);
+
+            let decrypted_result: u64 = cks.decrypt(&ct_res);
+            let decrypted_overflowed = cks.decrypt_bool(&result_overflowed);
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid result for mul, for ({clear_lhs} * {clear_rhs}) % {modulus} \
+                expected {expected_result}, got {decrypted_result}"
+            );
+            assert_eq!(
+                decrypted_overflowed,
+                expected_overflowed,
+                "Invalid overflow flag result for overflowing_mul, for ({clear_lhs} * {clear_rhs}) % {modulus} \
+                expected overflow flag {expected_overflowed}, got {decrypted_overflowed}"
+            );
+        }
+    }
+
+    // Test with trivial inputs
+    for _ in 0..4 {
+        let clear_0 = rng.gen::<u64>() % (modulus / sks.key.message_modulus.0 as u64);
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let a: RadixCiphertext = sks.create_trivial_radix(clear_0, NB_CTXT);
+        let b: RadixCiphertext = sks.create_trivial_radix(clear_1, NB_CTXT);
+
+        let (encrypted_result, encrypted_overflow) = executor.execute((&a, &b));
+
+        let (expected_result, expected_overflowed) =
+            overflowing_mul_under_modulus(clear_0, clear_1, modulus);
+
+        let decrypted_result: u64 = cks.decrypt(&encrypted_result);
+        let decrypted_overflowed = cks.decrypt_bool(&encrypted_overflow);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for mul, for ({clear_0} * {clear_1}) % {modulus} \
+                expected {expected_result}, got {decrypted_result}"
+        );
+        assert_eq!(
+            decrypted_overflowed,
+            expected_overflowed,
+            "Invalid overflow flag result for overflowing_mul, for ({clear_0} * {clear_1}) % {modulus} \
+                expected overflow flag {expected_overflowed}, got {decrypted_overflowed}"
+        );
+    }
+}
+
 pub(crate) fn default_sub_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -3600,3 +3757,322 @@ where
         }
     }
 }
+
+/// Shared test body for functions that count bits (leading/trailing zeros, ones, ilog2)
+///
+/// `clear_fn` receives the clear input and the number of bits of the ciphertext
+fn default_count_bits_test<P, T, F>(param: P, mut executor: T, clear_fn: F)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+    F: Fn(u64, u32) -> u64,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    executor.setup(&cks, sks.clone());
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+    let num_bits = modulus.ilog2();
+
+    for _ in 0..NB_TESTS_SMALLER {
+        // Make sure the interesting values are tested, as random ones
+        // will rarely have many leading/trailing zeros
+        let clear = match rng.gen_range(0..3) {
+            0 => rng.gen::<u64>() % modulus,
+            1 => 1u64 << rng.gen_range(0..num_bits),
+            _ => (rng.gen::<u64>() % modulus) >> rng.gen_range(0..num_bits),
+        };
+        let ctxt = cks.encrypt(clear);
+
+        let tmp = executor.execute(&ctxt);
+        let ct_res = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = clear_fn(clear, num_bits);
+        assert_eq!(
+            dec, expected,
+            "Invalid result for input {clear}, expected {expected}, got {dec}"
+        );
+
+        // Add a scalar to have non clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+        let clear = clear.wrapping_add(clear_2) % modulus;
+
+        let ct_res = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = clear_fn(clear, num_bits);
+        assert_eq!(
+            dec, expected,
+            "Invalid result for input {clear}, expected {expected}, got {dec}"
+        );
+    }
+}
+
+/// Number of leading zeros of `value` seen as a `num_bits` bits integer
+fn leading_zeros_under_num_bits(value: u64, num_bits: u32) -> u64 {
+    u64::from(value.leading_zeros() - (u64::BITS - num_bits))
+}
+
+/// Number of trailing zeros of `value` seen as a `num_bits` bits integer
+fn trailing_zeros_under_num_bits(value: u64, num_bits: u32) -> u64 {
+    u64::from(value.trailing_zeros().min(num_bits))
+}
+
+pub(crate) fn default_leading_zeros_test<P, T>(param: P, executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    default_count_bits_test(param, executor, leading_zeros_under_num_bits);
+}
+
+pub(crate) fn default_trailing_zeros_test<P, T>(param: P, executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    default_count_bits_test(param, executor, trailing_zeros_under_num_bits);
+}
+
+pub(crate) fn default_count_ones_test<P, T>(param: P, executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    default_count_bits_test(param, executor, |value, _| u64::from(value.count_ones()));
+}
+
+pub(crate) fn default_checked_ilog2_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, (RadixCiphertext, BooleanBlock)>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    executor.setup(&cks, sks);
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for clear in [0, 1, modulus - 1]
+        .into_iter()
+        .chain((0..NB_TESTS_SMALLER).map(|_| rng.gen::<u64>() % modulus))
+    {
+        let ctxt = cks.encrypt(clear);
+
+        let (ct_res, is_ok) = executor.execute(&ctxt);
+        let (tmp, tmp_is_ok) = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+        assert_eq!(is_ok, tmp_is_ok, "Failed determinism check");
+
+        let is_ok = cks.decrypt_bool(&is_ok);
+        assert_eq!(is_ok, clear != 0);
+        if clear != 0 {
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec,
+                u64::from(clear.ilog2()),
+                "Invalid ilog2 result for input {clear}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_isqrt_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    executor.setup(&cks, sks.clone());
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    let clears = [0, 1, modulus - 1]
+        .into_iter()
+        .chain((0..NB_TESTS_SMALLER).map(|_| rng.gen::<u64>() % modulus))
+        .collect::<Vec<_>>();
+    for clear in clears {
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = executor.execute(&ctxt);
+        let tmp = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = (clear as f64).sqrt() as u64;
+        assert_eq!(
+            dec, expected,
+            "Invalid isqrt result for input {clear}, expected {expected}, got {dec}"
+        );
+
+        // Add a scalar to have non clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+        let clear = clear.wrapping_add(clear_2) % modulus;
+
+        let ct_res = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = (clear as f64).sqrt() as u64;
+        assert_eq!(
+            dec, expected,
+            "Invalid isqrt result for input {clear}, expected {expected}, got {dec}"
+        );
+    }
+}
+
+pub(crate) fn default_scalar_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, u32), RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    executor.setup(&cks, sks.clone());
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let exponent = (rng.gen::<u64>() % modulus) as u32;
+
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = executor.execute((&ctxt, exponent));
+        let tmp = executor.execute((&ctxt, exponent));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = clear.wrapping_pow(exponent) % modulus;
+        assert_eq!(
+            dec, expected,
+            "Invalid scalar_pow result for {clear}^{exponent} % {modulus}, \
+            expected {expected}, got {dec}"
+        );
+
+        // Add a scalar to have non clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+        let clear = clear.wrapping_add(clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt, exponent));
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = clear.wrapping_pow(exponent) % modulus;
+        assert_eq!(
+            dec, expected,
+            "Invalid scalar_pow result for {clear}^{exponent} % {modulus}, \
+            expected {expected}, got {dec}"
+        );
+    }
+
+    // Edge case exponents
+    let clear = rng.gen::<u64>() % modulus;
+    let ctxt = cks.encrypt(clear);
+    for exponent in [0u32, 1, 2] {
+        let ct_res = executor.execute((&ctxt, exponent));
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec, clear.wrapping_pow(exponent) % modulus);
+    }
+}
+
+pub(crate) fn default_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    executor.setup(&cks, sks.clone());
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    let exponents = [0, 1]
+        .into_iter()
+        .chain((0..NB_TESTS_SMALLER).map(|_| rng.gen::<u64>() % modulus))
+        .collect::<Vec<_>>();
+    for exponent in exponents {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ctxt = cks.encrypt(clear);
+        let ctxt_exponent = cks.encrypt(exponent);
+
+        let ct_res = executor.execute((&ctxt, &ctxt_exponent));
+        let tmp = executor.execute((&ctxt, &ctxt_exponent));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = clear.wrapping_pow(exponent as u32) % modulus;
+        assert_eq!(
+            dec, expected,
+            "Invalid pow result for {clear}^{exponent} % {modulus}, \
+            expected {expected}, got {dec}"
+        );
+
+        // Add scalars to have non clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let clear_3 = random_non_zero_value(&mut rng, modulus);
+        let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+        let ctxt_exponent = sks.unchecked_scalar_add(&ctxt_exponent, clear_3);
+        let clear = clear.wrapping_add(clear_2) % modulus;
+        let exponent = exponent.wrapping_add(clear_3) % modulus;
+
+        let ct_res = executor.execute((&ctxt, &ctxt_exponent));
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        let expected = clear.wrapping_pow(exponent as u32) % modulus;
+        assert_eq!(
+            dec, expected,
+            "Invalid pow result for {clear}^{exponent} % {modulus}, \
+            expected {expected}, got {dec}"
+        );
+    }
+}
@@ -120,6 +120,12 @@ fn signed_mul_under_modulus(lhs: i64, rhs: i64, modulus: i64) -> i64 {
     res
 }
 
+fn signed_overflowing_mul_under_modulus(lhs: i64, rhs: i64, modulus: i64) -> (i64, bool) {
+    let res = signed_mul_under_modulus(lhs, rhs, modulus);
+    let overflowed = lhs * rhs != res;
+    (res, overflowed)
+}
+
 fn absolute_value_under_modulus(lhs: i64, modulus: i64) -> i64 {
     if lhs < 0 {
         signed_neg_under_modulus(lhs, modulus)
@@ -1383,11 +1389,13 @@ create_parametrized_test!(integer_signed_default_neg);
 create_parametrized_test!(integer_signed_default_sub);
 create_parametrized_test!(integer_signed_default_overflowing_sub);
 create_parametrized_test!(integer_signed_default_mul);
+create_parametrized_test!(integer_signed_default_overflowing_mul);
 create_parametrized_test!(integer_signed_default_bitnot);
 create_parametrized_test!(integer_signed_default_bitand);
 create_parametrized_test!(integer_signed_default_bitor);
 create_parametrized_test!(integer_signed_default_bitxor);
 create_parametrized_test!(integer_signed_default_absolute_value);
+create_parametrized_test!(integer_signed_default_count_bits);
 create_parametrized_test!(integer_signed_default_left_shift {
     // Requires 3 bits, so 1_1 parameters are not supported
     // until they get their own version of the algorithm
@@ -1433,6 +1441,70 @@ create_parametrized_test!(integer_signed_default_rotate_right {
     PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
 });
 
+fn integer_signed_default_count_bits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+    let num_bits = (modulus as u64).ilog2() + 1;
+    let mask = (1u64 << num_bits) - 1;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<i64>() % modulus;
+        // Bit pattern of the value as a num_bits integer
+        let clear_bits = (clear_0 as u64) & mask;
+
+        let ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+
+        let ct_res = sks.leading_zeros_parallelized(&ctxt_0);
+        let ct_res2 = sks.leading_zeros_parallelized(&ctxt_0);
+        assert_eq!(ct_res, ct_res2, "Failed determinism check");
+        let dec_res: u32 = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear_bits.leading_zeros() - (u64::BITS - num_bits),
+            "Invalid leading_zeros result for {clear_0}"
+        );
+
+        let ct_res = sks.trailing_zeros_parallelized(&ctxt_0);
+        let dec_res: u32 = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear_bits.trailing_zeros().min(num_bits),
+            "Invalid trailing_zeros result for {clear_0}"
+        );
+
+        let ct_res = sks.count_ones_parallelized(&ctxt_0);
+        let dec_res: u32 = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear_bits.count_ones(),
+            "Invalid count_ones result for {clear_0}"
+        );
+
+        let (ct_res, is_ok) = sks.checked_ilog2_parallelized(&ctxt_0);
+        let is_ok = cks.decrypt_bool(&is_ok);
+        assert_eq!(
+            is_ok,
+            clear_0 > 0,
+            "Invalid checked_ilog2 flag for {clear_0}"
+        );
+        if clear_0 > 0 {
+            let dec_res: u32 = cks.decrypt_radix(&ct_res);
+            assert_eq!(
+                dec_res,
+                clear_0.ilog2(),
+                "Invalid ilog2 result for {clear_0}"
+            );
+        }
+    }
+}
+
 fn integer_signed_default_add<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -1800,6 +1872,123 @@ where
     }
 }
 
+fn integer_signed_default_overflowing_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    // Products at the bounds of the signed range
+    for (clear_0, clear_1) in [
+        (-modulus, 1),
+        (-modulus, -1),
+        (-(modulus / 2), 2),
+        (modulus / 2, 2),
+        (modulus / 2, -2),
+        (0, -modulus),
+    ] {
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let (ct_res, result_overflowed) = sks.signed_overflowing_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+
+        let (expected_result, expected_overflowed) =
+            signed_overflowing_mul_under_modulus(clear_0, clear_1, modulus);
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let decrypted_overflowed = cks.decrypt_bool(&result_overflowed);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for mul, for ({clear_0} * {clear_1}) % {modulus} \
+             expected {expected_result}, got {decrypted_result}"
+        );
+        assert_eq!(
+            decrypted_overflowed,
+            expected_overflowed,
+            "Invalid overflow flag result for overflowing_mul for ({clear_0} * {clear_1}) % {modulus} \
+             expected overflow flag {expected_overflowed}, got {decrypted_overflowed}"
+        );
+    }
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<i64>() % modulus;
+        let clear_1 = rng.gen::<i64>() % modulus;
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let (ct_res, result_overflowed) = sks.signed_overflowing_mul_parallelized(&ctxt_0, &ctxt_1);
+        let (tmp_ct, tmp_o) = sks.signed_overflowing_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct, "Failed determinism check");
+        assert_eq!(tmp_o, result_overflowed, "Failed determinism check");
+
+        let (expected_result, expected_overflowed) =
+            signed_overflowing_mul_under_modulus(clear_0, clear_1, modulus);
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let decrypted_overflowed = cks.decrypt_bool(&result_overflowed);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for mul, for ({clear_0} * {clear_1}) % {modulus} \
+             expected {expected_result}, got {decrypted_result}"
+        );
+        assert_eq!(
+            decrypted_overflowed,
+            expected_overflowed,
+            "Invalid overflow flag result for overflowing_mul for ({clear_0} * {clear_1}) % {modulus} \
+             expected overflow flag {expected_overflowed}, got {decrypted_overflowed}"
+        );
+
+        for _ in 0..NB_TESTS_SMALLER {
+            // Add non zero scalar to have non clean ciphertexts
+            let clear_2 = random_non_zero_value(&mut rng, modulus);
+            let clear_3 = random_non_zero_value(&mut rng, modulus);
+
+            let ctxt_0 = sks.unchecked_scalar_add(&ctxt_0, clear_2);
+            let ctxt_1 = sks.unchecked_scalar_add(&ctxt_1, clear_3);
+
+            let clear_lhs = signed_add_under_modulus(clear_0, clear_2, modulus);
+            let clear_rhs = signed_add_under_modulus(clear_1, clear_3, modulus);
+
+            let d0: i64 = cks.decrypt_signed(&ctxt_0);
+            assert_eq!(d0, clear_lhs, "Failed sanity decryption check");
+            let d1: i64 = cks.decrypt_signed(&ctxt_1);
+            assert_eq!(d1, clear_rhs, "Failed sanity decryption check");
+
+            let (ct_res, result_overflowed) =
+                sks.signed_overflowing_mul_parallelized(&ctxt_0, &ctxt_1);
+            assert!(ct_res.block_carries_are_empty());
+
+            let (expected_result, expected_overflowed) =
+                signed_overflowing_mul_under_modulus(clear_lhs, clear_rhs, modulus);
+
+            let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+            let decrypted_overflowed = cks.decrypt_bool(&result_overflowed);
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid result for mul, for ({clear_lhs} * {clear_rhs}) % {modulus} \
+                expected {expected_result}, got {decrypted_result}"
+            );
+            assert_eq!(
+                decrypted_overflowed,
+                expected_overflowed,
+                "Invalid overflow flag result for overflowing_mul, for ({clear_lhs} * {clear_rhs}) % {modulus} \
+                expected overflow flag {expected_overflowed}, got {decrypted_overflowed}"
+            );
+        }
+    }
+}
+
 fn integer_signed_default_mul<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -1,5 +1,5 @@
 use crate::integer::keycache::KEY_CACHE;
-use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 use crate::shortint::parameters::*;
 use paste::paste;
 use rand::Rng;
@@ -228,6 +228,7 @@ create_parametrized_test!(integer_smart_block_mul);
 create_parametrized_test!(integer_default_block_mul);
 create_parametrized_test!(integer_smart_mul);
 create_parametrized_test!(integer_default_mul);
+create_parametrized_test!(integer_default_overflowing_mul);
 create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_default_scalar_sub);
 create_parametrized_test!(integer_smart_scalar_add);
@@ -235,6 +236,25 @@ create_parametrized_test!(integer_default_scalar_add);
 create_parametrized_test!(integer_smart_if_then_else);
 create_parametrized_test!(integer_default_if_then_else);
 create_parametrized_test!(integer_trim_radix_msb_blocks_handles_dirty_inputs);
+create_parametrized_test!(integer_default_leading_zeros);
+create_parametrized_test!(integer_default_trailing_zeros);
+create_parametrized_test!(integer_default_count_ones);
+create_parametrized_test!(integer_default_checked_ilog2);
+create_parametrized_test!(integer_default_isqrt);
+create_parametrized_test!(integer_default_scalar_pow);
+create_parametrized_test!(integer_default_pow);
+create_parametrized_test!(integer_default_sort {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
+create_parametrized_test!(integer_default_argmax_argmin {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
+create_parametrized_test!(integer_default_top_k {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
 
 create_parametrized_test!(integer_unchecked_add);
 create_parametrized_test!(integer_unchecked_mul);
@@ -320,6 +340,22 @@ where
     }
 }
 
+/// Unary fn returning a ciphertext and a boolean
+impl<'a, F> FunctionExecutor<&'a RadixCiphertext, (RadixCiphertext, BooleanBlock)>
+    for CpuFunctionExecutor<F>
+where
+    F: Fn(&ServerKey, &RadixCiphertext) -> (RadixCiphertext, BooleanBlock),
+{
+    fn setup(&mut self, _cks: &RadixClientKey, sks: Arc<ServerKey>) {
+        self.sks = Some(sks);
+    }
+
+    fn execute(&mut self, input: &'a RadixCiphertext) -> (RadixCiphertext, BooleanBlock) {
+        let sks = self.sks.as_ref().expect("setup was not properly called");
+        (self.func)(sks, input)
+    }
+}
+
 /// For binary operations
 impl<F, I1, I2, O> FunctionExecutor<(I1, I2), O> for CpuFunctionExecutor<F>
 where
@@ -767,6 +803,14 @@ where
     default_mul_test(param, executor);
 }
 
+fn integer_default_overflowing_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unsigned_overflowing_mul_parallelized);
+    default_overflowing_mul_test(param, executor);
+}
+
 fn integer_default_neg<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -807,6 +851,62 @@ where
     default_bitnot_test(param, executor);
 }
 
+fn integer_default_leading_zeros<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::leading_zeros_parallelized);
+    default_leading_zeros_test(param, executor);
+}
+
+fn integer_default_trailing_zeros<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::trailing_zeros_parallelized);
+    default_trailing_zeros_test(param, executor);
+}
+
+fn integer_default_count_ones<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::count_ones_parallelized);
+    default_count_ones_test(param, executor);
+}
+
+fn integer_default_checked_ilog2<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::checked_ilog2_parallelized);
+    default_checked_ilog2_test(param, executor);
+}
+
+fn integer_default_isqrt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::isqrt_parallelized);
+    default_isqrt_test(param, executor);
+}
+
+fn integer_default_scalar_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::scalar_pow_parallelized);
+    default_scalar_pow_test(param, executor);
+}
+
+fn integer_default_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::pow_parallelized);
+    default_pow_test(param, executor);
+}
+
 fn integer_default_div_rem<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -1118,6 +1218,108 @@ where
     assert_eq!(output, (msg2 + msg1) % (modulus));
 }
 
+fn integer_default_sort<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for len in [1, 2, 5, 8] {
+        let clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let mut cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        // Add a scalar to the first one to have a non clean ciphertext
+        let clear_2 = rng.gen_range(1..modulus);
+        sks.unchecked_scalar_add_assign(&mut cts[0], clear_2);
+        let mut clears = clears;
+        clears[0] = clears[0].wrapping_add(clear_2) % modulus;
+
+        sks.sort_parallelized(&mut cts);
+
+        let dec = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+        clears.sort_unstable();
+        assert_eq!(dec, clears, "Invalid sort result");
+        assert!(cts.iter().all(RadixCiphertext::block_carries_are_empty));
+    }
+}
+
+fn integer_default_argmax_argmin<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // Use a small range of values to have ties
+    let modulus = 4u64;
+
+    for len in [1, 2, 5, 8] {
+        let clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        let max = clears.iter().copied().max().unwrap();
+        let min = clears.iter().copied().min().unwrap();
+        let expected_argmax = clears.iter().position(|&v| v == max).unwrap() as u64;
+        let expected_argmin = clears.iter().position(|&v| v == min).unwrap() as u64;
+
+        let ct_res = sks.argmax_parallelized(&cts);
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec, expected_argmax, "Invalid argmax result for {clears:?}");
+
+        let ct_res = sks.argmin_parallelized(&cts);
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec, expected_argmin, "Invalid argmin result for {clears:?}");
+    }
+}
+
+fn integer_default_top_k<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    let clears = (0..6)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let cts = clears
+        .iter()
+        .map(|&clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    let mut expected = clears.clone();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+
+    for k in [0, 1, 3] {
+        let top = sks.top_k_parallelized(&cts, k);
+        let dec = top.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+        assert_eq!(dec, expected[..k], "Invalid top {k} result for {clears:?}");
+    }
+}
+
 fn integer_full_propagate<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -0,0 +1,169 @@
+//! This module defines threshold decryption for radix ciphertexts.
+//!
+//! The noise of a radix ciphertext is first squashed with a
+//! [NoiseSquashingKey](crate::integer::noise_squashing::NoiseSquashingKey), then each block of the
+//! [SquashedNoiseRadixCiphertext] is decrypted with the shortint threshold decryption, see
+//! [crate::shortint::threshold] for the description of the secret sharing scheme and of the
+//! smudging noise.
+
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::integer::noise_squashing::{NoiseSquashingPrivateKey, SquashedNoiseRadixCiphertext};
+use crate::shortint::threshold::{
+    combine_partial_decryptions_message_and_carry, PartialDecryptionShare,
+    ThresholdSecretKeyShare as ShortintThresholdSecretKeyShare,
+};
+pub use crate::shortint::threshold::{PartyId, ThresholdParameters};
+use serde::{Deserialize, Serialize};
+
+/// The share of a [NoiseSquashingPrivateKey] held by one party, it must be kept secret.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThresholdSecretKeyShare {
+    pub(crate) key: ShortintThresholdSecretKeyShare,
+}
+
+impl From<ShortintThresholdSecretKeyShare> for ThresholdSecretKeyShare {
+    fn from(key: ShortintThresholdSecretKeyShare) -> Self {
+        Self { key }
+    }
+}
+
+impl AsRef<ShortintThresholdSecretKeyShare> for ThresholdSecretKeyShare {
+    fn as_ref(&self) -> &ShortintThresholdSecretKeyShare {
+        &self.key
+    }
+}
+
+/// The contribution of one party to the decryption of a radix ciphertext, one share per block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RadixPartialDecryptionShare {
+    pub(crate) block_shares: Vec<PartialDecryptionShare>,
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Secret share this key among the parties described by `params`, returning the share of
+    /// each party in order.
+    ///
+    /// The shares are generated by the holder of the key acting as a trusted dealer, the key must
+    /// be discarded once the shares have been distributed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::threshold::{
+    ///     combine_radix_partial_decryptions, PartyId, ThresholdParameters,
+    /// };
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::{
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    /// let num_blocks = 4;
+    ///
+    /// let private_key = cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// let noise_squashing_key = cks.new_noise_squashing_key(&private_key);
+    ///
+    /// let msg = 191u64;
+    /// let ct = cks.encrypt_radix(msg, num_blocks);
+    ///
+    /// // 2 out of 3 parties are needed to decrypt
+    /// let key_shares = private_key.generate_threshold_key_shares(ThresholdParameters::new(3, 2));
+    /// drop(private_key);
+    ///
+    /// let squashed = noise_squashing_key.squash_radix_ciphertext_noise(&ct, &sks);
+    ///
+    /// let participants = [PartyId(1), PartyId(2)];
+    /// let partial_decryptions: Vec<_> = participants
+    ///     .iter()
+    ///     .map(|party| key_shares[party.0].partial_decrypt_radix(&squashed, &participants))
+    ///     .collect();
+    ///
+    /// let dec: u64 = combine_radix_partial_decryptions(&squashed, &partial_decryptions);
+    /// assert_eq!(dec, msg);
+    /// ```
+    pub fn generate_threshold_key_shares(
+        &self,
+        params: ThresholdParameters,
+    ) -> Vec<ThresholdSecretKeyShare> {
+        self.key
+            .generate_threshold_key_shares(params)
+            .into_iter()
+            .map(ThresholdSecretKeyShare::from)
+            .collect()
+    }
+}
+
+impl ThresholdSecretKeyShare {
+    pub fn party(&self) -> PartyId {
+        self.key.party()
+    }
+
+    /// Compute the contribution of this party to the decryption of `ctxt` by the `participants`.
+    ///
+    /// See [NoiseSquashingPrivateKey::generate_threshold_key_shares] for usage.
+    ///
+    /// # Panics
+    ///
+    /// See [ShortintThresholdSecretKeyShare::partial_decrypt].
+    pub fn partial_decrypt_radix(
+        &self,
+        ctxt: &SquashedNoiseRadixCiphertext,
+        participants: &[PartyId],
+    ) -> RadixPartialDecryptionShare {
+        RadixPartialDecryptionShare {
+            block_shares: ctxt
+                .blocks()
+                .iter()
+                .map(|block| self.key.partial_decrypt(block, participants))
+                .collect(),
+        }
+    }
+}
+
+/// Combine the partial decryptions of all the participants to recover the message encrypted in
+/// `ctxt`.
+///
+/// See [NoiseSquashingPrivateKey::generate_threshold_key_shares] for usage.
+///
+/// # Panics
+///
+/// Panics if the shares do not come from exactly the participants they were computed for, or were
+/// not computed for a ciphertext with the same number of blocks.
+pub fn combine_radix_partial_decryptions<T>(
+    ctxt: &SquashedNoiseRadixCiphertext,
+    shares: &[RadixPartialDecryptionShare],
+) -> T
+where
+    T: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    assert!(
+        shares
+            .iter()
+            .all(|share| share.block_shares.len() == ctxt.blocks.len()),
+        "Partial decryptions must have as many blocks as the ciphertext"
+    );
+
+    if ctxt.blocks.is_empty() {
+        return T::ZERO;
+    }
+
+    let bits_in_block = ctxt.blocks[0].message_modulus().0.ilog2();
+    let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+
+    for (index, block) in ctxt.blocks.iter().enumerate() {
+        let block_shares: Vec<_> = shares
+            .iter()
+            .map(|share| share.block_shares[index].clone())
+            .collect();
+        let decrypted_block = combine_partial_decryptions_message_and_carry(block, &block_shares);
+        if !recomposer.add_unmasked(decrypted_block) {
+            // End of T::BITS reached no need to try more
+            // recomposition
+            break;
+        }
+    }
+
+    recomposer.value()
+}
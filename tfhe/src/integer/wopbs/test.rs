@@ -92,8 +92,12 @@ pub fn wopbs_native_crt_bivariate() {
         lwe_dimension: wopbs_params.lwe_dimension,
         glwe_dimension: wopbs_params.glwe_dimension,
         polynomial_size: wopbs_params.polynomial_size,
-        lwe_modular_std_dev: wopbs_params.lwe_modular_std_dev,
-        glwe_modular_std_dev: wopbs_params.glwe_modular_std_dev,
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(
+            wopbs_params.lwe_modular_std_dev,
+        ),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(
+            wopbs_params.glwe_modular_std_dev,
+        ),
         pbs_base_log: wopbs_params.pbs_base_log,
         pbs_level: wopbs_params.pbs_level,
         ks_base_log: wopbs_params.ks_base_log,
@@ -102,6 +106,7 @@ pub fn wopbs_native_crt_bivariate() {
         carry_modulus: wopbs_params.carry_modulus,
         ciphertext_modulus: wopbs_params.ciphertext_modulus,
         encryption_key_choice: wopbs_params.encryption_key_choice,
+        secret_key_distribution: SecretKeyDistribution::Binary,
     };
 
     let params = (pbs_params, wopbs_params);
@@ -69,6 +69,23 @@ impl From<BooleanEncryptionKeyChoice>
     }
 }
 
+#[wasm_bindgen]
+pub enum BooleanSecretKeyDistribution {
+    Binary,
+    Ternary,
+}
+
+impl From<BooleanSecretKeyDistribution>
+    for crate::core_crypto::commons::parameters::SecretKeyDistribution
+{
+    fn from(value: BooleanSecretKeyDistribution) -> Self {
+        match value {
+            BooleanSecretKeyDistribution::Binary => Self::Binary,
+            BooleanSecretKeyDistribution::Ternary => Self::Ternary,
+        }
+    }
+}
+
 #[wasm_bindgen]
 impl Boolean {
     #[wasm_bindgen]
@@ -105,6 +122,36 @@ impl Boolean {
         ks_base_log: usize,
         ks_level: usize,
         encryption_key_choice: BooleanEncryptionKeyChoice,
+    ) -> BooleanParameters {
+        Self::new_parameters_with_secret_key_distribution(
+            lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            lwe_modular_std_dev,
+            glwe_modular_std_dev,
+            pbs_base_log,
+            pbs_level,
+            ks_base_log,
+            ks_level,
+            encryption_key_choice,
+            BooleanSecretKeyDistribution::Binary,
+        )
+    }
+
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_parameters_with_secret_key_distribution(
+        lwe_dimension: usize,
+        glwe_dimension: usize,
+        polynomial_size: usize,
+        lwe_modular_std_dev: f64,
+        glwe_modular_std_dev: f64,
+        pbs_base_log: usize,
+        pbs_level: usize,
+        ks_base_log: usize,
+        ks_level: usize,
+        encryption_key_choice: BooleanEncryptionKeyChoice,
+        secret_key_distribution: BooleanSecretKeyDistribution,
     ) -> BooleanParameters {
         set_hook(Box::new(console_error_panic_hook::hook));
         use crate::core_crypto::prelude::*;
@@ -112,13 +159,18 @@ impl Boolean {
             lwe_dimension: LweDimension(lwe_dimension),
             glwe_dimension: GlweDimension(glwe_dimension),
             polynomial_size: PolynomialSize(polynomial_size),
-            lwe_modular_std_dev: StandardDev(lwe_modular_std_dev),
-            glwe_modular_std_dev: StandardDev(glwe_modular_std_dev),
+            lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+                lwe_modular_std_dev,
+            )),
+            glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+                glwe_modular_std_dev,
+            )),
             pbs_base_log: DecompositionBaseLog(pbs_base_log),
             pbs_level: DecompositionLevelCount(pbs_level),
             ks_base_log: DecompositionBaseLog(ks_base_log),
             ks_level: DecompositionLevelCount(ks_level),
             encryption_key_choice: encryption_key_choice.into(),
+            secret_key_distribution: secret_key_distribution.into(),
         })
     }
 
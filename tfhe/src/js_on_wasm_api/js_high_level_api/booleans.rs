@@ -0,0 +1,105 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsError;
+
+use crate::high_level_api::prelude::*;
+use crate::js_on_wasm_api::js_high_level_api::{catch_panic, catch_panic_result, into_js_error};
+
+#[wasm_bindgen]
+pub struct FheBool(pub(crate) crate::high_level_api::FheBool);
+
+#[wasm_bindgen]
+impl FheBool {
+    #[wasm_bindgen]
+    pub fn encrypt_with_client_key(
+        value: bool,
+        client_key: &crate::js_on_wasm_api::js_high_level_api::keys::TfheClientKey,
+    ) -> Result<FheBool, JsError> {
+        catch_panic_result(|| {
+            crate::high_level_api::FheBool::try_encrypt(value, &client_key.0)
+                .map(FheBool)
+                .map_err(into_js_error)
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn encrypt_with_public_key(
+        value: bool,
+        public_key: &crate::js_on_wasm_api::js_high_level_api::keys::TfhePublicKey,
+    ) -> Result<FheBool, JsError> {
+        catch_panic_result(|| {
+            crate::high_level_api::FheBool::try_encrypt(value, &public_key.0)
+                .map(FheBool)
+                .map_err(into_js_error)
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn encrypt_with_compressed_public_key(
+        value: bool,
+        compressed_public_key: &crate::js_on_wasm_api::js_high_level_api::keys::TfheCompressedPublicKey,
+    ) -> Result<FheBool, JsError> {
+        catch_panic_result(|| {
+            crate::high_level_api::FheBool::try_encrypt(value, &compressed_public_key.0)
+                .map(FheBool)
+                .map_err(into_js_error)
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn decrypt(
+        &self,
+        client_key: &crate::js_on_wasm_api::js_high_level_api::keys::TfheClientKey,
+    ) -> Result<bool, JsError> {
+        catch_panic(|| self.0.decrypt(&client_key.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn serialize(&self) -> Result<Vec<u8>, JsError> {
+        catch_panic_result(|| bincode::serialize(&self.0).map_err(into_js_error))
+    }
+
+    #[wasm_bindgen]
+    pub fn deserialize(buffer: &[u8]) -> Result<FheBool, JsError> {
+        catch_panic_result(|| {
+            bincode::deserialize(buffer)
+                .map(FheBool)
+                .map_err(into_js_error)
+        })
+    }
+}
+
+// The operations require a server key to be set with `set_server_key`
+#[cfg(feature = "high-level-server-js-wasm-api")]
+#[allow(clippy::should_implement_trait)]
+#[wasm_bindgen]
+impl FheBool {
+    #[wasm_bindgen]
+    pub fn bitand(&self, rhs: &FheBool) -> Result<FheBool, JsError> {
+        catch_panic(|| FheBool(&self.0 & &rhs.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn bitor(&self, rhs: &FheBool) -> Result<FheBool, JsError> {
+        catch_panic(|| FheBool(&self.0 | &rhs.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn bitxor(&self, rhs: &FheBool) -> Result<FheBool, JsError> {
+        catch_panic(|| FheBool(&self.0 ^ &rhs.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn not(&self) -> Result<FheBool, JsError> {
+        catch_panic(|| FheBool(!&self.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn eq(&self, rhs: &FheBool) -> Result<FheBool, JsError> {
+        catch_panic(|| FheBool(self.0.eq(&rhs.0)))
+    }
+
+    #[wasm_bindgen]
+    pub fn ne(&self, rhs: &FheBool) -> Result<FheBool, JsError> {
+        catch_panic(|| FheBool(self.0.ne(&rhs.0)))
+    }
+}
@@ -3,6 +3,8 @@ use wasm_bindgen::JsError;
 
 use crate::high_level_api::prelude::*;
 use crate::integer::{I256, U256};
+#[cfg(feature = "high-level-server-js-wasm-api")]
+use crate::js_on_wasm_api::js_high_level_api::booleans::FheBool;
 use crate::js_on_wasm_api::js_high_level_api::{catch_panic, catch_panic_result, into_js_error};
 
 const U128_MAX_AS_STR: &str = "340282366920938463463374607431768211455";
@@ -792,3 +794,146 @@ create_wrapper_type_that_has_native_type!(
         native_type: i64,
     },
 );
+
+// The operations require a server key to be set with `set_server_key`
+#[cfg(feature = "high-level-server-js-wasm-api")]
+macro_rules! impl_server_side_operations(
+    ($($type_name:ident),* $(,)?) => {
+        $(
+            #[wasm_bindgen]
+            #[allow(clippy::should_implement_trait)]
+            impl $type_name {
+                #[wasm_bindgen]
+                pub fn add(&self, rhs: &$type_name) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(&self.0 + &rhs.0))
+                }
+
+                #[wasm_bindgen]
+                pub fn sub(&self, rhs: &$type_name) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(&self.0 - &rhs.0))
+                }
+
+                #[wasm_bindgen]
+                pub fn mul(&self, rhs: &$type_name) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(&self.0 * &rhs.0))
+                }
+
+                #[wasm_bindgen]
+                pub fn bitand(&self, rhs: &$type_name) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(&self.0 & &rhs.0))
+                }
+
+                #[wasm_bindgen]
+                pub fn bitor(&self, rhs: &$type_name) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(&self.0 | &rhs.0))
+                }
+
+                #[wasm_bindgen]
+                pub fn bitxor(&self, rhs: &$type_name) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(&self.0 ^ &rhs.0))
+                }
+
+                #[wasm_bindgen]
+                pub fn bitnot(&self) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(!&self.0))
+                }
+
+                #[wasm_bindgen]
+                pub fn neg(&self) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(-&self.0))
+                }
+
+                #[wasm_bindgen]
+                pub fn min(&self, rhs: &$type_name) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(self.0.min(&rhs.0)))
+                }
+
+                #[wasm_bindgen]
+                pub fn max(&self, rhs: &$type_name) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(self.0.max(&rhs.0)))
+                }
+
+                #[wasm_bindgen]
+                pub fn eq(&self, rhs: &$type_name) -> Result<FheBool, JsError> {
+                    catch_panic(|| FheBool(self.0.eq(&rhs.0)))
+                }
+
+                #[wasm_bindgen]
+                pub fn ne(&self, rhs: &$type_name) -> Result<FheBool, JsError> {
+                    catch_panic(|| FheBool(self.0.ne(&rhs.0)))
+                }
+
+                #[wasm_bindgen]
+                pub fn lt(&self, rhs: &$type_name) -> Result<FheBool, JsError> {
+                    catch_panic(|| FheBool(self.0.lt(&rhs.0)))
+                }
+
+                #[wasm_bindgen]
+                pub fn le(&self, rhs: &$type_name) -> Result<FheBool, JsError> {
+                    catch_panic(|| FheBool(self.0.le(&rhs.0)))
+                }
+
+                #[wasm_bindgen]
+                pub fn gt(&self, rhs: &$type_name) -> Result<FheBool, JsError> {
+                    catch_panic(|| FheBool(self.0.gt(&rhs.0)))
+                }
+
+                #[wasm_bindgen]
+                pub fn ge(&self, rhs: &$type_name) -> Result<FheBool, JsError> {
+                    catch_panic(|| FheBool(self.0.ge(&rhs.0)))
+                }
+
+                /// Returns `ct_then` if `condition` is true, `ct_else` otherwise
+                #[wasm_bindgen]
+                pub fn if_then_else(
+                    condition: &FheBool,
+                    ct_then: &$type_name,
+                    ct_else: &$type_name,
+                ) -> Result<$type_name, JsError> {
+                    catch_panic(|| $type_name(condition.0.if_then_else(&ct_then.0, &ct_else.0)))
+                }
+            }
+        )*
+    }
+);
+
+#[cfg(feature = "high-level-server-js-wasm-api")]
+impl_server_side_operations!(
+    FheUint8, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheInt8, FheInt16, FheInt32,
+    FheInt64, FheInt128, FheInt256,
+);
+
+// Generates `cast_into_<to_type>` for each (from_type, to_type) pair
+#[cfg(feature = "high-level-server-js-wasm-api")]
+macro_rules! impl_cast_into(
+    ($($from_type:ident),* => $to_list:tt) => {
+        $(
+            impl_cast_into!(@from $from_type => $to_list);
+        )*
+    };
+    (@from $from_type:ident => [$($to_type:ident),*]) => {
+        ::paste::paste! {
+            #[wasm_bindgen]
+            impl $from_type {
+                $(
+                    #[wasm_bindgen]
+                    pub fn [<cast_into_ $to_type:snake>](&self) -> Result<$to_type, JsError> {
+                        catch_panic(|| {
+                            $to_type(crate::high_level_api::$to_type::cast_from(self.0.clone()))
+                        })
+                    }
+                )*
+            }
+        }
+    };
+);
+
+#[cfg(feature = "high-level-server-js-wasm-api")]
+impl_cast_into!(
+    FheBool, FheUint8, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheInt8, FheInt16,
+    FheInt32, FheInt64, FheInt128, FheInt256
+    => [
+        FheUint8, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheInt8, FheInt16,
+        FheInt32, FheInt64, FheInt128, FheInt256
+    ]
+);
@@ -72,6 +72,41 @@ impl TfheCompressedServerKey {
                 .map_err(into_js_error)
         })
     }
+
+    #[cfg(feature = "high-level-server-js-wasm-api")]
+    #[wasm_bindgen]
+    pub fn decompress(&self) -> Result<TfheServerKey, JsError> {
+        catch_panic(|| TfheServerKey(self.0.clone().decompress()))
+    }
+}
+
+#[cfg(feature = "high-level-server-js-wasm-api")]
+#[wasm_bindgen]
+pub struct TfheServerKey(pub(crate) hlapi::ServerKey);
+
+#[cfg(feature = "high-level-server-js-wasm-api")]
+#[wasm_bindgen]
+impl TfheServerKey {
+    #[wasm_bindgen]
+    pub fn serialize(&self) -> Result<Vec<u8>, JsError> {
+        catch_panic_result(|| bincode::serialize(&self.0).map_err(into_js_error))
+    }
+
+    #[wasm_bindgen]
+    pub fn deserialize(buffer: &[u8]) -> Result<TfheServerKey, JsError> {
+        catch_panic_result(|| {
+            bincode::deserialize(buffer)
+                .map(Self)
+                .map_err(into_js_error)
+        })
+    }
+}
+
+/// Sets the server key used by the operations on the current thread
+#[cfg(feature = "high-level-server-js-wasm-api")]
+#[wasm_bindgen]
+pub fn set_server_key(server_key: &TfheServerKey) -> Result<(), JsError> {
+    catch_panic(|| hlapi::set_server_key(server_key.0.clone()))
 }
 
 #[wasm_bindgen]
@@ -1,5 +1,8 @@
 use wasm_bindgen::prelude::*;
 
+// using Self does not work well with #[wasm_bindgen] macro
+#[allow(clippy::use_self)]
+pub(crate) mod booleans;
 pub(crate) mod config;
 pub(crate) mod integers;
 // using Self does not work well with #[wasm_bindgen] macro
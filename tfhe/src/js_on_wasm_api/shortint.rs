@@ -46,6 +46,21 @@ impl From<ShortintEncryptionKeyChoice> for crate::shortint::parameters::Encrypti
     }
 }
 
+#[wasm_bindgen]
+pub enum ShortintSecretKeyDistribution {
+    Binary,
+    Ternary,
+}
+
+impl From<ShortintSecretKeyDistribution> for crate::shortint::parameters::SecretKeyDistribution {
+    fn from(value: ShortintSecretKeyDistribution) -> Self {
+        match value {
+            ShortintSecretKeyDistribution::Binary => Self::Binary,
+            ShortintSecretKeyDistribution::Ternary => Self::Ternary,
+        }
+    }
+}
+
 macro_rules! expose_predefined_parameters {
     (
         $(
@@ -312,6 +327,42 @@ impl Shortint {
         carry_modulus: usize,
         modulus_power_of_2_exponent: usize,
         encryption_key_choice: ShortintEncryptionKeyChoice,
+    ) -> ShortintParameters {
+        Self::new_parameters_with_secret_key_distribution(
+            lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            lwe_modular_std_dev,
+            glwe_modular_std_dev,
+            pbs_base_log,
+            pbs_level,
+            ks_base_log,
+            ks_level,
+            message_modulus,
+            carry_modulus,
+            modulus_power_of_2_exponent,
+            encryption_key_choice,
+            ShortintSecretKeyDistribution::Binary,
+        )
+    }
+
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_parameters_with_secret_key_distribution(
+        lwe_dimension: usize,
+        glwe_dimension: usize,
+        polynomial_size: usize,
+        lwe_modular_std_dev: f64,
+        glwe_modular_std_dev: f64,
+        pbs_base_log: usize,
+        pbs_level: usize,
+        ks_base_log: usize,
+        ks_level: usize,
+        message_modulus: usize,
+        carry_modulus: usize,
+        modulus_power_of_2_exponent: usize,
+        encryption_key_choice: ShortintEncryptionKeyChoice,
+        secret_key_distribution: ShortintSecretKeyDistribution,
     ) -> ShortintParameters {
         set_hook(Box::new(console_error_panic_hook::hook));
         use crate::core_crypto::prelude::*;
@@ -319,8 +370,12 @@ impl Shortint {
             lwe_dimension: LweDimension(lwe_dimension),
             glwe_dimension: GlweDimension(glwe_dimension),
             polynomial_size: PolynomialSize(polynomial_size),
-            lwe_modular_std_dev: StandardDev(lwe_modular_std_dev),
-            glwe_modular_std_dev: StandardDev(glwe_modular_std_dev),
+            lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+                lwe_modular_std_dev,
+            )),
+            glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+                glwe_modular_std_dev,
+            )),
             pbs_base_log: DecompositionBaseLog(pbs_base_log),
             pbs_level: DecompositionLevelCount(pbs_level),
             ks_base_log: DecompositionBaseLog(ks_base_log),
@@ -332,6 +387,7 @@ impl Shortint {
             )
             .unwrap(),
             encryption_key_choice: encryption_key_choice.into(),
+            secret_key_distribution: secret_key_distribution.into(),
         })
     }
 
@@ -122,4 +122,7 @@ pub mod safe_deserialization;
 
 pub mod conformance;
 
+#[cfg(feature = "zk-pok-experimental")]
+pub mod zk;
+
 pub mod named;
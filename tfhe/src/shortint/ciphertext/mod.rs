@@ -9,6 +9,10 @@ use std::fmt::Debug;
 
 use super::parameters::{CiphertextConformanceParams, CiphertextListConformanceParams};
 use super::CheckError;
+#[cfg(feature = "zk-pok-experimental")]
+use crate::core_crypto::algorithms::verify_lwe_compact_ciphertext_list;
+#[cfg(feature = "zk-pok-experimental")]
+use crate::zk::{CompactPkeProof, CompactPkePublicParams, ZkVerificationOutCome};
 
 /// This tracks the number of operations that has been done.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
@@ -420,6 +424,77 @@ impl CompactCiphertextList {
     }
 }
 
+/// A [CompactCiphertextList] with a zero-knowledge proof that it was correctly encrypted
+///
+/// It is created with
+/// [CompactPublicKey::encrypt_and_prove_slice](crate::shortint::CompactPublicKey::encrypt_and_prove_slice)
+/// and must be verified before being expanded.
+#[cfg(feature = "zk-pok-experimental")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProvenCompactCiphertextList {
+    pub(crate) ct_list: CompactCiphertextList,
+    pub(crate) proof: CompactPkeProof,
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl ParameterSetConformant for ProvenCompactCiphertextList {
+    type ParameterSet = CiphertextListConformanceParams;
+
+    fn is_conformant(&self, param: &CiphertextListConformanceParams) -> bool {
+        self.ct_list.is_conformant(param)
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl ProvenCompactCiphertextList {
+    /// Verifies the proof of the list against the public key used to encrypt it
+    pub fn verify(
+        &self,
+        public_params: &CompactPkePublicParams,
+        public_key: &crate::shortint::CompactPublicKey,
+    ) -> ZkVerificationOutCome {
+        if self.ct_list.message_modulus != public_key.parameters.message_modulus()
+            || self.ct_list.carry_modulus != public_key.parameters.carry_modulus()
+            || self.ct_list.pbs_order != public_key.pbs_order
+        {
+            return ZkVerificationOutCome::Invalid;
+        }
+
+        verify_lwe_compact_ciphertext_list(
+            &self.ct_list.ct_list,
+            &public_key.key,
+            &self.proof,
+            public_params,
+        )
+    }
+
+    /// Verifies the proof of the list and expands it
+    ///
+    /// Returns `None` if the proof is not valid.
+    pub fn verify_and_expand(
+        &self,
+        public_params: &CompactPkePublicParams,
+        public_key: &crate::shortint::CompactPublicKey,
+    ) -> Option<Vec<Ciphertext>> {
+        self.verify(public_params, public_key)
+            .is_valid()
+            .then(|| self.ct_list.expand())
+    }
+
+    /// Returns the list without checking the proof
+    pub fn into_unverified_list(self) -> CompactCiphertextList {
+        self.ct_list
+    }
+
+    pub fn size_elements(&self) -> usize {
+        self.ct_list.size_elements()
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        self.ct_list.size_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,12 +1,18 @@
 //! Module with the definition of the ClientKey.
 
+use crate::core_crypto::algorithms::allocate_and_expand_lwe_secret_key_to_binary;
+use crate::core_crypto::algorithms::misc::torus_modular_diff;
+use crate::core_crypto::commons::dispersion::Variance;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::prelude::decrypt_lwe_ciphertext;
-use crate::shortint::ciphertext::{Ciphertext, CompressedCiphertext};
+use crate::shortint::ciphertext::{Ciphertext, CompressedCiphertext, MaxNoiseLevel, NoiseLevel};
 use crate::shortint::engine::ShortintEngine;
-use crate::shortint::parameters::{MessageModulus, ShortintParameterSet};
-use crate::shortint::CarryModulus;
+use crate::shortint::parameters::{
+    CiphertextModulus, MessageModulus, SecretKeyDistribution, ShortintParameterSet,
+};
+use crate::shortint::{noise_model, CarryModulus};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 use super::PBSOrder;
@@ -29,6 +35,16 @@ pub struct ClientKey {
     pub parameters: ShortintParameterSet,
 }
 
+/// Base 2 logarithm of the ciphertext modulus, used to express torus noise values as bits in the
+/// integer domain of the ciphertext
+fn ciphertext_modulus_log2(ciphertext_modulus: CiphertextModulus) -> f64 {
+    if ciphertext_modulus.is_native_modulus() {
+        u64::BITS as f64
+    } else {
+        (ciphertext_modulus.get_custom_modulus() as f64).log2()
+    }
+}
+
 impl ClientKey {
     /// Generate a client key.
     ///
@@ -51,6 +67,18 @@ impl ClientKey {
         })
     }
 
+    /// Returns the LWE secret key the classic bootstrapping key is generated for: the small LWE
+    /// secret key itself if it is binary, its binary expansion otherwise.
+    pub(crate) fn bootstrap_input_lwe_secret_key(&self) -> Cow<'_, LweSecretKeyOwned<u64>> {
+        match self.parameters.secret_key_distribution() {
+            SecretKeyDistribution::Binary => Cow::Borrowed(&self.small_lwe_secret_key),
+            distribution => Cow::Owned(allocate_and_expand_lwe_secret_key_to_binary(
+                &self.small_lwe_secret_key,
+                distribution.coefficient_bit_count(),
+            )),
+        }
+    }
+
     /// Encrypt a small integer message using the client key.
     ///
     /// The input message is reduced to the encrypted message space modulus
@@ -287,10 +315,17 @@ impl ClientKey {
         // decryption
         let decrypted_encoded = decrypt_lwe_ciphertext(lwe_decryption_key, &ct.ct);
 
-        let decrypted_u64: u64 = decrypted_encoded.0;
+        self.decode_message_and_carry(decrypted_encoded.0)
+    }
+
+    /// Returns the scaling factor of messages with a padding bit.
+    fn delta(&self) -> u64 {
+        (1_u64 << 63)
+            / (self.parameters.message_modulus().0 * self.parameters.carry_modulus().0) as u64
+    }
 
-        let delta = (1_u64 << 63)
-            / (self.parameters.message_modulus().0 * self.parameters.carry_modulus().0) as u64;
+    fn decode_message_and_carry(&self, decrypted_u64: u64) -> u64 {
+        let delta = self.delta();
 
         //The bit before the message
         let rounding_bit = delta >> 1;
@@ -336,6 +371,96 @@ impl ClientKey {
         self.decrypt_message_and_carry(ct) % ct.message_modulus.0 as u64
     }
 
+    /// Decrypt a ciphertext encrypting a message using the client key, and measure its noise.
+    ///
+    /// The noise is returned as the base 2 logarithm of its absolute value in the integer domain
+    /// of the ciphertext (i.e. over 64 bits). A noiseless ciphertext, such as a trivial one, has a
+    /// noise of [`f64::NEG_INFINITY`], which still compares as lower than any bound. Decryption
+    /// is correct as long as the noise is below $\Delta / 2$, $\Delta$ being the scaling factor of
+    /// the message. A single measure is a sample of the noise distribution, see
+    /// [`Self::expected_noise_bits`] for the noise expected by the noise model.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// // Generate the client key
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 3;
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Decryption:
+    /// let (dec, noise_bits) = cks.decrypt_with_noise(&ct);
+    /// assert_eq!(msg, dec);
+    ///
+    /// // The noise of a fresh ciphertext is far below the scaling factor 2^59
+    /// let expected_noise_bits = cks.expected_noise_bits(&ct).unwrap();
+    /// assert!(noise_bits < expected_noise_bits + 8.0);
+    /// assert!(noise_bits < 58.0);
+    /// ```
+    pub fn decrypt_with_noise(&self, ct: &Ciphertext) -> (u64, f64) {
+        let lwe_decryption_key = match ct.pbs_order {
+            PBSOrder::KeyswitchBootstrap => &self.large_lwe_secret_key,
+            PBSOrder::BootstrapKeyswitch => &self.small_lwe_secret_key,
+        };
+
+        let decrypted_u64 = decrypt_lwe_ciphertext(lwe_decryption_key, &ct.ct).0;
+        let message_and_carry = self.decode_message_and_carry(decrypted_u64);
+
+        let ciphertext_modulus = ct.ct.ciphertext_modulus();
+        // Power of 2 moduli are stored in the MSBs, the torus distance is then the native one
+        let torus_modulus = if ciphertext_modulus.is_compatible_with_native_modulus() {
+            CiphertextModulus::new_native()
+        } else {
+            ciphertext_modulus
+        };
+        let noise = torus_modular_diff(
+            decrypted_u64,
+            message_and_carry.wrapping_mul(self.delta()),
+            torus_modulus,
+        );
+        let noise_bits = noise.abs().log2() + ciphertext_modulus_log2(ciphertext_modulus);
+
+        (message_and_carry % ct.message_modulus.0 as u64, noise_bits)
+    }
+
+    /// Return the standard deviation of the noise expected for the ciphertext by the
+    /// [`noise_model`](crate::shortint::noise_model), given its [`NoiseLevel`].
+    ///
+    /// The standard deviation is returned as its base 2 logarithm in the integer domain of the
+    /// ciphertext, like the noise measured by [`Self::decrypt_with_noise`]. The ciphertext is
+    /// assumed to be a linear combination of fresh encryptions or of outputs of PBS.
+    ///
+    /// Returns `None` if the noise level of the ciphertext is unknown or if the parameters do not
+    /// support the PBS.
+    pub fn expected_noise_bits(&self, ct: &Ciphertext) -> Option<f64> {
+        let params = self.parameters.pbs_parameters()?;
+        let noise_level = ct.noise_level();
+        if noise_level == NoiseLevel::UNKNOWN {
+            return None;
+        }
+
+        let output_variance = match ct.pbs_order {
+            PBSOrder::KeyswitchBootstrap => noise_model::pbs_variance(params).0,
+            PBSOrder::BootstrapKeyswitch => {
+                noise_model::pbs_variance(params).0 + noise_model::keyswitch_variance(params).0
+            }
+        };
+        let nominal_variance =
+            Variance(output_variance.max(noise_model::encryption_variance(params).0));
+        let variance = noise_model::linear_combination_variance(
+            nominal_variance,
+            MaxNoiseLevel::new(noise_level.get()),
+        );
+
+        Some(0.5 * variance.0.log2() + ciphertext_modulus_log2(params.ciphertext_modulus()))
+    }
+
     /// Encrypt a small integer message using the client key without padding bit.
     ///
     /// The input message is reduced to the encrypted message space modulus
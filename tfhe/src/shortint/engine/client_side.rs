@@ -1,7 +1,7 @@
 //! All the `ShortintEngine` method related to client side (encrypt / decrypt)
 use super::ShortintEngine;
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
@@ -12,15 +12,17 @@ use crate::shortint::{
 impl ShortintEngine {
     pub fn new_client_key(&mut self, parameters: ShortintParameterSet) -> ClientKey {
         // generate the lwe secret key
-        let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        let small_lwe_secret_key = allocate_and_generate_new_lwe_secret_key(
             parameters.lwe_dimension(),
+            parameters.secret_key_distribution(),
             &mut self.secret_generator,
         );
 
         // generate the rlwe secret key
-        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        let glwe_secret_key = allocate_and_generate_new_glwe_secret_key(
             parameters.glwe_dimension(),
             parameters.polynomial_size(),
+            parameters.secret_key_distribution(),
             &mut self.secret_generator,
         );
 
@@ -59,7 +61,7 @@ impl ShortintEngine {
         &mut self,
         client_key_parameters: &ShortintParameterSet,
         client_lwe_sk: &LweSecretKeyOwned<u64>,
-        noise_parameter: impl DispersionParameter,
+        noise_parameter: impl NoiseDistribution,
         message: u64,
         message_modulus: MessageModulus,
     ) -> LweCiphertextOwned<u64> {
@@ -95,11 +97,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -147,11 +149,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -202,11 +204,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -234,11 +236,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -290,11 +292,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -336,11 +338,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -379,11 +381,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -422,11 +424,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
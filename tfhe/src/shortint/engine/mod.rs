@@ -11,6 +11,9 @@ use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder
 use crate::core_crypto::entities::*;
 use crate::core_crypto::prelude::ContainerMut;
 use crate::core_crypto::seeders::new_seeder;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::server_key::MaxDegree;
 use crate::shortint::ServerKey;
 use std::cell::RefCell;
 use std::fmt::Debug;
@@ -87,18 +90,39 @@ where
         server_key.bootstrapping_key.glwe_size()
     );
 
+    fill_accumulator_for_moduli(
+        accumulator,
+        server_key.message_modulus,
+        server_key.carry_modulus,
+        f,
+    )
+}
+
+/// Fills the accumulator for the given message and carry moduli, without requiring a
+/// [`ServerKey`], the polynomial size is the one of the accumulator.
+pub(crate) fn fill_accumulator_for_moduli<F, C>(
+    accumulator: &mut GlweCiphertext<C>,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    f: F,
+) -> u64
+where
+    C: ContainerMut<Element = u64>,
+    F: Fn(u64) -> u64,
+{
+    let polynomial_size = accumulator.polynomial_size();
     let mut accumulator_view = accumulator.as_mut_view();
 
     accumulator_view.get_mut_mask().as_mut().fill(0);
 
     // Modulus of the msg contained in the msg bits and operations buffer
-    let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+    let modulus_sup = message_modulus.0 * carry_modulus.0;
 
     // N/(p/2) = size of each block
-    let box_size = server_key.bootstrapping_key.polynomial_size().0 / modulus_sup;
+    let box_size = polynomial_size.0 / modulus_sup;
 
     // Value of the shift we multiply our messages by
-    let delta = (1_u64 << 63) / (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+    let delta = (1_u64 << 63) / (message_modulus.0 * carry_modulus.0) as u64;
 
     let mut body = accumulator_view.get_mut_body();
     let accumulator_u64 = body.as_mut();
@@ -127,6 +151,87 @@ where
     max_value
 }
 
+/// Fills the accumulator with one sub-table per function, each of them covering the input
+/// values in `0..=input_max_degree` so that a single blind rotation evaluates all the functions.
+///
+/// Returns the max degree the input may have, the distance (in coefficients) between two
+/// sub-tables and the degree of the output of each function.
+pub(crate) fn fill_many_lut_accumulator<C>(
+    accumulator: &mut GlweCiphertext<C>,
+    server_key: &ServerKey,
+    functions: &[&dyn Fn(u64) -> u64],
+) -> (MaxDegree, usize, Vec<Degree>)
+where
+    C: ContainerMut<Element = u64>,
+{
+    assert_eq!(
+        accumulator.polynomial_size(),
+        server_key.bootstrapping_key.polynomial_size()
+    );
+    assert_eq!(
+        accumulator.glwe_size(),
+        server_key.bootstrapping_key.glwe_size()
+    );
+
+    let mut accumulator_view = accumulator.as_mut_view();
+
+    accumulator_view.get_mut_mask().as_mut().fill(0);
+
+    // Modulus of the msg contained in the msg bits and operations buffer
+    let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+
+    let fn_count = functions.len();
+    assert!(
+        fn_count > 0 && fn_count <= modulus_sup,
+        "Cannot pack {fn_count} functions in a lookup table for a message space of {modulus_sup}"
+    );
+
+    // N/(p/2) = size of each block
+    let box_size = server_key.bootstrapping_key.polynomial_size().0 / modulus_sup;
+
+    // Value of the shift we multiply our messages by
+    let delta = (1_u64 << 63) / (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+
+    // Each function gets an equal share of the message space
+    let input_max_degree = modulus_sup / fn_count - 1;
+    let sub_lut_size = (input_max_degree + 1) * box_size;
+
+    let mut body = accumulator_view.get_mut_body();
+    let accumulator_u64 = body.as_mut();
+    // Boxes not used by any function (when fn_count does not divide modulus_sup)
+    accumulator_u64.fill(0);
+
+    let mut per_function_output_degree = Vec::with_capacity(fn_count);
+    for (f, sub_lut) in functions
+        .iter()
+        .zip(accumulator_u64.chunks_exact_mut(sub_lut_size))
+    {
+        let mut max_value = 0;
+        for (i, lut_box) in sub_lut.chunks_exact_mut(box_size).enumerate() {
+            let f_eval = f(i as u64);
+            max_value = max_value.max(f_eval);
+            lut_box.fill(f_eval * delta);
+        }
+        per_function_output_degree.push(Degree(max_value as usize));
+    }
+
+    let half_box_size = box_size / 2;
+
+    // Negate the first half_box_size coefficients
+    for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+        *a_i = (*a_i).wrapping_neg();
+    }
+
+    // Rotate the accumulator
+    accumulator_u64.rotate_left(half_box_size);
+
+    (
+        MaxDegree(input_max_degree),
+        sub_lut_size,
+        per_function_output_degree,
+    )
+}
+
 /// Simple wrapper around [`std::error::Error`] to be able to
 /// forward all the possible `EngineError` type from [`core_crypto`](crate::core_crypto)
 #[allow(dead_code)]
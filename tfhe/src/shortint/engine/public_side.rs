@@ -27,11 +27,11 @@ impl ShortintEngine {
             match client_parameters.encryption_key_choice().into() {
                 crate::shortint::PBSOrder::KeyswitchBootstrap => (
                     &client_key.large_lwe_secret_key,
-                    client_parameters.glwe_modular_std_dev(),
+                    client_parameters.glwe_noise_distribution(),
                 ),
                 crate::shortint::PBSOrder::BootstrapKeyswitch => (
                     &client_key.small_lwe_secret_key,
-                    client_parameters.lwe_modular_std_dev(),
+                    client_parameters.lwe_noise_distribution(),
                 ),
             };
 
@@ -73,11 +73,11 @@ impl ShortintEngine {
             match client_parameters.encryption_key_choice().into() {
                 crate::shortint::PBSOrder::KeyswitchBootstrap => (
                     &client_key.large_lwe_secret_key,
-                    client_parameters.glwe_modular_std_dev(),
+                    client_parameters.glwe_noise_distribution(),
                 ),
                 crate::shortint::PBSOrder::BootstrapKeyswitch => (
                     &client_key.small_lwe_secret_key,
-                    client_parameters.lwe_modular_std_dev(),
+                    client_parameters.lwe_noise_distribution(),
                 ),
             };
 
@@ -64,11 +64,11 @@ impl ShortintEngine {
             crate::shortint::PBSParameters::PBS(pbs_params) => {
                 let bootstrap_key: LweBootstrapKeyOwned<u64> =
                     par_allocate_and_generate_new_lwe_bootstrap_key(
-                        &cks.small_lwe_secret_key,
+                        &cks.bootstrap_input_lwe_secret_key(),
                         &cks.glwe_secret_key,
                         pbs_params.pbs_base_log,
                         pbs_params.pbs_level,
-                        pbs_params.glwe_modular_std_dev,
+                        pbs_params.glwe_noise_distribution,
                         pbs_params.ciphertext_modulus,
                         &mut self.encryption_generator,
                     );
@@ -95,7 +95,7 @@ impl ShortintEngine {
                         pbs_params.pbs_base_log,
                         pbs_params.pbs_level,
                         pbs_params.grouping_factor,
-                        pbs_params.glwe_modular_std_dev,
+                        pbs_params.glwe_noise_distribution,
                         pbs_params.ciphertext_modulus,
                         &mut self.encryption_generator,
                     );
@@ -138,7 +138,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log(),
             cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
+            cks.parameters.lwe_noise_distribution(),
             cks.parameters.ciphertext_modulus(),
             &mut self.encryption_generator,
         );
@@ -173,7 +173,7 @@ impl ShortintEngine {
             &cks2.large_lwe_secret_key,
             params.ks_base_log,
             params.ks_level,
-            cks2.parameters.lwe_modular_std_dev(),
+            cks2.parameters.lwe_noise_distribution(),
             cks2.parameters.ciphertext_modulus(),
             &mut self.encryption_generator,
         )
@@ -197,22 +197,22 @@ impl ShortintEngine {
             crate::shortint::PBSParameters::PBS(pbs_params) => {
                 #[cfg(not(feature = "__wasm_api"))]
                 let bootstrapping_key = par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
-                    &cks.small_lwe_secret_key,
+                    &cks.bootstrap_input_lwe_secret_key(),
                     &cks.glwe_secret_key,
                     pbs_params.pbs_base_log,
                     pbs_params.pbs_level,
-                    pbs_params.glwe_modular_std_dev,
+                    pbs_params.glwe_noise_distribution,
                     pbs_params.ciphertext_modulus,
                     &mut self.seeder,
                 );
 
                 #[cfg(feature = "__wasm_api")]
                 let bootstrapping_key = allocate_and_generate_new_seeded_lwe_bootstrap_key(
-                    &cks.small_lwe_secret_key,
+                    &cks.bootstrap_input_lwe_secret_key(),
                     &cks.glwe_secret_key,
                     pbs_params.pbs_base_log,
                     pbs_params.pbs_level,
-                    pbs_params.glwe_modular_std_dev,
+                    pbs_params.glwe_noise_distribution,
                     pbs_params.ciphertext_modulus,
                     &mut self.seeder,
                 );
@@ -227,7 +227,7 @@ impl ShortintEngine {
                         &cks.glwe_secret_key,
                         pbs_params.pbs_base_log,
                         pbs_params.pbs_level,
-                        pbs_params.glwe_modular_std_dev,
+                        pbs_params.glwe_noise_distribution,
                         pbs_params.grouping_factor,
                         pbs_params.ciphertext_modulus,
                         &mut self.seeder,
@@ -240,7 +240,7 @@ impl ShortintEngine {
                         &cks.glwe_secret_key,
                         pbs_params.pbs_base_log,
                         pbs_params.pbs_level,
-                        pbs_params.glwe_modular_std_dev,
+                        pbs_params.glwe_noise_distribution,
                         pbs_params.grouping_factor,
                         pbs_params.ciphertext_modulus,
                         &mut self.seeder,
@@ -259,7 +259,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log(),
             cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
+            cks.parameters.lwe_noise_distribution(),
             cks.parameters.ciphertext_modulus(),
             &mut self.seeder,
         );
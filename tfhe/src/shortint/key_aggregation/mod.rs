@@ -0,0 +1,983 @@
+//! This module defines the generation of shortint keys by several parties without a trusted
+//! dealer.
+//!
+//! Each party samples its own secret keys with [PartySecretKey::new], the joint secret keys are
+//! never reconstructed:
+//!
+//! - the joint GLWE secret key is the sum of the GLWE secret keys of the parties;
+//! - the joint LWE secret key is the concatenation of the LWE secret keys of the parties, its
+//!   dimension is therefore `party_count` times the `lwe_dimension` of the parameters;
+//! - the joint GLWE secret key of the [noise squashing](crate::shortint::noise_squashing) is the
+//!   sum of the noise squashing GLWE secret keys of the parties.
+//!
+//! The protocol runs in two rounds, the messages of the parties being gathered by an aggregator
+//! which does not need to be trusted and holds no secret:
+//!
+//! 1. each party computes a [PublicKeyShare] with [PartySecretKey::generate_public_key_share] from
+//!    a seed common to all parties, the shares are summed with [aggregate_public_key_shares] into
+//!    an [AggregatedPublicKey], which contains the [CompactPublicKey] under the joint key;
+//! 2. each party encrypts the bits of its LWE secret key under the joint GLWE secret keys with
+//!    [PartySecretKey::generate_bootstrapping_key_share], the shares are put together in a
+//!    [ServerKey] by [AggregatedPublicKey::aggregate_server_key] and in a [NoiseSquashingKey] by
+//!    [AggregatedPublicKey::aggregate_noise_squashing_key].
+//!
+//! Ciphertexts encrypted with the [CompactPublicKey] and computed on with the [ServerKey] are
+//! squashed with the [NoiseSquashingKey] and decrypted with the
+//! [threshold decryption](crate::shortint::threshold): each party splits its noise squashing
+//! secret key in [ThresholdKeyContribution]s with
+//! [PartySecretKey::generate_threshold_key_contributions], and each party puts the contributions
+//! it received together in a
+//! [ThresholdSecretKeyShare](crate::shortint::threshold::ThresholdSecretKeyShare). No dealer ever
+//! holds the joint keys.
+//!
+//! # Common seed
+//!
+//! All the parties must use the same seed in the first round: the masks of their encryptions are
+//! then identical and their bodies can be summed to obtain encryptions under the joint key. The
+//! seed must be public and must not be known before the parties have sampled their secret keys.
+//!
+//! # Bootstrapping key
+//!
+//! Once the first round is complete, the [AggregatedPublicKey] contains a GLWE public key and
+//! GLev encryptions of the joint GLWE secret key, both under the joint GLWE secret key, and the
+//! same for the joint noise squashing GLWE secret key. Each party computes the GGSW encryption of
+//! a bit $b$ of its LWE secret key by encrypting $b$ and $0$ with the GLWE public key and adding
+//! $b$ times the GLev encryptions of the GLWE secret key. The coefficients of the LWE secret keys
+//! are encrypted as they are, they must therefore be bits: only parameters with a
+//! [Binary](SecretKeyDistribution::Binary) secret key distribution are supported. The noise
+//! squashing key is generated in the same way, with 128 bits ciphertexts.
+//!
+//! # Noise growth
+//!
+//! With `n` parties, and compared to the keys generated by a single party with the same
+//! parameters:
+//!
+//! - the public material of the first round is the sum of `n` encryptions: the noise variances of
+//!   the compact public key, of the key switching key and of the GLev encryptions are multiplied by
+//!   `n`;
+//! - the joint GLWE secret key is the sum of `n` binary keys, its coefficients lie in `[0, n]` and
+//!   their mean square is $n(n + 1) / 4$ instead of $1 / 2$: the rounding errors of the gadget
+//!   decompositions in the external products and in the keyswitch are multiplied by $n(n + 1) / 2$;
+//! - each GGSW row is a public key encryption, its noise is dominated by the product of the binary
+//!   randomness with the noise of the GLWE public key, the variance of which is $n \cdot (k + 1) N
+//!   / 2$ times the variance of a secret key encryption, for a GLWE dimension $k$ and a polynomial
+//!   size $N$;
+//! - the joint LWE secret key has `n` times more coefficients: the blind rotation does `n` times
+//!   more external products and the output of the keyswitch has `n` times more coefficients to be
+//!   rounded by the modulus switch.
+//!
+//! The variance of the noise brought by the bootstrapping key to the output of a PBS, which is
+//! the dominant term for the [ClassicPBSParameters], therefore grows as
+//! $n^2 \cdot (k + 1) N / 2$, i.e. by about $11 + 2 \log_2(n)$ bits of variance for $k = 1$ and
+//! $N = 2048$. The classic parameter sets are not designed for this noise, the
+//! `PARAM_KEY_AGGREGATION_*` parameter sets are (see
+//! [crate::shortint::parameters::key_aggregation]).
+//!
+//! The failure probability of a PBS with the joint keys is estimated by
+//! [log2_pbs_failure_probability], [PartySecretKey::new] rejects the parameters and number of
+//! parties for which it exceeds $2^{-40}$.
+//!
+//! The noise of the squashed ciphertexts grows in the same way, the smudging noise of the
+//! threshold decryption is sized for the noise of the joint noise squashing key.
+
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_add_mul_assign;
+use crate::core_crypto::algorithms::slice_algorithms::{
+    slice_wrapping_add_assign, slice_wrapping_add_scalar_mul_assign,
+};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::dispersion::DynamicDistribution;
+use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::prelude::{Fft128, UnsignedTorus};
+use crate::shortint::ciphertext::MaxNoiseLevel;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::noise_squashing::NoiseSquashingKey;
+use crate::shortint::parameters::{NoiseSquashingParameters, SecretKeyDistribution};
+use crate::shortint::server_key::{MaxDegree, ShortintBootstrappingKey};
+use crate::shortint::threshold::{
+    generate_threshold_key_contributions, PartyId, ThresholdKeyContribution, ThresholdParameters,
+};
+use crate::shortint::{
+    ClassicPBSParameters, CompactPublicKey, EncryptionKeyChoice, PBSOrder, ServerKey,
+};
+use serde::{Deserialize, Serialize};
+
+mod noise;
+#[cfg(test)]
+mod test;
+
+pub use noise::log2_pbs_failure_probability;
+use noise::squashed_noise_variance;
+
+/// The base 2 logarithm of the largest failure probability of a PBS accepted for the joint keys.
+const MAX_LOG2_PBS_FAILURE_PROBABILITY: f64 = -40.0;
+
+/// The secret keys sampled by one party, it must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartySecretKey {
+    party: PartyId,
+    party_count: usize,
+    parameters: ClassicPBSParameters,
+    noise_squashing_parameters: NoiseSquashingParameters,
+    glwe_secret_key: GlweSecretKeyOwned<u64>,
+    lwe_secret_key: LweSecretKeyOwned<u64>,
+    noise_squashing_glwe_secret_key: GlweSecretKeyOwned<u128>,
+}
+
+/// The contribution of one party to the first round of the key generation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PublicKeyShare {
+    party: PartyId,
+    party_count: usize,
+    parameters: ClassicPBSParameters,
+    noise_squashing_parameters: NoiseSquashingParameters,
+    compact_public_key: LweCompactPublicKeyOwned<u64>,
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    glwe_public_key: GlweCiphertextOwned<u64>,
+    glwe_secret_key_glev: GlweCiphertextListOwned<u64>,
+    noise_squashing_glwe_public_key: GlweCiphertextOwned<u128>,
+    noise_squashing_glwe_secret_key_glev: GlweCiphertextListOwned<u128>,
+}
+
+/// The public material under the joint secret keys obtained at the end of the first round of
+/// the key generation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AggregatedPublicKey {
+    party_count: usize,
+    parameters: ClassicPBSParameters,
+    noise_squashing_parameters: NoiseSquashingParameters,
+    compact_public_key: LweCompactPublicKeyOwned<u64>,
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    glwe_public_key: GlweCiphertextOwned<u64>,
+    glwe_secret_key_glev: GlweCiphertextListOwned<u64>,
+    noise_squashing_glwe_public_key: GlweCiphertextOwned<u128>,
+    noise_squashing_glwe_secret_key_glev: GlweCiphertextListOwned<u128>,
+}
+
+/// The contribution of one party to the second round of the key generation.
+///
+/// It contains the GGSW encryptions of the bits of its LWE secret key under the joint GLWE secret
+/// key and under the joint noise squashing GLWE secret key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BootstrappingKeyShare {
+    party: PartyId,
+    ggsw_list: GgswCiphertextListOwned<u64>,
+    noise_squashing_ggsw_list: GgswCiphertextListOwned<u128>,
+}
+
+impl PublicKeyShare {
+    pub fn party(&self) -> PartyId {
+        self.party
+    }
+}
+
+impl BootstrappingKeyShare {
+    pub fn party(&self) -> PartyId {
+        self.party
+    }
+}
+
+impl PartySecretKey {
+    /// Sample the secret keys of `party` among `party_count` parties.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::random::Seed;
+    /// use tfhe::shortint::key_aggregation::{aggregate_public_key_shares, PartySecretKey};
+    /// use tfhe::shortint::parameters::{
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2, PARAM_KEY_AGGREGATION_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::threshold::{
+    ///     combine_partial_decryptions, PartyId, ThresholdParameters, ThresholdSecretKeyShare,
+    /// };
+    ///
+    /// let party_count = 2;
+    /// let parties: Vec<_> = (0..party_count)
+    ///     .map(|party| {
+    ///         PartySecretKey::new(
+    ///             PartyId(party),
+    ///             party_count,
+    ///             PARAM_KEY_AGGREGATION_MESSAGE_2_CARRY_2_KS_PBS,
+    ///             NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+    ///         )
+    ///     })
+    ///     .collect();
+    ///
+    /// // First round, with a seed agreed upon by all the parties
+    /// let common_seed = Seed(0x5eed);
+    /// let public_key_shares: Vec<_> = parties
+    ///     .iter()
+    ///     .map(|party| party.generate_public_key_share(common_seed))
+    ///     .collect();
+    /// let public_key = aggregate_public_key_shares(&public_key_shares);
+    ///
+    /// // Second round
+    /// let bootstrapping_key_shares: Vec<_> = parties
+    ///     .iter()
+    ///     .map(|party| party.generate_bootstrapping_key_share(&public_key))
+    ///     .collect();
+    /// let server_key = public_key.aggregate_server_key(&bootstrapping_key_shares);
+    /// let noise_squashing_key = public_key.aggregate_noise_squashing_key(&bootstrapping_key_shares);
+    /// let compact_public_key = public_key.compact_public_key();
+    ///
+    /// // The shares of the threshold decryption, here any single party can decrypt: party i sends
+    /// // contributions[i][j] to party j
+    /// let threshold_params = ThresholdParameters::new(party_count, 1);
+    /// let contributions: Vec<_> = parties
+    ///     .iter()
+    ///     .map(|party| party.generate_threshold_key_contributions(threshold_params))
+    ///     .collect();
+    /// let key_shares: Vec<_> = (0..party_count)
+    ///     .map(|party| {
+    ///         let received: Vec<_> = contributions
+    ///             .iter()
+    ///             .map(|contributions| contributions[party].clone())
+    ///             .collect();
+    ///         ThresholdSecretKeyShare::from_contributions(&received)
+    ///     })
+    ///     .collect();
+    ///
+    /// let ct = compact_public_key.encrypt(1);
+    ///
+    /// // Apply a lookup table, which uses the bootstrapping key
+    /// let lookup_table = server_key.generate_lookup_table(|x| (x + 2) % 4);
+    /// let ct = server_key.apply_lookup_table(&ct, &lookup_table);
+    ///
+    /// let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &server_key);
+    ///
+    /// let participants = [PartyId(1)];
+    /// let partial_decryptions = [key_shares[1].partial_decrypt(&squashed, &participants)];
+    ///
+    /// assert_eq!(
+    ///     combine_partial_decryptions(&squashed, &partial_decryptions),
+    ///     3
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `party` is not in `0..party_count`, if the parameters do not encrypt under the
+    /// big key, do not use the native ciphertext modulus or do not use binary secret keys, if the
+    /// failure probability of a PBS with the keys of `party_count` parties exceeds $2^{-40}$
+    /// (see [log2_pbs_failure_probability]), or if the polynomial size of the noise squashing
+    /// parameters is smaller than the one of the parameters.
+    pub fn new(
+        party: PartyId,
+        party_count: usize,
+        parameters: ClassicPBSParameters,
+        noise_squashing_parameters: NoiseSquashingParameters,
+    ) -> Self {
+        assert!(
+            party.0 < party_count,
+            "Party must be in 0..{party_count}, got {}",
+            party.0
+        );
+        assert_eq!(
+            parameters.encryption_key_choice,
+            EncryptionKeyChoice::Big,
+            "Only parameters encrypting under the big key are supported"
+        );
+        assert!(
+            parameters.ciphertext_modulus.is_native_modulus()
+                && noise_squashing_parameters
+                    .ciphertext_modulus
+                    .is_native_modulus(),
+            "Only the native ciphertext modulus is supported"
+        );
+        assert_eq!(
+            parameters.secret_key_distribution,
+            SecretKeyDistribution::Binary,
+            "Only binary secret keys are supported"
+        );
+        assert!(
+            noise_squashing_parameters.polynomial_size.0 >= parameters.polynomial_size.0,
+            "The polynomial size of the noise squashing ({}) must be at least the one of the \
+            ciphertexts ({})",
+            noise_squashing_parameters.polynomial_size.0,
+            parameters.polynomial_size.0
+        );
+        let log2_p_fail = log2_pbs_failure_probability(parameters, party_count);
+        assert!(
+            log2_p_fail <= MAX_LOG2_PBS_FAILURE_PROBABILITY,
+            "The parameters do not support {party_count} parties, the failure probability of a \
+            PBS would be 2^{log2_p_fail:.1}"
+        );
+
+        let (glwe_secret_key, lwe_secret_key, noise_squashing_glwe_secret_key) =
+            ShortintEngine::with_thread_local_mut(|engine| {
+                let glwe_secret_key = allocate_and_generate_new_glwe_secret_key(
+                    parameters.glwe_dimension,
+                    parameters.polynomial_size,
+                    parameters.secret_key_distribution,
+                    &mut engine.secret_generator,
+                );
+                let lwe_secret_key = allocate_and_generate_new_lwe_secret_key(
+                    parameters.lwe_dimension,
+                    parameters.secret_key_distribution,
+                    &mut engine.secret_generator,
+                );
+                let noise_squashing_glwe_secret_key =
+                    allocate_and_generate_new_binary_glwe_secret_key(
+                        noise_squashing_parameters.glwe_dimension,
+                        noise_squashing_parameters.polynomial_size,
+                        &mut engine.secret_generator,
+                    );
+                (
+                    glwe_secret_key,
+                    lwe_secret_key,
+                    noise_squashing_glwe_secret_key,
+                )
+            });
+
+        Self {
+            party,
+            party_count,
+            parameters,
+            noise_squashing_parameters,
+            glwe_secret_key,
+            lwe_secret_key,
+            noise_squashing_glwe_secret_key,
+        }
+    }
+
+    pub fn party(&self) -> PartyId {
+        self.party
+    }
+
+    /// The LWE secret key of this party placed at its slot of the joint LWE secret key, the
+    /// other coefficients being zero.
+    fn padded_lwe_secret_key(&self) -> LweSecretKeyOwned<u64> {
+        let lwe_dimension = self.parameters.lwe_dimension.0;
+        let mut padded =
+            LweSecretKey::new_empty_key(0u64, LweDimension(lwe_dimension * self.party_count));
+        padded.as_mut()[self.party.0 * lwe_dimension..(self.party.0 + 1) * lwe_dimension]
+            .copy_from_slice(self.lwe_secret_key.as_ref());
+        padded
+    }
+
+    /// Compute the contribution of this party to the first round of the key generation.
+    ///
+    /// See [PartySecretKey::new] for usage.
+    pub fn generate_public_key_share(&self, common_seed: Seed) -> PublicKeyShare {
+        let params = &self.parameters;
+        let noise_squashing_params = &self.noise_squashing_parameters;
+        let large_lwe_secret_key = self.glwe_secret_key.as_lwe_secret_key();
+
+        let mut compact_public_key = LweCompactPublicKeyOwned::new(
+            0u64,
+            large_lwe_secret_key.lwe_dimension(),
+            params.ciphertext_modulus,
+        );
+        let mut key_switching_key = LweKeyswitchKeyOwned::new(
+            0u64,
+            params.ks_base_log,
+            params.ks_level,
+            large_lwe_secret_key.lwe_dimension(),
+            LweDimension(params.lwe_dimension.0 * self.party_count),
+            params.ciphertext_modulus,
+        );
+        let mut glwe_public_key = GlweCiphertextOwned::new(
+            0u64,
+            params.glwe_dimension.to_glwe_size(),
+            params.polynomial_size,
+            params.ciphertext_modulus,
+        );
+        let mut glwe_secret_key_glev = GlweCiphertextListOwned::new(
+            0u64,
+            params.glwe_dimension.to_glwe_size(),
+            params.polynomial_size,
+            GlweCiphertextCount(params.pbs_level.0 * params.glwe_dimension.0),
+            params.ciphertext_modulus,
+        );
+        let mut noise_squashing_glwe_public_key = GlweCiphertextOwned::new(
+            0u128,
+            noise_squashing_params.glwe_dimension.to_glwe_size(),
+            noise_squashing_params.polynomial_size,
+            noise_squashing_params.ciphertext_modulus,
+        );
+        let mut noise_squashing_glwe_secret_key_glev = GlweCiphertextListOwned::new(
+            0u128,
+            noise_squashing_params.glwe_dimension.to_glwe_size(),
+            noise_squashing_params.polynomial_size,
+            GlweCiphertextCount(
+                noise_squashing_params.decomp_level_count.0
+                    * noise_squashing_params.glwe_dimension.0,
+            ),
+            noise_squashing_params.ciphertext_modulus,
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            // The masks come from the common seed and are identical for all the parties, the
+            // noises are sampled from the private seeder of this party
+            let mut generator = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                common_seed,
+                &mut engine.seeder,
+            );
+
+            generate_lwe_compact_public_key(
+                &large_lwe_secret_key,
+                &mut compact_public_key,
+                params.glwe_noise_distribution,
+                &mut generator,
+            );
+
+            generate_lwe_keyswitch_key(
+                &large_lwe_secret_key,
+                &self.padded_lwe_secret_key(),
+                &mut key_switching_key,
+                params.lwe_noise_distribution,
+                &mut generator,
+            );
+
+            encrypt_glwe_public_key_and_secret_key_glev(
+                &self.glwe_secret_key,
+                &mut glwe_public_key,
+                &mut glwe_secret_key_glev,
+                params.pbs_base_log,
+                params.glwe_noise_distribution,
+                &mut generator,
+            );
+
+            encrypt_glwe_public_key_and_secret_key_glev(
+                &self.noise_squashing_glwe_secret_key,
+                &mut noise_squashing_glwe_public_key,
+                &mut noise_squashing_glwe_secret_key_glev,
+                noise_squashing_params.decomp_base_log,
+                noise_squashing_params.glwe_noise_distribution,
+                &mut generator,
+            );
+        });
+
+        PublicKeyShare {
+            party: self.party,
+            party_count: self.party_count,
+            parameters: self.parameters,
+            noise_squashing_parameters: self.noise_squashing_parameters,
+            compact_public_key,
+            key_switching_key,
+            glwe_public_key,
+            glwe_secret_key_glev,
+            noise_squashing_glwe_public_key,
+            noise_squashing_glwe_secret_key_glev,
+        }
+    }
+
+    /// Compute the contribution of this party to the second round of the key generation.
+    ///
+    /// See [PartySecretKey::new] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `public_key` was not generated for the same parameters and number of parties.
+    pub fn generate_bootstrapping_key_share(
+        &self,
+        public_key: &AggregatedPublicKey,
+    ) -> BootstrappingKeyShare {
+        assert_eq!(public_key.party_count, self.party_count);
+        assert_eq!(public_key.parameters, self.parameters);
+        assert_eq!(
+            public_key.noise_squashing_parameters,
+            self.noise_squashing_parameters
+        );
+
+        let params = &self.parameters;
+        let noise_squashing_params = &self.noise_squashing_parameters;
+
+        let mut ggsw_list = GgswCiphertextListOwned::new(
+            0u64,
+            params.glwe_dimension.to_glwe_size(),
+            params.polynomial_size,
+            params.pbs_base_log,
+            params.pbs_level,
+            GgswCiphertextCount(params.lwe_dimension.0),
+            params.ciphertext_modulus,
+        );
+        let mut noise_squashing_ggsw_list = GgswCiphertextListOwned::new(
+            0u128,
+            noise_squashing_params.glwe_dimension.to_glwe_size(),
+            noise_squashing_params.polynomial_size,
+            noise_squashing_params.decomp_base_log,
+            noise_squashing_params.decomp_level_count,
+            GgswCiphertextCount(params.lwe_dimension.0),
+            noise_squashing_params.ciphertext_modulus,
+        );
+
+        let noise_squashing_key_bits: Vec<_> = self
+            .lwe_secret_key
+            .as_ref()
+            .iter()
+            .map(|&bit| u128::from(bit))
+            .collect();
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            encrypt_key_bits_with_public_key(
+                self.lwe_secret_key.as_ref(),
+                &public_key.glwe_public_key,
+                &public_key.glwe_secret_key_glev,
+                &mut ggsw_list,
+                params.glwe_noise_distribution,
+                engine,
+            );
+
+            encrypt_key_bits_with_public_key(
+                &noise_squashing_key_bits,
+                &public_key.noise_squashing_glwe_public_key,
+                &public_key.noise_squashing_glwe_secret_key_glev,
+                &mut noise_squashing_ggsw_list,
+                noise_squashing_params.glwe_noise_distribution,
+                engine,
+            );
+        });
+
+        BootstrappingKeyShare {
+            party: self.party,
+            ggsw_list,
+            noise_squashing_ggsw_list,
+        }
+    }
+
+    /// Split the noise squashing secret key of this party in one contribution per party to the
+    /// shares of the threshold decryption, the contribution of index `i` must be sent to the party
+    /// `i` over a secure channel.
+    ///
+    /// All the parties must use the same `params`. The smudging noise is sized for the noise of
+    /// the joint noise squashing key.
+    ///
+    /// See [PartySecretKey::new] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `params` is not for the parties of the key generation.
+    pub fn generate_threshold_key_contributions(
+        &self,
+        params: ThresholdParameters,
+    ) -> Vec<ThresholdKeyContribution> {
+        assert_eq!(
+            params.party_count, self.party_count,
+            "The keys must be shared among the parties of the key generation"
+        );
+
+        generate_threshold_key_contributions(
+            self.party,
+            &self
+                .noise_squashing_glwe_secret_key
+                .clone()
+                .into_lwe_secret_key(),
+            params,
+            squashed_noise_variance(
+                self.parameters,
+                self.noise_squashing_parameters,
+                self.party_count,
+            ),
+        )
+    }
+}
+
+/// Encrypt zero in `glwe_public_key`, and $-S_r \cdot q / B^{level}$ in `glwe_secret_key_glev` for
+/// each level of the decomposition and each polynomial $S_r$ of `glwe_secret_key`.
+///
+/// The GLev encryptions of the shares $S$ of the parties sum to the first rows of a GGSW
+/// encryption of 1 under the joint key.
+fn encrypt_glwe_public_key_and_secret_key_glev<Scalar, Gen>(
+    glwe_secret_key: &GlweSecretKeyOwned<Scalar>,
+    glwe_public_key: &mut GlweCiphertextOwned<Scalar>,
+    glwe_secret_key_glev: &mut GlweCiphertextListOwned<Scalar>,
+    decomp_base_log: DecompositionBaseLog,
+    noise_distribution: DynamicDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    Gen: ByteRandomGenerator,
+{
+    let polynomial_size = glwe_secret_key.polynomial_size();
+    let glwe_dimension = glwe_secret_key.glwe_dimension().0;
+
+    encrypt_glwe_ciphertext(
+        glwe_secret_key,
+        glwe_public_key,
+        &PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0)),
+        noise_distribution,
+        generator,
+    );
+
+    let level_count = glwe_secret_key_glev.glwe_ciphertext_count().0 / glwe_dimension;
+    let key_polynomials = glwe_secret_key.as_polynomial_list();
+    let mut glev_iter = glwe_secret_key_glev.iter_mut();
+    for level in 1..=level_count {
+        let factor = (Scalar::ONE << (Scalar::BITS - decomp_base_log.0 * level)).wrapping_neg();
+        for key_polynomial in key_polynomials.iter() {
+            let plaintext_list = PlaintextList::from_container(
+                key_polynomial
+                    .as_ref()
+                    .iter()
+                    .map(|coefficient| coefficient.wrapping_mul(factor))
+                    .collect::<Vec<_>>(),
+            );
+            encrypt_glwe_ciphertext(
+                glwe_secret_key,
+                &mut glev_iter.next().unwrap(),
+                &plaintext_list,
+                noise_distribution,
+                generator,
+            );
+        }
+    }
+}
+
+/// Encrypt each bit of `key_bits` in a GGSW ciphertext of `ggsw_list`, under the joint GLWE secret
+/// key of `glwe_public_key` and `glwe_secret_key_glev`.
+fn encrypt_key_bits_with_public_key<Scalar>(
+    key_bits: &[Scalar],
+    glwe_public_key: &GlweCiphertextOwned<Scalar>,
+    glwe_secret_key_glev: &GlweCiphertextListOwned<Scalar>,
+    ggsw_list: &mut GgswCiphertextListOwned<Scalar>,
+    noise_distribution: DynamicDistribution,
+    engine: &mut ShortintEngine,
+) where
+    Scalar: UnsignedTorus,
+{
+    let glwe_dimension = ggsw_list.glwe_size().to_glwe_dimension().0;
+    let base_log = ggsw_list.decomposition_base_log().0;
+
+    for (mut ggsw, key_bit) in ggsw_list.iter_mut().zip(key_bits) {
+        for (level_index, mut level_matrix) in ggsw.iter_mut().enumerate() {
+            let decomp_level = level_index + 1;
+            let level_factor = Scalar::ONE << (Scalar::BITS - base_log * decomp_level);
+            let glev = glwe_secret_key_glev
+                .get_sub(level_index * glwe_dimension..(level_index + 1) * glwe_dimension);
+
+            for (row_index, mut row) in level_matrix.as_mut_glwe_list().iter_mut().enumerate() {
+                let constant = if row_index < glwe_dimension {
+                    Scalar::ZERO
+                } else {
+                    key_bit.wrapping_mul(level_factor)
+                };
+
+                encrypt_glwe_constant_with_public_key(
+                    glwe_public_key,
+                    &mut row,
+                    constant,
+                    noise_distribution,
+                    &mut engine.secret_generator,
+                    &mut engine.encryption_generator,
+                );
+
+                if row_index < glwe_dimension {
+                    slice_wrapping_add_scalar_mul_assign(
+                        row.as_mut(),
+                        glev.get(row_index).as_ref(),
+                        *key_bit,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Encrypt a constant polynomial under the GLWE secret key of `glwe_public_key`, which is an
+/// encryption of zero.
+///
+/// A random binary polynomial $u$ is sampled and each polynomial of the output is the product of
+/// $u$ with the corresponding polynomial of the public key, with fresh noise added.
+fn encrypt_glwe_constant_with_public_key<Scalar, OutputCont, SecretGen, EncryptionGen>(
+    glwe_public_key: &GlweCiphertextOwned<Scalar>,
+    output: &mut GlweCiphertext<OutputCont>,
+    constant: Scalar,
+    noise_distribution: DynamicDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) where
+    Scalar: UnsignedTorus,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+{
+    let mut random_polynomial = Polynomial::new(Scalar::ZERO, glwe_public_key.polynomial_size());
+    secret_generator.fill_slice_with_random_uniform_binary(random_polynomial.as_mut());
+
+    output.as_mut().fill(Scalar::ZERO);
+    for (mut output_polynomial, public_key_polynomial) in output
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(glwe_public_key.as_polynomial_list().iter())
+    {
+        polynomial_wrapping_add_mul_assign(
+            &mut output_polynomial,
+            &public_key_polynomial,
+            &random_polynomial,
+        );
+    }
+
+    encryption_generator
+        .unsigned_torus_slice_wrapping_add_random_noise_assign(output.as_mut(), noise_distribution);
+
+    let mut body = output.get_mut_body();
+    body.as_mut()[0] = body.as_ref()[0].wrapping_add(constant);
+}
+
+/// Add the bodies of `other` to the bodies of `output`, both being lists of ciphertexts of size
+/// `ciphertext_size` ending with a body of size `body_size`.
+///
+/// # Panics
+///
+/// Panics if the masks of the ciphertexts differ, which happens if the shares were not generated
+/// from the same common seed.
+fn sum_bodies<Scalar: UnsignedInteger>(
+    output: &mut [Scalar],
+    other: &[Scalar],
+    ciphertext_size: usize,
+    body_size: usize,
+) {
+    assert_eq!(output.len(), other.len(), "Mismatched key share sizes");
+
+    let mask_size = ciphertext_size - body_size;
+    for (output_ct, other_ct) in output
+        .chunks_exact_mut(ciphertext_size)
+        .zip(other.chunks_exact(ciphertext_size))
+    {
+        let (output_mask, output_body) = output_ct.split_at_mut(mask_size);
+        let (other_mask, other_body) = other_ct.split_at(mask_size);
+
+        assert_eq!(
+            output_mask, other_mask,
+            "Key shares must be generated from the same common seed"
+        );
+        slice_wrapping_add_assign(output_body, other_body);
+    }
+}
+
+/// Sum the contributions of all the parties to the first round of the key generation.
+///
+/// See [PartySecretKey::new] for usage.
+///
+/// # Panics
+///
+/// Panics if `shares` does not contain the share of each party exactly once, in order, or if the
+/// shares were not generated from the same common seed.
+pub fn aggregate_public_key_shares(shares: &[PublicKeyShare]) -> AggregatedPublicKey {
+    let (first, others) = shares
+        .split_first()
+        .expect("At least one public key share is needed");
+
+    assert!(
+        shares.len() == first.party_count
+            && shares.iter().enumerate().all(|(index, share)| {
+                share.party == PartyId(index)
+                    && share.party_count == first.party_count
+                    && share.parameters == first.parameters
+                    && share.noise_squashing_parameters == first.noise_squashing_parameters
+            }),
+        "There must be exactly one share for each party, in order, with the same parameters"
+    );
+
+    let mut compact_public_key = first.compact_public_key.clone();
+    let mut key_switching_key = first.key_switching_key.clone();
+    let mut glwe_public_key = first.glwe_public_key.clone();
+    let mut glwe_secret_key_glev = first.glwe_secret_key_glev.clone();
+    let mut noise_squashing_glwe_public_key = first.noise_squashing_glwe_public_key.clone();
+    let mut noise_squashing_glwe_secret_key_glev =
+        first.noise_squashing_glwe_secret_key_glev.clone();
+
+    let compact_public_key_size = compact_public_key.as_ref().len();
+    let lwe_size = key_switching_key.output_lwe_size().0;
+    let glwe_ciphertext_size = glwe_public_key.as_ref().len();
+    let polynomial_size = glwe_public_key.polynomial_size().0;
+    let noise_squashing_glwe_ciphertext_size = noise_squashing_glwe_public_key.as_ref().len();
+    let noise_squashing_polynomial_size = noise_squashing_glwe_public_key.polynomial_size().0;
+
+    for share in others {
+        sum_bodies(
+            compact_public_key.as_mut(),
+            share.compact_public_key.as_ref(),
+            compact_public_key_size,
+            compact_public_key_size / 2,
+        );
+        sum_bodies(
+            key_switching_key.as_mut(),
+            share.key_switching_key.as_ref(),
+            lwe_size,
+            1,
+        );
+        sum_bodies(
+            glwe_public_key.as_mut(),
+            share.glwe_public_key.as_ref(),
+            glwe_ciphertext_size,
+            polynomial_size,
+        );
+        sum_bodies(
+            glwe_secret_key_glev.as_mut(),
+            share.glwe_secret_key_glev.as_ref(),
+            glwe_ciphertext_size,
+            polynomial_size,
+        );
+        sum_bodies(
+            noise_squashing_glwe_public_key.as_mut(),
+            share.noise_squashing_glwe_public_key.as_ref(),
+            noise_squashing_glwe_ciphertext_size,
+            noise_squashing_polynomial_size,
+        );
+        sum_bodies(
+            noise_squashing_glwe_secret_key_glev.as_mut(),
+            share.noise_squashing_glwe_secret_key_glev.as_ref(),
+            noise_squashing_glwe_ciphertext_size,
+            noise_squashing_polynomial_size,
+        );
+    }
+
+    AggregatedPublicKey {
+        party_count: first.party_count,
+        parameters: first.parameters,
+        noise_squashing_parameters: first.noise_squashing_parameters,
+        compact_public_key,
+        key_switching_key,
+        glwe_public_key,
+        glwe_secret_key_glev,
+        noise_squashing_glwe_public_key,
+        noise_squashing_glwe_secret_key_glev,
+    }
+}
+
+impl AggregatedPublicKey {
+    /// The parameters of the keys under the joint secret keys, whose LWE dimension is
+    /// `party_count` times the one of the parameters of the parties.
+    pub fn joint_parameters(&self) -> ClassicPBSParameters {
+        ClassicPBSParameters {
+            lwe_dimension: LweDimension(self.parameters.lwe_dimension.0 * self.party_count),
+            ..self.parameters
+        }
+    }
+
+    /// Return the [CompactPublicKey] under the joint secret key.
+    ///
+    /// See [PartySecretKey::new] for usage.
+    pub fn compact_public_key(&self) -> CompactPublicKey {
+        CompactPublicKey {
+            key: self.compact_public_key.clone(),
+            parameters: self.joint_parameters().into(),
+            pbs_order: PBSOrder::KeyswitchBootstrap,
+        }
+    }
+
+    /// Put together the contributions of all the parties to the second round of the key
+    /// generation in a [ServerKey] under the joint secret keys.
+    ///
+    /// See [PartySecretKey::new] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shares` does not contain the share of each party exactly once, in order.
+    pub fn aggregate_server_key(&self, shares: &[BootstrappingKeyShare]) -> ServerKey {
+        assert!(
+            shares.len() == self.party_count
+                && shares
+                    .iter()
+                    .enumerate()
+                    .all(|(index, share)| share.party == PartyId(index)),
+            "There must be exactly one share for each party, in order"
+        );
+
+        let params = self.joint_parameters();
+
+        // The GGSWs of the parties are in the order of the concatenation of their LWE keys
+        let mut bootstrap_key = LweBootstrapKeyOwned::new(
+            0u64,
+            params.glwe_dimension.to_glwe_size(),
+            params.polynomial_size,
+            params.pbs_base_log,
+            params.pbs_level,
+            params.lwe_dimension,
+            params.ciphertext_modulus,
+        );
+        for (output, share) in bootstrap_key
+            .as_mut()
+            .chunks_exact_mut(bootstrap_key_share_size(&self.parameters))
+            .zip(shares.iter())
+        {
+            output.copy_from_slice(share.ggsw_list.as_ref());
+        }
+
+        let mut fourier_bsk = FourierLweBootstrapKey::new(
+            bootstrap_key.input_lwe_dimension(),
+            bootstrap_key.glwe_size(),
+            bootstrap_key.polynomial_size(),
+            bootstrap_key.decomposition_base_log(),
+            bootstrap_key.decomposition_level_count(),
+        );
+        par_convert_standard_lwe_bootstrap_key_to_fourier(&bootstrap_key, &mut fourier_bsk);
+
+        ServerKey {
+            key_switching_key: self.key_switching_key.clone(),
+            bootstrapping_key: ShortintBootstrappingKey::Classic(fourier_bsk),
+            message_modulus: params.message_modulus,
+            carry_modulus: params.carry_modulus,
+            max_degree: MaxDegree(params.message_modulus.0 * params.carry_modulus.0 - 1),
+            max_noise_level: MaxNoiseLevel::from_msg_carry_modulus(
+                params.message_modulus,
+                params.carry_modulus,
+            ),
+            ciphertext_modulus: params.ciphertext_modulus,
+            pbs_order: PBSOrder::KeyswitchBootstrap,
+        }
+    }
+
+    /// Put together the contributions of all the parties to the second round of the key
+    /// generation in a [NoiseSquashingKey] under the joint secret keys, which squashes the noise
+    /// of the ciphertexts of the [ServerKey] before a threshold decryption.
+    ///
+    /// See [PartySecretKey::new] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shares` does not contain the share of each party exactly once, in order.
+    pub fn aggregate_noise_squashing_key(
+        &self,
+        shares: &[BootstrappingKeyShare],
+    ) -> NoiseSquashingKey {
+        assert!(
+            shares.len() == self.party_count
+                && shares
+                    .iter()
+                    .enumerate()
+                    .all(|(index, share)| share.party == PartyId(index)),
+            "There must be exactly one share for each party, in order"
+        );
+
+        let params = &self.noise_squashing_parameters;
+
+        // The GGSWs are converted one at a time, to avoid holding the whole standard key
+        let mut bootstrapping_key = Fourier128LweBootstrapKey::new(
+            self.joint_parameters().lwe_dimension,
+            params.glwe_dimension.to_glwe_size(),
+            params.polynomial_size,
+            params.decomp_base_log,
+            params.decomp_level_count,
+        );
+        let fft = Fft128::new(params.polynomial_size);
+        for (mut fourier_ggsw, ggsw) in bootstrapping_key.as_mut_view().into_ggsw_iter().zip(
+            shares
+                .iter()
+                .flat_map(|share| share.noise_squashing_ggsw_list.iter()),
+        ) {
+            fourier_ggsw.fill_with_forward_fourier(&ggsw, fft.as_view());
+        }
+
+        NoiseSquashingKey {
+            bootstrapping_key,
+            params: self.noise_squashing_parameters,
+        }
+    }
+}
+
+/// The number of coefficients of the GGSWs encrypting the LWE secret key of one party.
+fn bootstrap_key_share_size(parameters: &ClassicPBSParameters) -> usize {
+    parameters.lwe_dimension.0
+        * ggsw_ciphertext_size(
+            parameters.glwe_dimension.to_glwe_size(),
+            parameters.polynomial_size,
+            parameters.pbs_level,
+        )
+}
@@ -0,0 +1,149 @@
+//! Noise model of the keys generated with the key aggregation protocol, built on the
+//! [noise model](crate::shortint::noise_model) of the keys generated by a single party.
+
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::LweDimension;
+use crate::shortint::ciphertext::MaxNoiseLevel;
+use crate::shortint::noise_model::{
+    decomposition_digit_variance, fft_variance, log2_decryption_failure_probability, log2_modulus,
+    noise_squashing_glwe_variance, noise_squashing_variance, noise_variance, rounding_variance,
+};
+use crate::shortint::parameters::{ClassicPBSParameters, NoiseSquashingParameters, PBSParameters};
+
+/// Return the base 2 logarithm of the probability that a PBS with the keys generated by
+/// `party_count` parties returns a wrong result, when applied on a linear combination of outputs
+/// of previous PBS or of encryptions with the joint compact public key.
+///
+/// See the [module documentation](super) for the noise growth of the joint keys. Compared to the
+/// [noise model](crate::shortint::noise_model) of the keys of a single party, this accounts for:
+///
+/// - the mean square $n(n + 1) / 4$ of the coefficients of the joint GLWE secret key of $n$
+///   parties, which multiplies the rounding errors of the decompositions;
+/// - the variance of the public material, which is the sum of the $n$ encryptions of the parties;
+/// - the GGSW ciphertexts of the bootstrapping key being encrypted with the GLWE public key;
+/// - the $n$ times larger joint LWE secret key.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::key_aggregation::log2_pbs_failure_probability;
+/// use tfhe::shortint::parameters::{
+///     PARAM_KEY_AGGREGATION_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+/// };
+///
+/// // The classic parameters are not designed for the noise of the joint keys
+/// assert!(log2_pbs_failure_probability(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 2) > -40.0);
+/// assert!(
+///     log2_pbs_failure_probability(PARAM_KEY_AGGREGATION_MESSAGE_2_CARRY_2_KS_PBS, 4) < -40.0
+/// );
+/// ```
+pub fn log2_pbs_failure_probability(parameters: ClassicPBSParameters, party_count: usize) -> f64 {
+    let params = PBSParameters::PBS(parameters);
+    let party_count = party_count as f64;
+    let log2_modulus = log2_modulus(&params);
+
+    let glwe_dimension = parameters.glwe_dimension.0 as f64;
+    let polynomial_size = parameters.polynomial_size.0 as f64;
+    let big_lwe_dimension = glwe_dimension * polynomial_size;
+    let joint_lwe_dimension = party_count * parameters.lwe_dimension.0 as f64;
+
+    let lwe_variance = noise_variance(&params, parameters.lwe_noise_distribution).0;
+    let glwe_variance = noise_variance(&params, parameters.glwe_noise_distribution).0;
+
+    // Each coefficient of the joint GLWE secret key is the sum of the binary coefficients of the
+    // parties
+    let joint_glwe_key_mean_square = party_count * (party_count + 1.0) / 4.0;
+
+    // A GGSW row is the product of a binary polynomial with the GLWE public key, whose noise is
+    // the sum of the noises of the parties, plus fresh noises multiplied by the joint GLWE secret
+    // key. The GLev encryptions of the joint key, whose noise is also the sum of the noises of
+    // the parties, are added to the mask rows of the bits set to 1.
+    let ggsw_row_variance = glwe_variance
+        * (1.0
+            + polynomial_size * party_count / 2.0
+            + big_lwe_dimension * joint_glwe_key_mean_square
+            + party_count / 2.0);
+
+    let pbs_base_log = parameters.pbs_base_log.0;
+    let pbs_level = parameters.pbs_level.0;
+    let external_product_variance = pbs_level as f64
+        * (glwe_dimension + 1.0)
+        * polynomial_size
+        * decomposition_digit_variance(pbs_base_log)
+        * ggsw_row_variance
+        + (1.0 + big_lwe_dimension * joint_glwe_key_mean_square)
+            * rounding_variance((pbs_base_log * pbs_level) as f64, log2_modulus)
+        + fft_variance(
+            glwe_dimension,
+            polynomial_size,
+            pbs_base_log,
+            pbs_level,
+            log2_modulus,
+        );
+    let pbs_variance = joint_lwe_dimension * external_product_variance;
+
+    // The key switching key is the sum of the key switching keys of the parties
+    let ks_base_log = parameters.ks_base_log.0;
+    let ks_level = parameters.ks_level.0;
+    let keyswitch_variance = big_lwe_dimension
+        * ks_level as f64
+        * decomposition_digit_variance(ks_base_log)
+        * party_count
+        * lwe_variance
+        + big_lwe_dimension
+            * joint_glwe_key_mean_square
+            * rounding_variance((ks_base_log * ks_level) as f64, log2_modulus);
+
+    let log2_two_n = (2 * parameters.polynomial_size.0).ilog2() as f64;
+    let modulus_switch_variance =
+        (1.0 + joint_lwe_dimension / 2.0) * rounding_variance(log2_two_n, log2_modulus);
+
+    // An encryption with the compact public key multiplies the public key by a binary polynomial
+    // and adds fresh noises
+    let compact_public_key_encryption_variance = glwe_variance
+        * (big_lwe_dimension * party_count / 2.0
+            + 1.0
+            + big_lwe_dimension * joint_glwe_key_mean_square);
+
+    let max_noise_level =
+        MaxNoiseLevel::from_msg_carry_modulus(parameters.message_modulus, parameters.carry_modulus)
+            .get() as f64;
+    let input_variance = max_noise_level
+        * max_noise_level
+        * pbs_variance.max(compact_public_key_encryption_variance)
+        + keyswitch_variance
+        + modulus_switch_variance;
+
+    log2_decryption_failure_probability(params, Variance(input_variance))
+}
+
+/// Return the variance of the noise of a ciphertext squashed by the joint noise squashing key of
+/// `party_count` parties.
+///
+/// The GGSW ciphertexts of the noise squashing key are encrypted with the GLWE public key of the
+/// noise squashing parameters in the same way as the ones of the bootstrapping key, see
+/// [log2_pbs_failure_probability].
+pub(crate) fn squashed_noise_variance(
+    parameters: ClassicPBSParameters,
+    noise_squashing_parameters: NoiseSquashingParameters,
+    party_count: usize,
+) -> Variance {
+    let party_count_f64 = party_count as f64;
+    let glwe_dimension = noise_squashing_parameters.glwe_dimension.0 as f64;
+    let polynomial_size = noise_squashing_parameters.polynomial_size.0 as f64;
+
+    let glwe_variance = noise_squashing_glwe_variance(&noise_squashing_parameters);
+    let joint_glwe_key_mean_square = party_count_f64 * (party_count_f64 + 1.0) / 4.0;
+    let ggsw_row_variance = glwe_variance
+        * (1.0
+            + polynomial_size * party_count_f64 / 2.0
+            + glwe_dimension * polynomial_size * joint_glwe_key_mean_square
+            + party_count_f64 / 2.0);
+
+    noise_squashing_variance(
+        &noise_squashing_parameters,
+        LweDimension(party_count * parameters.lwe_dimension.0),
+        ggsw_row_variance,
+        joint_glwe_key_mean_square,
+    )
+}
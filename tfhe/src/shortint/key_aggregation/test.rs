@@ -0,0 +1,192 @@
+use super::{aggregate_public_key_shares, AggregatedPublicKey, PartySecretKey};
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_add_assign;
+use crate::core_crypto::commons::math::random::Seed;
+use crate::core_crypto::entities::{GlweSecretKey, LweSecretKey};
+use crate::shortint::parameters::{
+    ClassicPBSParameters, SecretKeyDistribution, NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+    PARAM_KEY_AGGREGATION_MESSAGE_1_CARRY_1_KS_PBS, PARAM_KEY_AGGREGATION_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+};
+use crate::shortint::threshold::{
+    combine_partial_decryptions, PartyId, ThresholdParameters, ThresholdSecretKeyShare,
+};
+use crate::shortint::ClientKey;
+
+fn new_parties(params: ClassicPBSParameters, party_count: usize) -> Vec<PartySecretKey> {
+    (0..party_count)
+        .map(|party| {
+            PartySecretKey::new(
+                PartyId(party),
+                party_count,
+                params,
+                NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+            )
+        })
+        .collect()
+}
+
+/// The client key of the joint secret keys, which no party holds outside of the tests.
+fn joint_client_key(parties: &[PartySecretKey], public_key: &AggregatedPublicKey) -> ClientKey {
+    let mut glwe_secret_key = parties[0].glwe_secret_key.clone().into_container();
+    for party in &parties[1..] {
+        slice_wrapping_add_assign(&mut glwe_secret_key, party.glwe_secret_key.as_ref());
+    }
+    let glwe_secret_key = GlweSecretKey::from_container(
+        glwe_secret_key,
+        parties[0].glwe_secret_key.polynomial_size(),
+    );
+
+    let small_lwe_secret_key = LweSecretKey::from_container(
+        parties
+            .iter()
+            .flat_map(|party| party.lwe_secret_key.as_ref().iter().copied())
+            .collect::<Vec<_>>(),
+    );
+
+    ClientKey {
+        large_lwe_secret_key: glwe_secret_key.clone().into_lwe_secret_key(),
+        glwe_secret_key,
+        small_lwe_secret_key,
+        parameters: public_key.joint_parameters().into(),
+    }
+}
+
+fn key_aggregation(params: ClassicPBSParameters, party_count: usize) {
+    let parties = new_parties(params, party_count);
+
+    let public_key_shares: Vec<_> = parties
+        .iter()
+        .map(|party| party.generate_public_key_share(Seed(0)))
+        .collect();
+    let public_key = aggregate_public_key_shares(&public_key_shares);
+
+    let bootstrapping_key_shares: Vec<_> = parties
+        .iter()
+        .map(|party| party.generate_bootstrapping_key_share(&public_key))
+        .collect();
+    let sks = public_key.aggregate_server_key(&bootstrapping_key_shares);
+    let cpk = public_key.compact_public_key();
+    let cks = joint_client_key(&parties, &public_key);
+
+    let modulus = params.message_modulus.0 as u64;
+
+    for msg in 0..modulus {
+        // Two PBS to check the noise after the bootstrapping
+        let ct = sks.unchecked_scalar_add(&cpk.encrypt(msg), 1);
+        let ct = sks.message_extract(&ct);
+        let ct = sks.unchecked_scalar_add(&ct, 1);
+        let ct = sks.message_extract(&ct);
+
+        assert_eq!(cks.decrypt(&ct), (msg + 2) % modulus);
+    }
+}
+
+#[test]
+fn test_key_aggregation_param_message_1_carry_1_four_parties() {
+    key_aggregation(PARAM_KEY_AGGREGATION_MESSAGE_1_CARRY_1_KS_PBS, 4);
+}
+
+#[test]
+fn test_key_aggregation_param_message_2_carry_2_two_parties() {
+    key_aggregation(PARAM_KEY_AGGREGATION_MESSAGE_2_CARRY_2_KS_PBS, 2);
+}
+
+#[test]
+fn test_key_aggregation_threshold_decryption() {
+    // The noise squashing key of more parties does not fit in the memory of the test runners
+    let params = PARAM_KEY_AGGREGATION_MESSAGE_1_CARRY_1_KS_PBS;
+    let party_count = 2;
+    let parties = new_parties(params, party_count);
+
+    let public_key_shares: Vec<_> = parties
+        .iter()
+        .map(|party| party.generate_public_key_share(Seed(0)))
+        .collect();
+    let public_key = aggregate_public_key_shares(&public_key_shares);
+
+    let bootstrapping_key_shares: Vec<_> = parties
+        .iter()
+        .map(|party| party.generate_bootstrapping_key_share(&public_key))
+        .collect();
+    let sks = public_key.aggregate_server_key(&bootstrapping_key_shares);
+    let noise_squashing_key = public_key.aggregate_noise_squashing_key(&bootstrapping_key_shares);
+    drop(bootstrapping_key_shares);
+    let cpk = public_key.compact_public_key();
+
+    let modulus = params.message_modulus.0 as u64;
+
+    for threshold in [party_count, 1] {
+        let threshold_params = ThresholdParameters::new(party_count, threshold);
+        let contributions: Vec<_> = parties
+            .iter()
+            .map(|party| party.generate_threshold_key_contributions(threshold_params))
+            .collect();
+        let key_shares: Vec<_> = (0..party_count)
+            .map(|party| {
+                let received: Vec<_> = contributions
+                    .iter()
+                    .map(|contributions| contributions[party].clone())
+                    .collect();
+                ThresholdSecretKeyShare::from_contributions(&received)
+            })
+            .collect();
+
+        for msg in 0..modulus {
+            let ct = sks.unchecked_scalar_add(&cpk.encrypt(msg), 1);
+            let ct = sks.message_extract(&ct);
+            let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &sks);
+            let expected = (msg + 1) % modulus;
+
+            for participants in [
+                vec![PartyId(0)],
+                vec![PartyId(1)],
+                vec![PartyId(0), PartyId(1)],
+            ] {
+                if participants.len() < threshold {
+                    continue;
+                }
+                let shares: Vec<_> = participants
+                    .iter()
+                    .map(|party| key_shares[party.0].partial_decrypt(&squashed, &participants))
+                    .collect();
+
+                assert_eq!(combine_partial_decryptions(&squashed, &shares), expected);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "The parameters do not support 5 parties")]
+fn test_key_aggregation_too_many_parties() {
+    let _ = new_parties(PARAM_KEY_AGGREGATION_MESSAGE_2_CARRY_2_KS_PBS, 5);
+}
+
+#[test]
+#[should_panic(expected = "The parameters do not support 2 parties")]
+fn test_key_aggregation_classic_parameters() {
+    let _ = new_parties(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 2);
+}
+
+#[test]
+#[should_panic(expected = "Key shares must be generated from the same common seed")]
+fn test_key_aggregation_different_seeds() {
+    let party_count = 2;
+    let public_key_shares: Vec<_> =
+        new_parties(PARAM_KEY_AGGREGATION_MESSAGE_1_CARRY_1_KS_PBS, party_count)
+            .iter()
+            .enumerate()
+            .map(|(party, key)| key.generate_public_key_share(Seed(party as u128)))
+            .collect();
+
+    let _ = aggregate_public_key_shares(&public_key_shares);
+}
+
+#[test]
+#[should_panic(expected = "Only binary secret keys are supported")]
+fn test_key_aggregation_ternary_key() {
+    let mut params = PARAM_KEY_AGGREGATION_MESSAGE_1_CARRY_1_KS_PBS;
+    params.secret_key_distribution = SecretKeyDistribution::Ternary;
+
+    let _ = new_parties(params, 2);
+}
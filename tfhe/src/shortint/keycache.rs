@@ -54,6 +54,12 @@ named_params_impl!( ShortintParameterSet =>
     PARAM_MESSAGE_2_CARRY_2_PBS_KS,
     PARAM_MESSAGE_3_CARRY_3_PBS_KS,
     PARAM_MESSAGE_4_CARRY_4_PBS_KS,
+    // Ternary secret keys
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS_TERNARY,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TERNARY,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_TERNARY,
+    // Bounded noise
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM,
     // MultiBit Group 2
     PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_2_KS_PBS,
     PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
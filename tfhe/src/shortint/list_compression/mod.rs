@@ -0,0 +1,472 @@
+//! This module defines the compression of lists of shortint ciphertexts.
+//!
+//! - [CompressionKey] packs many ciphertexts in a few GLWEs with a packing keyswitch, those GLWEs
+//!   are then modulus switched to a small modulus, yielding a [CompressedCiphertextList] suited for
+//!   storage and transport.
+//! - [DecompressionKey] brings a ciphertext of a [CompressedCiphertextList] back to a regular
+//!   [Ciphertext] using a PBS.
+
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::{
+    CiphertextModulusLog, LweCiphertextCount, MonomialDegree,
+};
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::engine::{fill_accumulator_for_moduli, ShortintEngine};
+use crate::shortint::parameters::{
+    CarryModulus, CompressedCiphertextListConformanceParams, CompressionParameters, MessageModulus,
+};
+use crate::shortint::{Ciphertext, CiphertextModulus, ClientKey, PBSOrder};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod test;
+
+/// The secret key of the GLWEs ciphertexts are packed into, it must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionPrivateKeys {
+    pub(crate) post_packing_ks_key: GlweSecretKeyOwned<u64>,
+    pub params: CompressionParameters,
+}
+
+/// A key to compress ciphertexts into a [CompressedCiphertextList].
+///
+/// It is generated by the client and is meant to be published.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionKey {
+    pub(crate) packing_key_switching_key: LwePackingKeyswitchKeyOwned<u64>,
+    pub(crate) lwe_per_glwe: LweCiphertextCount,
+    pub(crate) storage_log_modulus: CiphertextModulusLog,
+}
+
+/// A key to get ciphertexts back from a [CompressedCiphertextList].
+///
+/// It is generated by the client and is meant to be published.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecompressionKey {
+    pub(crate) blind_rotate_key: FourierLweBootstrapKeyOwned,
+    pub(crate) lwe_per_glwe: LweCiphertextCount,
+}
+
+/// A list of shortint ciphertexts packed in modulus switched GLWEs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedCiphertextList {
+    pub(crate) modulus_switched_glwe_ciphertext_list:
+        Vec<CompressedModulusSwitchedGlweCiphertext<u64>>,
+    pub(crate) ciphertext_modulus: CiphertextModulus,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+    pub(crate) pbs_order: PBSOrder,
+    pub(crate) lwe_per_glwe: LweCiphertextCount,
+    pub(crate) degrees: Vec<Degree>,
+}
+
+impl crate::named::Named for CompressedCiphertextList {
+    const NAME: &'static str = "shortint::CompressedCiphertextList";
+}
+
+impl ParameterSetConformant for CompressedCiphertextList {
+    type ParameterSet = CompressedCiphertextListConformanceParams;
+
+    fn is_conformant(&self, params: &CompressedCiphertextListConformanceParams) -> bool {
+        self.is_well_formed()
+            && self
+                .modulus_switched_glwe_ciphertext_list
+                .iter()
+                .all(|glwe| glwe.is_conformant(&params.ct_params))
+            && params.ciphertext_count_constraint.is_valid(self.len())
+            && self.ciphertext_modulus == params.ct_params.uncompressed_ciphertext_modulus
+            && self.message_modulus == params.message_modulus
+            && self.carry_modulus == params.carry_modulus
+            && self.pbs_order == params.pbs_order
+            && self.lwe_per_glwe == params.lwe_per_glwe
+            && self
+                .degrees
+                .iter()
+                .all(|degree| degree.0 <= params.max_degree.0)
+    }
+}
+
+impl CompressedCiphertextList {
+    /// Return the number of ciphertexts in the list.
+    pub fn len(&self) -> usize {
+        self.degrees.len()
+    }
+
+    /// Return `true` if the list does not contain any ciphertext.
+    pub fn is_empty(&self) -> bool {
+        self.degrees.is_empty()
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+
+    /// Return `true` if the GLWEs of the list can hold its ciphertexts, i.e. if every GLWE but the
+    /// last one holds `lwe_per_glwe` ciphertexts and the last one holds the remainder.
+    fn is_well_formed(&self) -> bool {
+        if self.lwe_per_glwe.0 == 0
+            || self.message_modulus.0 == 0
+            || self.carry_modulus.0 == 0
+            || self.pbs_order != PBSOrder::KeyswitchBootstrap
+        {
+            return false;
+        }
+
+        let glwe_count = self.modulus_switched_glwe_ciphertext_list.len();
+        let expected_glwe_count =
+            self.len() / self.lwe_per_glwe.0 + usize::from(self.len() % self.lwe_per_glwe.0 != 0);
+
+        glwe_count == expected_glwe_count
+            && self
+                .modulus_switched_glwe_ciphertext_list
+                .iter()
+                .enumerate()
+                .all(|(glwe_index, glwe)| {
+                    let expected_bodies_count = if glwe_index + 1 == glwe_count {
+                        self.len() - glwe_index * self.lwe_per_glwe.0
+                    } else {
+                        self.lwe_per_glwe.0
+                    };
+
+                    glwe.is_well_formed()
+                        && glwe.bodies_count().0 == expected_bodies_count
+                        && glwe.uncompressed_ciphertext_modulus() == self.ciphertext_modulus
+                })
+    }
+}
+
+impl ClientKey {
+    /// Generate the secret key of the GLWEs ciphertexts are packed into when compressing them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+    /// ```
+    pub fn new_compression_private_key(
+        &self,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        assert!(
+            params.lwe_per_glwe.0 > 0
+                && params.lwe_per_glwe.0 <= params.packing_ks_polynomial_size.0,
+            "lwe_per_glwe must be in [1, {}] to pack LWEs in a GLWE of polynomial size {}, got {}",
+            params.packing_ks_polynomial_size.0,
+            params.packing_ks_polynomial_size.0,
+            params.lwe_per_glwe.0
+        );
+
+        let post_packing_ks_key = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_binary_glwe_secret_key(
+                params.packing_ks_glwe_dimension,
+                params.packing_ks_polynomial_size,
+                &mut engine.secret_generator,
+            )
+        });
+
+        CompressionPrivateKeys {
+            post_packing_ks_key,
+            params,
+        }
+    }
+
+    /// Generate the public keys used to compress and decompress ciphertexts encrypted under this
+    /// client key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let (compression_key, decompression_key) =
+    ///     cks.new_compression_decompression_keys(&private_compression_key);
+    ///
+    /// let cts: Vec<_> = (0..4).map(|msg| cks.encrypt(msg)).collect();
+    ///
+    /// let compressed = compression_key.compress_ciphertexts_into_list(&cts);
+    ///
+    /// for (index, msg) in (0..4).enumerate() {
+    ///     let ct = decompression_key.unpack(&compressed, index).unwrap();
+    ///     assert_eq!(cks.decrypt(&ct), msg);
+    /// }
+    /// ```
+    pub fn new_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        let params = &private_compression_key.params;
+        let pbs_params = self
+            .parameters
+            .pbs_parameters()
+            .expect("Compression keys require PBS parameters");
+
+        assert_eq!(
+            pbs_params.encryption_key_choice(),
+            crate::shortint::EncryptionKeyChoice::Big,
+            "Compression is only supported for ciphertexts encrypted under the big key"
+        );
+
+        let ciphertext_modulus = self.parameters.ciphertext_modulus();
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let packing_key_switching_key = allocate_and_generate_new_lwe_packing_keyswitch_key(
+                &self.large_lwe_secret_key,
+                &private_compression_key.post_packing_ks_key,
+                params.packing_ks_base_log,
+                params.packing_ks_level,
+                params.packing_ks_key_noise_distribution,
+                ciphertext_modulus,
+                &mut engine.encryption_generator,
+            );
+
+            let bootstrap_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+                &private_compression_key
+                    .post_packing_ks_key
+                    .as_lwe_secret_key(),
+                &self.glwe_secret_key,
+                params.br_base_log,
+                params.br_level,
+                self.parameters.glwe_noise_distribution(),
+                ciphertext_modulus,
+                &mut engine.encryption_generator,
+            );
+
+            let mut blind_rotate_key = FourierLweBootstrapKey::new(
+                bootstrap_key.input_lwe_dimension(),
+                bootstrap_key.glwe_size(),
+                bootstrap_key.polynomial_size(),
+                bootstrap_key.decomposition_base_log(),
+                bootstrap_key.decomposition_level_count(),
+            );
+
+            par_convert_standard_lwe_bootstrap_key_to_fourier(
+                &bootstrap_key,
+                &mut blind_rotate_key,
+            );
+
+            (
+                CompressionKey {
+                    packing_key_switching_key,
+                    lwe_per_glwe: params.lwe_per_glwe,
+                    storage_log_modulus: params.storage_log_modulus,
+                },
+                DecompressionKey {
+                    blind_rotate_key,
+                    lwe_per_glwe: params.lwe_per_glwe,
+                },
+            )
+        })
+    }
+}
+
+impl CompressionKey {
+    /// Compress ciphertexts into a [CompressedCiphertextList].
+    ///
+    /// The input ciphertexts must be encrypted under the big key
+    /// ([PBSOrder::KeyswitchBootstrap]), share the same message and carry moduli and have at most
+    /// a nominal noise level, e.g. be freshly encrypted or the output of a PBS.
+    ///
+    /// See [ClientKey::new_compression_decompression_keys] for usage.
+    pub fn compress_ciphertexts_into_list(
+        &self,
+        ciphertexts: &[Ciphertext],
+    ) -> CompressedCiphertextList {
+        assert!(
+            !ciphertexts.is_empty(),
+            "Cannot compress an empty list of ciphertexts"
+        );
+
+        let first_ct = &ciphertexts[0];
+        let message_modulus = first_ct.message_modulus;
+        let carry_modulus = first_ct.carry_modulus;
+        let ciphertext_modulus = first_ct.ct.ciphertext_modulus();
+        let lwe_size = first_ct.ct.lwe_size();
+
+        for ct in ciphertexts {
+            assert_eq!(
+                ct.pbs_order,
+                PBSOrder::KeyswitchBootstrap,
+                "Only ciphertexts encrypted under the big key can be compressed"
+            );
+            assert_eq!(ct.message_modulus, message_modulus);
+            assert_eq!(ct.carry_modulus, carry_modulus);
+            assert_eq!(ct.ct.lwe_size(), lwe_size);
+            assert!(
+                ct.noise_level().get() <= NoiseLevel::NOMINAL.get(),
+                "Ciphertexts must have at most a nominal noise level to be compressed, \
+                apply a PBS (e.g. message_extract) before compressing them"
+            );
+        }
+
+        let polynomial_size = self.packing_key_switching_key.output_polynomial_size();
+        let glwe_size = self
+            .packing_key_switching_key
+            .output_key_glwe_dimension()
+            .to_glwe_size();
+
+        let modulus_switched_glwe_ciphertext_list = ciphertexts
+            .par_chunks(self.lwe_per_glwe.0)
+            .map(|chunk| {
+                let lwe_list = LweCiphertextList::from_container(
+                    chunk
+                        .iter()
+                        .flat_map(|ct| ct.ct.as_ref().iter().copied())
+                        .collect::<Vec<_>>(),
+                    lwe_size,
+                    ciphertext_modulus,
+                );
+
+                let mut packed_glwe =
+                    GlweCiphertext::new(0, glwe_size, polynomial_size, ciphertext_modulus);
+
+                keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext(
+                    &self.packing_key_switching_key,
+                    &lwe_list,
+                    &mut packed_glwe,
+                );
+
+                CompressedModulusSwitchedGlweCiphertext::compress(
+                    &packed_glwe,
+                    self.storage_log_modulus,
+                    LweCiphertextCount(chunk.len()),
+                )
+            })
+            .collect();
+
+        CompressedCiphertextList {
+            modulus_switched_glwe_ciphertext_list,
+            ciphertext_modulus,
+            message_modulus,
+            carry_modulus,
+            pbs_order: PBSOrder::KeyswitchBootstrap,
+            lwe_per_glwe: self.lwe_per_glwe,
+            degrees: ciphertexts.iter().map(|ct| ct.degree).collect(),
+        }
+    }
+}
+
+impl DecompressionKey {
+    /// Get back the ciphertext at `index` in a [CompressedCiphertextList], returns `None` if
+    /// `index` is out of bounds, or if the list is malformed or was not compressed with the
+    /// [CompressionKey] matching this key.
+    ///
+    /// The returned ciphertext is the output of a PBS and thus has a nominal noise level.
+    ///
+    /// Lists coming from an untrusted source should be checked with
+    /// [ParameterSetConformant::is_conformant] beforehand, as this only checks that the list can
+    /// be decompressed without panicking.
+    ///
+    /// See [ClientKey::new_compression_decompression_keys] for usage.
+    pub fn unpack(&self, packed: &CompressedCiphertextList, index: usize) -> Option<Ciphertext> {
+        if index >= packed.len()
+            || packed.lwe_per_glwe != self.lwe_per_glwe
+            || !packed.is_well_formed()
+        {
+            return None;
+        }
+
+        let glwe_index = index / self.lwe_per_glwe.0;
+        let coefficient_index = index % self.lwe_per_glwe.0;
+
+        let compressed_glwe = &packed.modulus_switched_glwe_ciphertext_list[glwe_index];
+
+        if compressed_glwe
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(compressed_glwe.polynomial_size())
+            != self.blind_rotate_key.input_lwe_dimension()
+        {
+            return None;
+        }
+
+        let packed_glwe = compressed_glwe.extract();
+
+        let mut intermediate_lwe = LweCiphertext::new(
+            0,
+            self.blind_rotate_key.input_lwe_dimension().to_lwe_size(),
+            packed.ciphertext_modulus,
+        );
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &packed_glwe,
+            &mut intermediate_lwe,
+            MonomialDegree(coefficient_index),
+        );
+
+        // Identity lookup table over the whole message and carry space, bootstrapping back to the
+        // GLWE secret key of the client key also refreshes the noise
+        let mut accumulator = GlweCiphertext::new(
+            0,
+            self.blind_rotate_key.glwe_size(),
+            self.blind_rotate_key.polynomial_size(),
+            packed.ciphertext_modulus,
+        );
+        fill_accumulator_for_moduli(
+            &mut accumulator,
+            packed.message_modulus,
+            packed.carry_modulus,
+            |x| x,
+        );
+
+        let mut output_lwe = LweCiphertext::new(
+            0,
+            self.blind_rotate_key.output_lwe_dimension().to_lwe_size(),
+            packed.ciphertext_modulus,
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let fft = Fft::new(self.blind_rotate_key.polynomial_size());
+            let fft = fft.as_view();
+
+            let buffers = &mut engine.computation_buffers;
+            buffers.resize(
+                programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                    self.blind_rotate_key.glwe_size(),
+                    self.blind_rotate_key.polynomial_size(),
+                    fft,
+                )
+                .unwrap()
+                .unaligned_bytes_required(),
+            );
+
+            programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                &intermediate_lwe,
+                &mut output_lwe,
+                &accumulator,
+                &self.blind_rotate_key,
+                fft,
+                buffers.stack(),
+            );
+        });
+
+        Some(Ciphertext::new(
+            output_lwe,
+            packed.degrees[index],
+            NoiseLevel::NOMINAL,
+            packed.message_modulus,
+            packed.carry_modulus,
+            packed.pbs_order,
+        ))
+    }
+}
@@ -0,0 +1,113 @@
+use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+use crate::shortint::parameters::{
+    ClassicPBSParameters, CompressionParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM,
+};
+
+fn compression_decompression(params: ClassicPBSParameters, comp_params: CompressionParameters) {
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_compression_key = cks.new_compression_private_key(comp_params);
+    let (compression_key, decompression_key) =
+        cks.new_compression_decompression_keys(&private_compression_key);
+
+    let modulus = (cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0) as u64;
+
+    // Spans more than one GLWE
+    let count = comp_params.lwe_per_glwe.0 + 3;
+
+    let cts: Vec<_> = (0..count as u64)
+        .map(|i| {
+            if i % 2 == 0 {
+                cks.unchecked_encrypt(i % modulus)
+            } else {
+                // Output of a PBS
+                let ct = cks.encrypt(i % cks.parameters.message_modulus().0 as u64);
+                sks.message_extract(&ct)
+            }
+        })
+        .collect();
+
+    let compressed = compression_key.compress_ciphertexts_into_list(&cts);
+
+    assert_eq!(compressed.len(), count);
+    assert!(decompression_key.unpack(&compressed, count).is_none());
+
+    for (index, ct) in cts.iter().enumerate() {
+        let unpacked = decompression_key.unpack(&compressed, index).unwrap();
+
+        assert_eq!(unpacked.degree, ct.degree);
+        assert_eq!(unpacked.noise_level(), NoiseLevel::NOMINAL);
+        assert_eq!(
+            cks.decrypt_message_and_carry(&unpacked),
+            cks.decrypt_message_and_carry(ct)
+        );
+    }
+
+    let conformance_params = cks
+        .parameters
+        .pbs_parameters()
+        .unwrap()
+        .to_shortint_conformance_param()
+        .to_compressed_ct_list_conformance_parameters(
+            &comp_params,
+            ListSizeConstraint::exact_size(count),
+        );
+
+    assert!(compressed.is_conformant(&conformance_params));
+
+    // Malformed lists are rejected by the conformance check and cannot be unpacked
+    let malformed_lists = {
+        let mut truncated = compressed.clone();
+        truncated.modulus_switched_glwe_ciphertext_list[1]
+            .get_mut_packed_coefficients()
+            .pop();
+
+        let mut no_lwe_per_glwe = compressed.clone();
+        no_lwe_per_glwe.lwe_per_glwe.0 = 0;
+
+        let mut extra_degree = compressed.clone();
+        extra_degree.degrees.push(Degree(1));
+
+        let mut missing_glwe = compressed.clone();
+        missing_glwe.modulus_switched_glwe_ciphertext_list.pop();
+
+        let mut too_many_bodies = compressed.clone();
+        *too_many_bodies.modulus_switched_glwe_ciphertext_list[1].get_mut_bodies_count() =
+            comp_params.lwe_per_glwe;
+
+        [
+            truncated,
+            no_lwe_per_glwe,
+            extra_degree,
+            missing_glwe,
+            too_many_bodies,
+        ]
+    };
+
+    for malformed in malformed_lists {
+        assert!(!malformed.is_conformant(&conformance_params));
+        assert!(decompression_key.unpack(&malformed, count - 1).is_none());
+    }
+
+    let mut too_big_degree = compressed;
+    too_big_degree.degrees[0] = Degree(modulus as usize);
+    assert!(!too_big_degree.is_conformant(&conformance_params));
+}
+
+#[test]
+fn test_compression_decompression_ci_run_filter() {
+    compression_decompression(PARAM_MESSAGE_2_CARRY_2_KS_PBS, COMP_PARAM_MESSAGE_2_CARRY_2);
+}
+
+#[test]
+fn test_compression_decompression_tuniform_ci_run_filter() {
+    compression_decompression(
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM,
+        COMP_PARAM_MESSAGE_2_CARRY_2,
+    );
+}
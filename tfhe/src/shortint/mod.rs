@@ -50,21 +50,29 @@
 pub mod ciphertext;
 pub mod client_key;
 pub mod engine;
+#[cfg(feature = "threshold-decryption-experimental")]
+pub mod key_aggregation;
 pub mod key_switching_key;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
+pub mod list_compression;
+pub mod noise_model;
+pub mod noise_squashing;
 pub mod parameters;
 pub mod prelude;
 pub mod public_key;
 pub mod server_key;
+#[cfg(feature = "threshold-decryption-experimental")]
+pub mod threshold;
 pub mod wopbs;
 
 pub use ciphertext::{Ciphertext, CompressedCiphertext, PBSOrder};
 pub use client_key::ClientKey;
 pub use key_switching_key::KeySwitchingKey;
 pub use parameters::{
-    CarryModulus, CiphertextModulus, ClassicPBSParameters, EncryptionKeyChoice, MessageModulus,
-    MultiBitPBSParameters, PBSParameters, ShortintParameterSet, WopbsParameters,
+    CarryModulus, CiphertextModulus, ClassicPBSParameters, DynamicDistribution,
+    EncryptionKeyChoice, MessageModulus, MultiBitPBSParameters, PBSParameters,
+    SecretKeyDistribution, ShortintParameterSet, WopbsParameters,
 };
 pub use public_key::{
     CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey,
@@ -101,8 +109,12 @@ where
             lwe_dimension: wopbs_params.lwe_dimension,
             glwe_dimension: wopbs_params.glwe_dimension,
             polynomial_size: wopbs_params.polynomial_size,
-            lwe_modular_std_dev: wopbs_params.lwe_modular_std_dev,
-            glwe_modular_std_dev: wopbs_params.glwe_modular_std_dev,
+            lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(
+                wopbs_params.lwe_modular_std_dev,
+            ),
+            glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(
+                wopbs_params.glwe_modular_std_dev,
+            ),
             pbs_base_log: wopbs_params.pbs_base_log,
             pbs_level: wopbs_params.pbs_level,
             ks_base_log: wopbs_params.ks_base_log,
@@ -111,6 +123,7 @@ where
             carry_modulus: wopbs_params.carry_modulus,
             ciphertext_modulus: wopbs_params.ciphertext_modulus,
             encryption_key_choice: wopbs_params.encryption_key_choice,
+            secret_key_distribution: SecretKeyDistribution::Binary,
         };
 
         ShortintParameterSet::try_new_pbs_and_wopbs_param_set((pbs_params, wopbs_params)).unwrap()
@@ -0,0 +1,696 @@
+//! Theoretical noise model of the shortint operations.
+//!
+//! The functions of this module estimate the variance of the noise of ciphertexts after the main
+//! operations of a [`PBSParameters`] set, as well as the probability that a programmable
+//! bootstrapping (PBS) returns a wrong result. Variances are normalized to the torus, i.e. they are
+//! the variances of the noise divided by the square of the ciphertext modulus.
+//!
+//! The estimations rely on the usual heuristics of TFHE noise analysis: noise terms are
+//! independent and centered, and the errors introduced by the decompositions and by the modulus
+//! switching are uniformly distributed. The error of the floating point FFT used by the PBS
+//! follows an empirical model. The estimations are meant to validate custom parameter sets, they
+//! do not replace a security analysis with the lattice estimator.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::ciphertext::MaxNoiseLevel;
+//! use tfhe::shortint::noise_model;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//!
+//! let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//! let max_noise_level =
+//!     MaxNoiseLevel::from_msg_carry_modulus(params.message_modulus, params.carry_modulus);
+//!
+//! // Linear operations on fresh ciphertexts keep the noise far from the decryption bound
+//! let fresh_variance = noise_model::encryption_variance(params);
+//! let combination_variance =
+//!     noise_model::linear_combination_variance(fresh_variance, max_noise_level);
+//! assert!(
+//!     noise_model::log2_decryption_failure_probability(params, combination_variance) < -128.0
+//! );
+//!
+//! // The probability of error of a PBS is bounded by the one targeted by the parameter set
+//! let log2_p_fail = noise_model::log2_pbs_failure_probability(params, max_noise_level);
+//! assert!(log2_p_fail < -40.0);
+//! ```
+use crate::core_crypto::commons::dispersion::{DynamicDistribution, Variance};
+use crate::core_crypto::commons::parameters::{LweDimension, SecretKeyDistribution};
+use crate::shortint::ciphertext::MaxNoiseLevel;
+use crate::shortint::parameters::{EncryptionKeyChoice, NoiseSquashingParameters, PBSParameters};
+
+/// Base 2 logarithm of the ciphertext modulus of the parameters.
+pub(crate) fn log2_modulus(params: &PBSParameters) -> f64 {
+    let ciphertext_modulus = params.ciphertext_modulus();
+    if ciphertext_modulus.is_native_modulus() {
+        u64::BITS as f64
+    } else {
+        (ciphertext_modulus.get_custom_modulus() as f64).log2()
+    }
+}
+
+pub(crate) fn noise_variance(
+    params: &PBSParameters,
+    noise_distribution: DynamicDistribution,
+) -> Variance {
+    noise_distribution.variance(log2_modulus(params).ceil() as u32)
+}
+
+/// Variance of the error introduced by rounding a value of the torus to a multiple of
+/// `2^-log2_precision`, i.e. of a uniform error over a set of `2^(log2_modulus - log2_precision)`
+/// values.
+pub(crate) fn rounding_variance(log2_precision: f64, log2_modulus: f64) -> f64 {
+    (2.0f64.powf(-2.0 * log2_precision) - 2.0f64.powf(-2.0 * log2_modulus)) / 12.0
+}
+
+/// Variance of the error introduced by the 64 bits floating point FFT in an external product with
+/// a GGSW ciphertext of the given parameters.
+///
+/// This is an empirical model: the error grows with the magnitude of the decomposition digits, with
+/// the square of the polynomial size, and with the number of bits of the ciphertext modulus which
+/// do not fit in the mantissa of an [`f64`].
+pub(crate) fn fft_variance(
+    glwe_dimension: f64,
+    polynomial_size: f64,
+    base_log: usize,
+    level: usize,
+    log2_modulus: f64,
+) -> f64 {
+    fft_variance_for_mantissa(
+        glwe_dimension,
+        polynomial_size,
+        base_log,
+        level,
+        log2_modulus,
+        f64::from(f64::MANTISSA_DIGITS),
+    )
+}
+
+/// Variance of the error introduced by the 128 bits floating point FFT, which represents each
+/// value by two [`f64`], in an external product with a GGSW ciphertext of the given parameters.
+///
+/// The model of the 64 bits FFT is extrapolated to a mantissa of twice as many bits.
+fn fft128_variance(
+    glwe_dimension: f64,
+    polynomial_size: f64,
+    base_log: usize,
+    level: usize,
+    log2_modulus: f64,
+) -> f64 {
+    fft_variance_for_mantissa(
+        glwe_dimension,
+        polynomial_size,
+        base_log,
+        level,
+        log2_modulus,
+        f64::from(2 * f64::MANTISSA_DIGITS),
+    )
+}
+
+fn fft_variance_for_mantissa(
+    glwe_dimension: f64,
+    polynomial_size: f64,
+    base_log: usize,
+    level: usize,
+    log2_modulus: f64,
+    mantissa_bits: f64,
+) -> f64 {
+    // Natural logarithm of the constant fitted on measurements of the error
+    const LN_FFT_SCALING_WEIGHT: f64 = -2.577_224_94;
+
+    let lost_bits = (log2_modulus - mantissa_bits).max(0.0);
+    LN_FFT_SCALING_WEIGHT.exp()
+        * 2.0f64.powf(2.0 * (lost_bits - log2_modulus))
+        * level as f64
+        * 2.0f64.powi(2 * base_log as i32)
+        * polynomial_size
+        * polynomial_size
+        * (glwe_dimension + 1.0)
+}
+
+/// Variance of a decomposition digit in the balanced decomposition with base `2^base_log`.
+pub(crate) fn decomposition_digit_variance(base_log: usize) -> f64 {
+    (2.0f64.powi(2 * base_log as i32) + 2.0) / 12.0
+}
+
+/// Mean number of non zero coefficients per coefficient of a secret key, in the binary expansion
+/// the blind rotation uses (see
+/// [`expand_lwe_secret_key_to_binary`](crate::core_crypto::algorithms::expand_lwe_secret_key_to_binary)).
+///
+/// Each expanded mask coefficient is rounded independently by the modulus switching, so its
+/// rounding error is multiplied by one of these binary coefficients.
+fn expanded_secret_key_mean_weight(secret_key_distribution: SecretKeyDistribution) -> f64 {
+    match secret_key_distribution {
+        // Binary keys are not expanded, ternary keys have a single non zero bit for non zero
+        // coefficients, so this is the mean square of a coefficient
+        SecretKeyDistribution::Binary | SecretKeyDistribution::Ternary => {
+            secret_key_mean_square(secret_key_distribution)
+        }
+        SecretKeyDistribution::BoundedGaussian { std_dev, bound } => {
+            // Mean of the number of bits set in the absolute value of the rounded gaussian
+            let weight =
+                |value: u32| (-f64::from(value * value) / (2.0 * std_dev.0 * std_dev.0)).exp();
+            let (total_weight, total_bit_count) = (1..=bound).fold(
+                (weight(0), 0.0),
+                |(total_weight, total_bit_count), value| {
+                    // Both value and -value have the same bits set in their absolute value
+                    (
+                        total_weight + 2.0 * weight(value),
+                        total_bit_count + 2.0 * weight(value) * f64::from(value.count_ones()),
+                    )
+                },
+            );
+            total_bit_count / total_weight
+        }
+    }
+}
+
+/// Mean of the square of a secret key coefficient.
+fn secret_key_mean_square(secret_key_distribution: SecretKeyDistribution) -> f64 {
+    match secret_key_distribution {
+        SecretKeyDistribution::Binary => 0.5,
+        SecretKeyDistribution::Ternary => 2.0 / 3.0,
+        SecretKeyDistribution::BoundedGaussian { std_dev, bound } => {
+            // The rounding adds a uniform error, the truncation can only reduce the variance
+            (std_dev.0 * std_dev.0 + 1.0 / 12.0).min(f64::from(bound * bound))
+        }
+    }
+}
+
+/// Return the variance of a fresh encryption with the parameters.
+///
+/// Depending on the [`EncryptionKeyChoice`], fresh ciphertexts are encrypted under the big LWE
+/// key (with the GLWE noise) or under the small LWE key (with the LWE noise).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::noise_model;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// // The parameters encrypt under the big key, with the GLWE noise
+/// let std_dev = params.glwe_noise_distribution.gaussian_std_dev().unwrap().0;
+/// assert_eq!(
+///     noise_model::encryption_variance(params).0,
+///     std_dev * std_dev
+/// );
+/// ```
+pub fn encryption_variance(params: impl Into<PBSParameters>) -> Variance {
+    let params = params.into();
+    match params.encryption_key_choice() {
+        EncryptionKeyChoice::Big => noise_variance(&params, params.glwe_noise_distribution()),
+        EncryptionKeyChoice::Small => noise_variance(&params, params.lwe_noise_distribution()),
+    }
+}
+
+/// Return the variance added by a keyswitch from the big LWE key to the small LWE key.
+///
+/// It is made of the noise of the keyswitching key, amplified by the decomposition, and of the
+/// error of the rounding of the input mask to the precision of the decomposition.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::noise_model;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// // The keyswitch adds much more noise than a fresh encryption under the big key
+/// let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// assert!(noise_model::keyswitch_variance(params).0 > noise_model::encryption_variance(params).0);
+/// ```
+pub fn keyswitch_variance(params: impl Into<PBSParameters>) -> Variance {
+    let params = params.into();
+    let input_lwe_dimension = (params.glwe_dimension().0 * params.polynomial_size().0) as f64;
+    let base_log = params.ks_base_log().0;
+    let level = params.ks_level().0;
+    let ksk_variance = noise_variance(&params, params.lwe_noise_distribution()).0;
+
+    let key_term =
+        input_lwe_dimension * level as f64 * decomposition_digit_variance(base_log) * ksk_variance;
+    let rounding_term = input_lwe_dimension
+        * secret_key_mean_square(params.secret_key_distribution())
+        * rounding_variance((base_log * level) as f64, log2_modulus(&params));
+
+    Variance(key_term + rounding_term)
+}
+
+/// Return the variance added by the modulus switching to $2N$ which starts a PBS, $N$ being the
+/// polynomial size of the parameters.
+///
+/// The rounding error of each mask coefficient is multiplied by the coefficients of the binary
+/// key the bootstrapping key encrypts, so this variance grows with the LWE dimension.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::noise_model;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// // Rounding to 2N = 2^12 values gives an error of variance at least 2^-24 / 12
+/// assert!(noise_model::modulus_switch_variance(params).0 > 2.0f64.powi(-24) / 12.0);
+/// ```
+pub fn modulus_switch_variance(params: impl Into<PBSParameters>) -> Variance {
+    let params = params.into();
+    let lwe_dimension = params.lwe_dimension().0 as f64;
+    let log2_two_n = (2 * params.polynomial_size().0).ilog2() as f64;
+
+    // Each rounded mask coefficient is multiplied by a (product of) bit(s) of the binary key the
+    // bootstrapping key encrypts
+    let key_factor = match params {
+        PBSParameters::PBS(_) => {
+            // Non binary keys are expanded to binary keys before the blind rotation
+            lwe_dimension * expanded_secret_key_mean_weight(params.secret_key_distribution())
+        }
+        PBSParameters::MultiBitPBS(multi_bit_params) => {
+            // Each group rounds the combinations of its mask coefficients, the products of the
+            // key bits of a combination of size s have a mean square of 2^-s
+            let grouping_factor = multi_bit_params.grouping_factor.0 as i32;
+            lwe_dimension / grouping_factor as f64 * (1.5f64.powi(grouping_factor) - 1.0)
+        }
+    };
+
+    Variance((1.0 + key_factor) * rounding_variance(log2_two_n, log2_modulus(&params)))
+}
+
+/// Return the variance of the output of a PBS, which does not depend on the noise of its input
+/// as long as the PBS is correct.
+///
+/// Each external product of the blind rotation adds the noise of the bootstrapping key amplified
+/// by the decomposition, the error of the rounding of the accumulator to the precision of the
+/// decomposition, and the error of the floating point FFT.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::noise_model;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// // The output of a PBS is encrypted under the big key, with less noise than the
+/// // keyswitch which precedes the next PBS
+/// let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// assert!(noise_model::pbs_variance(params).0 < noise_model::keyswitch_variance(params).0);
+/// ```
+pub fn pbs_variance(params: impl Into<PBSParameters>) -> Variance {
+    let params = params.into();
+    let glwe_dimension = params.glwe_dimension().0 as f64;
+    let polynomial_size = params.polynomial_size().0 as f64;
+    let base_log = params.pbs_base_log().0;
+    let level = params.pbs_level().0;
+    let bsk_variance = noise_variance(&params, params.glwe_noise_distribution()).0;
+
+    // Number of external products of the blind rotation and number of GGSW ciphertexts summed in
+    // each of them
+    let lwe_dimension = params.lwe_dimension().0 as f64;
+    let (external_product_count, ggsw_per_external_product) = match params {
+        PBSParameters::PBS(_) => (
+            lwe_dimension * params.secret_key_distribution().binary_expansion_factor() as f64,
+            1.0,
+        ),
+        PBSParameters::MultiBitPBS(multi_bit_params) => {
+            let grouping_factor = multi_bit_params.grouping_factor.0;
+            (
+                lwe_dimension / grouping_factor as f64,
+                ((1 << grouping_factor) - 1) as f64,
+            )
+        }
+    };
+
+    let key_term = ggsw_per_external_product
+        * level as f64
+        * (glwe_dimension + 1.0)
+        * polynomial_size
+        * decomposition_digit_variance(base_log)
+        * bsk_variance;
+    let rounding_term = (1.0
+        + glwe_dimension
+            * polynomial_size
+            * secret_key_mean_square(params.secret_key_distribution()))
+        * rounding_variance((base_log * level) as f64, log2_modulus(&params));
+    // The GGSW ciphertexts of a multi-bit external product are summed in the Fourier domain, so
+    // there is a single FFT error per external product
+    let fft_term = fft_variance(
+        glwe_dimension,
+        polynomial_size,
+        base_log,
+        level,
+        log2_modulus(&params),
+    );
+
+    Variance(external_product_count * (key_term + rounding_term + fft_term))
+}
+
+/// Variance of the GLWE noise of the noise squashing parameters, normalized to their 128 bits
+/// ciphertext modulus.
+pub(crate) fn noise_squashing_glwe_variance(params: &NoiseSquashingParameters) -> f64 {
+    params.glwe_noise_distribution.variance(u128::BITS).0
+}
+
+/// Return the variance of the noise of a ciphertext squashed by a noise squashing key with the
+/// given parameters, whose bootstrapping key encrypts an LWE secret key of `input_lwe_dimension`
+/// binary coefficients.
+///
+/// As for [`pbs_variance`], this is the sum of the noises added by the external products of the
+/// blind rotation. `ggsw_row_variance` is the variance of the noise of the GLWE ciphertexts of the
+/// bootstrapping key and `glwe_key_mean_square` the mean square of the coefficients of its GLWE
+/// secret key, which are [`noise_squashing_glwe_variance`] and $1/2$ for a key generated by a
+/// single party.
+pub(crate) fn noise_squashing_variance(
+    params: &NoiseSquashingParameters,
+    input_lwe_dimension: LweDimension,
+    ggsw_row_variance: f64,
+    glwe_key_mean_square: f64,
+) -> Variance {
+    let glwe_dimension = params.glwe_dimension.0 as f64;
+    let polynomial_size = params.polynomial_size.0 as f64;
+    let base_log = params.decomp_base_log.0;
+    let level = params.decomp_level_count.0;
+    let log2_modulus = f64::from(u128::BITS);
+
+    let key_term = level as f64
+        * (glwe_dimension + 1.0)
+        * polynomial_size
+        * decomposition_digit_variance(base_log)
+        * ggsw_row_variance;
+    let rounding_term = (1.0 + glwe_dimension * polynomial_size * glwe_key_mean_square)
+        * rounding_variance((base_log * level) as f64, log2_modulus);
+    let fft_term = fft128_variance(
+        glwe_dimension,
+        polynomial_size,
+        base_log,
+        level,
+        log2_modulus,
+    );
+
+    Variance(input_lwe_dimension.0 as f64 * (key_term + rounding_term + fft_term))
+}
+
+/// Return the variance of a linear combination of ciphertexts of variance `input_variance`.
+///
+/// The [`MaxNoiseLevel`] bounds the norm of the coefficients of the combination, it is the
+/// maximum noise level a server key accepts before requiring a PBS.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::dispersion::Variance;
+/// use tfhe::shortint::ciphertext::MaxNoiseLevel;
+/// use tfhe::shortint::noise_model;
+///
+/// let variance = noise_model::linear_combination_variance(Variance(1.0), MaxNoiseLevel::new(5));
+/// assert_eq!(variance.0, 25.0);
+/// ```
+pub fn linear_combination_variance(
+    input_variance: Variance,
+    max_noise_level: MaxNoiseLevel,
+) -> Variance {
+    let norm = max_noise_level.get() as f64;
+    Variance(norm * norm * input_variance.0)
+}
+
+/// Return the variance of the noise of a ciphertext at the input of the blind rotation of a PBS,
+/// when the PBS is applied on a linear combination of outputs of previous PBS.
+///
+/// This is the noise which determines the correctness of the PBS.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::ciphertext::MaxNoiseLevel;
+/// use tfhe::shortint::noise_model;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// let max_noise_level =
+///     MaxNoiseLevel::from_msg_carry_modulus(params.message_modulus, params.carry_modulus);
+/// let variance = noise_model::pbs_input_variance(params, max_noise_level);
+/// // The noise stays well below the decryption bound
+/// assert!(variance.0.sqrt() < noise_model::decryption_noise_bound(params) / 4.0);
+/// ```
+pub fn pbs_input_variance(
+    params: impl Into<PBSParameters>,
+    max_noise_level: MaxNoiseLevel,
+) -> Variance {
+    let params = params.into();
+    let pbs_variance = pbs_variance(params);
+    let keyswitch_variance = keyswitch_variance(params);
+    let modulus_switch_variance = modulus_switch_variance(params);
+
+    let variance = match params.encryption_key_choice() {
+        // The keyswitch is applied on the linear combination, before the PBS
+        EncryptionKeyChoice::Big => {
+            linear_combination_variance(pbs_variance, max_noise_level).0 + keyswitch_variance.0
+        }
+        // The keyswitch directly follows the PBS, so its noise is part of the combined values
+        EncryptionKeyChoice::Small => {
+            linear_combination_variance(
+                Variance(pbs_variance.0 + keyswitch_variance.0),
+                max_noise_level,
+            )
+            .0
+        }
+    };
+
+    Variance(variance + modulus_switch_variance.0)
+}
+
+/// Return the bound on the absolute value of the noise (normalized to the torus) under which a
+/// ciphertext encrypted with the parameters is decrypted correctly.
+///
+/// Messages are encoded with a padding bit, so the bound is half the scaling factor $\Delta =
+/// \frac{1}{2 \cdot message\_modulus \cdot carry\_modulus}$.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::noise_model;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// // 4 bits of message and carry and a padding bit
+/// assert_eq!(
+///     noise_model::decryption_noise_bound(PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+///     1.0 / 64.0
+/// );
+/// ```
+pub fn decryption_noise_bound(params: impl Into<PBSParameters>) -> f64 {
+    let params = params.into();
+    let plaintext_modulus = (params.message_modulus().0 * params.carry_modulus().0) as f64;
+    1.0 / (4.0 * plaintext_modulus)
+}
+
+/// Return the base 2 logarithm of the probability that a centered gaussian noise of the given
+/// variance exceeds the decryption bound of the parameters.
+///
+/// The logarithm is returned as the probability itself is too small to be represented by an
+/// [`f64`] for good parameters.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::dispersion::Variance;
+/// use tfhe::shortint::noise_model;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// // A noise whose standard deviation is the bound exceeds it about a third of the time
+/// let bound = noise_model::decryption_noise_bound(params);
+/// let log2_p_fail =
+///     noise_model::log2_decryption_failure_probability(params, Variance(bound * bound));
+/// assert!((log2_p_fail - 0.3173f64.log2()).abs() < 1e-3);
+/// ```
+pub fn log2_decryption_failure_probability(
+    params: impl Into<PBSParameters>,
+    variance: Variance,
+) -> f64 {
+    let bound = decryption_noise_bound(params);
+    ln_erfc(bound / (2.0 * variance.0).sqrt()) / std::f64::consts::LN_2
+}
+
+/// Return the probability that a centered gaussian noise of the given variance exceeds the
+/// decryption bound of the parameters.
+///
+/// See [`log2_decryption_failure_probability`] for probabilities too small to be represented by
+/// an [`f64`].
+pub fn decryption_failure_probability(params: impl Into<PBSParameters>, variance: Variance) -> f64 {
+    log2_decryption_failure_probability(params, variance).exp2()
+}
+
+/// Return the base 2 logarithm of the probability that a PBS applied on a linear combination of
+/// outputs of previous PBS returns a wrong result, see [`pbs_input_variance`].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::ciphertext::MaxNoiseLevel;
+/// use tfhe::shortint::noise_model;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// let max_noise_level =
+///     MaxNoiseLevel::from_msg_carry_modulus(params.message_modulus, params.carry_modulus);
+/// // The parameter set targets a failure probability of 2^-40
+/// let log2_p_fail = noise_model::log2_pbs_failure_probability(params, max_noise_level);
+/// assert!((log2_p_fail + 40.0).abs() < 1.0);
+/// ```
+pub fn log2_pbs_failure_probability(
+    params: impl Into<PBSParameters>,
+    max_noise_level: MaxNoiseLevel,
+) -> f64 {
+    let params = params.into();
+    log2_decryption_failure_probability(params, pbs_input_variance(params, max_noise_level))
+}
+
+/// Natural logarithm of the complementary error function.
+///
+/// This uses the Chebyshev approximation of erfc from Numerical Recipes, which has a relative
+/// error below $1.2 \cdot 10^{-7}$ everywhere. Taking the logarithm of the approximation avoids
+/// the underflow of the exponential for large inputs.
+fn ln_erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let polynomial = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let ln_erfc_abs = t.ln() + polynomial;
+    if x >= 0.0 {
+        ln_erfc_abs
+    } else {
+        // erfc(-x) = 2 - erfc(x)
+        (2.0 - ln_erfc_abs.exp()).ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::multi_bit::PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS;
+    use crate::shortint::parameters::{
+        ClassicPBSParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS_TERNARY, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM,
+        PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    };
+
+    #[test]
+    fn test_ln_erfc() {
+        // Reference values of erfc
+        for (x, erfc) in [
+            (-1.0, 1.842_700_792_949_715),
+            (0.0, 1.0),
+            (0.5, 0.479_500_122_186_953_5),
+            (2.0, 0.004_677_734_981_047_266),
+            (5.0, 1.537_459_794_428_035e-12),
+        ] {
+            let relative_error = (ln_erfc(x).exp() - erfc).abs() / erfc;
+            assert!(
+                relative_error < 1.2e-7,
+                "x={x}, relative error={relative_error}"
+            );
+        }
+
+        // Large values do not underflow
+        assert!((ln_erfc(40.0) / std::f64::consts::LN_2 + 2314.5).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_noise_model_failure_probability() {
+        // The classic parameter sets target a PBS failure probability of 2^-40, the expected
+        // values are computed from the formulas of this module
+        for (params, expected_log2_p_fail) in [
+            (PARAM_MESSAGE_1_CARRY_1_KS_PBS, -38.809),
+            (PARAM_MESSAGE_2_CARRY_2_KS_PBS, -40.072),
+            (PARAM_MESSAGE_3_CARRY_3_KS_PBS, -40.542),
+        ] {
+            let max_noise_level =
+                MaxNoiseLevel::from_msg_carry_modulus(params.message_modulus, params.carry_modulus);
+            let log2_p_fail = log2_pbs_failure_probability(params, max_noise_level);
+            assert!(
+                (log2_p_fail - expected_log2_p_fail).abs() < 0.01,
+                "{log2_p_fail}"
+            );
+        }
+
+        // Expected variances of PARAM_MESSAGE_2_CARRY_2_KS_PBS
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+        for (variance, expected_log2_variance) in [
+            (keyswitch_variance(params), -18.398),
+            (modulus_switch_variance(params), -19.046),
+            (pbs_variance(params), -29.505),
+        ] {
+            let log2_variance = variance.0.log2();
+            assert!(
+                (log2_variance - expected_log2_variance).abs() < 0.01,
+                "{log2_variance}"
+            );
+        }
+
+        // The FFT error is a large part of the output noise of the PBS
+        let fft_log2_variance = (params.lwe_dimension.0 as f64
+            * fft_variance(
+                params.glwe_dimension.0 as f64,
+                params.polynomial_size.0 as f64,
+                params.pbs_base_log.0,
+                params.pbs_level.0,
+                64.0,
+            ))
+        .log2();
+        assert!(
+            (fft_log2_variance + 31.183).abs() < 0.01,
+            "{fft_log2_variance}"
+        );
+
+        // The multi-bit PBS rounds the combinations of the mask coefficients of each group, which
+        // adds noise compared to a classic PBS with the same dimensions
+        let params = PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS;
+        let classic_params = ClassicPBSParameters {
+            lwe_dimension: params.lwe_dimension,
+            glwe_dimension: params.glwe_dimension,
+            polynomial_size: params.polynomial_size,
+            ..PARAM_MESSAGE_2_CARRY_2_KS_PBS
+        };
+        assert!(modulus_switch_variance(params).0 > modulus_switch_variance(classic_params).0);
+
+        // Ternary keys increase the noise of the keyswitch, of the modulus switching and of the
+        // PBS, a non zero ternary coefficient having a single bit set in the binary expansion
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TERNARY;
+        let max_noise_level =
+            MaxNoiseLevel::from_msg_carry_modulus(params.message_modulus, params.carry_modulus);
+        assert_eq!(
+            expanded_secret_key_mean_weight(params.secret_key_distribution),
+            2.0 / 3.0
+        );
+        for (variance, expected_log2_variance) in [
+            (keyswitch_variance(params), -18.385),
+            (modulus_switch_variance(params), -18.632),
+            (pbs_variance(params), -28.208),
+        ] {
+            let log2_variance = variance.0.log2();
+            assert!(
+                (log2_variance - expected_log2_variance).abs() < 0.01,
+                "{log2_variance}"
+            );
+        }
+        let log2_p_fail = log2_pbs_failure_probability(params, max_noise_level);
+        assert!((log2_p_fail + 35.331).abs() < 0.01, "{log2_p_fail}");
+    }
+
+    #[test]
+    fn test_noise_model_t_uniform() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM;
+        // Big key encryption uses the GLWE noise, a TUniform with bound 2^17
+        let expected_variance = (2.0f64.powi(35) + 1.0) / 6.0 * 2.0f64.powi(-128);
+        assert_eq!(encryption_variance(params).0, expected_variance);
+
+        // The bounded noise has at least the variance of the gaussian noise of the classic
+        // parameters, which puts the failure probability well below 2^-40
+        let max_noise_level =
+            MaxNoiseLevel::from_msg_carry_modulus(params.message_modulus, params.carry_modulus);
+        let log2_p_fail = log2_pbs_failure_probability(params, max_noise_level);
+        assert!((log2_p_fail + 69.470).abs() < 0.01, "{log2_p_fail}");
+    }
+}
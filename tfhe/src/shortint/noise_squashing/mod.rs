@@ -0,0 +1,359 @@
+//! This module defines the noise squashing of shortint ciphertexts.
+//!
+//! The noise of a shortint ciphertext is only a few bits smaller than the scaling factor of its
+//! message. A [NoiseSquashingKey] bootstraps a ciphertext to a [SquashedNoiseCiphertext] which
+//! encrypts the same message and carry under a GLWE secret key with a 128 bits ciphertext
+//! modulus, and whose noise is smaller than the scaling factor by more than 50 bits (see
+//! [NoiseSquashingParameters]). This gap is what allows the [threshold
+//! decryption](crate::shortint::threshold) to hide the noise of a ciphertext behind a
+//! statistically larger smudging noise.
+//!
+//! - [ClientKey::new_noise_squashing_private_key] generates the GLWE secret key of the squashed
+//!   ciphertexts;
+//! - [ClientKey::new_noise_squashing_key] generates the public [NoiseSquashingKey];
+//! - [NoiseSquashingKey::squash_ciphertext_noise] squashes the noise of a ciphertext computed on
+//!   with the [ServerKey] of the client key.
+//!
+//! The squashed ciphertexts can only be decrypted.
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::LweDimension;
+use crate::core_crypto::entities::*;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{CarryModulus, MessageModulus, NoiseSquashingParameters};
+use crate::shortint::{noise_model, Ciphertext, ClientKey, PBSOrder, ServerKey};
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod test;
+
+/// The GLWE secret key of the squashed ciphertexts, it must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingPrivateKey {
+    pub(crate) glwe_secret_key: GlweSecretKeyOwned<u128>,
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub params: NoiseSquashingParameters,
+}
+
+/// A key to squash the noise of ciphertexts into [SquashedNoiseCiphertext]s.
+///
+/// It is generated by the client and is meant to be published.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingKey {
+    pub(crate) bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+    pub(crate) params: NoiseSquashingParameters,
+}
+
+/// A shortint ciphertext whose noise was squashed by a [NoiseSquashingKey].
+///
+/// The message and carry are encoded with the scaling factor
+/// $2^{127} / (\text{message\_modulus} \cdot \text{carry\_modulus})$ over a 128 bits ciphertext
+/// modulus.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SquashedNoiseCiphertext {
+    pub(crate) ct: LweCiphertextOwned<u128>,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+}
+
+impl SquashedNoiseCiphertext {
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+
+    /// Returns the scaling factor of messages with a padding bit.
+    pub(crate) fn delta(&self) -> u128 {
+        (1_u128 << 127) / (self.message_modulus.0 * self.carry_modulus.0) as u128
+    }
+
+    /// Round a decrypted value to the closest message and carry.
+    pub(crate) fn decode_message_and_carry(&self, decrypted_u128: u128) -> u64 {
+        let delta = self.delta();
+
+        //The bit before the message
+        let rounding_bit = delta >> 1;
+
+        //compute the rounding bit
+        let rounding = (decrypted_u128 & rounding_bit) << 1;
+
+        ((decrypted_u128.wrapping_add(rounding)) / delta) as u64
+    }
+}
+
+impl ClientKey {
+    /// Generate the GLWE secret key of the ciphertexts squashed with the keys of this client key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let noise_squashing_private_key =
+    ///     cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polynomial size of `params` is smaller than the one of the parameters of this
+    /// client key, or if the parameters do not use the native ciphertext moduli.
+    pub fn new_noise_squashing_private_key(
+        &self,
+        params: NoiseSquashingParameters,
+    ) -> NoiseSquashingPrivateKey {
+        assert!(
+            params.polynomial_size.0 >= self.parameters.polynomial_size().0,
+            "The polynomial size of the noise squashing ({}) must be at least the one of the \
+            ciphertexts ({})",
+            params.polynomial_size.0,
+            self.parameters.polynomial_size().0
+        );
+        assert!(
+            self.parameters.ciphertext_modulus().is_native_modulus()
+                && params.ciphertext_modulus.is_native_modulus(),
+            "Noise squashing is only supported for native ciphertext moduli"
+        );
+
+        let glwe_secret_key = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_binary_glwe_secret_key(
+                params.glwe_dimension,
+                params.polynomial_size,
+                &mut engine.secret_generator,
+            )
+        });
+
+        NoiseSquashingPrivateKey {
+            glwe_secret_key,
+            input_lwe_dimension: self.bootstrap_input_lwe_secret_key().lwe_dimension(),
+            params,
+        }
+    }
+
+    /// Generate the public key used to squash the noise of ciphertexts encrypted under this
+    /// client key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let noise_squashing_private_key =
+    ///     cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// let noise_squashing_key = cks.new_noise_squashing_key(&noise_squashing_private_key);
+    ///
+    /// let ct = sks.unchecked_add(&cks.encrypt(1), &cks.encrypt(2));
+    /// let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &sks);
+    ///
+    /// assert_eq!(noise_squashing_private_key.decrypt(&squashed), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `private_key` was not generated from this client key.
+    pub fn new_noise_squashing_key(
+        &self,
+        private_key: &NoiseSquashingPrivateKey,
+    ) -> NoiseSquashingKey {
+        let params = &private_key.params;
+
+        // The bits of the LWE secret key the bootstrapping key of the server key is generated for
+        let bootstrap_input_lwe_secret_key = self.bootstrap_input_lwe_secret_key();
+        let input_lwe_secret_key = LweSecretKey::from_container(
+            bootstrap_input_lwe_secret_key
+                .as_ref()
+                .as_ref()
+                .iter()
+                .map(|&bit| u128::from(bit))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            input_lwe_secret_key.lwe_dimension(),
+            private_key.input_lwe_dimension,
+            "The noise squashing private key was generated for another client key"
+        );
+
+        let bootstrap_key = ShortintEngine::with_thread_local_mut(|engine| {
+            par_allocate_and_generate_new_lwe_bootstrap_key(
+                &input_lwe_secret_key,
+                &private_key.glwe_secret_key,
+                params.decomp_base_log,
+                params.decomp_level_count,
+                params.glwe_noise_distribution,
+                params.ciphertext_modulus,
+                &mut engine.encryption_generator,
+            )
+        });
+
+        let mut bootstrapping_key = Fourier128LweBootstrapKey::new(
+            bootstrap_key.input_lwe_dimension(),
+            bootstrap_key.glwe_size(),
+            bootstrap_key.polynomial_size(),
+            bootstrap_key.decomposition_base_log(),
+            bootstrap_key.decomposition_level_count(),
+        );
+        convert_standard_lwe_bootstrap_key_to_fourier_128(&bootstrap_key, &mut bootstrapping_key);
+
+        NoiseSquashingKey {
+            bootstrapping_key,
+            params: *params,
+        }
+    }
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Decrypt the message and carry of a squashed ciphertext.
+    ///
+    /// See [ClientKey::new_noise_squashing_key] for usage.
+    pub fn decrypt_message_and_carry(&self, ct: &SquashedNoiseCiphertext) -> u64 {
+        let decrypted = decrypt_lwe_ciphertext(&self.glwe_secret_key.as_lwe_secret_key(), &ct.ct);
+
+        ct.decode_message_and_carry(decrypted.0)
+    }
+
+    /// Decrypt the message of a squashed ciphertext.
+    ///
+    /// See [ClientKey::new_noise_squashing_key] for usage.
+    pub fn decrypt(&self, ct: &SquashedNoiseCiphertext) -> u64 {
+        self.decrypt_message_and_carry(ct) % ct.message_modulus.0 as u64
+    }
+
+    /// The variance of the noise of the ciphertexts squashed with the key generated from this
+    /// private key.
+    pub(crate) fn squashed_noise_variance(&self) -> Variance {
+        noise_model::noise_squashing_variance(
+            &self.params,
+            self.input_lwe_dimension,
+            noise_model::noise_squashing_glwe_variance(&self.params),
+            0.5,
+        )
+    }
+}
+
+impl NoiseSquashingKey {
+    pub fn params(&self) -> NoiseSquashingParameters {
+        self.params
+    }
+
+    /// Squash the noise of `ct`, which must have been computed on with `server_key`.
+    ///
+    /// The ciphertext is bootstrapped with the identity over its message and carry: as for any
+    /// PBS, its noise must not exceed the noise level the parameters are designed for.
+    ///
+    /// See [ClientKey::new_noise_squashing_key] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the noise squashing key was not generated for the keys of `server_key`.
+    pub fn squash_ciphertext_noise(
+        &self,
+        ct: &Ciphertext,
+        server_key: &ServerKey,
+    ) -> SquashedNoiseCiphertext {
+        let input = match ct.pbs_order {
+            PBSOrder::KeyswitchBootstrap => {
+                let mut output = LweCiphertext::new(
+                    0u64,
+                    server_key.key_switching_key.output_lwe_size(),
+                    ct.ct.ciphertext_modulus(),
+                );
+                keyswitch_lwe_ciphertext(&server_key.key_switching_key, &ct.ct, &mut output);
+                output
+            }
+            PBSOrder::BootstrapKeyswitch => ct.ct.clone(),
+        };
+
+        // Move the ciphertext to the most significant bits of the 128 bits modulus
+        let input = LweCiphertext::from_container(
+            input
+                .as_ref()
+                .iter()
+                .map(|&coefficient| u128::from(coefficient) << 64)
+                .collect::<Vec<_>>(),
+            self.params.ciphertext_modulus,
+        );
+
+        let input_lwe_dimension = self.bootstrapping_key.input_lwe_dimension();
+        let input = if input.lwe_size().to_lwe_dimension() == input_lwe_dimension {
+            input
+        } else {
+            // The bootstrapping key encrypts the binary expansion of a non binary secret key
+            allocate_and_expand_lwe_ciphertext_for_binary_key(&input, input_lwe_dimension)
+        };
+        assert_eq!(
+            input.lwe_size().to_lwe_dimension(),
+            input_lwe_dimension,
+            "The noise squashing key was not generated for the keys of the server key"
+        );
+
+        let accumulator = self.generate_identity_accumulator(ct.message_modulus, ct.carry_modulus);
+
+        let mut output = LweCiphertext::new(
+            0u128,
+            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
+            self.params.ciphertext_modulus,
+        );
+        programmable_bootstrap_f128_lwe_ciphertext(
+            &input,
+            &mut output,
+            &accumulator,
+            &self.bootstrapping_key,
+        );
+
+        SquashedNoiseCiphertext {
+            ct: output,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+        }
+    }
+
+    /// The accumulator of the identity over the message and carry, encoded with a padding bit.
+    fn generate_identity_accumulator(
+        &self,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    ) -> GlweCiphertextOwned<u128> {
+        let polynomial_size = self.bootstrapping_key.polynomial_size();
+        let mut accumulator = GlweCiphertext::new(
+            0u128,
+            self.bootstrapping_key.glwe_size(),
+            polynomial_size,
+            self.params.ciphertext_modulus,
+        );
+
+        let modulus_sup = message_modulus.0 * carry_modulus.0;
+        let box_size = polynomial_size.0 / modulus_sup;
+        let delta = (1_u128 << 127) / modulus_sup as u128;
+
+        let mut body = accumulator.get_mut_body();
+        let body = body.as_mut();
+        for (value, chunk) in body.chunks_exact_mut(box_size).enumerate() {
+            chunk.fill(value as u128 * delta);
+        }
+
+        let half_box_size = box_size / 2;
+
+        // Negate the first half_box_size coefficients
+        for coefficient in body[0..half_box_size].iter_mut() {
+            *coefficient = coefficient.wrapping_neg();
+        }
+
+        // Rotate the accumulator
+        body.rotate_left(half_box_size);
+
+        accumulator
+    }
+}
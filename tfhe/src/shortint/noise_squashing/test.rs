@@ -0,0 +1,52 @@
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::{
+    ClassicPBSParameters, NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+};
+
+fn noise_squashing(params: ClassicPBSParameters) {
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_key = cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    let noise_squashing_key = cks.new_noise_squashing_key(&private_key);
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for msg in 0..modulus {
+        // Carries are kept by the noise squashing
+        let ct = sks.unchecked_add(&cks.encrypt(msg), &cks.encrypt(modulus - 1));
+        let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, sks);
+
+        assert_eq!(
+            private_key.decrypt_message_and_carry(&squashed),
+            msg + modulus - 1
+        );
+        assert_eq!(
+            private_key.decrypt(&squashed),
+            (msg + modulus - 1) % modulus
+        );
+    }
+}
+
+#[test]
+fn test_noise_squashing_ks_pbs() {
+    noise_squashing(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+}
+
+#[test]
+fn test_noise_squashing_pbs_ks() {
+    noise_squashing(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+}
+
+#[test]
+fn test_noise_squashing_variance() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let private_key = keys
+        .client_key()
+        .new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+
+    // The noise of the squashed ciphertexts is far below the scaling factor
+    let std_dev_log2 = private_key.squashed_noise_variance().0.log2() / 2.0;
+    assert!(std_dev_log2 < -55.0, "{std_dev_log2}");
+}
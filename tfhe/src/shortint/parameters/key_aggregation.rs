@@ -0,0 +1,62 @@
+//! Parameter sets for the keys generated by several parties with the
+//! [key aggregation](crate::shortint::key_aggregation) protocol.
+//!
+//! The joint keys are noisier than the keys generated by a single party, see
+//! [log2_pbs_failure_probability](crate::shortint::key_aggregation::log2_pbs_failure_probability):
+//! these sets reuse the LWE and GLWE dimensions and noise distributions of the classic parameter
+//! sets with the same polynomial size, so that the keys sampled by each party are as secure as the
+//! keys of these sets, and use decomposition parameters chosen for the noise of the joint keys.
+//!
+//! Both sets keep the failure probability of a PBS below $2^{-40}$ for up to 4 parties.
+
+use crate::shortint::parameters::{
+    CarryModulus, CiphertextModulus, ClassicPBSParameters, DecompositionBaseLog,
+    DecompositionLevelCount, DynamicDistribution, EncryptionKeyChoice, GlweDimension, LweDimension,
+    MessageModulus, PolynomialSize, SecretKeyDistribution, StandardDev,
+};
+
+/// Uses the dimensions and noise distributions of [super::PARAM_MESSAGE_2_CARRY_2_KS_PBS].
+pub const PARAM_KEY_AGGREGATION_MESSAGE_1_CARRY_1_KS_PBS: ClassicPBSParameters =
+    ClassicPBSParameters {
+        lwe_dimension: LweDimension(742),
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(2048),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.000007069849454709433,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.00000000000000029403601535432533,
+        )),
+        pbs_base_log: DecompositionBaseLog(21),
+        pbs_level: DecompositionLevelCount(1),
+        ks_level: DecompositionLevelCount(4),
+        ks_base_log: DecompositionBaseLog(4),
+        message_modulus: MessageModulus(2),
+        carry_modulus: CarryModulus(2),
+        ciphertext_modulus: CiphertextModulus::new_native(),
+        encryption_key_choice: EncryptionKeyChoice::Big,
+        secret_key_distribution: SecretKeyDistribution::Binary,
+    };
+
+/// Uses the dimensions and noise distributions of [super::PARAM_MESSAGE_2_CARRY_3_KS_PBS].
+pub const PARAM_KEY_AGGREGATION_MESSAGE_2_CARRY_2_KS_PBS: ClassicPBSParameters =
+    ClassicPBSParameters {
+        lwe_dimension: LweDimension(856),
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(4096),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000008775214009854235,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000000000000000002168404344971009,
+        )),
+        pbs_base_log: DecompositionBaseLog(24),
+        pbs_level: DecompositionLevelCount(1),
+        ks_level: DecompositionLevelCount(6),
+        ks_base_log: DecompositionBaseLog(3),
+        message_modulus: MessageModulus(4),
+        carry_modulus: CarryModulus(4),
+        ciphertext_modulus: CiphertextModulus::new_native(),
+        encryption_key_choice: EncryptionKeyChoice::Big,
+        secret_key_distribution: SecretKeyDistribution::Binary,
+    };
@@ -0,0 +1,88 @@
+use crate::conformance::ListSizeConstraint;
+use crate::core_crypto::commons::parameters::{
+    CiphertextModulusLog, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    LweCiphertextCount, PolynomialSize,
+};
+use crate::core_crypto::entities::CompressedModulusSwitchedGlweCiphertextParameters;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{
+    CarryModulus, CiphertextConformanceParams, DynamicDistribution, MessageModulus,
+};
+use crate::shortint::PBSOrder;
+
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters for the compression of shortint ciphertexts in a
+/// [`CompressedCiphertextList`](crate::shortint::list_compression::CompressedCiphertextList).
+///
+/// Ciphertexts are packed in GLWEs of `packing_ks_glwe_dimension` and
+/// `packing_ks_polynomial_size` by a packing keyswitch, those GLWEs are then modulus switched to
+/// $2^{\texttt{storage\_log\_modulus}}$. Decompression uses a PBS going from the packing GLWE
+/// secret key back to the GLWE secret key of the client key.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionParameters {
+    pub br_level: DecompositionLevelCount,
+    pub br_base_log: DecompositionBaseLog,
+    pub packing_ks_level: DecompositionLevelCount,
+    pub packing_ks_base_log: DecompositionBaseLog,
+    pub packing_ks_polynomial_size: PolynomialSize,
+    pub packing_ks_glwe_dimension: GlweDimension,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: CiphertextModulusLog,
+    pub packing_ks_key_noise_distribution: DynamicDistribution,
+}
+
+/// Compression parameters for the message 2 carry 2 KS_PBS parameter sets.
+///
+/// Compatible with [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](super::PARAM_MESSAGE_2_CARRY_2_KS_PBS) and
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM`](super::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM).
+pub const COMP_PARAM_MESSAGE_2_CARRY_2: CompressionParameters = CompressionParameters {
+    br_level: DecompositionLevelCount(1),
+    br_base_log: DecompositionBaseLog(23),
+    packing_ks_level: DecompositionLevelCount(4),
+    packing_ks_base_log: DecompositionBaseLog(4),
+    packing_ks_polynomial_size: PolynomialSize(256),
+    packing_ks_glwe_dimension: GlweDimension(4),
+    lwe_per_glwe: LweCiphertextCount(256),
+    storage_log_modulus: CiphertextModulusLog(12),
+    packing_ks_key_noise_distribution: DynamicDistribution::new_t_uniform(42),
+};
+
+/// Structure to store the expected properties of a compressed ciphertext list
+/// Can be used on a server to check if client inputs are well formed
+/// before running a computation on them
+pub struct CompressedCiphertextListConformanceParams {
+    pub ct_params: CompressedModulusSwitchedGlweCiphertextParameters<u64>,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub max_degree: Degree,
+    pub pbs_order: PBSOrder,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub ciphertext_count_constraint: ListSizeConstraint,
+}
+
+impl CiphertextConformanceParams {
+    /// Expected properties of a list compressed with `compression_params` from ciphertexts
+    /// conformant with `self`, the ciphertexts may have been computed on and can have any degree
+    /// fitting in the message and carry space.
+    pub fn to_compressed_ct_list_conformance_parameters(
+        &self,
+        compression_params: &CompressionParameters,
+        ciphertext_count_constraint: ListSizeConstraint,
+    ) -> CompressedCiphertextListConformanceParams {
+        CompressedCiphertextListConformanceParams {
+            ct_params: CompressedModulusSwitchedGlweCiphertextParameters {
+                glwe_dimension: compression_params.packing_ks_glwe_dimension,
+                polynomial_size: compression_params.packing_ks_polynomial_size,
+                log_modulus: compression_params.storage_log_modulus,
+                uncompressed_ciphertext_modulus: self.ct_params.ct_modulus,
+            },
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            max_degree: Degree(self.message_modulus.0 * self.carry_modulus.0 - 1),
+            pbs_order: self.pbs_order,
+            lwe_per_glwe: compression_params.lwe_per_glwe,
+            ciphertext_count_constraint,
+        }
+    }
+}
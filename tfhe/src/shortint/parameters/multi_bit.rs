@@ -1,7 +1,9 @@
 //! #Warning experimental
 
 use super::CiphertextConformanceParams;
-pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev};
+pub use crate::core_crypto::commons::dispersion::{
+    DispersionParameter, DynamicDistribution, StandardDev,
+};
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
@@ -21,8 +23,8 @@ pub struct MultiBitPBSParameters {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
-    pub lwe_modular_std_dev: StandardDev,
-    pub glwe_modular_std_dev: StandardDev,
+    pub lwe_noise_distribution: DynamicDistribution,
+    pub glwe_noise_distribution: DynamicDistribution,
     pub pbs_base_log: DecompositionBaseLog,
     pub pbs_level: DecompositionLevelCount,
     pub ks_base_log: DecompositionBaseLog,
@@ -98,8 +100,12 @@ pub const PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_2_KS_PBS: MultiBitPBSParameter
         lwe_dimension: LweDimension(764),
         glwe_dimension: GlweDimension(3),
         polynomial_size: PolynomialSize(512),
-        lwe_modular_std_dev: StandardDev(0.000006025673585415336),
-        glwe_modular_std_dev: StandardDev(0.0000000000039666089171633006),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.000006025673585415336,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000000000039666089171633006,
+        )),
         pbs_base_log: DecompositionBaseLog(18),
         pbs_level: DecompositionLevelCount(1),
         ks_base_log: DecompositionBaseLog(6),
@@ -117,8 +123,12 @@ pub const PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS: MultiBitPBSParameter
         lwe_dimension: LweDimension(818),
         glwe_dimension: GlweDimension(1),
         polynomial_size: PolynomialSize(2048),
-        lwe_modular_std_dev: StandardDev(0.000002226459789930014),
-        glwe_modular_std_dev: StandardDev(0.0000000000000003152931493498455),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.000002226459789930014,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000000000000003152931493498455,
+        )),
         pbs_base_log: DecompositionBaseLog(22),
         pbs_level: DecompositionLevelCount(1),
         ks_base_log: DecompositionBaseLog(5),
@@ -136,8 +146,12 @@ pub const PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS: MultiBitPBSParameter
         lwe_dimension: LweDimension(922),
         glwe_dimension: GlweDimension(1),
         polynomial_size: PolynomialSize(8192),
-        lwe_modular_std_dev: StandardDev(0.0000003272369292345697),
-        glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000003272369292345697,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000000000000000002168404344971009,
+        )),
         pbs_base_log: DecompositionBaseLog(14),
         pbs_level: DecompositionLevelCount(2),
         ks_base_log: DecompositionBaseLog(4),
@@ -156,8 +170,12 @@ pub const PARAM_MULTI_BIT_MESSAGE_1_CARRY_1_GROUP_3_KS_PBS: MultiBitPBSParameter
         lwe_dimension: LweDimension(765),
         glwe_dimension: GlweDimension(3),
         polynomial_size: PolynomialSize(512),
-        lwe_modular_std_dev: StandardDev(0.000005915594083804978),
-        glwe_modular_std_dev: StandardDev(0.0000000000039666089171633006),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.000005915594083804978,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000000000039666089171633006,
+        )),
         pbs_base_log: DecompositionBaseLog(18),
         pbs_level: DecompositionLevelCount(1),
         ks_base_log: DecompositionBaseLog(6),
@@ -175,8 +193,12 @@ pub const PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS: MultiBitPBSParameter
         lwe_dimension: LweDimension(888),
         glwe_dimension: GlweDimension(1),
         polynomial_size: PolynomialSize(2048),
-        lwe_modular_std_dev: StandardDev(0.0000006125031601933181),
-        glwe_modular_std_dev: StandardDev(0.0000000000000003152931493498455),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000006125031601933181,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000000000000003152931493498455,
+        )),
         pbs_base_log: DecompositionBaseLog(21),
         pbs_level: DecompositionLevelCount(1),
         ks_base_log: DecompositionBaseLog(7),
@@ -194,8 +216,12 @@ pub const PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS: MultiBitPBSParameter
         lwe_dimension: LweDimension(972),
         glwe_dimension: GlweDimension(1),
         polynomial_size: PolynomialSize(8192),
-        lwe_modular_std_dev: StandardDev(0.00000013016688349592805),
-        glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+        lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.00000013016688349592805,
+        )),
+        glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+            0.0000000000000000002168404344971009,
+        )),
         pbs_base_log: DecompositionBaseLog(14),
         pbs_level: DecompositionLevelCount(2),
         ks_base_log: DecompositionBaseLog(6),
@@ -0,0 +1,45 @@
+//! Parameter sets for the [noise squashing](crate::shortint::noise_squashing) of shortint
+//! ciphertexts.
+
+use crate::shortint::parameters::{
+    CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution,
+    GlweDimension, PolynomialSize,
+};
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters for the noise squashing of shortint ciphertexts.
+///
+/// The noise of a ciphertext is squashed by a PBS with a bootstrapping key encrypting the LWE
+/// secret key of the ciphertext under a GLWE secret key of `glwe_dimension` and `polynomial_size`,
+/// with a 128 bits ciphertext modulus. The polynomial size must be at least the one of the
+/// parameters of the ciphertexts, so that the failure probability of the noise squashing is not
+/// larger than the one of a PBS with these parameters.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingParameters {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_noise_distribution: DynamicDistribution,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub ciphertext_modulus: CoreCiphertextModulus<u128>,
+}
+
+/// Noise squashing parameters for the message 2 carry 2 KS_PBS and PBS_KS parameter sets.
+///
+/// The GLWE secret key has the dimension $k \cdot N = 4096$ and the noise distribution of a
+/// 128 bits security level for a 128 bits modulus. The standard deviation of the noise of a
+/// squashed ciphertext is below $2^{-55}$, for the keys of a single party and for the keys
+/// generated by up to 4 parties with the key aggregation parameter sets.
+///
+/// Compatible with [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](super::PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+/// [`PARAM_MESSAGE_2_CARRY_2_PBS_KS`](super::PARAM_MESSAGE_2_CARRY_2_PBS_KS) and the
+/// `PARAM_KEY_AGGREGATION_*` parameter sets.
+pub const NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2: NoiseSquashingParameters =
+    NoiseSquashingParameters {
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(4096),
+        glwe_noise_distribution: DynamicDistribution::new_t_uniform(30),
+        decomp_base_log: DecompositionBaseLog(24),
+        decomp_level_count: DecompositionLevelCount(3),
+        ciphertext_modulus: CoreCiphertextModulus::<u128>::new_native(),
+    };
@@ -10,18 +10,19 @@ pub use super::key_switching_key::KeySwitchingKey;
 pub use super::parameters::key_switching::PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS;
 pub use super::parameters::{
     CarryModulus, CiphertextModulus, ClassicPBSParameters, DecompositionBaseLog,
-    DecompositionLevelCount, EncryptionKeyChoice, GlweDimension, LweDimension, MessageModulus,
-    PolynomialSize, StandardDev, PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
-    PARAM_MESSAGE_1_CARRY_2, PARAM_MESSAGE_1_CARRY_2_KS_PBS, PARAM_MESSAGE_1_CARRY_3,
-    PARAM_MESSAGE_1_CARRY_3_KS_PBS, PARAM_MESSAGE_1_CARRY_4, PARAM_MESSAGE_1_CARRY_4_KS_PBS,
-    PARAM_MESSAGE_1_CARRY_5, PARAM_MESSAGE_1_CARRY_5_KS_PBS, PARAM_MESSAGE_1_CARRY_6,
-    PARAM_MESSAGE_1_CARRY_6_KS_PBS, PARAM_MESSAGE_1_CARRY_7, PARAM_MESSAGE_1_CARRY_7_KS_PBS,
-    PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_3,
-    PARAM_MESSAGE_2_CARRY_3_KS_PBS, PARAM_MESSAGE_2_CARRY_4, PARAM_MESSAGE_2_CARRY_4_KS_PBS,
-    PARAM_MESSAGE_2_CARRY_5, PARAM_MESSAGE_2_CARRY_5_KS_PBS, PARAM_MESSAGE_2_CARRY_6,
-    PARAM_MESSAGE_2_CARRY_6_KS_PBS, PARAM_MESSAGE_3_CARRY_3, PARAM_MESSAGE_3_CARRY_3_KS_PBS,
-    PARAM_MESSAGE_3_CARRY_4, PARAM_MESSAGE_3_CARRY_4_KS_PBS, PARAM_MESSAGE_3_CARRY_5,
-    PARAM_MESSAGE_3_CARRY_5_KS_PBS, PARAM_MESSAGE_4_CARRY_4, PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    DecompositionLevelCount, DynamicDistribution, EncryptionKeyChoice, GlweDimension, LweDimension,
+    MessageModulus, PolynomialSize, SecretKeyDistribution, StandardDev, PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_1_CARRY_2, PARAM_MESSAGE_1_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_1_CARRY_3, PARAM_MESSAGE_1_CARRY_3_KS_PBS, PARAM_MESSAGE_1_CARRY_4,
+    PARAM_MESSAGE_1_CARRY_4_KS_PBS, PARAM_MESSAGE_1_CARRY_5, PARAM_MESSAGE_1_CARRY_5_KS_PBS,
+    PARAM_MESSAGE_1_CARRY_6, PARAM_MESSAGE_1_CARRY_6_KS_PBS, PARAM_MESSAGE_1_CARRY_7,
+    PARAM_MESSAGE_1_CARRY_7_KS_PBS, PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_3, PARAM_MESSAGE_2_CARRY_3_KS_PBS, PARAM_MESSAGE_2_CARRY_4,
+    PARAM_MESSAGE_2_CARRY_4_KS_PBS, PARAM_MESSAGE_2_CARRY_5, PARAM_MESSAGE_2_CARRY_5_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_6, PARAM_MESSAGE_2_CARRY_6_KS_PBS, PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS, PARAM_MESSAGE_3_CARRY_4, PARAM_MESSAGE_3_CARRY_4_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_5, PARAM_MESSAGE_3_CARRY_5_KS_PBS, PARAM_MESSAGE_4_CARRY_4,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
 };
 pub use super::public_key::{CompactPublicKey, PublicKey};
 pub use super::server_key::ServerKey;
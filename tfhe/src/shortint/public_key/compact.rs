@@ -10,8 +10,14 @@ use crate::core_crypto::prelude::{
 
 use crate::core_crypto::prelude::encrypt_lwe_ciphertext_with_compact_public_key;
 
+#[cfg(feature = "zk-pok-experimental")]
+use crate::core_crypto::prelude::encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key;
+#[cfg(feature = "zk-pok-experimental")]
+use crate::shortint::ciphertext::ProvenCompactCiphertextList;
 use crate::shortint::ciphertext::{CompactCiphertextList, Degree, NoiseLevel};
 use crate::shortint::{Ciphertext, ClientKey, PBSOrder, ShortintParameterSet};
+#[cfg(feature = "zk-pok-experimental")]
+use crate::zk::CompactPkePublicParams;
 
 use crate::shortint::engine::ShortintEngine;
 
@@ -54,11 +60,11 @@ impl CompactPublicKey {
             match client_key.parameters.encryption_key_choice().into() {
                 crate::shortint::PBSOrder::KeyswitchBootstrap => (
                     &client_key.large_lwe_secret_key,
-                    parameters.glwe_modular_std_dev(),
+                    parameters.glwe_noise_distribution(),
                 ),
                 crate::shortint::PBSOrder::BootstrapKeyswitch => (
                     &client_key.small_lwe_secret_key,
-                    parameters.lwe_modular_std_dev(),
+                    parameters.lwe_noise_distribution(),
                 ),
             };
 
@@ -100,8 +106,12 @@ impl CompactPublicKey {
         );
 
         let encryption_noise = match self.pbs_order {
-            crate::shortint::PBSOrder::KeyswitchBootstrap => self.parameters.glwe_modular_std_dev(),
-            crate::shortint::PBSOrder::BootstrapKeyswitch => self.parameters.lwe_modular_std_dev(),
+            crate::shortint::PBSOrder::KeyswitchBootstrap => {
+                self.parameters.glwe_noise_distribution()
+            }
+            crate::shortint::PBSOrder::BootstrapKeyswitch => {
+                self.parameters.lwe_noise_distribution()
+            }
         };
 
         ShortintEngine::with_thread_local_mut(|engine| {
@@ -153,8 +163,8 @@ impl CompactPublicKey {
                     &self.key,
                     &mut ct_list,
                     &plaintext_list,
-                    self.parameters.glwe_modular_std_dev(),
-                    self.parameters.lwe_modular_std_dev(),
+                    self.parameters.glwe_noise_distribution(),
+                    self.parameters.lwe_noise_distribution(),
                     &mut engine.secret_generator,
                     &mut engine.encryption_generator,
                 );
@@ -170,8 +180,8 @@ impl CompactPublicKey {
                     &self.key,
                     &mut ct_list,
                     &plaintext_list,
-                    self.parameters.glwe_modular_std_dev(),
-                    self.parameters.lwe_modular_std_dev(),
+                    self.parameters.glwe_noise_distribution(),
+                    self.parameters.lwe_noise_distribution(),
                     &mut engine.secret_generator,
                     &mut engine.encryption_generator,
                 );
@@ -189,6 +199,55 @@ impl CompactPublicKey {
         }
     }
 
+    /// Encrypts the messages and proves that the encryption is well formed
+    ///
+    /// The `public_params` must have been generated for the parameters of this key, see
+    /// [CompactPkeCrs::from_shortint_params](crate::zk::CompactPkeCrs::from_shortint_params).
+    #[cfg(feature = "zk-pok-experimental")]
+    pub fn encrypt_and_prove_slice(
+        &self,
+        messages: &[u64],
+        public_params: &CompactPkePublicParams,
+    ) -> ProvenCompactCiphertextList {
+        let plaintext_container = to_plaintext_iterator(messages.iter().copied(), &self.parameters)
+            .map(|plaintext| plaintext.0)
+            .collect::<Vec<_>>();
+
+        let plaintext_list = PlaintextList::from_container(plaintext_container);
+        let mut ct_list = LweCompactCiphertextListOwned::new(
+            0u64,
+            self.key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(plaintext_list.plaintext_count().0),
+            self.parameters.ciphertext_modulus(),
+        );
+
+        let proof = ShortintEngine::with_thread_local_mut(|engine| {
+            encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+                &self.key,
+                &mut ct_list,
+                &plaintext_list,
+                self.parameters.glwe_noise_distribution(),
+                self.parameters.lwe_noise_distribution(),
+                &mut engine.secret_generator,
+                &mut engine.encryption_generator,
+                public_params,
+            )
+        });
+
+        let message_modulus = self.parameters.message_modulus();
+        ProvenCompactCiphertextList {
+            ct_list: CompactCiphertextList {
+                ct_list,
+                degree: Degree(message_modulus.0 - 1),
+                message_modulus,
+                carry_modulus: self.parameters.carry_modulus(),
+                pbs_order: self.pbs_order,
+                noise_level: NoiseLevel::NOMINAL,
+            },
+            proof,
+        }
+    }
+
     pub fn size_elements(&self) -> usize {
         self.key.size_elements()
     }
@@ -212,11 +271,11 @@ impl CompressedCompactPublicKey {
             match client_key.parameters.encryption_key_choice().into() {
                 crate::shortint::PBSOrder::KeyswitchBootstrap => (
                     &client_key.large_lwe_secret_key,
-                    parameters.glwe_modular_std_dev(),
+                    parameters.glwe_noise_distribution(),
                 ),
                 crate::shortint::PBSOrder::BootstrapKeyswitch => (
                     &client_key.small_lwe_secret_key,
-                    parameters.lwe_modular_std_dev(),
+                    parameters.lwe_noise_distribution(),
                 ),
             };
 
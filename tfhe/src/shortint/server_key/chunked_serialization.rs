@@ -0,0 +1,1143 @@
+//! Chunked serialization of the server keys.
+//!
+//! Unlike [safe_serialize](crate::safe_deserialization::safe_serialize), which writes a key as a
+//! single bincode object, the functions of this module write the key as a small header followed
+//! by its components (the key switching key and the bootstrapping key), each one stored as a
+//! separate chunk:
+//!
+//! - the serialization version and the [Named] type name of the key,
+//! - a header containing the parameters of the key (its fingerprint), its max degree and max noise
+//!   level, and the length and checksum of each component,
+//! - the checksum of the header,
+//! - the components, in the order given by the header.
+//!
+//! When deserializing, the header is checked against the expected parameters before any
+//! component is read, and the length of each component is checked against the size implied by
+//! these parameters, so that an invalid or malicious input is rejected before the (potentially
+//! large) keys are allocated. Each component is then checked against its checksum.
+//!
+//! A [CompressedServerKey] can also be read and decompressed with
+//! [safe_deserialize_chunked_compressed_server_key_and_decompress], which converts the compressed
+//! classic bootstrapping key directly to the Fourier domain, so that the standard domain
+//! bootstrapping key is never allocated. Note that the whole compressed bootstrapping key is
+//! still read in memory before being converted.
+//!
+//! The integer and high level API server keys are serialized with the same format, under their
+//! own type names, by the `safe_serialize_chunked_*` functions of these APIs.
+
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::generators::MaskRandomGenerator;
+use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    LweDimension, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyOwned;
+use crate::core_crypto::fft_impl::fft64::crypto::ggsw::fill_with_forward_fourier_scratch;
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use crate::core_crypto::prelude::decompress_seeded_ggsw_ciphertext_with_existing_generator;
+use crate::named::Named;
+use crate::shortint::ciphertext::MaxNoiseLevel;
+use crate::shortint::parameters::{
+    CarryModulus, CiphertextModulus, EncryptionKeyChoice, MessageModulus, PBSParameters,
+};
+use crate::shortint::server_key::{
+    decompress_bootstrapping_key, MaxDegree, ShortintBootstrappingKey,
+    ShortintCompressedBootstrappingKey,
+};
+use crate::shortint::{CompressedServerKey, PBSOrder, ServerKey};
+use bincode::Options;
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+// Version of the chunked format, it is distinct from the version used by `safe_serialize` so that
+// reading a chunked key with `safe_deserialize` (or the other way around) gives a clear error.
+const CHUNKED_SERIALIZATION_VERSION: &str = "chunked-0.1";
+
+const VERSION_LENGTH_LIMIT: u64 = 100;
+
+const TYPE_NAME_LENGTH_LIMIT: u64 = 1000;
+
+const HEADER_SIZE_LIMIT: u64 = 4096;
+
+// Upper bound of the size of a serialized component which is not taken by the key elements
+// (dimensions, decomposition parameters, seed, etc.)
+const COMPONENT_METADATA_SIZE_LIMIT: u64 = 1024;
+
+// Upper bound of the size of the metadata stored with each ggsw ciphertext of a bootstrapping key
+const GGSW_METADATA_SIZE_LIMIT: u64 = 64;
+
+/// 64-bit FNV-1a hash, used to detect corrupted chunks
+struct Checksum(u64);
+
+impl Checksum {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Writer computing the length and checksum of what goes through it
+struct ChecksumWriter<W> {
+    inner: W,
+    length: u64,
+    checksum: Checksum,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            length: 0,
+            checksum: Checksum::new(),
+        }
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.length += written as u64;
+        self.checksum.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reader computing the length and checksum of what goes through it
+struct ChecksumReader<R> {
+    inner: R,
+    length: u64,
+    checksum: Checksum,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            length: 0,
+            checksum: Checksum::new(),
+        }
+    }
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.length += read as u64;
+        self.checksum.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// The parameters a server key was generated with
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeyParametersFingerprint {
+    lwe_dimension: LweDimension,
+    /// Input dimension of the bootstrapping key, larger than `lwe_dimension` for non binary
+    /// secret keys as the bootstrapping key is generated for the binary expansion of the key
+    bootstrap_lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    pbs_base_log: DecompositionBaseLog,
+    pbs_level: DecompositionLevelCount,
+    ks_base_log: DecompositionBaseLog,
+    ks_level: DecompositionLevelCount,
+    grouping_factor: Option<LweBskGroupingFactor>,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    ciphertext_modulus: CiphertextModulus,
+    pbs_order: PBSOrder,
+}
+
+impl From<&PBSParameters> for KeyParametersFingerprint {
+    fn from(parameters: &PBSParameters) -> Self {
+        let pbs_order = match parameters.encryption_key_choice() {
+            EncryptionKeyChoice::Big => PBSOrder::KeyswitchBootstrap,
+            EncryptionKeyChoice::Small => PBSOrder::BootstrapKeyswitch,
+        };
+        let grouping_factor = match parameters {
+            PBSParameters::PBS(_) => None,
+            PBSParameters::MultiBitPBS(multi_bit) => Some(multi_bit.grouping_factor),
+        };
+
+        Self {
+            lwe_dimension: parameters.lwe_dimension(),
+            bootstrap_lwe_dimension: LweDimension(
+                parameters.lwe_dimension().0
+                    * parameters
+                        .secret_key_distribution()
+                        .binary_expansion_factor(),
+            ),
+            glwe_dimension: parameters.glwe_dimension(),
+            polynomial_size: parameters.polynomial_size(),
+            pbs_base_log: parameters.pbs_base_log(),
+            pbs_level: parameters.pbs_level(),
+            ks_base_log: parameters.ks_base_log(),
+            ks_level: parameters.ks_level(),
+            grouping_factor,
+            message_modulus: parameters.message_modulus(),
+            carry_modulus: parameters.carry_modulus(),
+            ciphertext_modulus: parameters.ciphertext_modulus(),
+            pbs_order,
+        }
+    }
+}
+
+impl From<&ServerKey> for KeyParametersFingerprint {
+    fn from(server_key: &ServerKey) -> Self {
+        let bsk = &server_key.bootstrapping_key;
+        let grouping_factor = match bsk {
+            ShortintBootstrappingKey::Classic(_) => None,
+            ShortintBootstrappingKey::MultiBit { fourier_bsk, .. } => {
+                Some(fourier_bsk.grouping_factor())
+            }
+        };
+
+        Self {
+            lwe_dimension: server_key.key_switching_key.output_key_lwe_dimension(),
+            bootstrap_lwe_dimension: bsk.input_lwe_dimension(),
+            glwe_dimension: bsk.glwe_size().to_glwe_dimension(),
+            polynomial_size: bsk.polynomial_size(),
+            pbs_base_log: bsk.decomposition_base_log(),
+            pbs_level: bsk.decomposition_level_count(),
+            ks_base_log: server_key.key_switching_key.decomposition_base_log(),
+            ks_level: server_key.key_switching_key.decomposition_level_count(),
+            grouping_factor,
+            message_modulus: server_key.message_modulus,
+            carry_modulus: server_key.carry_modulus,
+            ciphertext_modulus: server_key.ciphertext_modulus,
+            pbs_order: server_key.pbs_order,
+        }
+    }
+}
+
+impl From<&CompressedServerKey> for KeyParametersFingerprint {
+    fn from(server_key: &CompressedServerKey) -> Self {
+        let (
+            bootstrap_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            pbs_base_log,
+            pbs_level,
+            grouping_factor,
+        ) = match &server_key.bootstrapping_key {
+            ShortintCompressedBootstrappingKey::Classic(bsk) => (
+                bsk.input_lwe_dimension(),
+                bsk.glwe_size(),
+                bsk.polynomial_size(),
+                bsk.decomposition_base_log(),
+                bsk.decomposition_level_count(),
+                None,
+            ),
+            ShortintCompressedBootstrappingKey::MultiBit { seeded_bsk, .. } => (
+                seeded_bsk.input_lwe_dimension(),
+                seeded_bsk.glwe_size(),
+                seeded_bsk.polynomial_size(),
+                seeded_bsk.decomposition_base_log(),
+                seeded_bsk.decomposition_level_count(),
+                Some(seeded_bsk.grouping_factor()),
+            ),
+        };
+
+        Self {
+            lwe_dimension: server_key.key_switching_key.output_key_lwe_dimension(),
+            bootstrap_lwe_dimension,
+            glwe_dimension: glwe_size.to_glwe_dimension(),
+            polynomial_size,
+            pbs_base_log,
+            pbs_level,
+            ks_base_log: server_key.key_switching_key.decomposition_base_log(),
+            ks_level: server_key.key_switching_key.decomposition_level_count(),
+            grouping_factor,
+            message_modulus: server_key.message_modulus,
+            carry_modulus: server_key.carry_modulus,
+            ciphertext_modulus: server_key.ciphertext_modulus,
+            pbs_order: server_key.pbs_order,
+        }
+    }
+}
+
+impl KeyParametersFingerprint {
+    /// Returns the number of ggsw ciphertexts in the bootstrapping key
+    fn ggsw_count(&self) -> Option<u64> {
+        let lwe_dimension = self.bootstrap_lwe_dimension.0 as u64;
+        match self.grouping_factor {
+            None => Some(lwe_dimension),
+            Some(grouping_factor) => {
+                let factor = grouping_factor.0 as u64;
+                if factor == 0 || lwe_dimension % factor != 0 {
+                    return None;
+                }
+                (lwe_dimension / factor)
+                    .checked_mul(grouping_factor.ggsw_per_multi_bit_element().0 as u64)
+            }
+        }
+    }
+
+    /// Returns the number of elements in the key switching key,
+    /// `is_seeded` selects the compressed key
+    fn key_switching_key_element_count(&self, is_seeded: bool) -> Option<u64> {
+        let input_dimension =
+            (self.glwe_dimension.0 as u64).checked_mul(self.polynomial_size.0 as u64)?;
+        let per_level = if is_seeded {
+            // One body per seeded ciphertext
+            1
+        } else {
+            (self.lwe_dimension.0 as u64).checked_add(1)?
+        };
+        input_dimension
+            .checked_mul(self.ks_level.0 as u64)?
+            .checked_mul(per_level)
+    }
+
+    /// Returns the number of elements in the standard domain bootstrapping key,
+    /// `is_seeded` selects the compressed key
+    fn bootstrapping_key_element_count(&self, is_seeded: bool) -> Option<u64> {
+        let glwe_size = (self.glwe_dimension.0 as u64).checked_add(1)?;
+        let per_row = if is_seeded {
+            // Only the body of each row is stored
+            1
+        } else {
+            glwe_size
+        };
+        self.ggsw_count()?
+            .checked_mul(self.pbs_level.0 as u64)?
+            .checked_mul(glwe_size)?
+            .checked_mul(per_row)?
+            .checked_mul(self.polynomial_size.0 as u64)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum ComponentKind {
+    KeySwitchingKey,
+    BootstrappingKey,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct ComponentDescriptor {
+    kind: ComponentKind,
+    length: u64,
+    checksum: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ChunkedServerKeyHeader {
+    parameters: KeyParametersFingerprint,
+    max_degree: MaxDegree,
+    max_noise_level: Option<MaxNoiseLevel>,
+    components: Vec<ComponentDescriptor>,
+}
+
+impl ChunkedServerKeyHeader {
+    /// Checks the header is conformant with the given parameters and describes components whose
+    /// sizes match these parameters.
+    fn check(&self, parameters: &PBSParameters, is_seeded: bool) -> Result<(), String> {
+        let expected_parameters = KeyParametersFingerprint::from(parameters);
+        if self.parameters != expected_parameters {
+            return Err(format!(
+                "Chunked server key not conformant with given parameter set, \
+                 expected {expected_parameters:?}, got {:?}",
+                self.parameters
+            ));
+        }
+
+        let max_degree = (self.parameters.message_modulus.0 * self.parameters.carry_modulus.0)
+            .checked_sub(1)
+            .ok_or("Invalid message and carry moduli in chunked server key header")?;
+        if self.max_degree.0 > max_degree {
+            return Err(format!(
+                "Invalid max degree {} in chunked server key header, it must not exceed {max_degree}",
+                self.max_degree.0
+            ));
+        }
+
+        if let Some(max_noise_level) = self.max_noise_level {
+            let default_max_noise_level = (self.parameters.message_modulus.0 > 1).then(|| {
+                MaxNoiseLevel::from_msg_carry_modulus(
+                    self.parameters.message_modulus,
+                    self.parameters.carry_modulus,
+                )
+            });
+            if max_noise_level.get() == 0
+                || default_max_noise_level
+                    .map_or(true, |default| max_noise_level.get() > default.get())
+            {
+                return Err(format!(
+                    "Invalid max noise level {} in chunked server key header, \
+                     it must be between 1 and {}",
+                    max_noise_level.get(),
+                    default_max_noise_level.map_or(0, |default| default.get())
+                ));
+            }
+        }
+
+        let kinds = self
+            .components
+            .iter()
+            .map(|component| component.kind)
+            .collect::<Vec<_>>();
+        if kinds
+            != [
+                ComponentKind::KeySwitchingKey,
+                ComponentKind::BootstrappingKey,
+            ]
+        {
+            return Err(format!(
+                "Invalid components in chunked server key header, \
+                 expected [KeySwitchingKey, BootstrappingKey], got {kinds:?}"
+            ));
+        }
+
+        for component in &self.components {
+            let (element_count, metadata_size) = match component.kind {
+                ComponentKind::KeySwitchingKey => (
+                    self.parameters.key_switching_key_element_count(is_seeded),
+                    Some(COMPONENT_METADATA_SIZE_LIMIT),
+                ),
+                ComponentKind::BootstrappingKey => (
+                    self.parameters.bootstrapping_key_element_count(is_seeded),
+                    self.parameters
+                        .ggsw_count()
+                        .and_then(|count| count.checked_mul(GGSW_METADATA_SIZE_LIMIT))
+                        .and_then(|size| size.checked_add(COMPONENT_METADATA_SIZE_LIMIT)),
+                ),
+            };
+            let max_length = element_count
+                .and_then(|count| count.checked_mul(std::mem::size_of::<u64>() as u64))
+                .zip(metadata_size)
+                .and_then(|(size, metadata_size)| size.checked_add(metadata_size))
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid parameters in chunked server key header: {:?}",
+                        self.parameters
+                    )
+                })?;
+            if component.length > max_length {
+                return Err(format!(
+                    "Invalid length for component {:?} in chunked server key header, \
+                     got {} bytes while parameters allow at most {max_length} bytes",
+                    component.kind, component.length
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn bincode_options() -> impl Options + Copy {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_limit(0)
+}
+
+fn describe_component<T: Serialize>(
+    kind: ComponentKind,
+    component: &T,
+) -> bincode::Result<ComponentDescriptor> {
+    let mut writer = ChecksumWriter::new(std::io::sink());
+    bincode_options()
+        .with_no_limit()
+        .serialize_into(&mut writer, component)?;
+    Ok(ComponentDescriptor {
+        kind,
+        length: writer.length,
+        checksum: writer.checksum.0,
+    })
+}
+
+fn serialize_chunked<K, Ksk, Bsk>(
+    mut writer: impl Write,
+    parameters: KeyParametersFingerprint,
+    max_degree: MaxDegree,
+    max_noise_level: Option<MaxNoiseLevel>,
+    key_switching_key: &Ksk,
+    bootstrapping_key: &Bsk,
+) -> bincode::Result<()>
+where
+    K: Named,
+    Ksk: Serialize,
+    Bsk: Serialize,
+{
+    let options = bincode_options();
+
+    // Components are serialized a first time to get their length and checksum,
+    // so that they can be written in the header without buffering them.
+    let header = ChunkedServerKeyHeader {
+        parameters,
+        max_degree,
+        max_noise_level,
+        components: vec![
+            describe_component(ComponentKind::KeySwitchingKey, key_switching_key)?,
+            describe_component(ComponentKind::BootstrappingKey, bootstrapping_key)?,
+        ],
+    };
+
+    options
+        .with_limit(VERSION_LENGTH_LIMIT)
+        .serialize_into::<_, String>(&mut writer, &CHUNKED_SERIALIZATION_VERSION.to_owned())?;
+
+    options
+        .with_limit(TYPE_NAME_LENGTH_LIMIT)
+        .serialize_into::<_, String>(&mut writer, &K::NAME.to_owned())?;
+
+    let mut header_writer = ChecksumWriter::new(&mut writer);
+    options
+        .with_limit(HEADER_SIZE_LIMIT)
+        .serialize_into(&mut header_writer, &header)?;
+    let header_checksum = header_writer.checksum.0;
+    options
+        .with_no_limit()
+        .serialize_into(&mut writer, &header_checksum)?;
+
+    options
+        .with_no_limit()
+        .serialize_into(&mut writer, key_switching_key)?;
+    options
+        .with_no_limit()
+        .serialize_into(&mut writer, bootstrapping_key)?;
+
+    Ok(())
+}
+
+fn deserialize_header<K: Named>(
+    mut reader: impl Read,
+    parameters: &PBSParameters,
+    is_seeded: bool,
+) -> Result<ChunkedServerKeyHeader, String> {
+    let options = bincode_options();
+
+    let deserialized_version: String = options
+        .with_limit(VERSION_LENGTH_LIMIT)
+        .deserialize_from::<_, String>(&mut reader)
+        .map_err(|err| err.to_string())?;
+
+    if deserialized_version != CHUNKED_SERIALIZATION_VERSION {
+        return Err(format!(
+            "On deserialization, expected serialization version {CHUNKED_SERIALIZATION_VERSION}, got version {deserialized_version}"
+        ));
+    }
+
+    let deserialized_type: String = options
+        .with_limit(TYPE_NAME_LENGTH_LIMIT)
+        .deserialize_from::<_, String>(&mut reader)
+        .map_err(|err| err.to_string())?;
+
+    if deserialized_type != K::NAME {
+        return Err(format!(
+            "On deserialization, expected type {}, got type {}",
+            K::NAME,
+            deserialized_type
+        ));
+    }
+
+    let mut header_reader = ChecksumReader::new(&mut reader);
+    let header: ChunkedServerKeyHeader = options
+        .with_limit(HEADER_SIZE_LIMIT)
+        .deserialize_from(&mut header_reader)
+        .map_err(|err| format!("Failed to deserialize chunked server key header: {err}"))?;
+    let computed_checksum = header_reader.checksum.0;
+
+    let header_checksum: u64 = options
+        .with_no_limit()
+        .deserialize_from(&mut reader)
+        .map_err(|err| err.to_string())?;
+
+    if header_checksum != computed_checksum {
+        return Err(
+            "Chunked server key header is corrupted, its checksum does not match".to_owned(),
+        );
+    }
+
+    header.check(parameters, is_seeded)?;
+
+    Ok(header)
+}
+
+fn deserialize_component<T: DeserializeOwned>(
+    reader: impl Read,
+    descriptor: &ComponentDescriptor,
+) -> Result<T, String> {
+    let mut component_reader = ChecksumReader::new(reader);
+    let component = bincode_options()
+        .with_limit(descriptor.length)
+        .deserialize_from(&mut component_reader)
+        .map_err(|err| {
+            format!(
+                "Failed to deserialize component {:?} of chunked server key: {err}",
+                descriptor.kind
+            )
+        })?;
+
+    if component_reader.length != descriptor.length {
+        return Err(format!(
+            "Invalid length for component {:?} of chunked server key, expected {} bytes, read {}",
+            descriptor.kind, descriptor.length, component_reader.length
+        ));
+    }
+
+    if component_reader.checksum.0 != descriptor.checksum {
+        return Err(format!(
+            "Component {:?} of chunked server key is corrupted, its checksum does not match",
+            descriptor.kind
+        ));
+    }
+
+    Ok(component)
+}
+
+fn check_component(
+    kind: ComponentKind,
+    expected_element_count: Option<u64>,
+    element_count: usize,
+    is_consistent: bool,
+) -> Result<(), String> {
+    if !is_consistent || expected_element_count != Some(element_count as u64) {
+        return Err(format!(
+            "Component {kind:?} of chunked server key does not match the header parameters"
+        ));
+    }
+    Ok(())
+}
+
+// Checks a (seeded or not) key switching key matches the parameters of the header
+macro_rules! check_key_switching_key {
+    ($parameters:expr, $key_switching_key:expr, $is_seeded:expr) => {{
+        let parameters: &KeyParametersFingerprint = $parameters;
+        let key_switching_key = $key_switching_key;
+        let is_consistent = key_switching_key.input_key_lwe_dimension()
+            == parameters
+                .glwe_dimension
+                .to_equivalent_lwe_dimension(parameters.polynomial_size)
+            && key_switching_key.output_key_lwe_dimension() == parameters.lwe_dimension
+            && key_switching_key.decomposition_base_log() == parameters.ks_base_log
+            && key_switching_key.decomposition_level_count() == parameters.ks_level
+            && key_switching_key.ciphertext_modulus() == parameters.ciphertext_modulus;
+
+        check_component(
+            ComponentKind::KeySwitchingKey,
+            parameters.key_switching_key_element_count($is_seeded),
+            key_switching_key.as_ref().len(),
+            is_consistent,
+        )
+    }};
+}
+
+/// Serializes a [ServerKey] into a [writer](std::io::Write) using the chunked format.
+///
+/// See the [module documentation](self) for details about the format.
+pub fn safe_serialize_chunked_server_key(
+    server_key: &ServerKey,
+    writer: impl Write,
+) -> bincode::Result<()> {
+    serialize_server_key_as::<ServerKey>(server_key, writer)
+}
+
+/// Serializes the shortint key of a server key whose [Named] type name is `K::NAME`,
+/// used by the integer and high level API keys
+pub(crate) fn serialize_server_key_as<K: Named>(
+    server_key: &ServerKey,
+    writer: impl Write,
+) -> bincode::Result<()> {
+    serialize_chunked::<K, _, _>(
+        writer,
+        KeyParametersFingerprint::from(server_key),
+        server_key.max_degree,
+        Some(server_key.max_noise_level),
+        &server_key.key_switching_key,
+        &server_key.bootstrapping_key,
+    )
+}
+
+/// Serializes a [CompressedServerKey] into a [writer](std::io::Write) using the chunked format.
+///
+/// See the [module documentation](self) for details about the format.
+pub fn safe_serialize_chunked_compressed_server_key(
+    server_key: &CompressedServerKey,
+    writer: impl Write,
+) -> bincode::Result<()> {
+    serialize_compressed_server_key_as::<CompressedServerKey>(server_key, writer)
+}
+
+/// Serializes the shortint key of a compressed server key whose [Named] type name is `K::NAME`,
+/// used by the integer and high level API keys
+pub(crate) fn serialize_compressed_server_key_as<K: Named>(
+    server_key: &CompressedServerKey,
+    writer: impl Write,
+) -> bincode::Result<()> {
+    serialize_chunked::<K, _, _>(
+        writer,
+        KeyParametersFingerprint::from(server_key),
+        server_key.max_degree,
+        None,
+        &server_key.key_switching_key,
+        &server_key.bootstrapping_key,
+    )
+}
+
+/// Deserializes a [ServerKey] serialized by [safe_serialize_chunked_server_key]
+/// from a [reader](std::io::Read).
+///
+/// Checks that the serialization version, the name of the type and the parameters of the key
+/// are correct before reading the components of the key, and checks that each component is
+/// not corrupted.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::gen_keys;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::shortint::server_key::chunked_serialization::{
+///     safe_deserialize_chunked_server_key, safe_serialize_chunked_server_key,
+/// };
+///
+/// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// let mut buffer = vec![];
+/// safe_serialize_chunked_server_key(&sks, &mut buffer).unwrap();
+///
+/// let sks: tfhe::shortint::ServerKey = safe_deserialize_chunked_server_key(
+///     buffer.as_slice(),
+///     &PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
+/// )
+/// .unwrap();
+///
+/// let ct = cks.encrypt(2);
+/// let ct_res = sks.unchecked_scalar_mul(&ct, 3);
+/// assert_eq!(cks.decrypt(&ct_res), 2);
+/// ```
+pub fn safe_deserialize_chunked_server_key(
+    reader: impl Read,
+    parameter_set: &PBSParameters,
+) -> Result<ServerKey, String> {
+    deserialize_server_key_as::<ServerKey>(reader, parameter_set)
+}
+
+/// Deserializes the shortint key of a server key whose [Named] type name is `K::NAME`,
+/// used by the integer and high level API keys
+pub(crate) fn deserialize_server_key_as<K: Named>(
+    mut reader: impl Read,
+    parameter_set: &PBSParameters,
+) -> Result<ServerKey, String> {
+    let header = deserialize_header::<K>(&mut reader, parameter_set, false)?;
+    let parameters = &header.parameters;
+
+    let key_switching_key: LweKeyswitchKeyOwned<u64> =
+        deserialize_component(&mut reader, &header.components[0])?;
+    check_key_switching_key!(parameters, &key_switching_key, false)?;
+
+    let bootstrapping_key: ShortintBootstrappingKey =
+        deserialize_component(&mut reader, &header.components[1])?;
+
+    let server_key = ServerKey {
+        key_switching_key,
+        bootstrapping_key,
+        message_modulus: parameters.message_modulus,
+        carry_modulus: parameters.carry_modulus,
+        max_degree: header.max_degree,
+        max_noise_level: header.max_noise_level.unwrap_or_else(|| {
+            MaxNoiseLevel::from_msg_carry_modulus(
+                parameters.message_modulus,
+                parameters.carry_modulus,
+            )
+        }),
+        ciphertext_modulus: parameters.ciphertext_modulus,
+        pbs_order: parameters.pbs_order,
+    };
+
+    // A fourier polynomial stores half as many (complex) elements as a standard one
+    check_component(
+        ComponentKind::BootstrappingKey,
+        parameters.bootstrapping_key_element_count(false),
+        server_key
+            .bootstrapping_key
+            .bootstrapping_key_size_elements()
+            * 2,
+        KeyParametersFingerprint::from(&server_key) == *parameters,
+    )?;
+
+    Ok(server_key)
+}
+
+/// Deserializes a [CompressedServerKey] serialized by
+/// [safe_serialize_chunked_compressed_server_key] from a [reader](std::io::Read).
+///
+/// Checks that the serialization version, the name of the type and the parameters of the key
+/// are correct before reading the components of the key, and checks that each component is
+/// not corrupted.
+pub fn safe_deserialize_chunked_compressed_server_key(
+    reader: impl Read,
+    parameter_set: &PBSParameters,
+) -> Result<CompressedServerKey, String> {
+    deserialize_compressed_server_key_as::<CompressedServerKey>(reader, parameter_set)
+}
+
+/// Deserializes the shortint key of a compressed server key whose [Named] type name is
+/// `K::NAME`, used by the integer and high level API keys
+pub(crate) fn deserialize_compressed_server_key_as<K: Named>(
+    mut reader: impl Read,
+    parameter_set: &PBSParameters,
+) -> Result<CompressedServerKey, String> {
+    let header = deserialize_header::<K>(&mut reader, parameter_set, true)?;
+    let parameters = &header.parameters;
+
+    let key_switching_key = deserialize_seeded_key_switching_key(&mut reader, &header)?;
+    let bootstrapping_key = deserialize_seeded_bootstrapping_key(&mut reader, &header)?;
+
+    Ok(CompressedServerKey {
+        key_switching_key,
+        bootstrapping_key,
+        message_modulus: parameters.message_modulus,
+        carry_modulus: parameters.carry_modulus,
+        max_degree: header.max_degree,
+        ciphertext_modulus: parameters.ciphertext_modulus,
+        pbs_order: parameters.pbs_order,
+    })
+}
+
+/// Deserializes a [CompressedServerKey] serialized by
+/// [safe_serialize_chunked_compressed_server_key] from a [reader](std::io::Read),
+/// and decompresses it into a [ServerKey].
+///
+/// The compressed classic bootstrapping key is converted to the Fourier domain one ggsw
+/// ciphertext at a time, so that the standard domain bootstrapping key is never allocated.
+/// The compressed bootstrapping key is however fully read before being converted, so the peak
+/// memory usage is the size of the compressed key plus the size of the decompressed one.
+///
+/// The same checks as [safe_deserialize_chunked_compressed_server_key] are done.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::shortint::server_key::chunked_serialization::{
+///     safe_deserialize_chunked_compressed_server_key_and_decompress,
+///     safe_serialize_chunked_compressed_server_key,
+/// };
+/// use tfhe::shortint::{ClientKey, CompressedServerKey};
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+/// let compressed_sks = CompressedServerKey::new(&cks);
+///
+/// let mut buffer = vec![];
+/// safe_serialize_chunked_compressed_server_key(&compressed_sks, &mut buffer).unwrap();
+///
+/// let sks = safe_deserialize_chunked_compressed_server_key_and_decompress(
+///     buffer.as_slice(),
+///     &PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
+/// )
+/// .unwrap();
+///
+/// let ct = cks.encrypt(2);
+/// let ct_res = sks.unchecked_scalar_mul(&ct, 3);
+/// assert_eq!(cks.decrypt(&ct_res), 2);
+/// ```
+pub fn safe_deserialize_chunked_compressed_server_key_and_decompress(
+    reader: impl Read,
+    parameter_set: &PBSParameters,
+) -> Result<ServerKey, String> {
+    deserialize_compressed_server_key_and_decompress_as::<CompressedServerKey>(
+        reader,
+        parameter_set,
+    )
+}
+
+/// Deserializes and decompresses the shortint key of a compressed server key whose [Named] type
+/// name is `K::NAME`, used by the integer and high level API keys
+pub(crate) fn deserialize_compressed_server_key_and_decompress_as<K: Named>(
+    mut reader: impl Read,
+    parameter_set: &PBSParameters,
+) -> Result<ServerKey, String> {
+    let header = deserialize_header::<K>(&mut reader, parameter_set, true)?;
+    let parameters = &header.parameters;
+
+    let key_switching_key = deserialize_seeded_key_switching_key(&mut reader, &header)?
+        .par_decompress_into_lwe_keyswitch_key();
+
+    let bootstrapping_key = match deserialize_seeded_bootstrapping_key(&mut reader, &header)? {
+        ShortintCompressedBootstrappingKey::Classic(seeded_bsk) => {
+            ShortintBootstrappingKey::Classic(par_decompress_seeded_bootstrap_key_to_fourier(
+                &seeded_bsk,
+            ))
+        }
+        multi_bit @ ShortintCompressedBootstrappingKey::MultiBit { .. } => {
+            decompress_bootstrapping_key(multi_bit)
+        }
+    };
+
+    Ok(ServerKey {
+        key_switching_key,
+        bootstrapping_key,
+        message_modulus: parameters.message_modulus,
+        carry_modulus: parameters.carry_modulus,
+        max_degree: header.max_degree,
+        max_noise_level: MaxNoiseLevel::from_msg_carry_modulus(
+            parameters.message_modulus,
+            parameters.carry_modulus,
+        ),
+        ciphertext_modulus: parameters.ciphertext_modulus,
+        pbs_order: parameters.pbs_order,
+    })
+}
+
+fn deserialize_seeded_key_switching_key(
+    reader: impl Read,
+    header: &ChunkedServerKeyHeader,
+) -> Result<SeededLweKeyswitchKeyOwned<u64>, String> {
+    let key_switching_key: SeededLweKeyswitchKeyOwned<u64> =
+        deserialize_component(reader, &header.components[0])?;
+    check_key_switching_key!(&header.parameters, &key_switching_key, true)?;
+    Ok(key_switching_key)
+}
+
+fn deserialize_seeded_bootstrapping_key(
+    reader: impl Read,
+    header: &ChunkedServerKeyHeader,
+) -> Result<ShortintCompressedBootstrappingKey, String> {
+    let parameters = &header.parameters;
+    let bootstrapping_key: ShortintCompressedBootstrappingKey =
+        deserialize_component(reader, &header.components[1])?;
+
+    let (lwe_dimension, glwe_size, polynomial_size, base_log, level, modulus, grouping_factor) =
+        match &bootstrapping_key {
+            ShortintCompressedBootstrappingKey::Classic(bsk) => (
+                bsk.input_lwe_dimension(),
+                bsk.glwe_size(),
+                bsk.polynomial_size(),
+                bsk.decomposition_base_log(),
+                bsk.decomposition_level_count(),
+                bsk.ciphertext_modulus(),
+                None,
+            ),
+            ShortintCompressedBootstrappingKey::MultiBit { seeded_bsk, .. } => (
+                seeded_bsk.input_lwe_dimension(),
+                seeded_bsk.glwe_size(),
+                seeded_bsk.polynomial_size(),
+                seeded_bsk.decomposition_base_log(),
+                seeded_bsk.decomposition_level_count(),
+                seeded_bsk.ciphertext_modulus(),
+                Some(seeded_bsk.grouping_factor()),
+            ),
+        };
+    let is_consistent = lwe_dimension == parameters.bootstrap_lwe_dimension
+        && glwe_size == parameters.glwe_dimension.to_glwe_size()
+        && polynomial_size == parameters.polynomial_size
+        && base_log == parameters.pbs_base_log
+        && level == parameters.pbs_level
+        && modulus == parameters.ciphertext_modulus
+        && grouping_factor == parameters.grouping_factor;
+
+    check_component(
+        ComponentKind::BootstrappingKey,
+        parameters.bootstrapping_key_element_count(true),
+        bootstrapping_key.bootstrapping_key_size_elements(),
+        is_consistent,
+    )?;
+
+    Ok(bootstrapping_key)
+}
+
+/// Decompresses a seeded bootstrapping key directly into the Fourier domain,
+/// one ggsw ciphertext at a time
+fn par_decompress_seeded_bootstrap_key_to_fourier(
+    seeded_bsk: &SeededLweBootstrapKeyOwned<u64>,
+) -> FourierLweBootstrapKeyOwned {
+    let input_lwe_dimension = seeded_bsk.input_lwe_dimension();
+    let glwe_size = seeded_bsk.glwe_size();
+    let polynomial_size = seeded_bsk.polynomial_size();
+    let decomposition_base_log = seeded_bsk.decomposition_base_log();
+    let decomposition_level_count = seeded_bsk.decomposition_level_count();
+    let ciphertext_modulus = seeded_bsk.ciphertext_modulus();
+
+    let mut fourier_bsk = FourierLweBootstrapKeyOwned::new(
+        input_lwe_dimension,
+        glwe_size,
+        polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count,
+    );
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    // The generator must be forked the same way as when the key was encrypted
+    let mut generator =
+        MaskRandomGenerator::<ActivatedRandomGenerator>::new(seeded_bsk.compression_seed().seed);
+    let gen_iter = generator
+        .par_fork_bsk_to_ggsw::<u64>(
+            input_lwe_dimension,
+            decomposition_level_count,
+            glwe_size,
+            polynomial_size,
+        )
+        .unwrap();
+
+    let fourier_ggsws = fourier_bsk
+        .as_mut_view()
+        .into_ggsw_iter()
+        .collect::<Vec<_>>();
+
+    fourier_ggsws
+        .into_par_iter()
+        .zip(seeded_bsk.par_iter())
+        .zip(gen_iter)
+        .for_each_init(
+            || {
+                let ggsw = GgswCiphertext::new(
+                    0u64,
+                    glwe_size,
+                    polynomial_size,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                    ciphertext_modulus,
+                );
+                let mut buffers = ComputationBuffers::new();
+                buffers.resize(
+                    fill_with_forward_fourier_scratch(fft)
+                        .unwrap()
+                        .unaligned_bytes_required(),
+                );
+                (ggsw, buffers)
+            },
+            |(ggsw, buffers), ((fourier_ggsw, seeded_ggsw), mut loop_generator)| {
+                decompress_seeded_ggsw_ciphertext_with_existing_generator(
+                    ggsw,
+                    &seeded_ggsw,
+                    &mut loop_generator,
+                );
+                fourier_ggsw.fill_with_forward_fourier(ggsw.as_view(), fft, buffers.stack());
+            },
+        );
+
+    fourier_bsk
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+        PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    };
+    use crate::shortint::ClientKey;
+
+    #[test]
+    fn test_chunked_server_key_serialization() {
+        for parameters in [
+            PBSParameters::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+            PBSParameters::from(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS),
+        ] {
+            let cks = ClientKey::new(parameters);
+            let sks = ServerKey::new(&cks);
+
+            let mut buffer = vec![];
+            safe_serialize_chunked_server_key(&sks, &mut buffer).unwrap();
+
+            let deserialized =
+                safe_deserialize_chunked_server_key(buffer.as_slice(), &parameters).unwrap();
+            assert_eq!(deserialized, sks);
+
+            let other_parameters = PBSParameters::from(PARAM_MESSAGE_3_CARRY_3_KS_PBS);
+            let err = safe_deserialize_chunked_server_key(buffer.as_slice(), &other_parameters)
+                .unwrap_err();
+            assert!(err.contains("not conformant"), "{err}");
+
+            let err =
+                safe_deserialize_chunked_compressed_server_key(buffer.as_slice(), &parameters)
+                    .unwrap_err();
+            assert!(err.contains("expected type"), "{err}");
+
+            let mut invalid_sks = sks.clone();
+            invalid_sks.max_noise_level = MaxNoiseLevel::new(0);
+            let mut buffer = vec![];
+            safe_serialize_chunked_server_key(&invalid_sks, &mut buffer).unwrap();
+            let err =
+                safe_deserialize_chunked_server_key(buffer.as_slice(), &parameters).unwrap_err();
+            assert!(err.contains("max noise level"), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_chunked_compressed_server_key_serialization() {
+        for parameters in [
+            PBSParameters::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+            PBSParameters::from(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS),
+        ] {
+            let cks = ClientKey::new(parameters);
+            let compressed_sks = CompressedServerKey::new(&cks);
+
+            let mut buffer = vec![];
+            safe_serialize_chunked_compressed_server_key(&compressed_sks, &mut buffer).unwrap();
+
+            let deserialized =
+                safe_deserialize_chunked_compressed_server_key(buffer.as_slice(), &parameters)
+                    .unwrap();
+            assert_eq!(deserialized, compressed_sks);
+
+            let sks = safe_deserialize_chunked_compressed_server_key_and_decompress(
+                buffer.as_slice(),
+                &parameters,
+            )
+            .unwrap();
+            assert_eq!(sks, ServerKey::from(compressed_sks));
+
+            let msg = 2;
+            let ct = cks.encrypt(msg);
+            let acc = sks.generate_lookup_table(|x| (x + 1) % 4);
+            let ct_res = sks.apply_lookup_table(&ct, &acc);
+            assert_eq!(cks.decrypt(&ct_res), msg + 1);
+        }
+    }
+
+    #[test]
+    fn test_chunked_server_key_corruption() {
+        let parameters = PBSParameters::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let cks = ClientKey::new(parameters);
+        let compressed_sks = CompressedServerKey::new(&cks);
+
+        let mut buffer = vec![];
+        safe_serialize_chunked_compressed_server_key(&compressed_sks, &mut buffer).unwrap();
+
+        // Corrupt a byte of the elements of the bootstrapping key, which is the last component
+        let mut corrupted = buffer.clone();
+        let index = corrupted.len() - 1024;
+        corrupted[index] ^= 1;
+        let err = safe_deserialize_chunked_compressed_server_key(corrupted.as_slice(), &parameters)
+            .unwrap_err();
+        assert!(
+            err.contains("BootstrappingKey") && err.contains("corrupted"),
+            "{err}"
+        );
+
+        // Truncated input
+        let truncated = &buffer[..buffer.len() / 2];
+        assert!(
+            safe_deserialize_chunked_compressed_server_key_and_decompress(truncated, &parameters)
+                .is_err()
+        );
+
+        // Corrupt a byte of the header, which comes right after the version and type name
+        let mut corrupted = buffer;
+        let header_start =
+            8 + CHUNKED_SERIALIZATION_VERSION.len() + 8 + CompressedServerKey::NAME.len();
+        corrupted[header_start] ^= 1;
+        let err = safe_deserialize_chunked_compressed_server_key(corrupted.as_slice(), &parameters)
+            .unwrap_err();
+        assert!(err.contains("header"), "{err}");
+    }
+}
@@ -18,21 +18,27 @@ mod sub;
 pub mod compressed;
 pub use compressed::{CompressedServerKey, ShortintCompressedBootstrappingKey};
 
+#[cfg(feature = "safe-deserialization")]
+pub mod chunked_serialization;
+
 #[cfg(test)]
 mod tests;
 
 use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
-    ThreadCount,
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, MonomialDegree,
+    PolynomialSize, ThreadCount,
 };
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
-use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
+    FourierLweBootstrapKey, FourierLweBootstrapKeyOwned,
+};
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use crate::shortint::ciphertext::{Ciphertext, Degree, MaxNoiseLevel, NoiseLevel};
 use crate::shortint::client_key::ClientKey;
-use crate::shortint::engine::{fill_accumulator, ShortintEngine};
+use crate::shortint::engine::{fill_accumulator, fill_many_lut_accumulator, ShortintEngine};
 use crate::shortint::parameters::{
     CarryModulus, CiphertextConformanceParams, CiphertextModulus, MessageModulus,
 };
@@ -281,6 +287,10 @@ pub struct ServerKey {
     pub pbs_order: PBSOrder,
 }
 
+impl crate::named::Named for ServerKey {
+    const NAME: &'static str = "shortint::ServerKey";
+}
+
 impl ServerKey {
     pub fn conformance_params(&self) -> CiphertextConformanceParams {
         let lwe_dim = match self.pbs_order {
@@ -340,6 +350,29 @@ pub type LookupTableOwned = LookupTable<Vec<u64>>;
 pub type LookupTableMutView<'a> = LookupTable<&'a mut [u64]>;
 pub type LookupTableView<'a> = LookupTable<&'a [u64]>;
 
+/// A lookup table packing several functions, evaluated with a single blind rotation.
+///
+/// The message space is shared between the functions, so the input ciphertext's degree
+/// must not exceed `input_max_degree`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct ManyLookupTable<C: Container<Element = u64>> {
+    pub acc: GlweCiphertext<C>,
+    pub input_max_degree: MaxDegree,
+    // Distance (in coefficients) between the sub-tables of two consecutive functions
+    pub sample_extraction_stride: usize,
+    pub per_function_output_degree: Vec<Degree>,
+}
+
+pub type ManyLookupTableOwned = ManyLookupTable<Vec<u64>>;
+
+impl<C: Container<Element = u64>> ManyLookupTable<C> {
+    /// Returns the number of functions packed in the lookup table.
+    pub fn function_count(&self) -> usize {
+        self.per_function_output_degree.len()
+    }
+}
+
 #[must_use]
 pub struct BivariateLookupTable<C: Container<Element = u64>> {
     // A bivariate lookup table is an univariate loolookup table
@@ -472,6 +505,59 @@ impl ServerKey {
         self.generate_lookup_table(|x| f(x % modulus.0 as u64) % modulus.0 as u64)
     }
 
+    /// Constructs a lookup table evaluating all the given functions at once.
+    ///
+    /// The message space is evenly split between the functions, e.g. with 4 functions on
+    /// PARAM_MESSAGE_2_CARRY_2 parameters (message space of 16), the input must be in `0..4`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no functions or more functions than values in the message space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let f1 = |x: u64| x * x % 4;
+    /// let f2 = |x: u64| (x + 1) % 4;
+    /// let f3 = |x: u64| x / 2;
+    ///
+    /// let lut = sks.generate_many_lookup_table(&[&f1, &f2, &f3]);
+    /// let results = sks.apply_many_lookup_table(&ct, &lut);
+    ///
+    /// let dec: Vec<u64> = results.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(dec, vec![f1(msg), f2(msg), f3(msg)]);
+    /// ```
+    pub fn generate_many_lookup_table(
+        &self,
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> ManyLookupTableOwned {
+        let mut acc = GlweCiphertext::new(
+            0,
+            self.bootstrapping_key.glwe_size(),
+            self.bootstrapping_key.polynomial_size(),
+            self.ciphertext_modulus,
+        );
+        let (input_max_degree, sample_extraction_stride, per_function_output_degree) =
+            fill_many_lut_accumulator(&mut acc, self, functions);
+
+        ManyLookupTableOwned {
+            acc,
+            input_max_degree,
+            sample_extraction_stride,
+            per_function_output_degree,
+        }
+    }
+
     /// Generates a bivariate accumulator
     pub fn generate_lookup_table_bivariate_with_factor<F>(
         &self,
@@ -700,6 +786,47 @@ impl ServerKey {
         };
     }
 
+    /// Evaluates all the functions of a [ManyLookupTable] on the ciphertext,
+    /// using a single blind rotation.
+    ///
+    /// Returns one ciphertext per function, in the order they were given to
+    /// [Self::generate_many_lookup_table].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degree of `ct` exceeds the `input_max_degree` of the lookup table.
+    pub fn apply_many_lookup_table(
+        &self,
+        ct: &Ciphertext,
+        acc: &ManyLookupTableOwned,
+    ) -> Vec<Ciphertext> {
+        assert!(
+            ct.degree.0 <= acc.input_max_degree.0,
+            "The ciphertext degree ({}) exceeds the max input degree of the lookup table ({})",
+            ct.degree.0,
+            acc.input_max_degree.0
+        );
+
+        if ct.is_trivial() {
+            return self.trivial_many_lut(ct, acc);
+        }
+
+        let mut outputs = match self.pbs_order {
+            PBSOrder::KeyswitchBootstrap => self.keyswitch_programmable_bootstrap_many_lut(ct, acc),
+            PBSOrder::BootstrapKeyswitch => self.programmable_bootstrap_keyswitch_many_lut(ct, acc),
+        };
+
+        for (output, degree) in outputs
+            .iter_mut()
+            .zip(acc.per_function_output_degree.iter())
+        {
+            output.degree = *degree;
+            output.set_noise_level(NoiseLevel::NOMINAL);
+        }
+
+        outputs
+    }
+
     /// Generic programmable bootstrap where messages are concatenated into one ciphertext to
     /// evaluate a bivariate function. This is used to apply many binary operations (comparisons,
     /// multiplications, division).
@@ -990,9 +1117,7 @@ impl ServerKey {
             PBSOrder::KeyswitchBootstrap => {
                 self.bootstrapping_key.output_lwe_dimension().to_lwe_size()
             }
-            PBSOrder::BootstrapKeyswitch => {
-                self.bootstrapping_key.input_lwe_dimension().to_lwe_size()
-            }
+            PBSOrder::BootstrapKeyswitch => self.key_switching_key.output_lwe_size(),
         };
 
         let delta = (1_u64 << 63) / (self.message_modulus.0 * self.carry_modulus.0) as u64;
@@ -1079,6 +1204,154 @@ impl ServerKey {
         ct.degree = acc.degree;
     }
 
+    fn trivial_many_lut(&self, ct: &Ciphertext, acc: &ManyLookupTableOwned) -> Vec<Ciphertext> {
+        assert_eq!(ct.noise_level(), NoiseLevel::ZERO);
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        let delta = (1_u64 << 63) / (self.message_modulus.0 * self.carry_modulus.0) as u64;
+        let ct_value = *ct.ct.get_body().data / delta;
+
+        let box_size = self.bootstrapping_key.polynomial_size().0 / modulus_sup;
+        let padding_bit_is_set = ct_value >= modulus_sup as u64;
+        let ct_value = ct_value % modulus_sup as u64;
+
+        acc.per_function_output_degree
+            .iter()
+            .enumerate()
+            .map(|(fn_idx, degree)| {
+                let index_in_lut =
+                    ct_value as usize * box_size + fn_idx * acc.sample_extraction_stride;
+                let value = acc.acc.get_body().as_ref()[index_in_lut];
+                let mut output = ct.clone();
+                *output.ct.get_mut_body().data = if padding_bit_is_set {
+                    value.wrapping_neg()
+                } else {
+                    value
+                };
+                output.degree = *degree;
+                output
+            })
+            .collect()
+    }
+
+    /// Blind rotates the accumulator by the (small key) input ciphertext.
+    fn blind_rotate_assign<InputCont>(
+        &self,
+        input: &LweCiphertext<InputCont>,
+        acc: &mut GlweCiphertextOwned<u64>,
+        buffers: &mut ComputationBuffers,
+    ) where
+        InputCont: Container<Element = u64>,
+    {
+        match &self.bootstrapping_key {
+            ShortintBootstrappingKey::Classic(fourier_bsk) => {
+                let fft = Fft::new(fourier_bsk.polynomial_size());
+                let fft = fft.as_view();
+                buffers.resize(
+                    blind_rotate_assign_mem_optimized_requirement::<u64>(
+                        fourier_bsk.glwe_size(),
+                        fourier_bsk.polynomial_size(),
+                        fft,
+                    )
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+                let stack = buffers.stack();
+
+                let expanded_input = expand_bootstrap_input(input, fourier_bsk);
+                let input = expanded_input
+                    .as_ref()
+                    .map_or_else(|| input.as_view(), LweCiphertext::as_view);
+                blind_rotate_assign_mem_optimized(&input, acc, fourier_bsk, fft, stack);
+            }
+            ShortintBootstrappingKey::MultiBit {
+                fourier_bsk,
+                thread_count,
+                deterministic_execution,
+            } => {
+                if *deterministic_execution {
+                    multi_bit_deterministic_blind_rotate_assign(
+                        input,
+                        acc,
+                        fourier_bsk,
+                        *thread_count,
+                    );
+                } else {
+                    multi_bit_blind_rotate_assign(input, acc, fourier_bsk, *thread_count);
+                }
+            }
+        }
+    }
+
+    fn keyswitch_programmable_bootstrap_many_lut(
+        &self,
+        ct: &Ciphertext,
+        acc: &ManyLookupTableOwned,
+    ) -> Vec<Ciphertext> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let (mut ciphertext_buffers, buffers) = engine.get_buffers(self);
+
+            // Compute a key switch
+            keyswitch_lwe_ciphertext(
+                &self.key_switching_key,
+                &ct.ct,
+                &mut ciphertext_buffers.buffer_lwe_after_ks,
+            );
+
+            let mut rotated_acc = acc.acc.clone();
+            self.blind_rotate_assign(
+                &ciphertext_buffers.buffer_lwe_after_ks,
+                &mut rotated_acc,
+                buffers,
+            );
+
+            // Each function's result sits at the start of its sub-table
+            (0..acc.function_count())
+                .map(|fn_idx| {
+                    let mut output = ct.clone();
+                    extract_lwe_sample_from_glwe_ciphertext(
+                        &rotated_acc,
+                        &mut output.ct,
+                        MonomialDegree(fn_idx * acc.sample_extraction_stride),
+                    );
+                    output
+                })
+                .collect()
+        })
+    }
+
+    fn programmable_bootstrap_keyswitch_many_lut(
+        &self,
+        ct: &Ciphertext,
+        acc: &ManyLookupTableOwned,
+    ) -> Vec<Ciphertext> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let (mut ciphertext_buffers, buffers) = engine.get_buffers(self);
+
+            let mut rotated_acc = acc.acc.clone();
+            self.blind_rotate_assign(&ct.ct, &mut rotated_acc, buffers);
+
+            // Each function's result sits at the start of its sub-table
+            (0..acc.function_count())
+                .map(|fn_idx| {
+                    extract_lwe_sample_from_glwe_ciphertext(
+                        &rotated_acc,
+                        &mut ciphertext_buffers.buffer_lwe_after_pbs,
+                        MonomialDegree(fn_idx * acc.sample_extraction_stride),
+                    );
+
+                    // Compute a key switch
+                    let mut output = ct.clone();
+                    keyswitch_lwe_ciphertext(
+                        &self.key_switching_key,
+                        &ciphertext_buffers.buffer_lwe_after_pbs,
+                        &mut output.ct,
+                    );
+                    output
+                })
+                .collect()
+        })
+    }
+
     pub(crate) fn keyswitch_programmable_bootstrap_assign(
         &self,
         ct: &mut Ciphertext,
@@ -1116,8 +1389,16 @@ impl ServerKey {
                     let stack = buffers.stack();
 
                     // Compute a bootstrap
-                    programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                    let expanded_input = expand_bootstrap_input(
                         &ciphertext_buffers.buffer_lwe_after_ks,
+                        fourier_bsk,
+                    );
+                    let input = expanded_input.as_ref().map_or_else(
+                        || ciphertext_buffers.buffer_lwe_after_ks.as_view(),
+                        LweCiphertext::as_view,
+                    );
+                    programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                        &input,
                         &mut ct.ct,
                         &acc.acc,
                         fourier_bsk,
@@ -1184,8 +1465,9 @@ impl ServerKey {
                     let stack = buffers.stack();
 
                     // Compute a bootstrap
+                    let expanded_input = expand_bootstrap_input(&ct.ct, fourier_bsk);
                     programmable_bootstrap_lwe_ciphertext_mem_optimized(
-                        &ct.ct,
+                        expanded_input.as_ref().unwrap_or(&ct.ct),
                         &mut ciphertext_buffers.buffer_lwe_after_pbs,
                         &acc.acc,
                         fourier_bsk,
@@ -1231,6 +1513,20 @@ impl ServerKey {
     }
 }
 
+/// Returns the input re-encoded for the binary expansion of its secret key if the bootstrapping
+/// key was generated for a non binary key, `None` if it can be bootstrapped as is.
+fn expand_bootstrap_input<InputCont>(
+    input: &LweCiphertext<InputCont>,
+    fourier_bsk: &FourierLweBootstrapKeyOwned,
+) -> Option<LweCiphertextOwned<u64>>
+where
+    InputCont: Container<Element = u64>,
+{
+    (input.lwe_size().to_lwe_dimension() != fourier_bsk.input_lwe_dimension()).then(|| {
+        allocate_and_expand_lwe_ciphertext_for_binary_key(input, fourier_bsk.input_lwe_dimension())
+    })
+}
+
 impl From<CompressedServerKey> for ServerKey {
     fn from(compressed_server_key: CompressedServerKey) -> Self {
         let CompressedServerKey {
@@ -1245,65 +1541,7 @@ impl From<CompressedServerKey> for ServerKey {
 
         let (key_switching_key, bootstrapping_key) = rayon::join(
             || key_switching_key.par_decompress_into_lwe_keyswitch_key(),
-            || match bootstrapping_key {
-                ShortintCompressedBootstrappingKey::Classic(bootstrapping_key) => {
-                    let standard_bootstrapping_key =
-                        bootstrapping_key.par_decompress_into_lwe_bootstrap_key();
-
-                    let mut bootstrapping_key = FourierLweBootstrapKeyOwned::new(
-                        standard_bootstrapping_key.input_lwe_dimension(),
-                        standard_bootstrapping_key.glwe_size(),
-                        standard_bootstrapping_key.polynomial_size(),
-                        standard_bootstrapping_key.decomposition_base_log(),
-                        standard_bootstrapping_key.decomposition_level_count(),
-                    );
-
-                    par_convert_standard_lwe_bootstrap_key_to_fourier(
-                        &standard_bootstrapping_key,
-                        &mut bootstrapping_key,
-                    );
-
-                    ShortintBootstrappingKey::Classic(bootstrapping_key)
-                }
-                ShortintCompressedBootstrappingKey::MultiBit {
-                    seeded_bsk: bootstrapping_key,
-                    deterministic_execution,
-                } => {
-                    let standard_bootstrapping_key =
-                        bootstrapping_key.par_decompress_into_lwe_multi_bit_bootstrap_key();
-
-                    let mut bootstrapping_key = FourierLweMultiBitBootstrapKeyOwned::new(
-                        standard_bootstrapping_key.input_lwe_dimension(),
-                        standard_bootstrapping_key.glwe_size(),
-                        standard_bootstrapping_key.polynomial_size(),
-                        standard_bootstrapping_key.decomposition_base_log(),
-                        standard_bootstrapping_key.decomposition_level_count(),
-                        standard_bootstrapping_key.grouping_factor(),
-                    );
-
-                    par_convert_standard_lwe_multi_bit_bootstrap_key_to_fourier(
-                        &standard_bootstrapping_key,
-                        &mut bootstrapping_key,
-                    );
-
-                    let thread_count = ShortintEngine::with_thread_local_mut(|engine| {
-                        engine.get_thread_count_for_multi_bit_pbs(
-                            standard_bootstrapping_key.input_lwe_dimension(),
-                            standard_bootstrapping_key.glwe_size().to_glwe_dimension(),
-                            standard_bootstrapping_key.polynomial_size(),
-                            standard_bootstrapping_key.decomposition_base_log(),
-                            standard_bootstrapping_key.decomposition_level_count(),
-                            standard_bootstrapping_key.grouping_factor(),
-                        )
-                    });
-
-                    ShortintBootstrappingKey::MultiBit {
-                        fourier_bsk: bootstrapping_key,
-                        thread_count,
-                        deterministic_execution,
-                    }
-                }
-            },
+            || decompress_bootstrapping_key(bootstrapping_key),
         );
 
         let max_noise_level = MaxNoiseLevel::from_msg_carry_modulus(message_modulus, carry_modulus);
@@ -1320,3 +1558,68 @@ impl From<CompressedServerKey> for ServerKey {
         }
     }
 }
+
+/// Decompresses a seeded bootstrapping key and converts it to the Fourier domain
+pub(crate) fn decompress_bootstrapping_key(
+    bootstrapping_key: ShortintCompressedBootstrappingKey,
+) -> ShortintBootstrappingKey {
+    match bootstrapping_key {
+        ShortintCompressedBootstrappingKey::Classic(bootstrapping_key) => {
+            let standard_bootstrapping_key =
+                bootstrapping_key.par_decompress_into_lwe_bootstrap_key();
+
+            let mut bootstrapping_key = FourierLweBootstrapKeyOwned::new(
+                standard_bootstrapping_key.input_lwe_dimension(),
+                standard_bootstrapping_key.glwe_size(),
+                standard_bootstrapping_key.polynomial_size(),
+                standard_bootstrapping_key.decomposition_base_log(),
+                standard_bootstrapping_key.decomposition_level_count(),
+            );
+
+            par_convert_standard_lwe_bootstrap_key_to_fourier(
+                &standard_bootstrapping_key,
+                &mut bootstrapping_key,
+            );
+
+            ShortintBootstrappingKey::Classic(bootstrapping_key)
+        }
+        ShortintCompressedBootstrappingKey::MultiBit {
+            seeded_bsk: bootstrapping_key,
+            deterministic_execution,
+        } => {
+            let standard_bootstrapping_key =
+                bootstrapping_key.par_decompress_into_lwe_multi_bit_bootstrap_key();
+
+            let mut bootstrapping_key = FourierLweMultiBitBootstrapKeyOwned::new(
+                standard_bootstrapping_key.input_lwe_dimension(),
+                standard_bootstrapping_key.glwe_size(),
+                standard_bootstrapping_key.polynomial_size(),
+                standard_bootstrapping_key.decomposition_base_log(),
+                standard_bootstrapping_key.decomposition_level_count(),
+                standard_bootstrapping_key.grouping_factor(),
+            );
+
+            par_convert_standard_lwe_multi_bit_bootstrap_key_to_fourier(
+                &standard_bootstrapping_key,
+                &mut bootstrapping_key,
+            );
+
+            let thread_count = ShortintEngine::with_thread_local_mut(|engine| {
+                engine.get_thread_count_for_multi_bit_pbs(
+                    standard_bootstrapping_key.input_lwe_dimension(),
+                    standard_bootstrapping_key.glwe_size().to_glwe_dimension(),
+                    standard_bootstrapping_key.polynomial_size(),
+                    standard_bootstrapping_key.decomposition_base_log(),
+                    standard_bootstrapping_key.decomposition_level_count(),
+                    standard_bootstrapping_key.grouping_factor(),
+                )
+            });
+
+            ShortintBootstrappingKey::MultiBit {
+                fourier_bsk: bootstrapping_key,
+                thread_count,
+                deterministic_execution,
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::*;
 use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::Ciphertext;
 use paste::paste;
 use rand::Rng;
 
@@ -169,6 +170,46 @@ macro_rules! create_parametrized_test_bivariate_pbs_compliant{
     };
 }
 
+// Ternary secret keys only change the keys, run a representative subset of the operations
+create_parametrized_test!(shortint_encrypt_decrypt {
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS_TERNARY,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TERNARY,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_TERNARY
+});
+create_parametrized_test!(shortint_keyswitch_programmable_bootstrap {
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS_TERNARY,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TERNARY,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_TERNARY
+});
+create_parametrized_test!(shortint_many_lookup_table {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TERNARY
+});
+create_parametrized_test!(shortint_smart_add {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TERNARY
+});
+create_parametrized_test!(shortint_unchecked_mul_lsb {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TERNARY
+});
+
+// Bounded noise only changes the keys and fresh encryptions, run a representative subset of the
+// operations
+create_parametrized_test!(shortint_encrypt_decrypt {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM
+});
+create_parametrized_test!(shortint_keyswitch_programmable_bootstrap {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM
+});
+create_parametrized_test!(shortint_unchecked_mul_lsb {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM
+});
+
+create_parametrized_test!(shortint_decrypt_with_noise {
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM
+});
+
 //These functions are compatible with all parameter sets.
 create_parametrized_test!(shortint_encrypt_decrypt);
 create_parametrized_test!(shortint_encrypt_with_message_modulus_decrypt);
@@ -178,6 +219,7 @@ create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
 create_parametrized_test!(shortint_generate_lookup_table);
+create_parametrized_test!(shortint_many_lookup_table);
 create_parametrized_test!(shortint_unchecked_add);
 create_parametrized_test!(shortint_smart_add);
 create_parametrized_test!(shortint_default_add);
@@ -310,6 +352,85 @@ where
     }
 }
 
+/// test the noise measured at decryption against the noise model
+fn shortint_decrypt_with_noise<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    // A sample exceeding the standard deviation by 6 bits, i.e. 64 standard deviations, is not
+    // going to happen
+    let check_noise = |ct: &Ciphertext, clear: u64| {
+        let (dec, noise_bits) = cks.decrypt_with_noise(ct);
+        assert_eq!(dec, clear);
+        assert_eq!(dec, cks.decrypt(ct));
+        let expected_noise_bits = cks.expected_noise_bits(ct).unwrap();
+        assert!(
+            noise_bits < expected_noise_bits + 6.0,
+            "noise bits: {noise_bits}, expected noise bits: {expected_noise_bits}"
+        );
+    };
+
+    for _ in 0..NB_TESTS {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+        check_noise(&ct, clear);
+
+        let ct = sks.unchecked_scalar_mul(&ct, 2);
+        assert_eq!(ct.noise_level(), NoiseLevel::NOMINAL * 2);
+        check_noise(&ct, (clear * 2) % modulus);
+
+        let ct = sks.message_extract(&ct);
+        check_noise(&ct, (clear * 2) % modulus);
+    }
+
+    // Trivial ciphertexts have no noise
+    let ct = sks.create_trivial(1);
+    let (dec, noise_bits) = cks.decrypt_with_noise(&ct);
+    assert_eq!(dec, 1);
+    assert_eq!(noise_bits, f64::NEG_INFINITY);
+    assert_eq!(cks.expected_noise_bits(&ct), Some(f64::NEG_INFINITY));
+}
+
+/// test that the noise bits are expressed with respect to a non native ciphertext modulus
+#[test]
+fn test_shortint_decrypt_with_noise_non_native_modulus() {
+    let native_cks = crate::shortint::ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let mut params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    params.ciphertext_modulus = CiphertextModulus::try_new_power_of_2(62).unwrap();
+    let cks = crate::shortint::ClientKey::new(params);
+
+    let mut rng = rand::thread_rng();
+    let modulus = params.message_modulus.0 as u64;
+
+    for _ in 0..NB_TESTS {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+        let (dec, noise_bits) = cks.decrypt_with_noise(&ct);
+        assert_eq!(dec, clear);
+
+        // The same torus noise is 2 bits smaller in the integer domain of a 2^62 modulus
+        let expected_noise_bits = cks.expected_noise_bits(&ct).unwrap();
+        let native_expected_noise_bits = native_cks
+            .expected_noise_bits(&native_cks.encrypt(clear))
+            .unwrap();
+        assert!((expected_noise_bits - (native_expected_noise_bits - 2.0)).abs() < 1e-9);
+        assert!(
+            noise_bits < expected_noise_bits + 6.0,
+            "noise bits: {noise_bits}, expected noise bits: {expected_noise_bits}"
+        );
+    }
+}
+
 /// test encryption and decryption with the LWE client key
 fn shortint_encrypt_with_message_modulus_decrypt<P>(param: P)
 where
@@ -559,6 +680,63 @@ where
     }
 }
 
+/// test the evaluation of several functions with a single PBS
+fn shortint_many_lookup_table<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let msg_modulus = sks.message_modulus.0 as u64;
+    let full_modulus = (sks.message_modulus.0 * sks.carry_modulus.0) as u64;
+
+    let f1 = |x: u64| x % msg_modulus;
+    let f2 = |x: u64| (x * 2) % msg_modulus;
+    let f3 = |x: u64| x / msg_modulus;
+    let f4 = |x: u64| (x + 1) % msg_modulus;
+    let all_functions: [&dyn Fn(u64) -> u64; 4] = [&f1, &f2, &f3, &f4];
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for fn_count in 1..=all_functions.len().min(full_modulus as usize) {
+        let functions = &all_functions[..fn_count];
+        let lut = sks.generate_many_lookup_table(functions);
+        assert_eq!(lut.function_count(), fn_count);
+
+        let input_modulus = full_modulus / fn_count as u64;
+        assert_eq!(lut.input_max_degree.0 as u64, input_modulus - 1);
+
+        for _ in 0..NB_TESTS / all_functions.len() {
+            let clear = rng.gen::<u64>() % input_modulus;
+
+            // encryption of an integer, the carries may be used by the input
+            let mut ct = cks.unchecked_encrypt(clear);
+            ct.degree = Degree(input_modulus as usize - 1);
+
+            let results = sks.apply_many_lookup_table(&ct, &lut);
+            assert_eq!(results.len(), fn_count);
+
+            for (result, f) in results.iter().zip(functions.iter()) {
+                // decryption of the result
+                let dec_res = cks.decrypt_message_and_carry(result);
+
+                // assert
+                assert_eq!(f(clear), dec_res);
+                assert!(result.degree.0 as u64 >= f(clear));
+            }
+
+            // trivial ciphertexts must give the same results
+            let trivial_ct = sks.unchecked_create_trivial(clear);
+            let results = sks.apply_many_lookup_table(&trivial_ct, &lut);
+            for (result, f) in results.iter().zip(functions.iter()) {
+                assert!(result.is_trivial());
+                assert_eq!(f(clear), cks.decrypt_message_and_carry(result));
+            }
+        }
+    }
+}
+
 /// test addition with the LWE server key
 fn shortint_unchecked_add<P>(param: P)
 where
@@ -219,3 +219,59 @@ fn shortint_compact_public_key_base_list_smart_sub(params: ClassicPBSParameters)
         }
     }
 }
+
+#[cfg(feature = "zk-pok-experimental")]
+create_parametrized_test!(shortint_compact_public_key_base_list_prove_and_verify {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
+#[cfg(feature = "zk-pok-experimental")]
+fn shortint_compact_public_key_base_list_prove_and_verify(params: ClassicPBSParameters) {
+    use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+    use crate::zk::CompactPkeCrs;
+
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let max_ct: usize = 5;
+    let crs = CompactPkeCrs::from_shortint_params(params, max_ct);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let clear_vec = (0..max_ct)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let proven_list = pk.encrypt_and_prove_slice(&clear_vec, crs.public_params());
+
+    let conformance_params = sks
+        .conformance_params()
+        .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(max_ct));
+    assert!(proven_list.is_conformant(&conformance_params));
+    assert!(proven_list.verify(crs.public_params(), &pk).is_valid());
+
+    let expanded_vec = proven_list
+        .verify_and_expand(crs.public_params(), &pk)
+        .unwrap();
+    for (ct, clear) in expanded_vec.iter().zip(clear_vec.iter()) {
+        assert_eq!(cks.decrypt(ct), *clear);
+    }
+
+    // A list that was tampered with is rejected
+    let mut tampered_list = proven_list.clone();
+    let mut body_list = tampered_list.ct_list.ct_list.get_mut_body_list();
+    body_list.as_mut()[0] = body_list.as_ref()[0].wrapping_add(1 << 60);
+    assert!(tampered_list.verify(crs.public_params(), &pk).is_invalid());
+    assert!(tampered_list
+        .verify_and_expand(crs.public_params(), &pk)
+        .is_none());
+
+    // The proof is bound to the public key used for the encryption
+    let other_pk = crate::shortint::CompactPublicKey::new(cks);
+    assert!(proven_list
+        .verify(crs.public_params(), &other_pk)
+        .is_invalid());
+}
@@ -0,0 +1,555 @@
+//! This module defines threshold decryption for shortint ciphertexts.
+//!
+//! Ciphertexts are decrypted without ever reconstructing a secret key: the GLWE secret key of the
+//! [noise squashing](crate::shortint::noise_squashing) is secret shared among `party_count`
+//! parties so that any `threshold` of them can decrypt a [SquashedNoiseCiphertext] together, while
+//! fewer parties learn nothing about the key:
+//!
+//! - the server squashes the noise of a ciphertext with a
+//!   [NoiseSquashingKey](crate::shortint::noise_squashing::NoiseSquashingKey);
+//! - each participating party computes a [PartialDecryptionShare] of the squashed ciphertext with
+//!   [ThresholdSecretKeyShare::partial_decrypt];
+//! - [combine_partial_decryptions] recovers the message from the shares of all participants.
+//!
+//! # Key shares
+//!
+//! The key shares are generated either:
+//!
+//! - by a trusted dealer holding the [NoiseSquashingPrivateKey], with
+//!   [NoiseSquashingPrivateKey::generate_threshold_key_shares], the private key must then be
+//!   discarded;
+//! - or without any dealer, from the keys generated with the
+//!   [key aggregation](crate::shortint::key_aggregation) protocol: each party splits its own share
+//!   of the joint key in one [ThresholdKeyContribution] per party with
+//!   [PartySecretKey::generate_threshold_key_contributions](crate::shortint::key_aggregation::PartySecretKey::generate_threshold_key_contributions),
+//!   and each party puts the contributions it received together with
+//!   [ThresholdSecretKeyShare::from_contributions].
+//!
+//! # Secret sharing scheme
+//!
+//! Shamir secret sharing requires computing inverses of differences of evaluation points, which
+//! do not exist modulo $2^{128}$. The key is instead shared with a replicated secret sharing: the
+//! key is split in additive shares, one for each subset of `party_count - threshold + 1` parties,
+//! and each party holds the additive shares of all the subsets it belongs to. Any `threshold`
+//! parties hold all the additive shares between them, while fewer parties always miss at least
+//! one. The number of additive shares is $\binom{n}{t - 1}$, this scheme is therefore meant for a
+//! small number of parties.
+//!
+//! # Smudging noise
+//!
+//! A partial decryption reveals the product of the mask of the ciphertext with the key shares of
+//! the party, it is therefore masked by a fresh smudging noise which must hide the noise of the
+//! ciphertext, and with it the secret key, from the combiner. For a statistical security parameter
+//! $\lambda$ (see [ThresholdParameters::with_statistical_security], 40 by default), each party
+//! samples its smudging noise from a [TUniform] distribution whose bound is $2^{\lambda}$ times a
+//! bound of the noise of the squashed ciphertexts exceeded with a probability below
+//! $2^{-\lambda}$. This bound is estimated from the noise squashing parameters when the key shares
+//! are generated. The statistical distance between the partial decryptions of ciphertexts with
+//! different noises is then below $2^{1 - \lambda}$.
+//!
+//! The noise of squashed ciphertexts is more than 50 bits below their scaling factor, which leaves
+//! room for the smudging noises of all the participants: [ThresholdSecretKeyShare::partial_decrypt]
+//! panics if their sum could exceed half the scaling factor.
+//!
+//! # Security
+//!
+//! The parties are assumed to follow the protocol: the partial decryptions are not verifiable, a
+//! malicious party can make the decryption return a wrong value. This module is only available
+//! with the `threshold-decryption-experimental` feature, its API may change.
+
+use crate::core_crypto::algorithms::slice_algorithms::{
+    slice_wrapping_add_assign, slice_wrapping_dot_product, slice_wrapping_sub_assign,
+};
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::math::random::TUniform;
+use crate::core_crypto::entities::{LweSecretKey, LweSecretKeyOwned};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::noise_squashing::{NoiseSquashingPrivateKey, SquashedNoiseCiphertext};
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod test;
+
+/// The identifier of a party taking part in threshold decryption, in `0..party_count`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Serialize, Deserialize)]
+pub struct PartyId(pub usize);
+
+/// The number of parties the key is shared among, how many of them are needed to decrypt, and the
+/// statistical security of the smudging noise.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct ThresholdParameters {
+    pub party_count: usize,
+    pub threshold: usize,
+    pub statistical_security: u32,
+}
+
+impl ThresholdParameters {
+    /// The statistical security parameter $\lambda$ of the smudging noise used by default.
+    pub const DEFAULT_STATISTICAL_SECURITY: u32 = 40;
+
+    /// Create threshold parameters, `threshold` parties among `party_count` will be needed to
+    /// decrypt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is not in `1..=party_count`.
+    pub fn new(party_count: usize, threshold: usize) -> Self {
+        assert!(
+            threshold >= 1 && threshold <= party_count,
+            "The threshold must be between 1 and the number of parties ({party_count}), \
+            got {threshold}"
+        );
+
+        Self {
+            party_count,
+            threshold,
+            statistical_security: Self::DEFAULT_STATISTICAL_SECURITY,
+        }
+    }
+
+    /// Set the statistical security parameter $\lambda$ of the smudging noise: the partial
+    /// decryptions of ciphertexts with different noises are within a statistical distance of
+    /// $2^{1 - \lambda}$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `statistical_security` is zero.
+    pub fn with_statistical_security(self, statistical_security: u32) -> Self {
+        assert!(
+            statistical_security > 0,
+            "The statistical security must be positive"
+        );
+
+        Self {
+            statistical_security,
+            ..self
+        }
+    }
+
+    /// The subsets of parties an additive share of the key is given to, in lexicographic order.
+    fn share_subsets(&self) -> Vec<Vec<usize>> {
+        let subset_size = self.party_count - self.threshold + 1;
+
+        let mut subsets = Vec::new();
+        let mut current: Vec<usize> = (0..subset_size).collect();
+
+        loop {
+            subsets.push(current.clone());
+
+            // Find the rightmost element which can still be incremented
+            let Some(position) =
+                (0..subset_size).rfind(|&i| current[i] < self.party_count - subset_size + i)
+            else {
+                return subsets;
+            };
+
+            current[position] += 1;
+            for i in position + 1..subset_size {
+                current[i] = current[i - 1] + 1;
+            }
+        }
+    }
+
+    /// Split `key` in additive shares, one per subset of parties, and return the additive shares
+    /// held by each party, in order.
+    fn split_key(&self, key: &LweSecretKeyOwned<u128>) -> Vec<IndexedKeyShares> {
+        let subsets = self.share_subsets();
+
+        let additive_shares = ShortintEngine::with_thread_local_mut(|engine| {
+            let mut additive_shares: Vec<_> = (0..subsets.len() - 1)
+                .map(|_| {
+                    let mut share = LweSecretKey::new_empty_key(0u128, key.lwe_dimension());
+                    engine
+                        .secret_generator
+                        .fill_slice_with_random_uniform(share.as_mut());
+                    share
+                })
+                .collect();
+
+            // The last share completes the sum to the key
+            let mut last_share = key.clone();
+            for share in additive_shares.iter() {
+                slice_wrapping_sub_assign(last_share.as_mut(), share.as_ref());
+            }
+            additive_shares.push(last_share);
+
+            additive_shares
+        });
+
+        (0..self.party_count)
+            .map(|party| {
+                subsets
+                    .iter()
+                    .zip(additive_shares.iter())
+                    .enumerate()
+                    .filter(|(_, (subset, _))| subset.contains(&party))
+                    .map(|(index, (_, share))| (index, share.clone()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Return the base 2 logarithm of the bound of the smudging noise of each party, for squashed
+    /// ciphertexts with a noise of variance `noise_variance`.
+    fn smudging_noise_bound_log2(&self, noise_variance: Variance) -> u32 {
+        // The noise exceeds z standard deviations with probability below exp(-z^2 / 2) = 2^-lambda
+        let statistical_security = f64::from(self.statistical_security);
+        let z = (2.0 * statistical_security * std::f64::consts::LN_2).sqrt();
+
+        // The variance is normalized to the torus, the bound is on the 128 bits integers
+        let noise_bound_log2 = (z * noise_variance.0.sqrt()).log2() + 128.0;
+
+        noise_bound_log2.ceil().max(0.0) as u32 + self.statistical_security
+    }
+}
+
+/// The additive shares of a secret key held by one party, with the index of their subset.
+type IndexedKeyShares = Vec<(usize, LweSecretKeyOwned<u128>)>;
+
+/// The share of the noise squashing secret key held by one party, it must be kept secret.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThresholdSecretKeyShare {
+    party: PartyId,
+    params: ThresholdParameters,
+    lwe_secret_key_shares: IndexedKeyShares,
+    smudging_noise_bound_log2: u32,
+}
+
+/// The contribution of one party to the share of the secret key of another party, when the key
+/// shares are generated without a trusted dealer.
+///
+/// It must be sent to its recipient over a secure channel.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThresholdKeyContribution {
+    contributor: PartyId,
+    party: PartyId,
+    params: ThresholdParameters,
+    lwe_secret_key_shares: IndexedKeyShares,
+    smudging_noise_bound_log2: u32,
+}
+
+/// The contribution of one party to the decryption of a ciphertext.
+///
+/// It can be sent to the party in charge of combining the shares with
+/// [combine_partial_decryptions].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialDecryptionShare {
+    party: PartyId,
+    participants: Vec<PartyId>,
+    value: u128,
+}
+
+impl PartialDecryptionShare {
+    pub fn party(&self) -> PartyId {
+        self.party
+    }
+
+    pub fn participants(&self) -> &[PartyId] {
+        &self.participants
+    }
+}
+
+impl ThresholdKeyContribution {
+    /// The party which computed this contribution.
+    pub fn contributor(&self) -> PartyId {
+        self.contributor
+    }
+
+    /// The party this contribution is meant for.
+    pub fn party(&self) -> PartyId {
+        self.party
+    }
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Secret share this private key among the parties described by `params`, returning the share
+    /// of each party in order.
+    ///
+    /// The shares are generated by the holder of the private key acting as a trusted dealer, the
+    /// private key must be discarded once the shares have been distributed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::threshold::{combine_partial_decryptions, PartyId, ThresholdParameters};
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let noise_squashing_private_key =
+    ///     cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// let noise_squashing_key = cks.new_noise_squashing_key(&noise_squashing_private_key);
+    ///
+    /// // 2 out of 3 parties are needed to decrypt
+    /// let key_shares =
+    ///     noise_squashing_private_key.generate_threshold_key_shares(ThresholdParameters::new(3, 2));
+    /// drop(noise_squashing_private_key);
+    ///
+    /// let ct = sks.unchecked_add(&cks.encrypt(1), &cks.encrypt(2));
+    /// let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &sks);
+    ///
+    /// let participants = [PartyId(0), PartyId(2)];
+    /// let partial_decryptions: Vec<_> = participants
+    ///     .iter()
+    ///     .map(|party| key_shares[party.0].partial_decrypt(&squashed, &participants))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     combine_partial_decryptions(&squashed, &partial_decryptions),
+    ///     3
+    /// );
+    /// ```
+    pub fn generate_threshold_key_shares(
+        &self,
+        params: ThresholdParameters,
+    ) -> Vec<ThresholdSecretKeyShare> {
+        let smudging_noise_bound_log2 =
+            params.smudging_noise_bound_log2(self.squashed_noise_variance());
+
+        params
+            .split_key(&self.glwe_secret_key.clone().into_lwe_secret_key())
+            .into_iter()
+            .enumerate()
+            .map(|(party, lwe_secret_key_shares)| ThresholdSecretKeyShare {
+                party: PartyId(party),
+                params,
+                lwe_secret_key_shares,
+                smudging_noise_bound_log2,
+            })
+            .collect()
+    }
+}
+
+/// Split the additive share `key` of the noise squashing secret key held by `contributor` in one
+/// contribution per party.
+///
+/// `squashed_noise_variance` is the variance of the noise of the ciphertexts squashed with the key
+/// generated from the shares of all the contributors.
+pub(crate) fn generate_threshold_key_contributions(
+    contributor: PartyId,
+    key: &LweSecretKeyOwned<u128>,
+    params: ThresholdParameters,
+    squashed_noise_variance: Variance,
+) -> Vec<ThresholdKeyContribution> {
+    assert!(
+        contributor.0 < params.party_count,
+        "The contributor must be in 0..{}",
+        params.party_count
+    );
+
+    let smudging_noise_bound_log2 = params.smudging_noise_bound_log2(squashed_noise_variance);
+
+    params
+        .split_key(key)
+        .into_iter()
+        .enumerate()
+        .map(|(party, lwe_secret_key_shares)| ThresholdKeyContribution {
+            contributor,
+            party: PartyId(party),
+            params,
+            lwe_secret_key_shares,
+            smudging_noise_bound_log2,
+        })
+        .collect()
+}
+
+impl ThresholdSecretKeyShare {
+    /// Put together the contributions received by a party from all the parties, including
+    /// itself, into its share of the secret key.
+    ///
+    /// The key shared among the parties is the sum of the keys split by the contributors.
+    ///
+    /// See [PartySecretKey::new](crate::shortint::key_aggregation::PartySecretKey::new) for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `contributions` does not contain exactly one contribution from each party, or if
+    /// the contributions were not computed for the same party with the same parameters.
+    pub fn from_contributions(contributions: &[ThresholdKeyContribution]) -> Self {
+        let (first, others) = contributions
+            .split_first()
+            .expect("At least one contribution is needed");
+
+        let mut contributors: Vec<_> = contributions
+            .iter()
+            .map(|contribution| contribution.contributor)
+            .collect();
+        contributors.sort_unstable();
+
+        assert!(
+            contributors
+                .iter()
+                .copied()
+                .eq((0..first.params.party_count).map(PartyId)),
+            "There must be exactly one contribution from each party"
+        );
+        assert!(
+            others.iter().all(|contribution| {
+                contribution.party == first.party
+                    && contribution.params == first.params
+                    && contribution.smudging_noise_bound_log2 == first.smudging_noise_bound_log2
+            }),
+            "All contributions must be computed for the same party with the same parameters"
+        );
+
+        let mut lwe_secret_key_shares = first.lwe_secret_key_shares.clone();
+        for contribution in others {
+            for ((index, share), (other_index, other_share)) in lwe_secret_key_shares
+                .iter_mut()
+                .zip(contribution.lwe_secret_key_shares.iter())
+            {
+                assert_eq!(index, other_index);
+                slice_wrapping_add_assign(share.as_mut(), other_share.as_ref());
+            }
+        }
+
+        Self {
+            party: first.party,
+            params: first.params,
+            lwe_secret_key_shares,
+            smudging_noise_bound_log2: first.smudging_noise_bound_log2,
+        }
+    }
+
+    pub fn party(&self) -> PartyId {
+        self.party
+    }
+
+    pub fn params(&self) -> ThresholdParameters {
+        self.params
+    }
+
+    /// Compute the contribution of this party to the decryption of the squashed ciphertext `ct`
+    /// by the `participants`.
+    ///
+    /// All the participants must use the same set of participants, which must contain this party
+    /// and at least `threshold` parties.
+    ///
+    /// See [NoiseSquashingPrivateKey::generate_threshold_key_shares] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the participants are invalid, or if the sum of the smudging noises of the
+    /// participants could exceed half the scaling factor of the message of `ct`.
+    pub fn partial_decrypt(
+        &self,
+        ct: &SquashedNoiseCiphertext,
+        participants: &[PartyId],
+    ) -> PartialDecryptionShare {
+        let mut participants = participants.to_vec();
+        participants.sort_unstable();
+        participants.dedup();
+
+        assert!(
+            participants.len() >= self.params.threshold,
+            "At least {} parties are needed to decrypt, got {}",
+            self.params.threshold,
+            participants.len()
+        );
+        assert!(
+            participants
+                .iter()
+                .all(|party| party.0 < self.params.party_count),
+            "Participants must be in 0..{}",
+            self.params.party_count
+        );
+        assert!(
+            participants.contains(&self.party),
+            "The participants must contain the party computing the partial decryption"
+        );
+
+        // The smudging noises of the participants and the noise of the ciphertext must stay below
+        // half the scaling factor
+        let noise_bound_log2 =
+            self.smudging_noise_bound_log2 + (participants.len() + 1).next_power_of_two().ilog2();
+        assert!(
+            noise_bound_log2 < ct.delta().ilog2(),
+            "The smudging noises of {} participants do not fit in the ciphertext, lower the \
+            statistical security or the number of participants",
+            participants.len()
+        );
+
+        let subsets = self.params.share_subsets();
+        let mask = ct.ct.get_mask();
+
+        // Each additive share is accounted for by the first participant holding it
+        let mask_key_product = self
+            .lwe_secret_key_shares
+            .iter()
+            .filter(|(index, _)| {
+                participants
+                    .iter()
+                    .find(|party| subsets[*index].contains(&party.0))
+                    == Some(&self.party)
+            })
+            .fold(0u128, |acc, (_, share)| {
+                acc.wrapping_add(slice_wrapping_dot_product(mask.as_ref(), share.as_ref()))
+            });
+
+        let smudging_noise: u128 = ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .encryption_generator
+                .random_noise(TUniform::new(self.smudging_noise_bound_log2))
+        });
+
+        PartialDecryptionShare {
+            party: self.party,
+            participants,
+            value: mask_key_product.wrapping_add(smudging_noise),
+        }
+    }
+}
+
+/// Combine the partial decryptions of all the participants to recover the message and carry
+/// encrypted in the squashed ciphertext `ct`.
+///
+/// # Panics
+///
+/// Panics if the shares do not come from exactly the participants they were computed for.
+pub fn combine_partial_decryptions_message_and_carry(
+    ct: &SquashedNoiseCiphertext,
+    shares: &[PartialDecryptionShare],
+) -> u64 {
+    assert!(
+        !shares.is_empty(),
+        "Cannot combine an empty list of partial decryptions"
+    );
+
+    let participants = &shares[0].participants;
+    let mut parties: Vec<_> = shares.iter().map(|share| share.party).collect();
+    parties.sort_unstable();
+
+    assert!(
+        shares
+            .iter()
+            .all(|share| &share.participants == participants),
+        "All partial decryptions must be computed for the same participants"
+    );
+    assert_eq!(
+        &parties, participants,
+        "Exactly one partial decryption per participant is required"
+    );
+
+    let mask_key_product = shares
+        .iter()
+        .fold(0u128, |acc, share| acc.wrapping_add(share.value));
+
+    ct.decode_message_and_carry((*ct.ct.get_body().data).wrapping_sub(mask_key_product))
+}
+
+/// Combine the partial decryptions of all the participants to recover the message encrypted in
+/// the squashed ciphertext `ct`.
+///
+/// See [NoiseSquashingPrivateKey::generate_threshold_key_shares] for usage.
+///
+/// # Panics
+///
+/// Panics if the shares do not come from exactly the participants they were computed for.
+pub fn combine_partial_decryptions(
+    ct: &SquashedNoiseCiphertext,
+    shares: &[PartialDecryptionShare],
+) -> u64 {
+    combine_partial_decryptions_message_and_carry(ct, shares) % ct.message_modulus.0 as u64
+}
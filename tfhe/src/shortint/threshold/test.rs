@@ -0,0 +1,145 @@
+use super::{combine_partial_decryptions, PartyId, ThresholdParameters};
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::{
+    ClassicPBSParameters, NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+};
+
+fn threshold_decryption(params: ClassicPBSParameters) {
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_key = cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    let noise_squashing_key = cks.new_noise_squashing_key(&private_key);
+
+    let threshold_params = ThresholdParameters::new(4, 3);
+    let key_shares = private_key.generate_threshold_key_shares(threshold_params);
+    assert_eq!(key_shares.len(), 4);
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for msg in 0..modulus {
+        let ct = sks.unchecked_scalar_add(&cks.encrypt(msg), 1);
+        let ct = sks.message_extract(&ct);
+        let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, sks);
+        let expected = (msg + 1) % modulus;
+
+        // Every set of 3 parties, and all of them
+        let participant_sets: [&[usize]; 5] = [
+            &[0, 1, 2],
+            &[0, 1, 3],
+            &[0, 2, 3],
+            &[1, 2, 3],
+            &[0, 1, 2, 3],
+        ];
+
+        for participants in participant_sets {
+            let participants: Vec<_> = participants.iter().copied().map(PartyId).collect();
+            let shares: Vec<_> = participants
+                .iter()
+                .map(|party| key_shares[party.0].partial_decrypt(&squashed, &participants))
+                .collect();
+
+            assert_eq!(combine_partial_decryptions(&squashed, &shares), expected);
+        }
+    }
+}
+
+#[test]
+fn test_threshold_decryption_ks_pbs() {
+    threshold_decryption(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+}
+
+#[test]
+fn test_threshold_decryption_pbs_ks() {
+    threshold_decryption(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+}
+
+#[test]
+#[should_panic(expected = "At least 3 parties are needed to decrypt")]
+fn test_threshold_decryption_too_few_parties() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_key = cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    let noise_squashing_key = cks.new_noise_squashing_key(&private_key);
+    let key_shares = private_key.generate_threshold_key_shares(ThresholdParameters::new(4, 3));
+
+    let squashed = noise_squashing_key.squash_ciphertext_noise(&cks.encrypt(1), sks);
+    let _ = key_shares[0].partial_decrypt(&squashed, &[PartyId(0), PartyId(1)]);
+}
+
+#[test]
+#[should_panic(expected = "Exactly one partial decryption per participant is required")]
+fn test_threshold_decryption_missing_share() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_key = cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    let noise_squashing_key = cks.new_noise_squashing_key(&private_key);
+    let key_shares = private_key.generate_threshold_key_shares(ThresholdParameters::new(3, 2));
+
+    let squashed = noise_squashing_key.squash_ciphertext_noise(&cks.encrypt(1), sks);
+    let participants = [PartyId(0), PartyId(1)];
+    let share = key_shares[0].partial_decrypt(&squashed, &participants);
+    let _ = combine_partial_decryptions(&squashed, &[share]);
+}
+
+#[test]
+#[should_panic(expected = "The smudging noises of 3 participants do not fit in the ciphertext")]
+fn test_threshold_decryption_smudging_noise_too_large() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_key = cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    let noise_squashing_key = cks.new_noise_squashing_key(&private_key);
+
+    // The noise of the squashed ciphertexts leaves no room for this statistical security
+    let params = ThresholdParameters::new(3, 3).with_statistical_security(100);
+    let key_shares = private_key.generate_threshold_key_shares(params);
+
+    let squashed = noise_squashing_key.squash_ciphertext_noise(&cks.encrypt(1), sks);
+    let participants = [PartyId(0), PartyId(1), PartyId(2)];
+    let _ = key_shares[0].partial_decrypt(&squashed, &participants);
+}
+
+#[test]
+fn test_smudging_noise_bound() {
+    let params = ThresholdParameters::new(3, 2);
+    assert_eq!(params.statistical_security, 40);
+
+    // A standard deviation of 2^-64 is exceeded with probability 2^-40 at about 7.4 standard
+    // deviations, i.e. below 2^(64 + 3) on 128 bits, which is smudged by a noise 2^40 larger
+    let variance = Variance(2.0f64.powi(-128));
+    assert_eq!(params.smudging_noise_bound_log2(variance), 67 + 40);
+
+    let params = params.with_statistical_security(20);
+    assert_eq!(params.smudging_noise_bound_log2(variance), 67 + 20);
+}
+
+#[test]
+fn test_share_subsets() {
+    let subsets = ThresholdParameters::new(4, 3).share_subsets();
+    assert_eq!(
+        subsets,
+        vec![
+            vec![0, 1],
+            vec![0, 2],
+            vec![0, 3],
+            vec![1, 2],
+            vec![1, 3],
+            vec![2, 3]
+        ]
+    );
+
+    // All parties are needed: plain additive sharing
+    assert_eq!(
+        ThresholdParameters::new(3, 3).share_subsets(),
+        vec![vec![0], vec![1], vec![2]]
+    );
+    assert_eq!(
+        ThresholdParameters::new(3, 1).share_subsets(),
+        vec![vec![0, 1, 2]]
+    );
+}
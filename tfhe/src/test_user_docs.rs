@@ -21,11 +21,18 @@ doctest!(
 );
 doctest!("../docs/how_to/public_key.md", how_to_public_key);
 doctest!("../docs/how_to/serialization.md", how_to_serialize);
+#[cfg(feature = "threshold-decryption-experimental")]
+doctest!(
+    "../docs/how_to/threshold_decryption.md",
+    how_to_threshold_decryption
+);
 doctest!("../docs/how_to/trait_bounds.md", how_to_trait_bounds);
 doctest!(
     "../docs/how_to/trivial_ciphertext.md",
     how_to_trivial_ciphertext
 );
+#[cfg(feature = "zk-pok-experimental")]
+doctest!("../docs/how_to/zk_pok.md", how_to_zk_pok);
 
 //FINE GRAINED API
 doctest!(
@@ -0,0 +1,144 @@
+//! Zero-knowledge proofs that lists encrypted with a compact public key are well formed
+//!
+//! A client encrypting a list with a compact public key can attach a proof showing that each
+//! plaintext lies within the message modulus (i.e. that the padding and carry bits are zero), that
+//! the noise of the encryption is bounded and that the encryption randomness is binary. The server
+//! can then check the list before using it, without learning anything about the messages.
+//!
+//! Proving and verifying require public parameters ([CompactPkeCrs]) that are generated once for
+//! a given parameter set and maximum number of messages, and shared between clients and servers.
+//!
+//! This module is experimental and only available with the `zk-pok-experimental` feature.
+
+use crate::core_crypto::commons::dispersion::{DynamicDistribution, NoiseDistribution};
+use crate::core_crypto::commons::parameters::LweDimension;
+use serde::{Deserialize, Serialize};
+
+pub use tfhe_zk_pok::proofs::pke::{
+    Proof as CompactPkeProof, PublicParams as CompactPkePublicParams,
+};
+
+/// Log2 of the number of standard deviations under which Gaussian noises are proven to be.
+///
+/// This bound is a heuristic, it is not derived from a security analysis:
+/// - an ideal Gaussian exceeds 16 standard deviations with a probability below 2^-180, but the
+///   noises are sampled with a finite precision sampler whose tails are not covered by this
+///   estimate, an honest encryption could therefore (very rarely) fail to be proven;
+/// - the proof only shows that the noises are within the bound, not that they follow the
+///   distribution. A malicious client can pick any noise up to 16 standard deviations, which is far
+///   beyond the noise assumed by the failure probability of the parameter sets.
+const GAUSSIAN_BOUND_IN_STD_DEV_LOG2: u32 = 4;
+
+/// Result of the verification of a proof
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ZkVerificationOutCome {
+    /// The proof and its entity were valid
+    Valid,
+    /// The proof and its entity were not valid
+    Invalid,
+}
+
+impl ZkVerificationOutCome {
+    pub fn is_valid(self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    pub fn is_invalid(self) -> bool {
+        matches!(self, Self::Invalid)
+    }
+}
+
+/// Log2 of the bound used to prove the noise sampled from the given distribution
+///
+/// Noises are proven to lie in `[-2^bound_log2, 2^bound_log2[`.
+pub(crate) fn noise_bound_log2(distribution: impl NoiseDistribution) -> u32 {
+    match distribution.to_dynamic_distribution() {
+        // TUniform samples lie in [-2^b, 2^b], the upper bound is included
+        DynamicDistribution::TUniform(t_uniform) => t_uniform.bound_log2() + 1,
+        DynamicDistribution::Gaussian(std) => {
+            let modular_std_log2 = (std.0 * 2f64.powi(64)).log2().ceil().max(0.0) as u32;
+            modular_std_log2 + GAUSSIAN_BOUND_IN_STD_DEV_LOG2
+        }
+    }
+}
+
+/// Public parameters (common reference string) used to prove and verify encryptions with a
+/// compact public key
+///
+/// They are generated once for a compact public key size, message space, noise distributions
+/// and a maximum number of messages per list.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactPkeCrs {
+    public_params: CompactPkePublicParams,
+}
+
+impl CompactPkeCrs {
+    /// Generates the public parameters
+    ///
+    /// - `lwe_dimension` is the dimension of the compact public key
+    /// - `max_num_message` is the maximum number of messages a proven list can hold
+    /// - `message_modulus` and `carry_modulus` describe the plaintext space, plaintexts are encoded
+    ///   with a delta of `2^63 / (message_modulus * carry_modulus)` and proven to be smaller than
+    ///   `message_modulus`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension or the moduli are not powers of two.
+    pub fn new(
+        lwe_dimension: LweDimension,
+        max_num_message: usize,
+        mask_noise_distribution: impl NoiseDistribution,
+        body_noise_distribution: impl NoiseDistribution,
+        message_modulus: u64,
+        carry_modulus: u64,
+    ) -> Self {
+        assert!(
+            lwe_dimension.0.is_power_of_two(),
+            "The lwe dimension of a compact public key must be a power of two"
+        );
+        assert!(message_modulus.is_power_of_two() && carry_modulus.is_power_of_two());
+
+        let public_params = tfhe_zk_pok::proofs::pke::crs_gen(
+            lwe_dimension.0,
+            max_num_message,
+            message_modulus.ilog2(),
+            carry_modulus.ilog2(),
+            noise_bound_log2(mask_noise_distribution),
+            noise_bound_log2(body_noise_distribution),
+            &mut rand::thread_rng(),
+        );
+
+        Self { public_params }
+    }
+
+    /// Generates the public parameters matching the compact public keys of the given shortint
+    /// parameters
+    #[cfg(feature = "shortint")]
+    pub fn from_shortint_params<P>(params: P, max_num_message: usize) -> Self
+    where
+        P: Into<crate::shortint::ShortintParameterSet>,
+    {
+        use crate::shortint::parameters::EncryptionKeyChoice;
+
+        let params = params.into();
+        let lwe_dimension = match params.encryption_key_choice() {
+            EncryptionKeyChoice::Big => params
+                .glwe_dimension()
+                .to_equivalent_lwe_dimension(params.polynomial_size()),
+            EncryptionKeyChoice::Small => params.lwe_dimension(),
+        };
+
+        Self::new(
+            lwe_dimension,
+            max_num_message,
+            params.glwe_noise_distribution(),
+            params.lwe_noise_distribution(),
+            params.message_modulus().0 as u64,
+            params.carry_modulus().0 as u64,
+        )
+    }
+
+    pub fn public_params(&self) -> &CompactPkePublicParams {
+        &self.public_params
+    }
+}